@@ -5,12 +5,23 @@ use dharitri_sc::imports::*;
 use dharitri_sc_modules::pause;
 
 #[dharitri_sc::contract]
+#[when_not_paused]
 pub trait CheckPauseContract: pause::PauseModule {
     #[init]
     fn init(&self) {}
 
+    #[allow_when_paused]
     #[endpoint(checkPause)]
     fn check_pause(&self) -> bool {
         self.is_paused()
     }
+
+    #[endpoint(increment)]
+    fn increment(&self) {
+        self.counter().update(|counter| *counter += 1);
+    }
+
+    #[view(getCounter)]
+    #[storage_mapper("counter")]
+    fn counter(&self) -> SingleValueMapper<u32>;
 }
@@ -5,9 +5,9 @@
 ////////////////////////////////////////////////////
 
 // Init:                                 1
-// Endpoints:                            4
+// Endpoints:                            6
 // Async Callback (empty):               1
-// Total number of exported functions:   6
+// Total number of exported functions:   8
 
 #![no_std]
 
@@ -19,6 +19,8 @@ dharitri_sc_wasm_adapter::endpoints! {
     (
         init => init
         checkPause => check_pause
+        increment => increment
+        getCounter => counter
         pause => pause_endpoint
         unpause => unpause_endpoint
         isPaused => paused_status
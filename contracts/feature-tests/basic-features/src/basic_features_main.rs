@@ -7,6 +7,7 @@ pub mod big_num_operators;
 pub mod block_info_features;
 pub mod blockchain_api_features;
 pub mod codec_err_test;
+pub mod contract_error_features;
 pub mod crypto_features;
 pub mod echo;
 pub mod echo_managed;
@@ -27,6 +28,7 @@ pub mod storage_mapper_linked_list;
 pub mod storage_mapper_map;
 pub mod storage_mapper_map_storage;
 pub mod storage_mapper_non_fungible_token;
+pub mod storage_mapper_priority_queue;
 pub mod storage_mapper_queue;
 pub mod storage_mapper_set;
 pub mod storage_mapper_single;
@@ -46,6 +48,7 @@ pub trait BasicFeatures:
     + block_info_features::BlockInfoFeatures
     + blockchain_api_features::BlockchainApiFeatures
     + codec_err_test::CodecErrorTest
+    + contract_error_features::ContractErrorFeatures
     + crypto_features::CryptoFeatures
     + echo::EchoTypes
     + echo_managed::EchoManagedTypes
@@ -61,6 +64,7 @@ pub trait BasicFeatures:
     + storage_mapper_address_to_id::AddressToIdMapperFeatures
     + storage_mapper_linked_list::LinkedListMapperFeatures
     + storage_mapper_queue::QueueMapperFeatures
+    + storage_mapper_priority_queue::PriorityQueueMapperFeatures
     + storage_mapper_map::MapMapperFeatures
     + storage_mapper_map_storage::MapStorageMapperFeatures
     + storage_mapper_set::SetMapperFeatures
@@ -102,4 +106,7 @@ pub trait BasicFeatures:
 
     #[storage_mapper("coolTree")]
     fn cool_tree(&self) -> OrderedBinaryTreeMapper<Self::Api, BigUint>;
+
+    #[storage_mapper("avlTree")]
+    fn avl_tree(&self) -> AvlTreeMapper<Self::Api, BigUint>;
 }
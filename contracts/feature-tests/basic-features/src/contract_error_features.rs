@@ -0,0 +1,58 @@
+dharitri_sc::imports!();
+dharitri_sc::derive_imports!();
+
+/// Errors that can occur when withdrawing from the test balance.
+#[derive(ContractError, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum WithdrawError {
+    #[error("amount must be positive")]
+    ZeroAmount = 1,
+
+    /// The balance is lower than the requested amount.
+    #[error("insufficient funds")]
+    InsufficientFunds,
+
+    #[error("withdrawals are disabled")]
+    Disabled = 10,
+}
+
+/// Endpoints returning typed custom errors.
+#[dharitri_sc::module]
+pub trait ContractErrorFeatures {
+    #[endpoint]
+    fn contract_error_deposit(&self, amount: u64) {
+        self.contract_error_balance()
+            .update(|balance| *balance += amount);
+    }
+
+    #[endpoint]
+    fn contract_error_set_disabled(&self, disabled: bool) {
+        self.contract_error_disabled().set(disabled);
+    }
+
+    /// Returns the remaining balance.
+    #[endpoint]
+    fn contract_error_withdraw(&self, amount: u64) -> Result<u64, WithdrawError> {
+        if self.contract_error_disabled().get() {
+            return Err(WithdrawError::Disabled);
+        }
+        if amount == 0 {
+            return Err(WithdrawError::ZeroAmount);
+        }
+
+        let balance = self.contract_error_balance().get();
+        if amount > balance {
+            return Err(WithdrawError::InsufficientFunds);
+        }
+
+        let remaining = balance - amount;
+        self.contract_error_balance().set(remaining);
+        Ok(remaining)
+    }
+
+    #[view]
+    #[storage_mapper("contract_error_balance")]
+    fn contract_error_balance(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("contract_error_disabled")]
+    fn contract_error_disabled(&self) -> SingleValueMapper<bool>;
+}
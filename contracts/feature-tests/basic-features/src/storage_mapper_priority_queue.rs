@@ -0,0 +1,46 @@
+use dharitri_sc::storage::StorageKey;
+
+dharitri_sc::imports!();
+
+/// Storage mapper test.
+#[dharitri_sc::module]
+pub trait PriorityQueueMapperFeatures {
+    #[view]
+    #[storage_mapper("priority_queue_mapper")]
+    fn priority_queue_mapper(&self) -> PriorityQueueMapper<u32>;
+
+    #[endpoint]
+    fn priority_queue_mapper_push(&self, item: u32) -> u32 {
+        self.priority_queue_mapper().push(item)
+    }
+
+    #[endpoint]
+    fn priority_queue_mapper_pop(&self) -> Option<u32> {
+        self.priority_queue_mapper().pop()
+    }
+
+    #[endpoint]
+    fn priority_queue_mapper_update(&self, handle: u32, item: u32) {
+        self.priority_queue_mapper().update(handle, item);
+    }
+
+    #[endpoint]
+    fn priority_queue_mapper_remove(&self, handle: u32) -> Option<u32> {
+        self.priority_queue_mapper().remove(handle)
+    }
+
+    #[view]
+    fn priority_queue_mapper_peek(&self) -> Option<u32> {
+        self.priority_queue_mapper().peek()
+    }
+
+    #[view]
+    fn priority_queue_mapper_peek_at_address(&self, address: ManagedAddress) -> Option<u32> {
+        let mapper: PriorityQueueMapper<Self::Api, u32, MinFirst, _> =
+            PriorityQueueMapper::new_from_address(
+                address,
+                StorageKey::from("priority_queue_mapper"),
+            );
+        mapper.peek()
+    }
+}
@@ -0,0 +1,167 @@
+use basic_features::BasicFeatures;
+
+use dharitri_sc_scenario::imports::*;
+
+const OWNER_ADDRESS: TestAddress = TestAddress::new("owner");
+const BASIC_FEATURES_ADDRESS: TestSCAddress = TestSCAddress::new("basic-features");
+const BASIC_FEATURES_PATH: DrtscPath = DrtscPath::new("output/basic-features.drtsc.json");
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+
+    blockchain.set_current_dir_from_workspace("contracts/feature-tests/basic-features");
+    blockchain.register_contract(BASIC_FEATURES_PATH, basic_features::ContractBuilder);
+
+    blockchain.account(OWNER_ADDRESS).nonce(1);
+    blockchain
+        .account(BASIC_FEATURES_ADDRESS)
+        .nonce(1)
+        .code(BASIC_FEATURES_PATH);
+
+    blockchain
+}
+
+fn basic_features_whitebox() -> WhiteboxContract<basic_features::ContractObj<DebugApi>> {
+    WhiteboxContract::new(
+        BASIC_FEATURES_ADDRESS.eval_to_expr().as_str(),
+        basic_features::contract_obj,
+    )
+}
+
+#[test]
+fn avl_tree_balanced_on_sorted_insert_test() {
+    let mut world = world();
+
+    world.whitebox_call(
+        &basic_features_whitebox(),
+        ScCallStep::new().from(OWNER_ADDRESS.eval_to_expr().as_str()),
+        |sc| {
+            let mut my_tree_mapper = sc.avl_tree();
+            assert_eq!(my_tree_mapper.get_root(), None);
+            assert!(my_tree_mapper.is_empty());
+
+            for i in 1u32..=7 {
+                assert!(my_tree_mapper.insert(i.into()));
+            }
+            assert!(!my_tree_mapper.insert(5u32.into()));
+
+            //////////////////// 4 /////////////////////////////
+            //////////// 2 ///////////// 6 //////////////////////
+            //////// 1 ///// 3 ///// 5 ///// 7 //////////////////
+
+            assert_eq!(
+                my_tree_mapper.get_root(),
+                Some(AvlTreeNode {
+                    current_node_id: 4,
+                    left_id: 2,
+                    right_id: 6,
+                    parent_id: NULL_NODE_ID,
+                    height: 3,
+                    size: 7,
+                    data: managed_biguint!(4)
+                })
+            );
+            assert_eq!(my_tree_mapper.len(), 7);
+            assert_eq!(my_tree_mapper.height(), 3);
+
+            let items: Vec<BigUint<DebugApi>> = my_tree_mapper.iter().collect();
+            let expected: Vec<BigUint<DebugApi>> = (1u32..=7).map(BigUint::from).collect();
+            assert_eq!(items, expected);
+
+            for i in 8u32..=100 {
+                my_tree_mapper.insert(i.into());
+            }
+            assert_eq!(my_tree_mapper.len(), 100);
+            assert_eq!(my_tree_mapper.height(), 7);
+        },
+    );
+}
+
+#[test]
+fn avl_tree_queries_test() {
+    let mut world = world();
+
+    world.whitebox_call(
+        &basic_features_whitebox(),
+        ScCallStep::new().from(OWNER_ADDRESS.eval_to_expr().as_str()),
+        |sc| {
+            let mut my_tree_mapper = sc.avl_tree();
+            for i in [50u32, 10, 40, 20, 30] {
+                my_tree_mapper.insert(i.into());
+            }
+
+            assert!(my_tree_mapper.contains(&30u32.into()));
+            assert!(!my_tree_mapper.contains(&35u32.into()));
+
+            let lower_bound = my_tree_mapper.lower_bound(&30u32.into()).unwrap();
+            assert_eq!(lower_bound.data, managed_biguint!(30));
+            let lower_bound = my_tree_mapper.lower_bound(&31u32.into()).unwrap();
+            assert_eq!(lower_bound.data, managed_biguint!(40));
+            let upper_bound = my_tree_mapper.upper_bound(&30u32.into()).unwrap();
+            assert_eq!(upper_bound.data, managed_biguint!(40));
+            assert_eq!(my_tree_mapper.upper_bound(&50u32.into()), None);
+
+            assert_eq!(my_tree_mapper.rank(&10u32.into()), 0);
+            assert_eq!(my_tree_mapper.rank(&35u32.into()), 3);
+            assert_eq!(my_tree_mapper.rank(&100u32.into()), 5);
+
+            let selected = my_tree_mapper.select(1).unwrap();
+            assert_eq!(selected.data, managed_biguint!(20));
+            let selected = my_tree_mapper.select(4).unwrap();
+            assert_eq!(selected.data, managed_biguint!(50));
+            assert_eq!(my_tree_mapper.select(5), None);
+
+            let items: Vec<BigUint<DebugApi>> =
+                my_tree_mapper.range(&15u32.into(), 40u32.into()).collect();
+            assert_eq!(items, vec![managed_biguint!(20), managed_biguint!(30)]);
+
+            assert!(my_tree_mapper.remove(&40u32.into()));
+            assert!(!my_tree_mapper.remove(&40u32.into()));
+            assert!(my_tree_mapper.remove(&10u32.into()));
+            assert_eq!(my_tree_mapper.len(), 3);
+
+            let items: Vec<BigUint<DebugApi>> = my_tree_mapper.iter().collect();
+            assert_eq!(
+                items,
+                vec![
+                    managed_biguint!(20),
+                    managed_biguint!(30),
+                    managed_biguint!(50)
+                ]
+            );
+
+            for i in [20u32, 30, 50] {
+                assert!(my_tree_mapper.remove(&i.into()));
+            }
+            assert!(my_tree_mapper.is_empty());
+            assert_eq!(my_tree_mapper.get_root(), None);
+        },
+    );
+}
+
+#[test]
+fn avl_tree_migrate_test() {
+    let mut world = world();
+
+    world.whitebox_call(
+        &basic_features_whitebox(),
+        ScCallStep::new().from(OWNER_ADDRESS.eval_to_expr().as_str()),
+        |sc| {
+            let mut old_tree_mapper = sc.cool_tree();
+            for i in 1u32..=10 {
+                old_tree_mapper.insert_element(i.into());
+            }
+
+            let mut my_tree_mapper = sc.avl_tree();
+            assert!(!my_tree_mapper.migrate_from(&mut old_tree_mapper, 6));
+            assert_eq!(my_tree_mapper.len(), 6);
+            assert!(my_tree_mapper.migrate_from(&mut old_tree_mapper, 6));
+            assert_eq!(my_tree_mapper.len(), 10);
+            assert_eq!(old_tree_mapper.get_root(), None);
+
+            let items: Vec<BigUint<DebugApi>> = my_tree_mapper.iter().collect();
+            let expected: Vec<BigUint<DebugApi>> = (1u32..=10).map(BigUint::from).collect();
+            assert_eq!(items, expected);
+        },
+    );
+}
@@ -0,0 +1,73 @@
+use dharitri_sc::{contract_base::ContractAbiProvider, types::ContractError};
+use dharitri_sc_scenario::scenario_model::TxResponseStatus;
+
+use basic_features::contract_error_features::WithdrawError;
+
+#[test]
+fn contract_error_codes_and_messages_test() {
+    assert_eq!(WithdrawError::ZeroAmount.error_code(), 1);
+    assert_eq!(WithdrawError::InsufficientFunds.error_code(), 2);
+    assert_eq!(WithdrawError::Disabled.error_code(), 10);
+
+    assert_eq!(
+        WithdrawError::InsufficientFunds.error_message(),
+        "insufficient funds"
+    );
+    assert_eq!(
+        WithdrawError::InsufficientFunds.signaled_message(),
+        "E2: insufficient funds"
+    );
+
+    assert_eq!(
+        WithdrawError::from_error_code(10),
+        Some(WithdrawError::Disabled)
+    );
+    assert_eq!(WithdrawError::from_error_code(3), None);
+}
+
+#[test]
+fn contract_error_from_signaled_message_test() {
+    assert_eq!(
+        WithdrawError::from_signaled_message("E1: amount must be positive"),
+        Some(WithdrawError::ZeroAmount)
+    );
+    assert_eq!(WithdrawError::from_signaled_message("E7: unknown"), None);
+    assert_eq!(
+        WithdrawError::from_signaled_message("insufficient funds"),
+        None
+    );
+}
+
+#[test]
+fn contract_error_tx_response_status_test() {
+    let status = TxResponseStatus {
+        status: 4,
+        message: "E10: withdrawals are disabled".to_string(),
+    };
+    assert_eq!(
+        status.contract_error::<WithdrawError>(),
+        Some(WithdrawError::Disabled)
+    );
+
+    let success = TxResponseStatus::default();
+    assert_eq!(success.contract_error::<WithdrawError>(), None);
+}
+
+#[test]
+fn contract_error_abi_test() {
+    let contract_abi = basic_features::AbiProvider::abi();
+    let withdraw_errors: Vec<_> = contract_abi
+        .errors
+        .iter()
+        .filter(|error| error.error_type == "WithdrawError")
+        .map(|error| (error.name.as_str(), error.code))
+        .collect();
+    assert_eq!(
+        withdraw_errors,
+        vec![
+            ("ZeroAmount", 1),
+            ("InsufficientFunds", 2),
+            ("Disabled", 10)
+        ]
+    );
+}
@@ -54,6 +54,11 @@ fn codec_err_go() {
     world().run("scenarios/codec_err.scen.json");
 }
 
+#[test]
+fn contract_errors_go() {
+    world().run("scenarios/contract_errors.scen.json");
+}
+
 #[test]
 fn count_ones_go() {
     world().run("scenarios/count_ones.scen.json");
@@ -391,6 +396,11 @@ fn storage_mapper_non_fungible_token_go() {
     world().run("scenarios/storage_mapper_non_fungible_token.scen.json");
 }
 
+#[test]
+fn storage_mapper_priority_queue_go() {
+    world().run("scenarios/storage_mapper_priority_queue.scen.json");
+}
+
 #[test]
 fn storage_mapper_queue_go() {
     world().run("scenarios/storage_mapper_queue.scen.json");
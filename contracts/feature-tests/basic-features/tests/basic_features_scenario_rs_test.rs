@@ -65,6 +65,11 @@ fn codec_err_rs() {
     world().run("scenarios/codec_err.scen.json");
 }
 
+#[test]
+fn contract_errors_rs() {
+    world().run("scenarios/contract_errors.scen.json");
+}
+
 #[test]
 fn count_ones_rs() {
     world().run("scenarios/count_ones.scen.json");
@@ -409,6 +414,11 @@ fn storage_mapper_non_fungible_token_rs() {
     world().run("scenarios/storage_mapper_non_fungible_token.scen.json");
 }
 
+#[test]
+fn storage_mapper_priority_queue_rs() {
+    world().run("scenarios/storage_mapper_priority_queue.scen.json");
+}
+
 #[test]
 fn storage_mapper_queue_rs() {
     world().run("scenarios/storage_mapper_queue.scen.json");
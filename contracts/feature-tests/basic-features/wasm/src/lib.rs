@@ -5,9 +5,9 @@
 ////////////////////////////////////////////////////
 
 // Init:                                 1
-// Endpoints:                          389
+// Endpoints:                          400
 // Async Callback:                       1
-// Total number of exported functions: 391
+// Total number of exported functions: 402
 
 #![no_std]
 
@@ -137,6 +137,10 @@ dharitri_sc_wasm_adapter::endpoints! {
         codec_err_event_data => codec_err_event_data
         codec_err_contract_init => codec_err_contract_init
         codec_err_contract_call => codec_err_contract_call
+        contract_error_deposit => contract_error_deposit
+        contract_error_set_disabled => contract_error_set_disabled
+        contract_error_withdraw => contract_error_withdraw
+        contract_error_balance => contract_error_balance
         compute_sha256 => compute_sha256
         compute_keccak256 => compute_keccak256
         compute_ripemd160 => compute_ripemd160
@@ -281,6 +285,13 @@ dharitri_sc_wasm_adapter::endpoints! {
         queue_mapper_push_back => queue_mapper_push_back
         queue_mapper_pop_front => queue_mapper_pop_front
         queue_mapper_front => queue_mapper_front
+        priority_queue_mapper => priority_queue_mapper
+        priority_queue_mapper_push => priority_queue_mapper_push
+        priority_queue_mapper_pop => priority_queue_mapper_pop
+        priority_queue_mapper_update => priority_queue_mapper_update
+        priority_queue_mapper_remove => priority_queue_mapper_remove
+        priority_queue_mapper_peek => priority_queue_mapper_peek
+        priority_queue_mapper_peek_at_address => priority_queue_mapper_peek_at_address
         map_mapper => map_mapper
         map_mapper_keys => map_mapper_keys
         map_mapper_values => map_mapper_values
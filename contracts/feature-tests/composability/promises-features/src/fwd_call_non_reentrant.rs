@@ -0,0 +1,83 @@
+dharitri_sc::imports!();
+
+use crate::vault_proxy;
+
+/// Endpoints and callbacks protected by the `#[non_reentrant]` guard.
+#[dharitri_sc::module]
+pub trait NonReentrantModule {
+    /// Calls an endpoint synchronously, while the guard is set.
+    ///
+    /// Calling back into any guarded method of this contract fails.
+    #[non_reentrant]
+    #[endpoint]
+    fn non_reentrant_sync_call(
+        &self,
+        to: ManagedAddress,
+        endpoint_name: ManagedBuffer,
+        args: MultiValueEncoded<ManagedBuffer>,
+    ) {
+        let half_gas = self.blockchain().get_gas_left() / 2;
+
+        self.tx()
+            .to(&to)
+            .gas(half_gas)
+            .raw_call(endpoint_name)
+            .arguments_raw(args.to_arg_buffer())
+            .sync_call();
+
+        self.non_reentrant_calls().update(|calls| *calls += 1);
+    }
+
+    #[non_reentrant]
+    #[endpoint]
+    fn non_reentrant_increment(&self) {
+        self.non_reentrant_calls().update(|calls| *calls += 1);
+    }
+
+    /// The guard is released when the endpoint returns, so the guarded callback can run.
+    #[non_reentrant]
+    #[endpoint]
+    fn non_reentrant_promise_echo(
+        &self,
+        to: ManagedAddress,
+        args: MultiValueEncoded<ManagedBuffer>,
+    ) {
+        let gas_limit = self.blockchain().get_gas_left() - 20_000_000;
+
+        self.tx()
+            .to(&to)
+            .typed(vault_proxy::VaultProxy)
+            .echo_arguments(args)
+            .gas(gas_limit)
+            .callback(self.callbacks().non_reentrant_callback())
+            .gas_for_callback(10_000_000)
+            .register_promise();
+    }
+
+    /// Never returns, so the guard stays set until the legacy callback releases it.
+    #[non_reentrant]
+    #[endpoint]
+    fn non_reentrant_legacy_async_echo(
+        &self,
+        to: ManagedAddress,
+        args: MultiValueEncoded<ManagedBuffer>,
+    ) {
+        self.non_reentrant_calls().update(|calls| *calls += 1);
+
+        self.tx()
+            .to(&to)
+            .typed(vault_proxy::VaultProxy)
+            .echo_arguments(args)
+            .async_call_and_exit();
+    }
+
+    #[non_reentrant]
+    #[promises_callback]
+    fn non_reentrant_callback(&self, #[call_result] _result: MultiValueEncoded<ManagedBuffer>) {
+        self.non_reentrant_calls().update(|calls| *calls += 1);
+    }
+
+    #[view]
+    #[storage_mapper("non_reentrant_calls")]
+    fn non_reentrant_calls(&self) -> SingleValueMapper<u32>;
+}
@@ -250,6 +250,80 @@ where
             .argument(&amount)
             .original_result()
     }
+
+    /// Calls an endpoint synchronously, while the guard is set. 
+    ///  
+    /// Calling back into any guarded method of this contract fails. 
+    pub fn non_reentrant_sync_call<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>>,
+    >(
+        self,
+        to: Arg0,
+        endpoint_name: Arg1,
+        args: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("non_reentrant_sync_call")
+            .argument(&to)
+            .argument(&endpoint_name)
+            .argument(&args)
+            .original_result()
+    }
+
+    pub fn non_reentrant_increment(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("non_reentrant_increment")
+            .original_result()
+    }
+
+    /// The guard is released when the endpoint returns, so the guarded callback can run. 
+    pub fn non_reentrant_promise_echo<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>>,
+    >(
+        self,
+        to: Arg0,
+        args: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("non_reentrant_promise_echo")
+            .argument(&to)
+            .argument(&args)
+            .original_result()
+    }
+
+    /// Never returns, so the guard stays set until the legacy callback releases it. 
+    pub fn non_reentrant_legacy_async_echo<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>>,
+    >(
+        self,
+        to: Arg0,
+        args: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("non_reentrant_legacy_async_echo")
+            .argument(&to)
+            .argument(&args)
+            .original_result()
+    }
+
+    pub fn non_reentrant_calls(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u32> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("non_reentrant_calls")
+            .original_result()
+    }
 }
 
 #[type_abi]
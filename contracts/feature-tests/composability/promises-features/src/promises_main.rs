@@ -2,6 +2,7 @@
 #![allow(clippy::type_complexity)]
 
 mod common;
+mod fwd_call_non_reentrant;
 mod fwd_call_promise_direct;
 mod fwd_call_promises;
 mod fwd_call_promises_bt;
@@ -18,6 +19,7 @@ pub trait PromisesFeatures:
     + fwd_call_promise_direct::CallPromisesDirectModule
     + fwd_call_sync_bt::BackTransfersFeatureModule
     + fwd_call_promises_bt::CallPromisesBackTransfersModule
+    + fwd_call_non_reentrant::NonReentrantModule
 {
     #[init]
     fn init(&self) {}
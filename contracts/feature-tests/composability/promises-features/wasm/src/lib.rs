@@ -5,10 +5,10 @@
 ////////////////////////////////////////////////////
 
 // Init:                                 1
-// Endpoints:                           10
-// Async Callback (empty):               1
-// Promise callbacks:                    3
-// Total number of exported functions:  15
+// Endpoints:                           15
+// Async Callback:                       1
+// Promise callbacks:                    4
+// Total number of exported functions:  21
 
 #![no_std]
 
@@ -29,10 +29,16 @@ dharitri_sc_wasm_adapter::endpoints! {
         forward_sync_retrieve_funds_bt => forward_sync_retrieve_funds_bt
         forward_sync_retrieve_funds_bt_twice => forward_sync_retrieve_funds_bt_twice
         forward_promise_retrieve_funds_back_transfers => forward_promise_retrieve_funds_back_transfers
+        non_reentrant_sync_call => non_reentrant_sync_call
+        non_reentrant_increment => non_reentrant_increment
+        non_reentrant_promise_echo => non_reentrant_promise_echo
+        non_reentrant_legacy_async_echo => non_reentrant_legacy_async_echo
+        non_reentrant_calls => non_reentrant_calls
         retrieve_funds_callback => retrieve_funds_callback
         the_one_callback => the_one_callback
         retrieve_funds_back_transfers_callback => retrieve_funds_back_transfers_callback
+        non_reentrant_callback => non_reentrant_callback
     )
 }
 
-dharitri_sc_wasm_adapter::async_callback_empty! {}
+dharitri_sc_wasm_adapter::async_callback! { promises_features }
@@ -132,7 +132,6 @@ fn forw_raw_direct_multi_dcdt_rs() {
 }
 
 #[test]
-#[ignore = "not yet supported"]
 fn forw_raw_init_async_rs() {
     world().run("scenarios/forw_raw_init_async.scen.json");
 }
@@ -0,0 +1,152 @@
+use promises_features::promises_feature_proxy;
+
+use dharitri_sc_scenario::imports::*;
+
+const USER_ADDRESS: TestAddress = TestAddress::new("user");
+const PROMISES_FEATURES_ADDRESS: TestSCAddress = TestSCAddress::new("promises-features");
+const PROMISES_FEATURES_PATH: DrtscPath =
+    DrtscPath::new("promises-features/output/promises-feature.drtsc.json");
+const VAULT_ADDRESS: TestSCAddress = TestSCAddress::new("vault");
+const VAULT_PATH: DrtscPath = DrtscPath::new("vault/output/vault.drtsc.json");
+
+const REENTRANT_CALL_ERR: &str = "Reentrant call not allowed";
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+
+    blockchain.register_contract(PROMISES_FEATURES_PATH, promises_features::ContractBuilder);
+    blockchain.register_contract(VAULT_PATH, vault::ContractBuilder);
+    blockchain
+}
+
+struct NonReentrantTestState {
+    world: ScenarioWorld,
+}
+
+impl NonReentrantTestState {
+    fn new() -> Self {
+        let mut world = world();
+
+        world.account(USER_ADDRESS).nonce(1);
+        world
+            .account(PROMISES_FEATURES_ADDRESS)
+            .nonce(1)
+            .code(PROMISES_FEATURES_PATH);
+        world.account(VAULT_ADDRESS).nonce(1).code(VAULT_PATH);
+
+        Self { world }
+    }
+
+    fn sync_call(&mut self, to: TestSCAddress, endpoint_name: &str, args: Vec<&str>) -> &mut Self {
+        self.world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(PROMISES_FEATURES_ADDRESS)
+            .gas(100_000_000)
+            .typed(promises_feature_proxy::PromisesFeaturesProxy)
+            .non_reentrant_sync_call(to, endpoint_name, MultiValueVec::from(args))
+            .run();
+        self
+    }
+
+    fn check_non_reentrant_calls(&mut self, expected: u32) {
+        self.world
+            .query()
+            .to(PROMISES_FEATURES_ADDRESS)
+            .typed(promises_feature_proxy::PromisesFeaturesProxy)
+            .non_reentrant_calls()
+            .returns(ExpectValue(expected))
+            .run();
+    }
+}
+
+#[test]
+fn non_reentrant_sync_call_to_other_contract_test() {
+    let mut state = NonReentrantTestState::new();
+
+    state.sync_call(VAULT_ADDRESS, "echo_arguments", vec!["arg1", "arg2"]);
+    state.check_non_reentrant_calls(1);
+
+    // the guard is released at the end of the endpoint
+    state.sync_call(VAULT_ADDRESS, "echo_arguments", vec![]);
+    state.check_non_reentrant_calls(2);
+
+    state
+        .world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(PROMISES_FEATURES_ADDRESS)
+        .typed(promises_feature_proxy::PromisesFeaturesProxy)
+        .non_reentrant_increment()
+        .run();
+    state.check_non_reentrant_calls(3);
+}
+
+#[test]
+fn non_reentrant_sync_call_reentry_test() {
+    let mut state = NonReentrantTestState::new();
+
+    state
+        .world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(PROMISES_FEATURES_ADDRESS)
+        .gas(100_000_000)
+        .typed(promises_feature_proxy::PromisesFeaturesProxy)
+        .non_reentrant_sync_call(
+            PROMISES_FEATURES_ADDRESS,
+            "non_reentrant_increment",
+            MultiValueVec::<Vec<u8>>::new(),
+        )
+        .with_result(ExpectError(4, REENTRANT_CALL_ERR))
+        .run();
+    state.check_non_reentrant_calls(0);
+
+    // unguarded endpoints of the same contract can still be called back
+    state.sync_call(PROMISES_FEATURES_ADDRESS, "clear_callback_data", vec![]);
+    state.check_non_reentrant_calls(1);
+}
+
+#[test]
+fn non_reentrant_promise_callback_test() {
+    let mut state = NonReentrantTestState::new();
+
+    state
+        .world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(PROMISES_FEATURES_ADDRESS)
+        .gas(100_000_000)
+        .typed(promises_feature_proxy::PromisesFeaturesProxy)
+        .non_reentrant_promise_echo(VAULT_ADDRESS, MultiValueVec::from(vec!["arg"]))
+        .run();
+
+    // the callback is also guarded, but only runs after the endpoint has released the guard
+    state.check_non_reentrant_calls(1);
+}
+
+#[test]
+fn non_reentrant_legacy_async_call_test() {
+    let mut state = NonReentrantTestState::new();
+
+    state
+        .world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(PROMISES_FEATURES_ADDRESS)
+        .typed(promises_feature_proxy::PromisesFeaturesProxy)
+        .non_reentrant_legacy_async_echo(VAULT_ADDRESS, MultiValueVec::from(vec!["arg"]))
+        .run();
+    state.check_non_reentrant_calls(1);
+
+    // the endpoint never returned, the legacy callback released the guard
+    state
+        .world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(PROMISES_FEATURES_ADDRESS)
+        .typed(promises_feature_proxy::PromisesFeaturesProxy)
+        .non_reentrant_increment()
+        .run();
+    state.check_non_reentrant_calls(2);
+}
@@ -0,0 +1,10 @@
+use dharitri_sc_modules::access_control;
+
+dharitri_sc::imports!();
+
+#[dharitri_sc::module]
+pub trait OnlyRoleTestModule: access_control::AccessControlModule {
+    #[only_role("minter")]
+    #[endpoint]
+    fn only_role_mod_endpoint(&self) {}
+}
@@ -12,6 +12,7 @@ mod only_admin_derived_mod;
 mod only_admin_mod;
 mod only_owner_derived_mod;
 mod only_owner_mod;
+mod only_role_mod;
 pub mod token_merge_mod_impl;
 
 dharitri_sc::imports!();
@@ -38,6 +39,7 @@ pub trait UseModule:
     + only_owner_derived_mod::OnlyOwnerDerivedTestModule
     + only_admin_mod::OnlyAdminTestModule
     + only_admin_derived_mod::OnlyAdminDerivedTestModule
+    + only_role_mod::OnlyRoleTestModule
     + ongoing_operation_mod_example::OngoingOperationModExample
     + token_merge_mod_impl::TokenMergeModImpl
     + dharitri_sc_modules::claim_developer_rewards::ClaimDeveloperRewardsModule
@@ -54,6 +56,7 @@ pub trait UseModule:
     + dharitri_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
     + dharitri_sc_modules::only_admin::OnlyAdminModule
     + dharitri_sc_modules::ongoing_operation::OngoingOperationModule
+    + dharitri_sc_modules::access_control::AccessControlModule
 {
     /// Validates that the "featureName" feature is on.
     /// Uses the `feature_guard!` macro.
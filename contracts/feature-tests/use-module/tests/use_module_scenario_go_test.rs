@@ -45,6 +45,11 @@ fn use_module_only_owner_go() {
     world().run("scenarios/use_module_only_owner.scen.json");
 }
 
+#[test]
+fn use_module_only_role_go() {
+    world().run("scenarios/use_module_only_role.scen.json");
+}
+
 #[test]
 fn use_module_pause_go() {
     world().run("scenarios/use_module_pause.scen.json");
@@ -76,6 +76,11 @@ fn use_module_only_owner_rs() {
     world().run("scenarios/use_module_only_owner.scen.json");
 }
 
+#[test]
+fn use_module_only_role_rs() {
+    world().run("scenarios/use_module_only_role.scen.json");
+}
+
 #[test]
 fn use_module_pause_rs() {
     world().run("scenarios/use_module_pause.scen.json");
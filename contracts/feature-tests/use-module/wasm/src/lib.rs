@@ -5,9 +5,9 @@
 ////////////////////////////////////////////////////
 
 // Init:                                 1
-// Endpoints:                           63
+// Endpoints:                           71
 // Async Callback:                       1
-// Total number of exported functions:  65
+// Total number of exported functions:  73
 
 #![no_std]
 
@@ -29,6 +29,7 @@ dharitri_sc_wasm_adapter::endpoints! {
         call_derived_not_owner_only => call_derived_not_owner_only
         only_admin_mod_endpoint => only_admin_mod_endpoint
         call_derived_not_admin_only => call_derived_not_admin_only
+        only_role_mod_endpoint => only_role_mod_endpoint
         countTo100 => count_to_100
         mergeTokens => merge_tokens_endpoint
         mergeTokensCustomAttributes => merge_tokens_custom_attributes_endpoint
@@ -81,6 +82,13 @@ dharitri_sc_wasm_adapter::endpoints! {
         addAdmin => add_admin
         removeAdmin => remove_admin
         getAdmins => admins
+        hasRole => has_role
+        grantRole => grant_role
+        revokeRole => revoke_role
+        renounceRole => renounce_role
+        setRoleAdmin => set_role_admin
+        getRoleMembers => role_members
+        getRoleAdmin => role_admin
     )
 }
 
@@ -0,0 +1,134 @@
+dharitri_sc::imports!();
+
+/// Standard smart contract module that, when added to a smart contract, offers role-based access control.
+///
+/// Roles are identified by name, e.g. "pauser", "minter" or "oracle".
+/// Each role has a set of members and, optionally, an admin role,
+/// whose members are allowed to grant and revoke it.
+/// The contract owner can always grant and revoke any role.
+///
+/// It offers:
+/// * endpoints to grant, revoke and renounce roles
+/// * an endpoint where the owner can set the admin role of a role
+/// * events for all role changes
+/// * a method to restrict endpoints to role members,
+///   called automatically for endpoints annotated with `#[only_role("...")]`
+///
+#[dharitri_sc::module]
+pub trait AccessControlModule {
+    #[view(hasRole)]
+    fn has_role(&self, role: ManagedBuffer, address: ManagedAddress) -> bool {
+        self.role_members(&role).contains(&address)
+    }
+
+    #[endpoint(grantRole)]
+    fn grant_role(&self, role: ManagedBuffer, address: ManagedAddress) {
+        self.require_caller_is_role_admin(&role);
+        self.grant_role_internal(&role, address);
+    }
+
+    #[endpoint(revokeRole)]
+    fn revoke_role(&self, role: ManagedBuffer, address: ManagedAddress) {
+        self.require_caller_is_role_admin(&role);
+        self.revoke_role_internal(&role, address);
+    }
+
+    /// Removes the role from the caller.
+    #[endpoint(renounceRole)]
+    fn renounce_role(&self, role: ManagedBuffer) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.role_members(&role).contains(&caller),
+            "Caller does not have the role"
+        );
+        self.revoke_role_internal(&role, caller);
+    }
+
+    /// Sets the role whose members can grant and revoke the given role.
+    ///
+    /// An empty admin role means that only the owner can do it.
+    #[only_owner]
+    #[endpoint(setRoleAdmin)]
+    fn set_role_admin(&self, role: ManagedBuffer, admin_role: ManagedBuffer) {
+        let previous_admin_role = self.role_admin(&role).get();
+        if admin_role.is_empty() {
+            self.role_admin(&role).clear();
+        } else {
+            self.role_admin(&role).set(&admin_role);
+        }
+
+        self.role_admin_changed_event(&role, &previous_admin_role, &admin_role);
+    }
+
+    /// Adds the address to the role, without any caller check.
+    ///
+    /// Useful in the constructor, to set up the initial role members.
+    fn grant_role_internal(&self, role: &ManagedBuffer, address: ManagedAddress) {
+        if self.role_members(role).insert(address.clone()) {
+            let caller = self.blockchain().get_caller();
+            self.role_granted_event(role, &address, &caller);
+        }
+    }
+
+    /// Removes the address from the role, without any caller check.
+    fn revoke_role_internal(&self, role: &ManagedBuffer, address: ManagedAddress) {
+        if self.role_members(role).swap_remove(&address) {
+            let caller = self.blockchain().get_caller();
+            self.role_revoked_event(role, &address, &caller);
+        }
+    }
+
+    fn require_caller_has_role(&self, role: &str) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.role_members(&ManagedBuffer::from(role))
+                .contains(&caller),
+            "Endpoint can only be called by role members"
+        );
+    }
+
+    fn require_caller_is_role_admin(&self, role: &ManagedBuffer) {
+        let caller = self.blockchain().get_caller();
+        if caller == self.blockchain().get_owner_address() {
+            return;
+        }
+
+        let admin_role = self.role_admin(role).get();
+        require!(
+            !admin_role.is_empty() && self.role_members(&admin_role).contains(&caller),
+            "Caller is not an admin of the role"
+        );
+    }
+
+    #[view(getRoleMembers)]
+    #[storage_mapper("access_control_module:role_members")]
+    fn role_members(&self, role: &ManagedBuffer) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[view(getRoleAdmin)]
+    #[storage_mapper("access_control_module:role_admin")]
+    fn role_admin(&self, role: &ManagedBuffer) -> SingleValueMapper<ManagedBuffer>;
+
+    #[event("roleGranted")]
+    fn role_granted_event(
+        &self,
+        #[indexed] role: &ManagedBuffer,
+        #[indexed] address: &ManagedAddress,
+        #[indexed] sender: &ManagedAddress,
+    );
+
+    #[event("roleRevoked")]
+    fn role_revoked_event(
+        &self,
+        #[indexed] role: &ManagedBuffer,
+        #[indexed] address: &ManagedAddress,
+        #[indexed] sender: &ManagedAddress,
+    );
+
+    #[event("roleAdminChanged")]
+    fn role_admin_changed_event(
+        &self,
+        #[indexed] role: &ManagedBuffer,
+        #[indexed] previous_admin_role: &ManagedBuffer,
+        #[indexed] new_admin_role: &ManagedBuffer,
+    );
+}
@@ -1,5 +1,6 @@
 #![no_std]
 
+pub mod access_control;
 pub mod bonding_curve;
 pub mod claim_developer_rewards;
 pub mod default_issue_callbacks;
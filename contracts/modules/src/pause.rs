@@ -8,6 +8,9 @@ dharitri_sc::imports!();
 /// It offers:
 /// * an endpoint where the owner can pause/unpause contract
 /// * a method to check if contract is paused or not
+/// * a method that fails while paused,
+///   called automatically for endpoints annotated with `#[when_not_paused]`
+///   (or for all endpoints of a contract or module annotated with it, unless they opt out with `#[allow_when_paused]`)
 ///
 #[dharitri_sc::module]
 pub trait PauseModule {
@@ -1,8 +1,10 @@
 mod build_info_abi;
 mod contract_abi;
+mod contract_error_abi;
 mod endpoint_abi;
 mod dcdt_attribute_abi;
 mod event_abi;
+mod storage_abi;
 mod type_abi;
 mod type_abi_from;
 mod type_abi_impl_basic;
@@ -15,9 +17,11 @@ mod type_abi_impl_big_int;
 
 pub use build_info_abi::*;
 pub use contract_abi::*;
+pub use contract_error_abi::ContractErrorAbi;
 pub use endpoint_abi::*;
 pub use dcdt_attribute_abi::DcdtAttributeAbi;
 pub use event_abi::*;
+pub use storage_abi::*;
 pub use type_abi::*;
 pub use type_abi_from::*;
 pub use type_description::*;
@@ -1,4 +1,5 @@
 use super::*;
+use crate::types::ContractError;
 use alloc::{
     string::{String, ToString},
     vec::Vec,
@@ -14,7 +15,9 @@ pub struct ContractAbi {
     pub endpoints: Vec<EndpointAbi>,
     pub promise_callbacks: Vec<EndpointAbi>,
     pub events: Vec<EventAbi>,
+    pub storage: Vec<StorageAbi>,
     pub dcdt_attributes: Vec<DcdtAttributeAbi>,
+    pub errors: Vec<ContractErrorAbi>,
     pub has_callback: bool,
     pub type_descriptions: TypeDescriptionContainerImpl,
 }
@@ -31,7 +34,9 @@ impl ContractAbi {
             endpoints: Vec::new(),
             promise_callbacks: Vec::new(),
             events: Vec::new(),
+            storage: Vec::new(),
             dcdt_attributes: Vec::new(),
+            errors: Vec::new(),
             has_callback,
             type_descriptions: TypeDescriptionContainerImpl::new(),
         }
@@ -50,6 +55,12 @@ impl ContractAbi {
         self.type_descriptions.insert_all(&other.type_descriptions);
         self.dcdt_attributes
             .extend_from_slice(other.dcdt_attributes.as_slice());
+        for storage in other.storage {
+            self.add_storage(storage);
+        }
+        for error in other.errors {
+            self.add_error(error);
+        }
     }
 
     /// A type can provide more than 1 type descripions.
@@ -58,6 +69,28 @@ impl ContractAbi {
         T::provide_type_descriptions(&mut self.type_descriptions);
     }
 
+    /// Storage items are identified by their key.
+    /// Only the first one is kept, since getters, setters and mappers can share the same key.
+    pub fn add_storage(&mut self, storage: StorageAbi) {
+        if !self.storage.iter().any(|existing| existing.key == storage.key) {
+            self.storage.push(storage);
+        }
+    }
+
+    /// Adds all variants of a custom error type, skipping the ones already present,
+    /// since the same error type can be returned by several endpoints.
+    pub fn add_errors<E: ContractError>(&mut self) {
+        for error in E::error_abis() {
+            self.add_error(error);
+        }
+    }
+
+    fn add_error(&mut self, error: ContractErrorAbi) {
+        if !self.errors.contains(&error) {
+            self.errors.push(error);
+        }
+    }
+
     /// Contract main crate name.
     pub fn get_crate_name(&self) -> &str {
         self.build_info.contract_crate.name
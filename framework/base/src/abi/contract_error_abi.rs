@@ -0,0 +1,27 @@
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+/// Describes one variant of a custom contract error type.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ContractErrorAbi {
+    pub docs: Vec<String>,
+    pub error_type: String,
+    pub name: String,
+    pub code: u32,
+    pub message: String,
+}
+
+impl ContractErrorAbi {
+    /// Used in code generation.
+    pub fn new(docs: &[&str], error_type: &str, name: &str, code: u32, message: &str) -> Self {
+        ContractErrorAbi {
+            docs: docs.iter().map(|s| s.to_string()).collect(),
+            error_type: error_type.to_string(),
+            name: name.to_string(),
+            code,
+            message: message.to_string(),
+        }
+    }
+}
@@ -44,6 +44,8 @@ pub struct EndpointAbi {
     pub rust_method_name: String,
     pub only_owner: bool,
     pub only_admin: bool,
+    pub only_role: Option<String>,
+    pub when_not_paused: bool,
     pub labels: Vec<String>,
     pub endpoint_type: EndpointTypeAbi,
     pub mutability: EndpointMutabilityAbi,
@@ -64,6 +66,8 @@ impl EndpointAbi {
         rust_method_name: &str,
         only_owner: bool,
         only_admin: bool,
+        only_role: Option<&str>,
+        when_not_paused: bool,
         mutability: EndpointMutabilityAbi,
         endpoint_type: EndpointTypeAbi,
         payable_in_tokens: &[&str],
@@ -76,6 +80,8 @@ impl EndpointAbi {
             rust_method_name: rust_method_name.to_string(),
             only_owner,
             only_admin,
+            only_role: only_role.map(|s| s.to_string()),
+            when_not_paused,
             labels: labels.iter().map(|s| s.to_string()).collect(),
             endpoint_type,
             mutability,
@@ -0,0 +1,58 @@
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use super::TypeName;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StorageKeyArgAbi {
+    pub arg_name: String,
+    pub type_name: TypeName,
+}
+
+/// Describes a storage item, as declared via `#[storage_mapper]`, `#[storage_get]` or `#[storage_set]`.
+///
+/// The full storage key is the key, followed by the nested-encoded key arguments,
+/// followed by the mapper-specific suffixes, if any.
+///
+/// Type names are the Rust types, as written in the contract, without the API generics.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StorageAbi {
+    pub docs: Vec<String>,
+    pub key: String,
+    pub rust_method_name: String,
+    /// The storage mapper type name, e.g. `VecMapper`. `None` for plain storage getters and setters.
+    pub mapper: Option<String>,
+    pub key_args: Vec<StorageKeyArgAbi>,
+    /// The mapper type arguments, or the stored type, for plain getters and setters.
+    pub value_types: Vec<TypeName>,
+}
+
+impl StorageAbi {
+    /// Used in code generation.
+    pub fn new(
+        docs: &[&str],
+        key: &str,
+        rust_method_name: &str,
+        mapper: Option<&str>,
+        value_types: &[&str],
+    ) -> Self {
+        StorageAbi {
+            docs: docs.iter().map(|s| s.to_string()).collect(),
+            key: key.to_string(),
+            rust_method_name: rust_method_name.to_string(),
+            mapper: mapper.map(|s| s.to_string()),
+            key_args: Vec::new(),
+            value_types: value_types.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    /// Used in code generation.
+    pub fn add_key_arg(&mut self, arg_name: &str, type_name: &str) {
+        self.key_args.push(StorageKeyArgAbi {
+            arg_name: arg_name.to_string(),
+            type_name: type_name.to_string(),
+        });
+    }
+}
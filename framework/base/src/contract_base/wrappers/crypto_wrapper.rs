@@ -56,6 +56,9 @@ where
         ManagedByteArray::from_handle(new_handle)
     }
 
+    /// Will crash if the verification fails, the returned value is kept for compatibility.
+    ///
+    /// The error comes straight from the VM, the message is "signature is invalid".
     pub fn verify_bls(
         &self,
         key: &ManagedBuffer<A>,
@@ -85,6 +88,8 @@ where
         )
     }
 
+    /// Will crash if the verification fails, the returned value is kept for compatibility.
+    ///
     /// Note: the signature is minimum 2 bytes in length,
     /// the second byte encodes the length of the remaining signature bytes.
     pub fn verify_secp256k1(
@@ -100,6 +105,7 @@ where
         )
     }
 
+    /// Will crash if the verification fails, the returned value is kept for compatibility.
     pub fn verify_custom_secp256k1(
         &self,
         key: &ManagedBuffer<A>,
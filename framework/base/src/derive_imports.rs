@@ -3,5 +3,5 @@ pub use crate::{
     codec::derive::{
         NestedDecode, NestedEncode, TopDecode, TopDecodeOrDefault, TopEncode, TopEncodeOrDefault,
     },
-    derive::{type_abi, ManagedVecItem, TypeAbi},
+    derive::{type_abi, ContractError, ManagedVecItem, TypeAbi},
 };
@@ -41,6 +41,8 @@ pub fn external_view_contract_constructor_abi() -> EndpointAbi {
         EXTERNAL_VIEW_CONSTRUCTOR_FLAG,
         false,
         false,
+        None,
+        false,
         EndpointMutabilityAbi::Mutable,
         EndpointTypeAbi::Init,
         &[],
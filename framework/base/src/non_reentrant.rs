@@ -0,0 +1,66 @@
+use crate::{
+    api::{ErrorApiImpl, VMApi},
+    err_msg,
+    storage::{
+        protected_keys::FRAMEWORK_RESERVED_KEY_PREFIX, storage_clear, storage_get_len, storage_set,
+        StorageKey,
+    },
+    types::ManagedType,
+};
+
+/// Name of the flag that is set while a `#[non_reentrant]` method is executing.
+///
+/// The flag is stored under the framework reserved prefix, so it cannot clash with contract storage.
+pub const NON_REENTRANT_GUARD_NAME: &[u8] = b"non-reentrant-guard";
+
+fn guard_key<A>() -> StorageKey<A>
+where
+    A: VMApi,
+{
+    let mut key = StorageKey::new(FRAMEWORK_RESERVED_KEY_PREFIX);
+    key.append_bytes(NON_REENTRANT_GUARD_NAME);
+    key
+}
+
+/// Checks that no `#[non_reentrant]` method is currently executing in this contract, then sets the flag.
+///
+/// Called automatically at the start of methods annotated with `#[non_reentrant]`.
+pub fn non_reentrant_enter<A>()
+where
+    A: VMApi,
+{
+    let key = guard_key::<A>();
+    if storage_get_len(key.as_ref()) > 0 {
+        A::error_api_impl().signal_error(err_msg::NON_REENTRANT_CALL);
+    }
+
+    storage_set(key.as_ref(), &true);
+}
+
+/// Clears the flag set by `non_reentrant_enter`.
+///
+/// Called automatically at the end of methods annotated with `#[non_reentrant]`.
+pub fn non_reentrant_exit<A>()
+where
+    A: VMApi,
+{
+    let key = guard_key::<A>();
+    storage_clear(key.as_ref());
+}
+
+/// A method ending with a legacy `async_call_and_exit` never returns, so it cannot clear the flag.
+/// The flag stays set until the legacy callback, which clears it before anything else.
+///
+/// Until then, every `#[non_reentrant]` method of the contract fails, for all callers, not just the original one.
+/// For a cross-shard call this lasts until the callback arrives from the other shard, several blocks later.
+///
+/// Called automatically at the start of the legacy callback.
+pub fn non_reentrant_exit_in_callback<A>()
+where
+    A: VMApi,
+{
+    let key = guard_key::<A>();
+    if storage_get_len(key.as_ref()) > 0 {
+        storage_clear(key.as_ref());
+    }
+}
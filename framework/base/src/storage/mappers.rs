@@ -1,10 +1,12 @@
 mod address_to_id_mapper;
+mod avl_tree_mapper;
 mod bi_di_mapper;
 mod linked_list_mapper;
 mod map_mapper;
 mod map_storage_mapper;
 mod mapper;
 mod ordered_binary_tree_mapper;
+mod priority_queue_mapper;
 mod queue_mapper;
 mod set_mapper;
 mod single_value_mapper;
@@ -16,6 +18,7 @@ mod vec_mapper;
 mod whitelist_mapper;
 
 pub use address_to_id_mapper::{AddressId, AddressToIdMapper, NULL_ID};
+pub use avl_tree_mapper::{AvlTreeMapper, AvlTreeNode};
 pub use bi_di_mapper::BiDiMapper;
 pub use linked_list_mapper::{LinkedListMapper, LinkedListNode};
 pub use map_mapper::MapMapper;
@@ -24,6 +27,7 @@ pub use mapper::{StorageClearable, StorageMapper};
 pub use ordered_binary_tree_mapper::{
     NodeId, OrderedBinaryTreeMapper, OrderedBinaryTreeNode, NULL_NODE_ID,
 };
+pub use priority_queue_mapper::{MaxFirst, MinFirst, PriorityOrder, PriorityQueueMapper};
 pub use queue_mapper::QueueMapper;
 pub use set_mapper::SetMapper;
 pub use single_value_mapper::{SingleValue, SingleValueMapper};
@@ -0,0 +1,669 @@
+use core::marker::PhantomData;
+
+use codec::Empty;
+
+use crate::{
+    api::{ErrorApiImpl, StorageMapperApi},
+    storage::StorageKey,
+    storage_set,
+    types::{ManagedAddress, ManagedType},
+};
+
+use super::{
+    ordered_binary_tree_mapper::{NodeId, OrderedBinaryTreeMapper, NULL_NODE_ID},
+    set_mapper::{CurrentStorage, StorageAddress},
+    StorageMapper,
+};
+
+use crate::codec::{
+    self,
+    derive::{TopDecode, TopEncode},
+    NestedDecode, NestedEncode,
+};
+
+static ROOT_ID_SUFFIX: &[u8] = b"_rootId";
+static ID_SUFFIX: &[u8] = b"_id";
+static LAST_ID_KEY_SUFFIX: &[u8] = b"_lastId";
+
+static CORRUPT_TREE_ERR_MGS: &[u8] = b"Corrupt tree";
+
+// https://en.wikipedia.org/wiki/AVL_tree
+
+/// Same layout as the `OrderedBinaryTreeNode`, with the extra subtree information needed for balancing:
+/// - `height` of the subtree starting at this node, a leaf has height 1;
+/// - `size`, the number of nodes in the subtree starting at this node, used for rank and select queries.
+#[derive(TopEncode, TopDecode, Clone, PartialEq, Debug)]
+pub struct AvlTreeNode<T: NestedEncode + NestedDecode + PartialOrd + PartialEq + Clone> {
+    pub current_node_id: NodeId,
+    pub left_id: NodeId,
+    pub right_id: NodeId,
+    pub parent_id: NodeId,
+    pub height: u32,
+    pub size: u64,
+    pub data: T,
+}
+
+impl<T> AvlTreeNode<T>
+where
+    T: NestedEncode + NestedDecode + PartialOrd + PartialEq + Clone,
+{
+    pub fn new(current_node_id: NodeId, parent_id: NodeId, data: T) -> Self {
+        Self {
+            data,
+            current_node_id,
+            left_id: NULL_NODE_ID,
+            right_id: NULL_NODE_ID,
+            parent_id,
+            height: 1,
+            size: 1,
+        }
+    }
+}
+
+/// Self-balancing ordered binary tree, keeps the heights of the two subtrees of any node within 1 of each other.
+///
+/// Insert, delete and search are all O(log n), regardless of the order in which the elements are inserted.
+/// Duplicate elements are ignored.
+pub struct AvlTreeMapper<SA, T, A = CurrentStorage>
+where
+    SA: StorageMapperApi,
+    A: StorageAddress<SA>,
+    T: NestedEncode + NestedDecode + PartialOrd + PartialEq + Clone,
+{
+    address: A,
+    key: StorageKey<SA>,
+    _phantom_api: PhantomData<SA>,
+    _phantom_item: PhantomData<T>,
+}
+
+impl<SA, T> StorageMapper<SA> for AvlTreeMapper<SA, T, CurrentStorage>
+where
+    SA: StorageMapperApi,
+    T: NestedEncode + NestedDecode + PartialOrd + PartialEq + Clone + 'static,
+{
+    #[inline]
+    fn new(base_key: StorageKey<SA>) -> Self {
+        AvlTreeMapper {
+            address: CurrentStorage,
+            key: base_key,
+            _phantom_api: PhantomData,
+            _phantom_item: PhantomData,
+        }
+    }
+}
+
+impl<SA, T> AvlTreeMapper<SA, T, ManagedAddress<SA>>
+where
+    SA: StorageMapperApi,
+    T: NestedEncode + NestedDecode + PartialOrd + PartialEq + Clone,
+{
+    pub fn new_from_address(address: ManagedAddress<SA>, base_key: StorageKey<SA>) -> Self {
+        AvlTreeMapper {
+            address,
+            key: base_key,
+            _phantom_api: PhantomData,
+            _phantom_item: PhantomData,
+        }
+    }
+}
+
+impl<SA, T> AvlTreeMapper<SA, T, CurrentStorage>
+where
+    SA: StorageMapperApi,
+    T: NestedEncode + NestedDecode + PartialOrd + PartialEq + Clone,
+{
+    /// Returns `false` if the element was already in the tree.
+    pub fn insert(&mut self, data: T) -> bool {
+        let root_id = self.get_root_id();
+        let (new_root_id, inserted) = self.insert_at(root_id, NULL_NODE_ID, data);
+        if inserted {
+            self.set_root_id(new_root_id);
+        }
+
+        inserted
+    }
+
+    /// Returns `false` if the element was not found.
+    pub fn remove(&mut self, data: &T) -> bool {
+        let root_id = self.get_root_id();
+        let (new_root_id, removed) = self.remove_at(root_id, data);
+        if removed {
+            self.set_root_id(new_root_id);
+        }
+
+        removed
+    }
+
+    /// Moves at most `max_elements` from an unbalanced `OrderedBinaryTreeMapper` into this tree,
+    /// removing them from the old one.
+    ///
+    /// Large trees should be migrated in several transactions, to stay within the gas limit.
+    /// Returns `true` once the old tree is empty.
+    pub fn migrate_from(
+        &mut self,
+        source: &mut OrderedBinaryTreeMapper<SA, T>,
+        max_elements: usize,
+    ) -> bool {
+        for _ in 0..max_elements {
+            let Some(root) = source.get_root() else {
+                return true;
+            };
+
+            let min_data = source.find_min(root).data;
+            source.delete_node(min_data.clone());
+            self.insert(min_data);
+        }
+
+        source.get_root().is_none()
+    }
+
+    fn insert_at(&mut self, node_id: NodeId, parent_id: NodeId, data: T) -> (NodeId, bool) {
+        if node_id == NULL_NODE_ID {
+            let new_node_id = self.get_and_increment_last_id();
+            let new_node = AvlTreeNode::new(new_node_id, parent_id, data);
+            self.set_item(new_node_id, &new_node);
+
+            return (new_node_id, true);
+        }
+
+        let mut node = self.try_get_node_by_id(node_id);
+        if data == node.data {
+            return (node_id, false);
+        }
+
+        if data < node.data {
+            let (new_left_id, inserted) = self.insert_at(node.left_id, node_id, data);
+            if !inserted {
+                return (node_id, false);
+            }
+            node.left_id = new_left_id;
+        } else {
+            let (new_right_id, inserted) = self.insert_at(node.right_id, node_id, data);
+            if !inserted {
+                return (node_id, false);
+            }
+            node.right_id = new_right_id;
+        }
+
+        (self.rebalance(node), true)
+    }
+
+    fn remove_at(&mut self, node_id: NodeId, data: &T) -> (NodeId, bool) {
+        if node_id == NULL_NODE_ID {
+            return (NULL_NODE_ID, false);
+        }
+
+        let mut node = self.try_get_node_by_id(node_id);
+        if data < &node.data {
+            let (new_left_id, removed) = self.remove_at(node.left_id, data);
+            if !removed {
+                return (node_id, false);
+            }
+            node.left_id = new_left_id;
+
+            return (self.rebalance(node), true);
+        }
+
+        if data > &node.data {
+            let (new_right_id, removed) = self.remove_at(node.right_id, data);
+            if !removed {
+                return (node_id, false);
+            }
+            node.right_id = new_right_id;
+
+            return (self.rebalance(node), true);
+        }
+
+        if node.left_id == NULL_NODE_ID || node.right_id == NULL_NODE_ID {
+            let child_id = if node.left_id == NULL_NODE_ID {
+                node.right_id
+            } else {
+                node.left_id
+            };
+            self.set_parent_id(child_id, node.parent_id);
+            self.clear_item(node_id);
+
+            return (child_id, true);
+        }
+
+        // the node keeps its place in the tree, with the data of its successor
+        let (new_right_id, successor_data) = self.remove_min(node.right_id);
+        node.right_id = new_right_id;
+        node.data = successor_data;
+
+        (self.rebalance(node), true)
+    }
+
+    fn remove_min(&mut self, node_id: NodeId) -> (NodeId, T) {
+        let mut node = self.try_get_node_by_id(node_id);
+        if node.left_id == NULL_NODE_ID {
+            self.set_parent_id(node.right_id, node.parent_id);
+            self.clear_item(node_id);
+
+            return (node.right_id, node.data);
+        }
+
+        let (new_left_id, min_data) = self.remove_min(node.left_id);
+        node.left_id = new_left_id;
+
+        (self.rebalance(node), min_data)
+    }
+
+    /// Restores the balance of the subtree starting at the given node, assuming both its subtrees are balanced.
+    ///
+    /// Saves all modified nodes and returns the id of the new subtree root.
+    fn rebalance(&mut self, mut node: AvlTreeNode<T>) -> NodeId {
+        let balance = self.get_height(node.left_id) as i64 - self.get_height(node.right_id) as i64;
+        if balance > 1 {
+            let left = self.try_get_node_by_id(node.left_id);
+            if self.get_height(left.left_id) < self.get_height(left.right_id) {
+                node.left_id = self.rotate_left(left);
+            }
+
+            return self.rotate_right(node);
+        }
+
+        if balance < -1 {
+            let right = self.try_get_node_by_id(node.right_id);
+            if self.get_height(right.right_id) < self.get_height(right.left_id) {
+                node.right_id = self.rotate_right(right);
+            }
+
+            return self.rotate_left(node);
+        }
+
+        self.update_subtree_info(&mut node);
+        self.set_item(node.current_node_id, &node);
+
+        node.current_node_id
+    }
+
+    fn rotate_left(&mut self, mut node: AvlTreeNode<T>) -> NodeId {
+        let mut pivot = self.try_get_node_by_id(node.right_id);
+
+        node.right_id = pivot.left_id;
+        self.set_parent_id(pivot.left_id, node.current_node_id);
+
+        pivot.parent_id = node.parent_id;
+        pivot.left_id = node.current_node_id;
+        node.parent_id = pivot.current_node_id;
+
+        self.update_subtree_info(&mut node);
+        self.set_item(node.current_node_id, &node);
+        self.update_subtree_info(&mut pivot);
+        self.set_item(pivot.current_node_id, &pivot);
+
+        pivot.current_node_id
+    }
+
+    fn rotate_right(&mut self, mut node: AvlTreeNode<T>) -> NodeId {
+        let mut pivot = self.try_get_node_by_id(node.left_id);
+
+        node.left_id = pivot.right_id;
+        self.set_parent_id(pivot.right_id, node.current_node_id);
+
+        pivot.parent_id = node.parent_id;
+        pivot.right_id = node.current_node_id;
+        node.parent_id = pivot.current_node_id;
+
+        self.update_subtree_info(&mut node);
+        self.set_item(node.current_node_id, &node);
+        self.update_subtree_info(&mut pivot);
+        self.set_item(pivot.current_node_id, &pivot);
+
+        pivot.current_node_id
+    }
+
+    fn update_subtree_info(&self, node: &mut AvlTreeNode<T>) {
+        let left = self.get_node_by_id(node.left_id);
+        let right = self.get_node_by_id(node.right_id);
+        let (left_height, left_size) = left.map_or((0, 0), |left| (left.height, left.size));
+        let (right_height, right_size) = right.map_or((0, 0), |right| (right.height, right.size));
+
+        node.height = core::cmp::max(left_height, right_height) + 1;
+        node.size = left_size + right_size + 1;
+    }
+
+    fn set_parent_id(&mut self, id: NodeId, parent_id: NodeId) {
+        if id == NULL_NODE_ID {
+            return;
+        }
+
+        let mut node = self.try_get_node_by_id(id);
+        node.parent_id = parent_id;
+        self.set_item(id, &node);
+    }
+
+    fn set_root_id(&mut self, root_id: NodeId) {
+        let root_id_key = self.build_root_id_key();
+        if root_id == NULL_NODE_ID {
+            storage_set(root_id_key.as_ref(), &Empty);
+        } else {
+            storage_set(root_id_key.as_ref(), &root_id);
+        }
+    }
+
+    fn get_and_increment_last_id(&self) -> NodeId {
+        let key = self.build_last_id_key();
+        let last_id: NodeId = self.address.address_storage_get(key.as_ref());
+        let new_id = last_id + 1;
+        storage_set(key.as_ref(), &new_id);
+
+        new_id
+    }
+
+    fn set_item(&mut self, id: NodeId, node: &AvlTreeNode<T>) {
+        let key = self.build_key_for_item(id);
+        storage_set(key.as_ref(), node);
+    }
+
+    fn clear_item(&mut self, id: NodeId) {
+        let key = self.build_key_for_item(id);
+        storage_set(key.as_ref(), &Empty);
+    }
+}
+
+impl<SA, T, A> AvlTreeMapper<SA, T, A>
+where
+    SA: StorageMapperApi,
+    A: StorageAddress<SA>,
+    T: NestedEncode + NestedDecode + PartialOrd + PartialEq + Clone,
+{
+    pub fn get_root(&self) -> Option<AvlTreeNode<T>> {
+        self.get_node_by_id(self.get_root_id())
+    }
+
+    pub fn len(&self) -> u64 {
+        self.get_root().map_or(0, |root| root.size)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.get_root_id() == NULL_NODE_ID
+    }
+
+    /// Height of the whole tree, 0 if empty.
+    pub fn height(&self) -> u32 {
+        self.get_height(self.get_root_id())
+    }
+
+    pub fn contains(&self, data: &T) -> bool {
+        self.search(data).is_some()
+    }
+
+    pub fn search(&self, data: &T) -> Option<AvlTreeNode<T>> {
+        let mut opt_node = self.get_root();
+        while let Some(node) = opt_node {
+            if &node.data == data {
+                return Some(node);
+            }
+
+            if data < &node.data {
+                opt_node = self.get_node_by_id(node.left_id);
+            } else {
+                opt_node = self.get_node_by_id(node.right_id);
+            }
+        }
+
+        None
+    }
+
+    pub fn find_max(&self, mut node: AvlTreeNode<T>) -> AvlTreeNode<T> {
+        while node.right_id != NULL_NODE_ID {
+            node = self.try_get_node_by_id(node.right_id);
+        }
+
+        node
+    }
+
+    pub fn find_min(&self, mut node: AvlTreeNode<T>) -> AvlTreeNode<T> {
+        while node.left_id != NULL_NODE_ID {
+            node = self.try_get_node_by_id(node.left_id);
+        }
+
+        node
+    }
+
+    pub fn find_successor(&self, mut node: AvlTreeNode<T>) -> Option<AvlTreeNode<T>> {
+        if node.right_id != NULL_NODE_ID {
+            let right_node = self.try_get_node_by_id(node.right_id);
+            return Some(self.find_min(right_node));
+        }
+
+        while node.parent_id != NULL_NODE_ID {
+            let parent = self.try_get_node_by_id(node.parent_id);
+            if node.current_node_id != parent.right_id {
+                return Some(parent);
+            }
+
+            node = parent;
+        }
+
+        None
+    }
+
+    pub fn find_predecessor(&self, mut node: AvlTreeNode<T>) -> Option<AvlTreeNode<T>> {
+        if node.left_id != NULL_NODE_ID {
+            let left_node = self.try_get_node_by_id(node.left_id);
+            return Some(self.find_max(left_node));
+        }
+
+        while node.parent_id != NULL_NODE_ID {
+            let parent = self.try_get_node_by_id(node.parent_id);
+            if node.current_node_id != parent.left_id {
+                return Some(parent);
+            }
+
+            node = parent;
+        }
+
+        None
+    }
+
+    /// The node with the smallest element greater than or equal to the given one.
+    pub fn lower_bound(&self, data: &T) -> Option<AvlTreeNode<T>> {
+        let mut result = None;
+        let mut opt_node = self.get_root();
+        while let Some(node) = opt_node {
+            if &node.data < data {
+                opt_node = self.get_node_by_id(node.right_id);
+            } else {
+                opt_node = self.get_node_by_id(node.left_id);
+                result = Some(node);
+            }
+        }
+
+        result
+    }
+
+    /// The node with the smallest element strictly greater than the given one.
+    pub fn upper_bound(&self, data: &T) -> Option<AvlTreeNode<T>> {
+        let mut result = None;
+        let mut opt_node = self.get_root();
+        while let Some(node) = opt_node {
+            if &node.data <= data {
+                opt_node = self.get_node_by_id(node.right_id);
+            } else {
+                opt_node = self.get_node_by_id(node.left_id);
+                result = Some(node);
+            }
+        }
+
+        result
+    }
+
+    /// Number of elements strictly smaller than the given one.
+    pub fn rank(&self, data: &T) -> u64 {
+        let mut rank = 0;
+        let mut opt_node = self.get_root();
+        while let Some(node) = opt_node {
+            if &node.data < data {
+                rank += self.get_size(node.left_id) + 1;
+                opt_node = self.get_node_by_id(node.right_id);
+            } else {
+                opt_node = self.get_node_by_id(node.left_id);
+            }
+        }
+
+        rank
+    }
+
+    /// The element at the given position in the sorted order, starting from 0.
+    pub fn select(&self, mut index: u64) -> Option<AvlTreeNode<T>> {
+        let mut opt_node = self.get_root();
+        while let Some(node) = opt_node {
+            let left_size = self.get_size(node.left_id);
+            if index == left_size {
+                return Some(node);
+            }
+
+            if index < left_size {
+                opt_node = self.get_node_by_id(node.left_id);
+            } else {
+                index -= left_size + 1;
+                opt_node = self.get_node_by_id(node.right_id);
+            }
+        }
+
+        None
+    }
+
+    /// Iterates in order over all elements `x` with `from <= x < to`.
+    pub fn range(&self, from: &T, to: T) -> Iter<SA, T, A> {
+        Iter::new(self, self.lower_bound(from), Some(to))
+    }
+
+    /// Iterates over all elements, in order.
+    pub fn iter(&self) -> Iter<SA, T, A> {
+        let first = self.get_root().map(|root| self.find_min(root));
+        Iter::new(self, first, None)
+    }
+
+    fn get_height(&self, id: NodeId) -> u32 {
+        self.get_node_by_id(id).map_or(0, |node| node.height)
+    }
+
+    fn get_size(&self, id: NodeId) -> u64 {
+        self.get_node_by_id(id).map_or(0, |node| node.size)
+    }
+
+    fn get_root_id(&self) -> NodeId {
+        let root_id_key = self.build_root_id_key();
+        self.address.address_storage_get(root_id_key.as_ref())
+    }
+
+    fn get_node_by_id(&self, id: NodeId) -> Option<AvlTreeNode<T>> {
+        if id == NULL_NODE_ID {
+            return None;
+        }
+
+        let key = self.build_key_for_item(id);
+        let storage_len = self.address.address_storage_get_len(key.as_ref());
+        if storage_len == 0 {
+            return None;
+        }
+
+        Some(self.address.address_storage_get(key.as_ref()))
+    }
+
+    fn try_get_node_by_id(&self, id: NodeId) -> AvlTreeNode<T> {
+        let opt_node = self.get_node_by_id(id);
+        if opt_node.is_none() {
+            SA::error_api_impl().signal_error(CORRUPT_TREE_ERR_MGS);
+        }
+
+        unsafe { opt_node.unwrap_unchecked() }
+    }
+
+    fn build_root_id_key(&self) -> StorageKey<SA> {
+        let mut key = self.key.clone();
+        key.append_bytes(ROOT_ID_SUFFIX);
+
+        key
+    }
+
+    fn build_key_for_item(&self, id: NodeId) -> StorageKey<SA> {
+        let mut item_key = self.key.clone();
+        item_key.append_bytes(ID_SUFFIX);
+        item_key.append_item(&id);
+
+        item_key
+    }
+
+    fn build_last_id_key(&self) -> StorageKey<SA> {
+        let mut key = self.key.clone();
+        key.append_bytes(LAST_ID_KEY_SUFFIX);
+
+        key
+    }
+}
+
+impl<'a, SA, T, A> IntoIterator for &'a AvlTreeMapper<SA, T, A>
+where
+    SA: StorageMapperApi,
+    A: StorageAddress<SA>,
+    T: NestedEncode + NestedDecode + PartialOrd + PartialEq + Clone,
+{
+    type Item = T;
+
+    type IntoIter = Iter<'a, SA, T, A>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An in-order iterator over the elements of an `AvlTreeMapper`.
+///
+/// This `struct` is created by [`AvlTreeMapper::iter()`] and [`AvlTreeMapper::range()`].
+pub struct Iter<'a, SA, T, A>
+where
+    SA: StorageMapperApi,
+    A: StorageAddress<SA>,
+    T: NestedEncode + NestedDecode + PartialOrd + PartialEq + Clone,
+{
+    tree: &'a AvlTreeMapper<SA, T, A>,
+    opt_next: Option<AvlTreeNode<T>>,
+    opt_end: Option<T>,
+}
+
+impl<'a, SA, T, A> Iter<'a, SA, T, A>
+where
+    SA: StorageMapperApi,
+    A: StorageAddress<SA>,
+    T: NestedEncode + NestedDecode + PartialOrd + PartialEq + Clone,
+{
+    fn new(
+        tree: &'a AvlTreeMapper<SA, T, A>,
+        opt_next: Option<AvlTreeNode<T>>,
+        opt_end: Option<T>,
+    ) -> Self {
+        Iter {
+            tree,
+            opt_next,
+            opt_end,
+        }
+    }
+}
+
+impl<SA, T, A> Iterator for Iter<'_, SA, T, A>
+where
+    SA: StorageMapperApi,
+    A: StorageAddress<SA>,
+    T: NestedEncode + NestedDecode + PartialOrd + PartialEq + Clone,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let node = self.opt_next.take()?;
+        if let Some(end) = &self.opt_end {
+            if &node.data >= end {
+                return None;
+            }
+        }
+
+        let data = node.data.clone();
+        self.opt_next = self.tree.find_successor(node);
+
+        Some(data)
+    }
+}
@@ -0,0 +1,515 @@
+use core::marker::PhantomData;
+
+use super::{
+    set_mapper::{CurrentStorage, StorageAddress},
+    StorageClearable, StorageMapper,
+};
+use crate::{
+    abi::{TypeAbi, TypeAbiFrom, TypeDescriptionContainer, TypeName},
+    api::{ErrorApiImpl, StorageMapperApi},
+    codec::{
+        multi_encode_iter_or_handle_err, EncodeErrorHandler, TopDecode, TopEncode, TopEncodeMulti,
+        TopEncodeMultiOutput,
+    },
+    storage::{storage_clear, storage_set, StorageKey},
+    types::{ManagedAddress, ManagedType, MultiValueEncoded},
+};
+
+const LEN_SUFFIX: &[u8] = b".len";
+const LAST_HANDLE_SUFFIX: &[u8] = b".last_handle";
+const HEAP_SUFFIX: &[u8] = b".heap";
+const POSITION_SUFFIX: &[u8] = b".position";
+const VALUE_SUFFIX: &[u8] = b".value";
+
+static INVALID_HANDLE_ERR_MSG: &[u8] = b"invalid priority queue handle";
+
+/// Decides which of two elements leaves the priority queue first.
+pub trait PriorityOrder<T> {
+    /// True if `a` should leave the queue before `b`.
+    fn has_priority(a: &T, b: &T) -> bool;
+}
+
+/// The smallest element is at the top of the queue.
+pub struct MinFirst;
+
+impl<T: PartialOrd> PriorityOrder<T> for MinFirst {
+    fn has_priority(a: &T, b: &T) -> bool {
+        a < b
+    }
+}
+
+/// The largest element is at the top of the queue.
+pub struct MaxFirst;
+
+impl<T: PartialOrd> PriorityOrder<T> for MaxFirst {
+    fn has_priority(a: &T, b: &T) -> bool {
+        a > b
+    }
+}
+
+/// A priority queue, organized as a binary heap in storage.
+///
+/// Push, pop, update and remove all take O(log n) storage reads and writes, peek is O(1).
+///
+/// Every pushed element receives a handle, which stays the same while the element moves around the heap.
+/// The handle can later be used to change the element (e.g. to change its priority), or to remove it.
+/// Handles start from 1 and are never reused.
+///
+/// Storage layout, relative to the base key:
+/// - `.len`: the number of elements;
+/// - `.last_handle`: the last handle given out;
+/// - `.heap` + position: the handle of the element at that position in the heap, positions start from 1;
+/// - `.position` + handle: the position of the element in the heap;
+/// - `.value` + handle: the element itself.
+pub struct PriorityQueueMapper<SA, T, O = MinFirst, A = CurrentStorage>
+where
+    SA: StorageMapperApi,
+    A: StorageAddress<SA>,
+    T: TopEncode + TopDecode + 'static,
+    O: PriorityOrder<T>,
+{
+    _phantom_api: PhantomData<SA>,
+    address: A,
+    base_key: StorageKey<SA>,
+    _phantom_item: PhantomData<T>,
+    _phantom_order: PhantomData<O>,
+}
+
+impl<SA, T, O> StorageMapper<SA> for PriorityQueueMapper<SA, T, O, CurrentStorage>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode,
+    O: PriorityOrder<T> + 'static,
+{
+    fn new(base_key: StorageKey<SA>) -> Self {
+        PriorityQueueMapper {
+            _phantom_api: PhantomData,
+            address: CurrentStorage,
+            base_key,
+            _phantom_item: PhantomData,
+            _phantom_order: PhantomData,
+        }
+    }
+}
+
+impl<SA, T, O> PriorityQueueMapper<SA, T, O, ManagedAddress<SA>>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode,
+    O: PriorityOrder<T>,
+{
+    pub fn new_from_address(address: ManagedAddress<SA>, base_key: StorageKey<SA>) -> Self {
+        PriorityQueueMapper {
+            _phantom_api: PhantomData,
+            address,
+            base_key,
+            _phantom_item: PhantomData,
+            _phantom_order: PhantomData,
+        }
+    }
+}
+
+impl<SA, T, O> StorageClearable for PriorityQueueMapper<SA, T, O, CurrentStorage>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode,
+    O: PriorityOrder<T>,
+{
+    fn clear(&mut self) {
+        self.clear();
+    }
+}
+
+impl<SA, T, O, A> PriorityQueueMapper<SA, T, O, A>
+where
+    SA: StorageMapperApi,
+    A: StorageAddress<SA>,
+    T: TopEncode + TopDecode,
+    O: PriorityOrder<T>,
+{
+    fn build_name_key(&self, name: &[u8]) -> StorageKey<SA> {
+        let mut name_key = self.base_key.clone();
+        name_key.append_bytes(name);
+        name_key
+    }
+
+    fn build_id_named_key(&self, name: &[u8], id: u32) -> StorageKey<SA> {
+        let mut named_key = self.base_key.clone();
+        named_key.append_bytes(name);
+        named_key.append_item(&id);
+        named_key
+    }
+
+    /// Number of elements in the queue.
+    pub fn len(&self) -> usize {
+        self.get_len() as usize
+    }
+
+    /// True if the queue contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.get_len() == 0
+    }
+
+    /// The element at the top of the queue, without removing it.
+    pub fn peek(&self) -> Option<T> {
+        self.peek_handle().map(|handle| self.get_value(handle))
+    }
+
+    /// The handle of the element at the top of the queue.
+    pub fn peek_handle(&self) -> Option<u32> {
+        if self.is_empty() {
+            return None;
+        }
+
+        Some(self.get_heap_handle(1))
+    }
+
+    /// True if the handle belongs to an element currently in the queue.
+    pub fn contains(&self, handle: u32) -> bool {
+        handle != 0 && self.get_position(handle) != 0
+    }
+
+    /// The element with the given handle, if still in the queue.
+    pub fn get(&self, handle: u32) -> Option<T> {
+        if !self.contains(handle) {
+            return None;
+        }
+
+        Some(self.get_value(handle))
+    }
+
+    /// Iterates over all elements, in heap order, which is not sorted.
+    ///
+    /// Only the first element is guaranteed to be the one with the highest priority.
+    pub fn iter(&self) -> Iter<SA, T, O, A> {
+        Iter::new(self)
+    }
+
+    fn get_len(&self) -> u32 {
+        self.address
+            .address_storage_get(self.build_name_key(LEN_SUFFIX).as_ref())
+    }
+
+    fn get_heap_handle(&self, position: u32) -> u32 {
+        self.address
+            .address_storage_get(self.build_id_named_key(HEAP_SUFFIX, position).as_ref())
+    }
+
+    fn get_position(&self, handle: u32) -> u32 {
+        self.address
+            .address_storage_get(self.build_id_named_key(POSITION_SUFFIX, handle).as_ref())
+    }
+
+    fn get_value(&self, handle: u32) -> T {
+        self.address
+            .address_storage_get(self.build_id_named_key(VALUE_SUFFIX, handle).as_ref())
+    }
+}
+
+impl<SA, T, O> PriorityQueueMapper<SA, T, O, CurrentStorage>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode,
+    O: PriorityOrder<T>,
+{
+    /// Adds an element to the queue, returns its handle.
+    pub fn push(&mut self, item: T) -> u32 {
+        let len = self.get_len() + 1;
+        self.set_len(len);
+
+        let handle = self.get_last_handle() + 1;
+        self.set_last_handle(handle);
+        self.set_value(handle, &item);
+        self.sift_up(len, handle, &item);
+
+        handle
+    }
+
+    /// Removes and returns the element at the top of the queue.
+    pub fn pop(&mut self) -> Option<T> {
+        let handle = self.peek_handle()?;
+        self.remove(handle)
+    }
+
+    /// Replaces the element with the given handle, moving it to its new place in the queue.
+    ///
+    /// Signals an error if the handle is not in the queue.
+    pub fn update(&mut self, handle: u32, item: T) {
+        if !self.contains(handle) {
+            SA::error_api_impl().signal_error(INVALID_HANDLE_ERR_MSG);
+        }
+
+        self.set_value(handle, &item);
+        let position = self.get_position(handle);
+        self.restore_heap_at(position, handle, &item);
+    }
+
+    /// Removes the element with the given handle, returns it, or `None` if the handle is not in the queue.
+    pub fn remove(&mut self, handle: u32) -> Option<T> {
+        if !self.contains(handle) {
+            return None;
+        }
+
+        let item = self.get_value(handle);
+        let position = self.get_position(handle);
+        self.clear_value(handle);
+        self.clear_position(handle);
+
+        // the last element of the heap takes the place of the removed one
+        let last_position = self.get_len();
+        self.set_len(last_position - 1);
+        if position != last_position {
+            let last_handle = self.get_heap_handle(last_position);
+            let last_item = self.get_value(last_handle);
+            self.restore_heap_at(position, last_handle, &last_item);
+        }
+        self.clear_heap_handle(last_position);
+
+        Some(item)
+    }
+
+    /// Removes all elements. Handles are not reset, they keep increasing.
+    pub fn clear(&mut self) {
+        let len = self.get_len();
+        for position in 1..=len {
+            let handle = self.get_heap_handle(position);
+            self.clear_value(handle);
+            self.clear_position(handle);
+            self.clear_heap_handle(position);
+        }
+        self.set_len(0);
+    }
+
+    /// Places an element at a position where the heap property might not hold, then moves it up or down.
+    fn restore_heap_at(&mut self, position: u32, handle: u32, item: &T) {
+        if position > 1 {
+            let parent_item = self.get_value(self.get_heap_handle(position / 2));
+            if O::has_priority(item, &parent_item) {
+                self.sift_up(position, handle, item);
+                return;
+            }
+        }
+
+        self.sift_down(position, handle, item);
+    }
+
+    /// Moves parents down until the element can be placed.
+    fn sift_up(&mut self, mut position: u32, handle: u32, item: &T) {
+        while position > 1 {
+            let parent_position = position / 2;
+            let parent_handle = self.get_heap_handle(parent_position);
+            let parent_item = self.get_value(parent_handle);
+            if !O::has_priority(item, &parent_item) {
+                break;
+            }
+
+            self.place(position, parent_handle);
+            position = parent_position;
+        }
+
+        self.place(position, handle);
+    }
+
+    /// Moves children up until the element can be placed.
+    fn sift_down(&mut self, mut position: u32, handle: u32, item: &T) {
+        let len = self.get_len();
+        loop {
+            let left_position = position * 2;
+            if left_position > len {
+                break;
+            }
+
+            let mut child_position = left_position;
+            let mut child_handle = self.get_heap_handle(left_position);
+            let mut child_item = self.get_value(child_handle);
+
+            let right_position = left_position + 1;
+            if right_position <= len {
+                let right_handle = self.get_heap_handle(right_position);
+                let right_item = self.get_value(right_handle);
+                if O::has_priority(&right_item, &child_item) {
+                    child_position = right_position;
+                    child_handle = right_handle;
+                    child_item = right_item;
+                }
+            }
+
+            if !O::has_priority(&child_item, item) {
+                break;
+            }
+
+            self.place(position, child_handle);
+            position = child_position;
+        }
+
+        self.place(position, handle);
+    }
+
+    fn place(&mut self, position: u32, handle: u32) {
+        storage_set(
+            self.build_id_named_key(HEAP_SUFFIX, position).as_ref(),
+            &handle,
+        );
+        storage_set(
+            self.build_id_named_key(POSITION_SUFFIX, handle).as_ref(),
+            &position,
+        );
+    }
+
+    fn set_len(&mut self, len: u32) {
+        storage_set(self.build_name_key(LEN_SUFFIX).as_ref(), &len);
+    }
+
+    fn get_last_handle(&self) -> u32 {
+        self.address
+            .address_storage_get(self.build_name_key(LAST_HANDLE_SUFFIX).as_ref())
+    }
+
+    fn set_last_handle(&mut self, handle: u32) {
+        storage_set(self.build_name_key(LAST_HANDLE_SUFFIX).as_ref(), &handle);
+    }
+
+    fn set_value(&mut self, handle: u32, item: &T) {
+        storage_set(self.build_id_named_key(VALUE_SUFFIX, handle).as_ref(), item);
+    }
+
+    fn clear_value(&mut self, handle: u32) {
+        storage_clear(self.build_id_named_key(VALUE_SUFFIX, handle).as_ref());
+    }
+
+    fn clear_position(&mut self, handle: u32) {
+        storage_clear(self.build_id_named_key(POSITION_SUFFIX, handle).as_ref());
+    }
+
+    fn clear_heap_handle(&mut self, position: u32) {
+        storage_clear(self.build_id_named_key(HEAP_SUFFIX, position).as_ref());
+    }
+}
+
+impl<'a, SA, T, O, A> IntoIterator for &'a PriorityQueueMapper<SA, T, O, A>
+where
+    SA: StorageMapperApi,
+    A: StorageAddress<SA>,
+    T: TopEncode + TopDecode + 'static,
+    O: PriorityOrder<T>,
+{
+    type Item = T;
+
+    type IntoIter = Iter<'a, SA, T, O, A>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the elements of a `PriorityQueueMapper`, in heap order.
+///
+/// This `struct` is created by [`PriorityQueueMapper::iter()`]. See its
+/// documentation for more.
+pub struct Iter<'a, SA, T, O, A>
+where
+    SA: StorageMapperApi,
+    A: StorageAddress<SA>,
+    T: TopEncode + TopDecode + 'static,
+    O: PriorityOrder<T>,
+{
+    position: u32,
+    len: u32,
+    queue: &'a PriorityQueueMapper<SA, T, O, A>,
+}
+
+impl<'a, SA, T, O, A> Iter<'a, SA, T, O, A>
+where
+    SA: StorageMapperApi,
+    A: StorageAddress<SA>,
+    T: TopEncode + TopDecode + 'static,
+    O: PriorityOrder<T>,
+{
+    fn new(queue: &'a PriorityQueueMapper<SA, T, O, A>) -> Iter<'a, SA, T, O, A> {
+        Iter {
+            position: 1,
+            len: queue.get_len(),
+            queue,
+        }
+    }
+}
+
+impl<SA, T, O, A> Iterator for Iter<'_, SA, T, O, A>
+where
+    SA: StorageMapperApi,
+    A: StorageAddress<SA>,
+    T: TopEncode + TopDecode + 'static,
+    O: PriorityOrder<T>,
+{
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        if self.position > self.len {
+            return None;
+        }
+
+        let handle = self.queue.get_heap_handle(self.position);
+        self.position += 1;
+        Some(self.queue.get_value(handle))
+    }
+}
+
+/// Behaves like a MultiResultVec when an endpoint result.
+impl<SA, T, O> TopEncodeMulti for PriorityQueueMapper<SA, T, O, CurrentStorage>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode,
+    O: PriorityOrder<T>,
+{
+    fn multi_encode_or_handle_err<OO, H>(&self, output: &mut OO, h: H) -> Result<(), H::HandledErr>
+    where
+        OO: TopEncodeMultiOutput,
+        H: EncodeErrorHandler,
+    {
+        multi_encode_iter_or_handle_err(self.iter(), output, h)
+    }
+}
+
+impl<SA, T, O> TypeAbiFrom<PriorityQueueMapper<SA, T, O, CurrentStorage>>
+    for MultiValueEncoded<SA, T>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode,
+    O: PriorityOrder<T> + 'static,
+{
+}
+
+impl<SA, T, O> TypeAbiFrom<Self> for PriorityQueueMapper<SA, T, O, CurrentStorage>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode,
+    O: PriorityOrder<T> + 'static,
+{
+}
+
+/// Behaves like a MultiResultVec when an endpoint result.
+impl<SA, T, O> TypeAbi for PriorityQueueMapper<SA, T, O, CurrentStorage>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode + TypeAbi,
+    O: PriorityOrder<T> + 'static,
+{
+    type Unmanaged = Self;
+
+    fn type_name() -> TypeName {
+        crate::abi::type_name_variadic::<T>()
+    }
+
+    fn type_name_rust() -> TypeName {
+        crate::abi::type_name_multi_value_encoded::<T>()
+    }
+
+    fn provide_type_descriptions<TDC: TypeDescriptionContainer>(accumulator: &mut TDC) {
+        T::provide_type_descriptions(accumulator);
+    }
+
+    fn is_variadic() -> bool {
+        true
+    }
+}
@@ -1,2 +1,7 @@
 pub static NUMBAT_REWARD_KEY: &[u8] = b"NUMBATreward";
 pub static NUMBAT_DCDT_LOCAL_ROLES_KEY: &[u8] = b"NUMBATroledcdt";
+
+/// Prefix of the storage keys that the framework itself uses internally, e.g. for the reentrancy guard.
+///
+/// Storage mappers and other contract storage should not use keys starting with it.
+pub static FRAMEWORK_RESERVED_KEY_PREFIX: &[u8] = b"\x00drt-framework.";
@@ -18,6 +18,11 @@ static DCDT_ROLE_NFT_BURN: &[u8] = b"DCDTRoleNFTBurn";
 static DCDT_ROLE_NFT_ADD_URI: &[u8] = b"DCDTRoleNFTAddURI";
 static DCDT_ROLE_NFT_UPDATE_ATTRIBUTES: &[u8] = b"DCDTRoleNFTUpdateAttributes";
 static DCDT_ROLE_TRANSFER: &[u8] = b"DCDTTransferRole";
+static DCDT_ROLE_SET_NEW_URI: &[u8] = b"DCDTRoleSetNewURI";
+static DCDT_ROLE_MODIFY_ROYALTIES: &[u8] = b"DCDTRoleModifyRoyalties";
+static DCDT_ROLE_MODIFY_CREATOR: &[u8] = b"DCDTRoleModifyCreator";
+static DCDT_ROLE_NFT_RECREATE: &[u8] = b"DCDTRoleNFTRecreate";
+static DCDT_ROLE_NFT_UPDATE: &[u8] = b"DCDTRoleNFTUpdate";
 
 #[type_abi]
 #[derive(TopDecode, TopEncode, NestedDecode, NestedEncode, Clone, PartialEq, Eq, Debug, Copy)]
@@ -31,6 +36,11 @@ pub enum DcdtLocalRole {
     NftAddUri,
     NftUpdateAttributes,
     Transfer,
+    SetNewUri,
+    ModifyRoyalties,
+    ModifyCreator,
+    NftRecreate,
+    NftUpdate,
 }
 
 impl DcdtLocalRole {
@@ -45,6 +55,11 @@ impl DcdtLocalRole {
             Self::NftAddUri => 6,
             Self::NftUpdateAttributes => 7,
             Self::Transfer => 8,
+            Self::SetNewUri => 9,
+            Self::ModifyRoyalties => 10,
+            Self::ModifyCreator => 11,
+            Self::NftRecreate => 12,
+            Self::NftUpdate => 13,
         }
     }
 
@@ -59,6 +74,11 @@ impl DcdtLocalRole {
             Self::NftAddUri => DCDT_ROLE_NFT_ADD_URI,
             Self::NftUpdateAttributes => DCDT_ROLE_NFT_UPDATE_ATTRIBUTES,
             Self::Transfer => DCDT_ROLE_TRANSFER,
+            Self::SetNewUri => DCDT_ROLE_SET_NEW_URI,
+            Self::ModifyRoyalties => DCDT_ROLE_MODIFY_ROYALTIES,
+            Self::ModifyCreator => DCDT_ROLE_MODIFY_CREATOR,
+            Self::NftRecreate => DCDT_ROLE_NFT_RECREATE,
+            Self::NftUpdate => DCDT_ROLE_NFT_UPDATE,
         }
     }
 
@@ -73,13 +93,18 @@ impl DcdtLocalRole {
             Self::NftAddUri => DcdtLocalRoleFlags::NFT_ADD_URI,
             Self::NftUpdateAttributes => DcdtLocalRoleFlags::NFT_UPDATE_ATTRIBUTES,
             Self::Transfer => DcdtLocalRoleFlags::TRANSFER,
+            Self::SetNewUri => DcdtLocalRoleFlags::SET_NEW_URI,
+            Self::ModifyRoyalties => DcdtLocalRoleFlags::MODIFY_ROYALTIES,
+            Self::ModifyCreator => DcdtLocalRoleFlags::MODIFY_CREATOR,
+            Self::NftRecreate => DcdtLocalRoleFlags::NFT_RECREATE,
+            Self::NftUpdate => DcdtLocalRoleFlags::NFT_UPDATE,
         }
     }
 }
 
 // TODO: can be done with macros, but I didn't find a public library that does it and is no_std
 // we can implement it, it's easy
-const ALL_ROLES: [DcdtLocalRole; 8] = [
+const ALL_ROLES: [DcdtLocalRole; 13] = [
     DcdtLocalRole::Mint,
     DcdtLocalRole::Burn,
     DcdtLocalRole::NftCreate,
@@ -88,6 +113,11 @@ const ALL_ROLES: [DcdtLocalRole; 8] = [
     DcdtLocalRole::NftAddUri,
     DcdtLocalRole::NftUpdateAttributes,
     DcdtLocalRole::Transfer,
+    DcdtLocalRole::SetNewUri,
+    DcdtLocalRole::ModifyRoyalties,
+    DcdtLocalRole::ModifyCreator,
+    DcdtLocalRole::NftRecreate,
+    DcdtLocalRole::NftUpdate,
 ];
 
 impl DcdtLocalRole {
@@ -108,6 +138,11 @@ impl From<u16> for DcdtLocalRole {
             6 => Self::NftAddUri,
             7 => Self::NftUpdateAttributes,
             8 => Self::Transfer,
+            9 => Self::SetNewUri,
+            10 => Self::ModifyRoyalties,
+            11 => Self::ModifyCreator,
+            12 => Self::NftRecreate,
+            13 => Self::NftUpdate,
             _ => Self::None,
         }
     }
@@ -132,6 +167,16 @@ impl<'a> From<&'a [u8]> for DcdtLocalRole {
             Self::NftUpdateAttributes
         } else if byte_slice == DCDT_ROLE_TRANSFER {
             Self::Transfer
+        } else if byte_slice == DCDT_ROLE_SET_NEW_URI {
+            Self::SetNewUri
+        } else if byte_slice == DCDT_ROLE_MODIFY_ROYALTIES {
+            Self::ModifyRoyalties
+        } else if byte_slice == DCDT_ROLE_MODIFY_CREATOR {
+            Self::ModifyCreator
+        } else if byte_slice == DCDT_ROLE_NFT_RECREATE {
+            Self::NftRecreate
+        } else if byte_slice == DCDT_ROLE_NFT_UPDATE {
+            Self::NftUpdate
         } else {
             Self::None
         }
@@ -0,0 +1,41 @@
+use alloc::vec::Vec;
+
+use crate::abi::ContractErrorAbi;
+
+/// Prefix of the error code in the signaled error message.
+pub const CONTRACT_ERROR_CODE_PREFIX: &str = "E";
+
+/// Separates the error code from the error message in the signaled error message.
+pub const CONTRACT_ERROR_CODE_SEPARATOR: &str = ": ";
+
+/// Typed custom contract errors, each variant having a stable code and message.
+///
+/// Normally implemented via `#[derive(ContractError)]`,
+/// with the message given by the `#[error("...")]` attribute of each variant
+/// and the code given by the variant discriminant.
+///
+/// Endpoints can return `Result<T, E>`, where `E` is a contract error.
+/// Returning an error signals it, with a message of the form `E{code}: {message}`,
+/// which can then be decoded back into the variant by the caller.
+pub trait ContractError: Sized + 'static {
+    fn error_code(&self) -> u32;
+
+    fn error_message(&self) -> &'static str;
+
+    /// The full message signaled by the contract, containing both the code and the message.
+    fn signaled_message(&self) -> &'static str;
+
+    fn from_error_code(code: u32) -> Option<Self>;
+
+    /// Retrieves the variant from a signaled error message, based on the code it contains.
+    fn from_signaled_message(message: &str) -> Option<Self> {
+        let (code_str, _) = message
+            .strip_prefix(CONTRACT_ERROR_CODE_PREFIX)?
+            .split_once(CONTRACT_ERROR_CODE_SEPARATOR)?;
+        let code = code_str.parse::<u32>().ok()?;
+        Self::from_error_code(code)
+    }
+
+    /// Describes all variants, for the contract ABI.
+    fn error_abis() -> Vec<ContractErrorAbi>;
+}
@@ -1,4 +1,5 @@
 mod codec_multi_value_aliases;
+mod contract_error;
 mod operation_completion_status;
 mod sc_error;
 mod sc_error_managed;
@@ -6,6 +7,7 @@ mod sc_error_static;
 mod sc_result;
 
 pub use codec_multi_value_aliases::*;
+pub use contract_error::*;
 pub use operation_completion_status::OperationCompletionStatus;
 pub use sc_error::SCError;
 pub use sc_error_managed::ManagedSCError;
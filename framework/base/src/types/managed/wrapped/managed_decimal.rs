@@ -11,9 +11,12 @@ mod managed_decimal_op_mul;
 mod managed_decimal_op_mul_signed;
 mod managed_decimal_op_sub;
 mod managed_decimal_op_sub_signed;
+mod managed_decimal_pow;
+mod managed_decimal_rounding;
 mod managed_decimal_signed;
 
 pub use decimals::{ConstDecimals, Decimals, NumDecimals};
+pub use managed_decimal_rounding::RoundingMode;
 pub use managed_decimal_signed::ManagedDecimalSigned;
 
 use crate::{
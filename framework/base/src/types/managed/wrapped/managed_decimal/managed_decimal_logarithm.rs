@@ -1,4 +1,5 @@
 use super::decimals::{ConstDecimals, Decimals};
+use super::managed_decimal_pow::{ln_fixed, pow10, GUARD_DECIMALS};
 use super::ManagedDecimalSigned;
 use super::{ManagedDecimal, NumDecimals};
 
@@ -73,6 +74,33 @@ fn compute_log2<M: ManagedTypeApi>(
     ))
 }
 
+fn compute_log10<M: ManagedTypeApi>(
+    data: &BigUint<M>,
+    num_decimals: NumDecimals,
+) -> Option<ManagedDecimalSigned<M, ConstDecimals<9>>> {
+    if data == &0u64 {
+        return None;
+    }
+
+    let working_decimals = core::cmp::max(num_decimals, 9) + GUARD_DECIMALS;
+    let one = pow10::<M>(working_decimals);
+    let x = data * &pow10::<M>(working_decimals - num_decimals);
+
+    let (sign, ln_x) = ln_fixed(&x, &one);
+    let (_, ln_10) = ln_fixed(&(&one * 10u32), &one);
+    let log10_x = ln_x * &one / ln_10;
+
+    // rounding to nearest, so that exact powers of 10 are not truncated to x.999999999
+    let divisor = pow10::<M>(working_decimals - 9);
+    let half_divisor = &divisor / 2u32;
+    let result = (log10_x + half_divisor) / divisor;
+
+    Some(ManagedDecimalSigned::from_raw_units(
+        BigInt::from_biguint(sign, result),
+        ConstDecimals,
+    ))
+}
+
 impl<M: ManagedTypeApi, D: Decimals> ManagedDecimal<M, D> {
     /// Natural logarithm of a number.
     ///
@@ -91,6 +119,15 @@ impl<M: ManagedTypeApi, D: Decimals> ManagedDecimal<M, D> {
     pub fn log2(&self) -> Option<ManagedDecimalSigned<M, ConstDecimals<9>>> {
         compute_log2(&self.data, self.decimals.num_decimals())
     }
+
+    /// Base 10 logarithm of a number.
+    ///
+    /// Returns `None` for 0.
+    ///
+    /// Unlike `ln` and `log2`, all 9 returned decimals are precise, the result is rounded to the nearest value.
+    pub fn log10(&self) -> Option<ManagedDecimalSigned<M, ConstDecimals<9>>> {
+        compute_log10(&self.data, self.decimals.num_decimals())
+    }
 }
 
 impl<M: ManagedTypeApi, D: Decimals> ManagedDecimalSigned<M, D> {
@@ -121,4 +158,18 @@ impl<M: ManagedTypeApi, D: Decimals> ManagedDecimalSigned<M, D> {
         let bu = unsafe { ManagedRef::wrap_handle(self.data.handle.clone()) };
         compute_log2(&bu, self.decimals.num_decimals())
     }
+
+    /// Base 10 logarithm of a number.
+    ///
+    /// Returns `None` for 0 and negative numbers.
+    ///
+    /// Unlike `ln` and `log2`, all 9 returned decimals are precise, the result is rounded to the nearest value.
+    pub fn log10(&self) -> Option<ManagedDecimalSigned<M, ConstDecimals<9>>> {
+        if self.sign() != Sign::Plus {
+            return None;
+        }
+
+        let bu = unsafe { ManagedRef::wrap_handle(self.data.handle.clone()) };
+        compute_log10(&bu, self.decimals.num_decimals())
+    }
 }
@@ -0,0 +1,321 @@
+use super::decimals::{Decimals, NumDecimals};
+use super::{ManagedDecimal, ManagedDecimalSigned};
+
+use crate::{
+    api::ManagedTypeApi,
+    contract_base::ErrorHelper,
+    types::{BigUint, Sign},
+};
+
+use core::ops::Deref;
+
+/// Extra decimals kept during the intermediate computations of `exp`, `pow_decimal` and `log10`.
+///
+/// They absorb the truncation errors of the series and of the repeated multiplications,
+/// so that only the final truncation is visible in the result.
+pub(super) const GUARD_DECIMALS: NumDecimals = 9;
+
+pub(super) fn pow10<M: ManagedTypeApi>(num_decimals: NumDecimals) -> BigUint<M> {
+    BigUint::from(10u64).pow(num_decimals as u32)
+}
+
+fn to_u32_or_fail<M: ManagedTypeApi>(value: &BigUint<M>, err_msg: &str) -> u32 {
+    value
+        .to_u64()
+        .and_then(|value| u32::try_from(value).ok())
+        .unwrap_or_else(|| ErrorHelper::<M>::signal_error_with_message(err_msg))
+}
+
+/// An upper bound of the number of decimal digits in the integer part of a number.
+fn int_part_digits<M: ManagedTypeApi>(data: &BigUint<M>, num_decimals: NumDecimals) -> usize {
+    let int_part = data / num_decimals.scaling_factor().deref();
+    int_part
+        .log2_floor()
+        .map_or(0, |log2_floor| log2_floor as usize / 3 + 1)
+}
+
+/// Multiplication by squaring, all numbers scaled by `one`.
+fn pow_fixed<M: ManagedTypeApi>(base: &BigUint<M>, mut exp: u32, one: &BigUint<M>) -> BigUint<M> {
+    let mut result = one.clone();
+    let mut base = base.clone();
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = &result * &base / one;
+        }
+        exp >>= 1;
+        if exp > 0 {
+            base = &base * &base / one;
+        }
+    }
+
+    result
+}
+
+/// Taylor series of e^x, converges quickly for 0 <= x < 1.
+fn exp_taylor<M: ManagedTypeApi>(x: &BigUint<M>, one: &BigUint<M>) -> BigUint<M> {
+    let mut sum = one.clone();
+    let mut term = one.clone();
+    let mut i = 1u64;
+    loop {
+        term = &term * x / one / i;
+        if term == 0u64 {
+            return sum;
+        }
+
+        sum += &term;
+        i += 1;
+    }
+}
+
+/// e^x, x and the result both scaled by `one`.
+///
+/// e is raised to the integer part of x, the series only being used for the fractional part.
+fn exp_fixed<M: ManagedTypeApi>(x: &BigUint<M>, one: &BigUint<M>) -> BigUint<M> {
+    let int_part = to_u32_or_fail::<M>(&(x / one), "exp overflow");
+    let frac_part = x % one;
+
+    let e = exp_taylor(one, one);
+    pow_fixed(&e, int_part, one) * exp_taylor(&frac_part, one) / one
+}
+
+/// Series of atanh(num / den), for 0 <= num / den <= 1/3.
+fn atanh_fixed<M: ManagedTypeApi>(
+    num: &BigUint<M>,
+    den: &BigUint<M>,
+    one: &BigUint<M>,
+) -> BigUint<M> {
+    let z = num * one / den;
+    let z_squared = &z * &z / one;
+
+    let mut sum = BigUint::zero();
+    let mut term = z;
+    let mut i = 1u64;
+    while term != 0u64 {
+        sum += &term / i;
+        term = &term * &z_squared / one;
+        i += 2;
+    }
+
+    sum
+}
+
+/// ln(x), x and the result both scaled by `one`, x > 0.
+///
+/// Since the result can be negative, its sign is returned separately.
+///
+/// x is first brought into [one/2, 2*one) with a bit shift,
+/// then ln(m) = 2*atanh((m - 1) / (m + 1)) and ln(x) = k*ln(2) + ln(m).
+pub(super) fn ln_fixed<M: ManagedTypeApi>(x: &BigUint<M>, one: &BigUint<M>) -> (Sign, BigUint<M>) {
+    let x_log2_floor = x.log2_floor().unwrap_or_default() as i64;
+    let one_log2_floor = one.log2_floor().unwrap_or_default() as i64;
+    let k = x_log2_floor - one_log2_floor;
+    let m = if k >= 0 {
+        x >> k as usize
+    } else {
+        x << k.unsigned_abs() as usize
+    };
+
+    let m_plus_one = &m + one;
+    let (m_sign, ln_m) = if &m >= one {
+        (
+            Sign::Plus,
+            atanh_fixed(&(&m - one), &m_plus_one, one) * 2u32,
+        )
+    } else {
+        (
+            Sign::Minus,
+            atanh_fixed(&(one - &m), &m_plus_one, one) * 2u32,
+        )
+    };
+
+    let ln_2 = atanh_fixed(one, &(one * 3u32), one) * 2u32;
+    let k_ln_2 = ln_2 * k.unsigned_abs();
+    let k_sign = if k < 0 { Sign::Minus } else { Sign::Plus };
+
+    if k_sign == m_sign {
+        (k_sign, k_ln_2 + ln_m)
+    } else if k_ln_2 >= ln_m {
+        (k_sign, k_ln_2 - ln_m)
+    } else {
+        (m_sign, ln_m - k_ln_2)
+    }
+}
+
+fn compute_exp<M: ManagedTypeApi>(data: &BigUint<M>, num_decimals: NumDecimals) -> BigUint<M> {
+    // the result has about int_part / 2.3 digits before the decimal point, all need to be precise
+    let int_part = data / num_decimals.scaling_factor().deref();
+    let int_part = to_u32_or_fail::<M>(&int_part, "exp overflow") as usize;
+    let extra_decimals = GUARD_DECIMALS + int_part / 2 + 1;
+
+    let one = pow10::<M>(num_decimals + extra_decimals);
+    let guard_factor = pow10::<M>(extra_decimals);
+    exp_fixed(&(data * &guard_factor), &one) / &guard_factor
+}
+
+fn compute_exp_neg<M: ManagedTypeApi>(data: &BigUint<M>, num_decimals: NumDecimals) -> BigUint<M> {
+    let one = pow10::<M>(num_decimals + GUARD_DECIMALS);
+    let guard_factor = pow10::<M>(GUARD_DECIMALS);
+    let exp_abs = exp_fixed(&(data * &guard_factor), &one);
+    &one * &one / &exp_abs / &guard_factor
+}
+
+fn compute_pow_decimal<M: ManagedTypeApi>(
+    data: &BigUint<M>,
+    num_decimals: NumDecimals,
+    exponent: &BigUint<M>,
+    exponent_decimals: NumDecimals,
+) -> BigUint<M> {
+    if exponent == &0u64 {
+        return num_decimals.scaling_factor().clone_value();
+    }
+    if data == &0u64 {
+        return BigUint::zero();
+    }
+
+    let exponent_scaling_factor = exponent_decimals.scaling_factor();
+    let exponent_int = to_u32_or_fail::<M>(
+        &(exponent / exponent_scaling_factor.deref()),
+        "pow exponent too large",
+    );
+    let exponent_frac = exponent % exponent_scaling_factor.deref();
+
+    // enough extra decimals to also cover the digits of the result before the decimal point
+    let extra_decimals =
+        GUARD_DECIMALS + (exponent_int as usize + 1) * int_part_digits(data, num_decimals);
+    let working_decimals = num_decimals + extra_decimals;
+    let one = pow10::<M>(working_decimals);
+    let guard_factor = pow10::<M>(extra_decimals);
+    let x = data * &guard_factor;
+
+    let exponent_frac = if exponent_decimals <= working_decimals {
+        exponent_frac * pow10::<M>(working_decimals - exponent_decimals)
+    } else {
+        exponent_frac / pow10::<M>(exponent_decimals - working_decimals)
+    };
+
+    // x^(n + f) = x^n * e^(f * ln(x))
+    let int_pow = pow_fixed(&x, exponent_int, &one);
+    let (ln_sign, ln_x) = ln_fixed(&x, &one);
+    let frac_exponent = exponent_frac * ln_x / &one;
+    let frac_pow = if ln_sign == Sign::Minus {
+        &one * &one / exp_fixed(&frac_exponent, &one)
+    } else {
+        exp_fixed(&frac_exponent, &one)
+    };
+
+    int_pow * frac_pow / &one / &guard_factor
+}
+
+/// Largest integer r such that r^n <= value, via Newton's method.
+fn integer_nth_root<M: ManagedTypeApi>(value: &BigUint<M>, n: u32) -> BigUint<M> {
+    let Some(log2_floor) = value.log2_floor() else {
+        return BigUint::zero();
+    };
+
+    // initial guess is 2^ceil(bits / n), always above the root
+    let mut root = BigUint::from(1u64) << (log2_floor / n + 1) as usize;
+    loop {
+        let next = (&root * (n - 1) + value / &root.pow(n - 1)) / n;
+        if next >= root {
+            return root;
+        }
+
+        root = next;
+    }
+}
+
+impl<M: ManagedTypeApi, D: Decimals + Clone> ManagedDecimal<M, D> {
+    /// e raised to the power of the number.
+    ///
+    /// Computed with at least 9 extra decimals, the result is then truncated to the same number of decimals.
+    /// All returned decimals are correct, except that the last one can be 1 lower than the rounded-down value.
+    pub fn exp(&self) -> ManagedDecimal<M, D> {
+        let num_decimals = self.decimals.num_decimals();
+        ManagedDecimal::from_raw_units(compute_exp(&self.data, num_decimals), self.decimals.clone())
+    }
+
+    /// Raises the number to an integer power.
+    ///
+    /// Exact, apart from the final truncation to the same number of decimals.
+    pub fn pow(&self, exp: u32) -> ManagedDecimal<M, D> {
+        if exp == 0 {
+            return ManagedDecimal::from_raw_units(
+                self.scaling_factor().clone_value(),
+                self.decimals.clone(),
+            );
+        }
+
+        let divisor = self.scaling_factor().pow(exp - 1);
+        ManagedDecimal::from_raw_units(self.data.pow(exp) / divisor, self.decimals.clone())
+    }
+
+    /// Raises the number to a power that can have a fractional part.
+    ///
+    /// Computed as x^n * e^(f * ln(x)), where n and f are the integer and fractional parts of the exponent.
+    /// The same precision as for `exp` applies: at least 9 extra decimals are used for the intermediate values,
+    /// the result is truncated to the number of decimals of the base.
+    pub fn pow_decimal<D2: Decimals>(
+        &self,
+        exponent: &ManagedDecimal<M, D2>,
+    ) -> ManagedDecimal<M, D> {
+        ManagedDecimal::from_raw_units(
+            compute_pow_decimal(
+                &self.data,
+                self.decimals.num_decimals(),
+                &exponent.data,
+                exponent.decimals.num_decimals(),
+            ),
+            self.decimals.clone(),
+        )
+    }
+
+    /// Square root, truncated to the same number of decimals. Exact otherwise.
+    pub fn sqrt(&self) -> ManagedDecimal<M, D> {
+        let scaled = &self.data * self.scaling_factor().deref();
+        ManagedDecimal::from_raw_units(scaled.sqrt(), self.decimals.clone())
+    }
+
+    /// The n-th root, truncated to the same number of decimals. Exact otherwise.
+    ///
+    /// Will signal an error for n = 0.
+    pub fn nth_root(&self, n: u32) -> ManagedDecimal<M, D> {
+        if n == 0 {
+            ErrorHelper::<M>::signal_error_with_message("zeroth root is undefined");
+        }
+
+        let scaled = &self.data * &self.scaling_factor().pow(n - 1);
+        ManagedDecimal::from_raw_units(integer_nth_root(&scaled, n), self.decimals.clone())
+    }
+}
+
+impl<M: ManagedTypeApi, D: Decimals + Clone> ManagedDecimalSigned<M, D> {
+    /// e raised to the power of the number.
+    ///
+    /// Same precision as `ManagedDecimal::exp`.
+    pub fn exp(&self) -> ManagedDecimal<M, D> {
+        let num_decimals = self.decimals.num_decimals();
+        let magnitude = self.data.magnitude();
+        let data = if self.sign() == Sign::Minus {
+            compute_exp_neg(&magnitude, num_decimals)
+        } else {
+            compute_exp(&magnitude, num_decimals)
+        };
+
+        ManagedDecimal::from_raw_units(data, self.decimals.clone())
+    }
+
+    /// Raises the number to an integer power.
+    ///
+    /// Exact, apart from the final truncation towards zero.
+    pub fn pow(&self, exp: u32) -> ManagedDecimalSigned<M, D> {
+        if exp == 0 {
+            return ManagedDecimalSigned::from_raw_units(
+                self.scaling_factor().clone_value().into_big_int(),
+                self.decimals.clone(),
+            );
+        }
+
+        let divisor = self.scaling_factor().pow(exp - 1);
+        ManagedDecimalSigned::from_raw_units(self.data.pow(exp) / divisor, self.decimals.clone())
+    }
+}
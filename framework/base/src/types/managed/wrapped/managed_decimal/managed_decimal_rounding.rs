@@ -0,0 +1,186 @@
+use super::decimals::{Decimals, NumDecimals};
+use super::{ManagedDecimal, ManagedDecimalSigned};
+
+use crate::{
+    api::ManagedTypeApi,
+    types::{BigInt, BigUint, Sign},
+};
+
+use core::ops::Deref;
+
+/// Specifies how to round the digits that do not fit in the number of decimals of the result.
+///
+/// All modes act on the absolute value, so for negative numbers "up" means away from zero.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RoundingMode {
+    /// Truncates the extra digits, rounding towards zero.
+    ///
+    /// This is what the arithmetic operators and `rescale` do.
+    Down,
+
+    /// Rounds away from zero whenever the extra digits are not all zero.
+    Up,
+
+    /// Rounds to the nearest value, halfway values away from zero.
+    HalfUp,
+
+    /// Rounds to the nearest value, halfway values to the even neighbour.
+    ///
+    /// Also known as banker's rounding, it does not accumulate a bias over many operations.
+    HalfEven,
+}
+
+/// Divides two non-negative numbers, rounding the quotient according to the given mode.
+fn div_rounded<M: ManagedTypeApi>(
+    numerator: &BigUint<M>,
+    denominator: &BigUint<M>,
+    rounding: RoundingMode,
+) -> BigUint<M> {
+    let quotient = numerator / denominator;
+    let remainder = numerator % denominator;
+    if remainder == 0u64 {
+        return quotient;
+    }
+
+    let double_remainder = remainder * 2u32;
+    let round_up = match rounding {
+        RoundingMode::Down => false,
+        RoundingMode::Up => true,
+        RoundingMode::HalfUp => double_remainder >= *denominator,
+        RoundingMode::HalfEven => {
+            double_remainder > *denominator
+                || (double_remainder == *denominator && &quotient % 2u32 == 1u32)
+        },
+    };
+
+    if round_up {
+        quotient + 1u32
+    } else {
+        quotient
+    }
+}
+
+/// Signed division, the rounding mode being applied to the absolute value of the quotient.
+fn div_rounded_signed<M: ManagedTypeApi>(
+    numerator: &BigInt<M>,
+    denominator: &BigInt<M>,
+    rounding: RoundingMode,
+) -> BigInt<M> {
+    let is_negative = numerator.sign().is_minus() != denominator.sign().is_minus();
+    let magnitude = div_rounded(&numerator.magnitude(), &denominator.magnitude(), rounding);
+    let sign = if is_negative { Sign::Minus } else { Sign::Plus };
+    BigInt::from_biguint(sign, magnitude)
+}
+
+fn rescale_data_rounded<M: ManagedTypeApi>(
+    data: &BigUint<M>,
+    from_num_decimals: NumDecimals,
+    scale_to_num_decimals: NumDecimals,
+    rounding: RoundingMode,
+) -> BigUint<M> {
+    if from_num_decimals <= scale_to_num_decimals {
+        let delta_decimals = scale_to_num_decimals - from_num_decimals;
+        return data * delta_decimals.scaling_factor().deref();
+    }
+
+    let delta_decimals = from_num_decimals - scale_to_num_decimals;
+    div_rounded(data, &delta_decimals.scaling_factor(), rounding)
+}
+
+fn rescale_data_rounded_signed<M: ManagedTypeApi>(
+    data: &BigInt<M>,
+    from_num_decimals: NumDecimals,
+    scale_to_num_decimals: NumDecimals,
+    rounding: RoundingMode,
+) -> BigInt<M> {
+    let magnitude = rescale_data_rounded(
+        &data.magnitude(),
+        from_num_decimals,
+        scale_to_num_decimals,
+        rounding,
+    );
+    BigInt::from_biguint(data.sign(), magnitude)
+}
+
+impl<M: ManagedTypeApi, D1: Decimals + Clone> ManagedDecimal<M, D1> {
+    /// Same as `rescale`, but rounding according to the given mode when decimals are lost.
+    pub fn rescale_with_rounding<T: Decimals>(
+        &self,
+        scale_to: T,
+        rounding: RoundingMode,
+    ) -> ManagedDecimal<M, T> {
+        let data = rescale_data_rounded(
+            &self.data,
+            self.decimals.num_decimals(),
+            scale_to.num_decimals(),
+            rounding,
+        );
+        ManagedDecimal::from_raw_units(data, scale_to)
+    }
+
+    /// Multiplication that keeps the decimals of `self`, rounding the result according to the given mode.
+    pub fn mul_with_rounding<D2: Decimals>(
+        &self,
+        other: &ManagedDecimal<M, D2>,
+        rounding: RoundingMode,
+    ) -> ManagedDecimal<M, D1> {
+        let product = &self.data * &other.data;
+        let data = div_rounded(&product, &other.scaling_factor(), rounding);
+        ManagedDecimal::from_raw_units(data, self.decimals.clone())
+    }
+
+    /// Division that keeps the decimals of `self`, rounding the result according to the given mode.
+    pub fn div_with_rounding<D2: Decimals>(
+        &self,
+        other: &ManagedDecimal<M, D2>,
+        rounding: RoundingMode,
+    ) -> ManagedDecimal<M, D1> {
+        let numerator = &self.data * other.scaling_factor().deref();
+        let data = div_rounded(&numerator, &other.data, rounding);
+        ManagedDecimal::from_raw_units(data, self.decimals.clone())
+    }
+}
+
+impl<M: ManagedTypeApi, D1: Decimals + Clone> ManagedDecimalSigned<M, D1> {
+    /// Same as `rescale`, but rounding according to the given mode when decimals are lost.
+    pub fn rescale_with_rounding<T: Decimals>(
+        &self,
+        scale_to: T,
+        rounding: RoundingMode,
+    ) -> ManagedDecimalSigned<M, T> {
+        let data = rescale_data_rounded_signed(
+            &self.data,
+            self.decimals.num_decimals(),
+            scale_to.num_decimals(),
+            rounding,
+        );
+        ManagedDecimalSigned::from_raw_units(data, scale_to)
+    }
+
+    /// Multiplication that keeps the decimals of `self`, rounding the result according to the given mode.
+    pub fn mul_with_rounding<D2: Decimals>(
+        &self,
+        other: &ManagedDecimalSigned<M, D2>,
+        rounding: RoundingMode,
+    ) -> ManagedDecimalSigned<M, D1> {
+        let product = &self.data * &other.data;
+        let data = rescale_data_rounded_signed(
+            &product,
+            self.decimals.num_decimals() + other.decimals.num_decimals(),
+            self.decimals.num_decimals(),
+            rounding,
+        );
+        ManagedDecimalSigned::from_raw_units(data, self.decimals.clone())
+    }
+
+    /// Division that keeps the decimals of `self`, rounding the result according to the given mode.
+    pub fn div_with_rounding<D2: Decimals>(
+        &self,
+        other: &ManagedDecimalSigned<M, D2>,
+        rounding: RoundingMode,
+    ) -> ManagedDecimalSigned<M, D1> {
+        let numerator = &self.data * &other.scaling_factor().value;
+        let data = div_rounded_signed(&numerator, &other.data, rounding);
+        ManagedDecimalSigned::from_raw_units(data, self.decimals.clone())
+    }
+}
@@ -0,0 +1,165 @@
+use crate::parse::attributes::{extract_doc, ErrorMessageAttribute};
+use quote::quote;
+
+struct ContractErrorVariant {
+    ident: syn::Ident,
+    docs: Vec<String>,
+    code: u32,
+    message: String,
+}
+
+fn explicit_code(variant: &syn::Variant) -> Option<u32> {
+    match &variant.discriminant {
+        Some((
+            _,
+            syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Int(val),
+                ..
+            }),
+        )) => Some(
+            val.base10_parse()
+                .expect("ContractError codes must be u32 integer literals"),
+        ),
+        Some(_) => panic!("ContractError codes must be u32 integer literals"),
+        None => None,
+    }
+}
+
+fn error_message(variant: &syn::Variant) -> String {
+    let mut messages = variant
+        .attrs
+        .iter()
+        .filter_map(ErrorMessageAttribute::parse);
+    let message = messages.next().unwrap_or_else(|| {
+        panic!(
+            "ContractError variant `{}` requires an #[error(\"...\")] message",
+            variant.ident
+        )
+    });
+    assert!(
+        messages.next().is_none(),
+        "only one #[error] attribute allowed per ContractError variant"
+    );
+    message.message
+}
+
+/// Codes follow the same rules as the enum discriminants:
+/// explicit if specified, otherwise the previous code + 1, starting from 0.
+fn parse_variants(data_enum: &syn::DataEnum) -> Vec<ContractErrorVariant> {
+    let mut variants: Vec<ContractErrorVariant> = Vec::new();
+    for variant in &data_enum.variants {
+        assert!(
+            matches!(variant.fields, syn::Fields::Unit),
+            "ContractError variants cannot have fields"
+        );
+        let code = explicit_code(variant).unwrap_or_else(|| match variants.last() {
+            Some(previous) => previous
+                .code
+                .checked_add(1)
+                .expect("ContractError code overflow"),
+            None => 0,
+        });
+        assert!(
+            variants.iter().all(|other| other.code != code),
+            "duplicate ContractError code {code}"
+        );
+        variants.push(ContractErrorVariant {
+            ident: variant.ident.clone(),
+            docs: extract_doc(variant.attrs.as_slice()),
+            code,
+            message: error_message(variant),
+        });
+    }
+    variants
+}
+
+pub fn contract_error_derive(input: proc_macro::TokenStream) -> proc_macro2::TokenStream {
+    let ast: syn::DeriveInput = syn::parse(input).unwrap();
+    let data_enum = match &ast.data {
+        syn::Data::Enum(data_enum) => data_enum,
+        _ => panic!("ContractError can only be derived for enums"),
+    };
+    assert!(
+        ast.generics.params.is_empty(),
+        "ContractError enums cannot be generic"
+    );
+
+    let variants = parse_variants(data_enum);
+    assert!(
+        !variants.is_empty(),
+        "ContractError enums need at least one variant"
+    );
+
+    let name = &ast.ident;
+    let name_str = name.to_string();
+    let idents: Vec<&syn::Ident> = variants.iter().map(|v| &v.ident).collect();
+    let codes: Vec<u32> = variants.iter().map(|v| v.code).collect();
+    let messages: Vec<&str> = variants.iter().map(|v| v.message.as_str()).collect();
+    let signaled_messages: Vec<String> = variants
+        .iter()
+        .map(|v| format!("E{}: {}", v.code, v.message))
+        .collect();
+    let error_abi_snippets = variants.iter().map(|v| {
+        let docs = &v.docs;
+        let variant_name_str = v.ident.to_string();
+        let code = v.code;
+        let message = &v.message;
+        quote! {
+            error_abis.push(dharitri_sc::abi::ContractErrorAbi::new(
+                &[ #(#docs),* ],
+                #name_str,
+                #variant_name_str,
+                #code,
+                #message,
+            ));
+        }
+    });
+
+    quote! {
+        impl dharitri_sc::types::ContractError for #name {
+            fn error_code(&self) -> u32 {
+                match self {
+                    #(Self::#idents => #codes,)*
+                }
+            }
+
+            fn error_message(&self) -> &'static str {
+                match self {
+                    #(Self::#idents => #messages,)*
+                }
+            }
+
+            fn signaled_message(&self) -> &'static str {
+                match self {
+                    #(Self::#idents => #signaled_messages,)*
+                }
+            }
+
+            fn from_error_code(code: u32) -> core::option::Option<Self> {
+                match code {
+                    #(#codes => core::option::Option::Some(Self::#idents),)*
+                    _ => core::option::Option::None,
+                }
+            }
+
+            fn error_abis() -> dharitri_sc::types::heap::Vec<dharitri_sc::abi::ContractErrorAbi> {
+                let mut error_abis = dharitri_sc::types::heap::Vec::new();
+                #(#error_abi_snippets)*
+                error_abis
+            }
+        }
+
+        impl dharitri_sc::codec::TopEncodeMulti for #name {
+            fn multi_encode_or_handle_err<O, H>(&self, output: &mut O, h: H) -> core::result::Result<(), H::HandledErr>
+            where
+                O: dharitri_sc::codec::TopEncodeMultiOutput,
+                H: dharitri_sc::codec::EncodeErrorHandler,
+            {
+                let sc_error = dharitri_sc::types::StaticSCError::from(
+                    dharitri_sc::types::ContractError::signaled_message(self),
+                );
+                output.push_multi_specialized(&sc_error, h)
+            }
+        }
+    }
+}
@@ -25,6 +25,7 @@ pub fn contract_implementation(
     let endpoints_mod = generate_endpoints_mod(contract, is_contract_main);
     let function_selector_body = generate_function_selector_body(contract);
     let (callback_selector_body, callback_body) = generate_callback_selector_and_main(contract);
+    let has_non_reentrant_methods_const = generate_has_non_reentrant_methods_const(contract);
     let (callbacks_def, callbacks_impl, callback_proxies_obj) = generate_callback_proxies(contract);
 
     // this definition is common to release and debug mode
@@ -73,6 +74,8 @@ pub fn contract_implementation(
             + #trait_name_ident
             #(#endpoint_wrapper_supertrait_decl)*
         {
+            #has_non_reentrant_methods_const
+
             #(#call_methods)*
 
             fn call(&self, fn_name: &str) -> bool {
@@ -1,14 +1,17 @@
-use super::util::*;
+use super::{callback_gen::has_non_reentrant_methods, util::*};
 use crate::model::{
-    AutoImpl, ContractTrait, EndpointMutabilityMetadata, EndpointTypeMetadata, Method, MethodImpl,
-    PublicRole,
+    AutoImpl, ContractTrait, EndpointMutabilityMetadata, EndpointTypeMetadata, Method,
+    MethodArgument, MethodImpl, PublicRole,
 };
 
+#[allow(clippy::too_many_arguments)]
 fn generate_endpoint_snippet(
     m: &Method,
     endpoint_name: &str,
     only_owner: bool,
     only_admin: bool,
+    only_role: Option<&str>,
+    when_not_paused: bool,
     mutability: EndpointMutabilityMetadata,
     endpoint_type: EndpointTypeMetadata,
     allow_multiple_var_args: bool,
@@ -42,14 +45,25 @@ fn generate_endpoint_snippet(
         syn::ReturnType::Type(_, ty) => {
             let mut res_type = ty.clone();
             clear_all_type_lifetimes(&mut res_type);
+            let errors_snippet = match result_error_type(&res_type) {
+                Some(error_type) => quote! {
+                    contract_abi.add_errors::<#error_type>();
+                },
+                None => quote! {},
+            };
             quote! {
                 endpoint_abi.add_output::<#res_type>(&[ #(#output_names),* ]);
                 contract_abi.add_type_descriptions::<#res_type>();
+                #errors_snippet
             }
         },
     };
 
     let label_names = &m.label_names;
+    let only_role_tokens = match only_role {
+        Some(role_name) => quote! { Some(#role_name) },
+        None => quote! { None },
+    };
     let mutability_tokens = mutability.to_tokens();
     let endpoint_type_tokens = endpoint_type.to_tokens();
 
@@ -60,6 +74,8 @@ fn generate_endpoint_snippet(
             #rust_method_name,
             #only_owner,
             #only_admin,
+            #only_role_tokens,
+            #when_not_paused,
             #mutability_tokens,
             #endpoint_type_tokens,
             &[ #(#payable_in_tokens),* ],
@@ -82,6 +98,8 @@ fn generate_endpoint_snippets(contract: &ContractTrait) -> Vec<proc_macro2::Toke
                     "init",
                     false,
                     false,
+                    None,
+                    false,
                     EndpointMutabilityMetadata::Mutable,
                     EndpointTypeMetadata::Init,
                     m.is_allow_multiple_var_args(),
@@ -97,6 +115,8 @@ fn generate_endpoint_snippets(contract: &ContractTrait) -> Vec<proc_macro2::Toke
                     "upgrade",
                     false,
                     false,
+                    None,
+                    false,
                     EndpointMutabilityMetadata::Mutable,
                     EndpointTypeMetadata::Upgrade,
                     m.is_allow_multiple_var_args(),
@@ -112,6 +132,8 @@ fn generate_endpoint_snippets(contract: &ContractTrait) -> Vec<proc_macro2::Toke
                     &endpoint_metadata.public_name.to_string(),
                     endpoint_metadata.only_owner,
                     endpoint_metadata.only_admin,
+                    endpoint_metadata.only_role.as_deref(),
+                    endpoint_metadata.when_not_paused,
                     endpoint_metadata.mutability.clone(),
                     EndpointTypeMetadata::Endpoint,
                     endpoint_metadata.allow_multiple_var_args,
@@ -127,6 +149,8 @@ fn generate_endpoint_snippets(contract: &ContractTrait) -> Vec<proc_macro2::Toke
                     &callback_metadata.callback_name.to_string(),
                     false,
                     false,
+                    None,
+                    false,
                     EndpointMutabilityMetadata::Mutable,
                     EndpointTypeMetadata::PromisesCallback,
                     m.is_allow_multiple_var_args(),
@@ -186,13 +210,186 @@ fn generate_event_snippets(contract: &ContractTrait) -> Vec<proc_macro2::TokenSt
         .collect()
 }
 
+fn is_self_api(gen_arg: &syn::GenericArgument) -> bool {
+    if let syn::GenericArgument::Type(syn::Type::Path(type_path)) = gen_arg {
+        let segments = &type_path.path.segments;
+        segments.len() == 2 && segments[0].ident == "Self" && segments[1].ident == "Api"
+    } else {
+        false
+    }
+}
+
+/// Removes references, lifetimes and `Self::Api` generic arguments, recursively,
+/// so that storage types show up in the ABI the way they are written in the contract.
+fn strip_api_generics(ty: &mut syn::Type) {
+    if let syn::Type::Reference(r) = ty {
+        let mut elem = (*r.elem).clone();
+        strip_api_generics(&mut elem);
+        *ty = elem;
+        return;
+    }
+
+    match ty {
+        syn::Type::Path(type_path) => {
+            for segment in type_path.path.segments.iter_mut() {
+                if let syn::PathArguments::AngleBracketed(generic_args) = &mut segment.arguments {
+                    generic_args.args = generic_args
+                        .args
+                        .iter()
+                        .filter(|gen_arg| {
+                            !is_self_api(gen_arg)
+                                && !matches!(gen_arg, syn::GenericArgument::Lifetime(_))
+                        })
+                        .cloned()
+                        .collect();
+                    for gen_arg in generic_args.args.iter_mut() {
+                        if let syn::GenericArgument::Type(gen_ty) = gen_arg {
+                            strip_api_generics(gen_ty);
+                        }
+                    }
+                    if generic_args.args.is_empty() {
+                        segment.arguments = syn::PathArguments::None;
+                    }
+                }
+            }
+        },
+        syn::Type::Tuple(tuple) => tuple.elems.iter_mut().for_each(strip_api_generics),
+        syn::Type::Array(array) => strip_api_generics(&mut array.elem),
+        syn::Type::Slice(slice) => strip_api_generics(&mut slice.elem),
+        _ => {},
+    }
+}
+
+fn storage_type_name(ty: &syn::Type) -> String {
+    let mut ty = ty.clone();
+    strip_api_generics(&mut ty);
+    quote! { #ty }
+        .to_string()
+        .replace(" <", "<")
+        .replace("< ", "<")
+        .replace(" >", ">")
+        .replace(" ,", ",")
+        .replace(" ;", ";")
+        .replace(" :: ", "::")
+        .replace(":: ", "::")
+}
+
+/// Splits a storage mapper type into the mapper name and its type arguments.
+fn storage_mapper_type_names(ty: &syn::Type) -> (String, Vec<String>) {
+    let mut ty = ty.clone();
+    strip_api_generics(&mut ty);
+    let syn::Type::Path(type_path) = &ty else {
+        panic!("storage mapper must be a path type");
+    };
+    let last_segment = type_path
+        .path
+        .segments
+        .last()
+        .expect("storage mapper type path cannot be empty");
+    let type_args = match &last_segment.arguments {
+        syn::PathArguments::AngleBracketed(generic_args) => generic_args
+            .args
+            .iter()
+            .filter_map(|gen_arg| match gen_arg {
+                syn::GenericArgument::Type(gen_ty) => Some(storage_type_name(gen_ty)),
+                _ => None,
+            })
+            .collect(),
+        _ => Vec::new(),
+    };
+    (last_segment.ident.to_string(), type_args)
+}
+
+fn generate_storage_snippet(
+    m: &Method,
+    identifier: &str,
+    mapper: Option<String>,
+    key_args: &[MethodArgument],
+    value_types: Vec<String>,
+) -> proc_macro2::TokenStream {
+    let storage_docs = &m.docs;
+    let rust_method_name = m.name.to_string();
+    let mapper_tokens = match mapper {
+        Some(mapper_name) => quote! { Some(#mapper_name) },
+        None => quote! { None },
+    };
+    let key_arg_snippets = key_args.iter().map(|arg| {
+        let arg_name_str = pat_string(&arg.pat);
+        let type_name = storage_type_name(&arg.ty);
+        quote! {
+            storage_abi.add_key_arg(#arg_name_str, #type_name);
+        }
+    });
+
+    quote! {
+        let mut storage_abi = dharitri_sc::abi::StorageAbi::new(
+            &[ #(#storage_docs),* ],
+            #identifier,
+            #rust_method_name,
+            #mapper_tokens,
+            &[ #(#value_types),* ],
+        );
+        #(#key_arg_snippets)*
+        contract_abi.add_storage(storage_abi);
+    }
+}
+
+fn generate_storage_snippets(contract: &ContractTrait) -> Vec<proc_macro2::TokenStream> {
+    contract
+        .methods
+        .iter()
+        .filter_map(|m| {
+            let return_type = match &m.return_type {
+                syn::ReturnType::Default => None,
+                syn::ReturnType::Type(_, ty) => Some(ty.as_ref()),
+            };
+            match &m.implementation {
+                MethodImpl::Generated(AutoImpl::StorageGetter { identifier }) => {
+                    let value_type = storage_type_name(return_type?);
+                    Some(generate_storage_snippet(
+                        m,
+                        identifier,
+                        None,
+                        &m.method_args,
+                        vec![value_type],
+                    ))
+                },
+                MethodImpl::Generated(AutoImpl::StorageSetter { identifier }) => {
+                    let (value_arg, key_args) = m.method_args.split_last()?;
+                    let value_type = storage_type_name(&value_arg.ty);
+                    Some(generate_storage_snippet(
+                        m,
+                        identifier,
+                        None,
+                        key_args,
+                        vec![value_type],
+                    ))
+                },
+                MethodImpl::Generated(AutoImpl::StorageMapper { identifier }) => {
+                    let (mapper, value_types) = storage_mapper_type_names(return_type?);
+                    Some(generate_storage_snippet(
+                        m,
+                        identifier,
+                        Some(mapper),
+                        &m.method_args,
+                        value_types,
+                    ))
+                },
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+/// Contracts with `#[non_reentrant]` methods always need the legacy callback, since it releases the guard.
 fn has_callback(contract: &ContractTrait) -> bool {
-    contract.methods.iter().any(|m| {
-        matches!(
-            m.public_role,
-            PublicRole::Callback(_) | PublicRole::CallbackRaw
-        )
-    })
+    has_non_reentrant_methods(contract)
+        || contract.methods.iter().any(|m| {
+            matches!(
+                m.public_role,
+                PublicRole::Callback(_) | PublicRole::CallbackRaw
+            )
+        })
 }
 
 fn generate_supertrait_snippets(contract: &ContractTrait) -> Vec<proc_macro2::TokenStream> {
@@ -232,6 +429,7 @@ fn generate_abi_method_body(
     let contract_name = &contract.trait_name.to_string();
     let endpoint_snippets = generate_endpoint_snippets(contract);
     let event_snippets = generate_event_snippets(contract);
+    let storage_snippets = generate_storage_snippets(contract);
     let has_callbacks = has_callback(contract);
     let supertrait_snippets: Vec<proc_macro2::TokenStream> = if is_contract_main {
         generate_supertrait_snippets(contract)
@@ -260,6 +458,7 @@ fn generate_abi_method_body(
         );
         #(#endpoint_snippets)*
         #(#event_snippets)*
+        #(#storage_snippets)*
         #(#supertrait_snippets)*
         #(#dcdt_attributes)*
         contract_abi
@@ -1,6 +1,7 @@
 use super::{
     method_call_gen::{
-        generate_body_with_result, generate_call_to_method_expr, generate_endpoint_call_method_body,
+        generate_body_with_result, generate_endpoint_call_method_body,
+        generate_guarded_call_to_method_expr,
     },
     payable_gen::*,
     util::*,
@@ -16,9 +17,40 @@ use crate::{
 /// so as not to burden the contract at runtime.
 pub const CALLBACK_NAME_MAX_LENGTH: usize = 32;
 
+/// Methods ending with a legacy `async_call_and_exit` never release their `#[non_reentrant]` guard,
+/// so the legacy callback does it, before anything else.
+///
+/// The condition is a constant, so contracts without `#[non_reentrant]` methods, in modules included,
+/// pay nothing for it.
+fn release_non_reentrant_guard_snippet() -> proc_macro2::TokenStream {
+    quote! {
+        if <Self as self::EndpointWrappers>::HAS_NON_REENTRANT_METHODS {
+            dharitri_sc::non_reentrant::non_reentrant_exit_in_callback::<Self::Api>();
+        }
+    }
+}
+
+/// Defines `HAS_NON_REENTRANT_METHODS` in `EndpointWrappers`,
+/// true if the contract or any of its modules has `#[non_reentrant]` methods.
+pub fn generate_has_non_reentrant_methods_const(
+    contract: &ContractTrait,
+) -> proc_macro2::TokenStream {
+    let own = has_non_reentrant_methods(contract);
+    let modules = contract.supertraits.iter().map(|supertrait| {
+        let module_path = &supertrait.module_path;
+        quote! {
+            || <Self as #module_path EndpointWrappers>::HAS_NON_REENTRANT_METHODS
+        }
+    });
+    quote! {
+        const HAS_NON_REENTRANT_METHODS: bool = #own #(#modules)*;
+    }
+}
+
 pub fn generate_callback_selector_and_main(
     contract: &ContractTrait,
 ) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+    let release_guard = release_non_reentrant_guard_snippet();
     let raw_decl = find_raw_callback(&contract.methods);
     if let Some(raw) = raw_decl {
         let as_call_method = generate_endpoint_call_method_body(&raw);
@@ -27,6 +59,7 @@ pub fn generate_callback_selector_and_main(
             dharitri_sc::types::CallbackSelectorResult::Processed
         };
         let cb_main_body = quote! {
+            #release_guard
             let _ = self::EndpointWrappers::callback_selector(
                 self,
                 dharitri_sc::types::CallbackClosureForDeser::no_callback(),
@@ -41,11 +74,11 @@ pub fn generate_callback_selector_and_main(
             let cb_selector_body = quote! {
                 dharitri_sc::types::CallbackSelectorResult::NotProcessed(___cb_closure___)
             };
-            let cb_main_body = quote! {};
-            (cb_selector_body, cb_main_body)
+            (cb_selector_body, release_guard)
         } else {
             let cb_selector_body = callback_selector_body(match_arms, module_calls);
             let cb_main_body = quote! {
+                #release_guard
                 if let Some(___cb_closure___) = dharitri_sc::types::CallbackClosureForDeser::storage_load_and_clear::<Self::Api>() {
                     if let dharitri_sc::types::CallbackSelectorResult::NotProcessed(_) =
                         self::EndpointWrappers::callback_selector(self, ___cb_closure___) {
@@ -61,6 +94,10 @@ pub fn generate_callback_selector_and_main(
     }
 }
 
+pub fn has_non_reentrant_methods(contract: &ContractTrait) -> bool {
+    contract.methods.iter().any(Method::is_non_reentrant)
+}
+
 fn find_raw_callback(methods: &[Method]) -> Option<Method> {
     methods
         .iter()
@@ -97,7 +134,7 @@ fn match_arms(methods: &[Method]) -> Vec<proc_macro2::TokenStream> {
                 let callback_name_literal = byte_str_literal(callback_name_str.as_bytes());
                 let load_call_result_args = load_call_result_args_snippet(m);
                 let load_cb_closure_args = load_legacy_cb_closure_args_snippet(m);
-                let call = generate_call_to_method_expr(m);
+                let call = generate_guarded_call_to_method_expr(m);
                 let body_with_result = generate_body_with_result(&m.return_type, &call);
 
                 let match_arm = quote! {
@@ -22,6 +22,24 @@ pub fn generate_call_to_method_expr(m: &Method) -> proc_macro2::TokenStream {
     }
 }
 
+/// Same as `generate_call_to_method_expr`, but for `#[non_reentrant]` methods
+/// the call is surrounded by the reentrancy guard.
+pub fn generate_guarded_call_to_method_expr(m: &Method) -> proc_macro2::TokenStream {
+    let call = generate_call_to_method_expr(m);
+    if !m.is_non_reentrant() {
+        return call;
+    }
+
+    quote! {
+        {
+            dharitri_sc::non_reentrant::non_reentrant_enter::<Self::Api>();
+            let ___guarded_result___ = #call;
+            dharitri_sc::non_reentrant::non_reentrant_exit::<Self::Api>();
+            ___guarded_result___
+        }
+    }
+}
+
 pub fn generate_call_method(m: &Method) -> proc_macro2::TokenStream {
     let call_method_ident = generate_call_method_name(&m.name);
     let call_method_body = generate_endpoint_call_method_body(m);
@@ -52,6 +70,12 @@ pub fn generate_body_with_result(
         syn::ReturnType::Default => quote! {
             #mbody;
         },
+        syn::ReturnType::Type(_, ty) if result_error_type(ty).is_some() => quote! {
+            match #mbody {
+                core::result::Result::Ok(result) => dharitri_sc::io::finish_multi::<Self::Api, _>(&result),
+                core::result::Result::Err(err) => dharitri_sc::io::finish_multi::<Self::Api, _>(&err),
+            }
+        },
         syn::ReturnType::Type(_, _) => {
             quote! {
                 let result = #mbody;
@@ -66,10 +90,12 @@ pub fn generate_endpoint_call_method_body(m: &Method) -> proc_macro2::TokenStrea
     let payable_snippet = generate_payable_snippet(m);
     let only_owner_snippet = generate_only_owner_snippet(m);
     let only_admin_snippet = generate_only_admin_snippet(m);
+    let only_role_snippet = generate_only_role_snippet(m);
     let only_user_account_snippet = generate_only_user_account_snippet(m);
+    let when_not_paused_snippet = generate_when_not_paused_snippet(m);
     let arg_load = generate_call_method_arg_load(m);
 
-    let call = generate_call_to_method_expr(m);
+    let call = generate_guarded_call_to_method_expr(m);
     let body_with_result = generate_body_with_result(&m.return_type, &call);
 
     quote! {
@@ -77,7 +103,9 @@ pub fn generate_endpoint_call_method_body(m: &Method) -> proc_macro2::TokenStrea
         #payable_snippet
         #only_owner_snippet
         #only_admin_snippet
+        #only_role_snippet
         #only_user_account_snippet
+        #when_not_paused_snippet
         #arg_load
         #body_with_result
     }
@@ -89,7 +117,7 @@ pub fn generate_promises_callback_call_method_body(m: &Method) -> proc_macro2::T
     let cb_closure_args_snippet = load_cb_closure_args_snippet(m);
     let call_result_args_snippet = load_call_result_args_snippet(m);
 
-    let call = generate_call_to_method_expr(m);
+    let call = generate_guarded_call_to_method_expr(m);
     let body_with_result = generate_body_with_result(&m.return_type, &call);
 
     quote! {
@@ -22,6 +22,28 @@ pub fn generate_only_admin_snippet(m: &Method) -> proc_macro2::TokenStream {
     quote! {}
 }
 
+pub fn generate_only_role_snippet(m: &Method) -> proc_macro2::TokenStream {
+    if let PublicRole::Endpoint(endpoint_metadata) = &m.public_role {
+        if let Some(role_name) = &endpoint_metadata.only_role {
+            return quote! {
+                self.require_caller_has_role(#role_name);
+            };
+        }
+    }
+    quote! {}
+}
+
+pub fn generate_when_not_paused_snippet(m: &Method) -> proc_macro2::TokenStream {
+    if let PublicRole::Endpoint(endpoint_metadata) = &m.public_role {
+        if endpoint_metadata.when_not_paused {
+            return quote! {
+                self.require_not_paused();
+            };
+        }
+    }
+    quote! {}
+}
+
 pub fn generate_only_user_account_snippet(m: &Method) -> proc_macro2::TokenStream {
     if let PublicRole::Endpoint(endpoint_metadata) = &m.public_role {
         if endpoint_metadata.only_user_account {
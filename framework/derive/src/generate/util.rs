@@ -81,3 +81,27 @@ pub fn clear_all_type_lifetimes(ty: &mut syn::Type) {
         _ => {},
     }
 }
+
+/// The error type `E`, for endpoints returning `Result<T, E>`.
+///
+/// Only `Ok` values are returned as results, errors are signaled, and their variants listed in the ABI.
+/// `E` is expected to be a custom contract error, usually `#[derive(ContractError)]`.
+pub fn result_error_type(ty: &syn::Type) -> Option<&syn::Type> {
+    let syn::Type::Path(type_path) = ty else {
+        return None;
+    };
+    let last_segment = type_path.path.segments.last()?;
+    if last_segment.ident != "Result" {
+        return None;
+    }
+    let syn::PathArguments::AngleBracketed(generic_args) = &last_segment.arguments else {
+        return None;
+    };
+    if generic_args.args.len() != 2 {
+        return None;
+    }
+    match generic_args.args.last()? {
+        syn::GenericArgument::Type(error_type) => Some(error_type),
+        _ => None,
+    }
+}
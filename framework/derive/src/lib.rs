@@ -7,6 +7,7 @@ extern crate syn;
 #[macro_use]
 extern crate quote;
 
+mod contract_error_derive;
 mod contract_impl;
 mod format;
 mod generate;
@@ -58,6 +59,11 @@ pub fn type_abi(
     type_abi_derive::type_abi_full(input).into()
 }
 
+#[proc_macro_derive(ContractError, attributes(error))]
+pub fn contract_error_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    contract_error_derive::contract_error_derive(input).into()
+}
+
 #[proc_macro_derive(ManagedVecItem)]
 pub fn managed_vec_item_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let ast = syn::parse(input).unwrap();
@@ -61,6 +61,7 @@ pub struct TraitProperties {
     pub only_owner: bool,
     pub only_admin: bool,
     pub only_user_account: bool,
+    pub when_not_paused: bool,
     pub allow_multiple_var_args: bool,
     pub dcdt_attribute: Vec<DcdtAttribute>,
 }
@@ -12,7 +12,10 @@ pub struct EndpointMetadata {
     pub payable: MethodPayableMetadata,
     pub only_owner: bool,
     pub only_admin: bool,
+    pub only_role: Option<String>,
     pub only_user_account: bool,
+    pub when_not_paused: bool,
+    pub non_reentrant: bool,
     pub mutability: EndpointMutabilityMetadata,
     pub allow_multiple_var_args: bool,
 }
@@ -21,6 +24,7 @@ pub struct EndpointMetadata {
 pub struct CallbackMetadata {
     pub callback_name: syn::Ident,
     pub allow_multiple_var_args: bool,
+    pub non_reentrant: bool,
 }
 
 /// Method visibility from the point of view of the smart contract
@@ -111,4 +111,13 @@ impl Method {
             PublicRole::Private => false,
         }
     }
+
+    pub fn is_non_reentrant(&self) -> bool {
+        match &self.public_role {
+            PublicRole::Endpoint(endpoint_metadata) => endpoint_metadata.non_reentrant,
+            PublicRole::Callback(callback_metadata)
+            | PublicRole::CallbackPromise(callback_metadata) => callback_metadata.non_reentrant,
+            _ => false,
+        }
+    }
 }
@@ -1,20 +0,0 @@
-mod argument_attr;
-mod attr_names;
-mod doc_attr;
-mod endpoint_attr;
-mod event_attr;
-mod label_attr;
-mod payable_attr;
-mod storage_attr;
-mod trait_argument_prop;
-mod trait_prop_names;
-mod util;
-
-pub use argument_attr::*;
-pub use doc_attr::{extract_doc, extract_macro_attributes, OutputNameAttribute, TitleAttribute};
-pub use endpoint_attr::*;
-pub use event_attr::*;
-pub use label_attr::*;
-pub use payable_attr::*;
-pub use storage_attr::*;
-pub use trait_argument_prop::*;
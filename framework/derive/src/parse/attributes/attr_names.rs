@@ -1,7 +1,11 @@
 pub(super) static ATTR_PAYABLE: &str = "payable";
 pub(super) static ATTR_ONLY_OWNER: &str = "only_owner";
 pub(super) static ATTR_ONLY_ADMIN: &str = "only_admin";
+pub(super) static ATTR_ONLY_ROLE: &str = "only_role";
+pub(super) static ATTR_WHEN_NOT_PAUSED: &str = "when_not_paused";
+pub(super) static ATTR_ALLOW_WHEN_PAUSED: &str = "allow_when_paused";
 pub(super) static ATTR_ONLY_USER_ACCOUNT: &str = "only_user_account";
+pub(super) static ATTR_NON_REENTRANT: &str = "non_reentrant";
 pub(super) static ATTR_OUTPUT_NAME: &str = "output_name";
 pub(super) static ATTR_PAYMENT: &str = "payment"; // synonymous with `payment_amount`
 pub(super) static ATTR_PAYMENT_AMOUNT: &str = "payment_amount";
@@ -25,5 +29,6 @@ pub(super) static ATTR_STORAGE_IS_EMPTY: &str = "storage_is_empty";
 pub(super) static ATTR_STORAGE_CLEAR: &str = "storage_clear";
 pub(super) static ATTR_PROXY: &str = "proxy";
 pub(super) static ATTR_LABEL: &str = "label";
+pub(super) static ATTR_ERROR: &str = "error";
 pub(super) static ATTR_ALLOW_MULTIPLE_VAR_ARGS: &str = "allow_multiple_var_args";
 pub(super) static ATTR_UPGRADE: &str = "upgrade";
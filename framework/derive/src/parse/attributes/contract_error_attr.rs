@@ -0,0 +1,13 @@
+use super::{attr_names::ATTR_ERROR, util::is_attr_one_string_arg};
+
+/// The message of a `#[derive(ContractError)]` enum variant.
+pub struct ErrorMessageAttribute {
+    pub message: String,
+}
+
+impl ErrorMessageAttribute {
+    pub fn parse(attr: &syn::Attribute) -> Option<Self> {
+        is_attr_one_string_arg(attr, ATTR_ERROR)
+            .map(|arg_str| ErrorMessageAttribute { message: arg_str })
+    }
+}
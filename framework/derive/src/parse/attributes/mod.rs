@@ -1,5 +1,6 @@
 mod argument_attr;
 mod attr_names;
+mod contract_error_attr;
 mod doc_attr;
 mod endpoint_attr;
 mod event_attr;
@@ -11,6 +12,7 @@ mod trait_prop_names;
 mod util;
 
 pub use argument_attr::*;
+pub use contract_error_attr::ErrorMessageAttribute;
 pub use doc_attr::{extract_doc, extract_macro_attributes, OutputNameAttribute};
 pub use endpoint_attr::*;
 pub use event_attr::*;
@@ -10,6 +10,10 @@ pub fn is_only_admin_prop(attr: &syn::Attribute) -> bool {
     is_attribute_with_no_args(attr, PROP_ADMIN_OWNER)
 }
 
+pub fn is_when_not_paused_prop(attr: &syn::Attribute) -> bool {
+    is_attribute_with_no_args(attr, PROP_WHEN_NOT_PAUSED)
+}
+
 pub fn get_dcdt_attribute_prop(attr: &syn::Attribute) -> Option<DcdtAttribute> {
     get_attribute_with_one_type_arg(attr, PROP_DCDT_ATTRIBUTE)
 }
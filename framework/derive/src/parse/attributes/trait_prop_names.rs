@@ -1,3 +1,4 @@
 pub(super) static PROP_ONLY_OWNER: &str = "only_owner";
 pub(super) static PROP_ADMIN_OWNER: &str = "only_admin";
+pub(super) static PROP_WHEN_NOT_PAUSED: &str = "when_not_paused";
 pub(super) static PROP_DCDT_ATTRIBUTE: &str = "dcdt_attribute";
@@ -5,8 +5,9 @@ use crate::model::{
 
 use super::{
     attributes::{
-        is_allow_multiple_var_args, is_callback_raw, is_init, is_only_admin, is_only_owner,
-        is_only_user_account, is_upgrade, CallbackAttribute, EndpointAttribute,
+        is_allow_multiple_var_args, is_allow_when_paused, is_callback_raw, is_init,
+        is_non_reentrant, is_only_admin, is_only_owner, is_only_user_account, is_upgrade,
+        is_when_not_paused, only_role_name, CallbackAttribute, EndpointAttribute,
         ExternalViewAttribute, LabelAttribute, OutputNameAttribute, PromisesCallbackAttribute,
         ViewAttribute,
     },
@@ -88,6 +89,22 @@ pub fn process_only_admin_attribute(
     is_only_admin
 }
 
+pub fn process_only_role_attribute(
+    attr: &syn::Attribute,
+    pass_1_data: &mut MethodAttributesPass1,
+) -> bool {
+    if let Some(role_name) = only_role_name(attr) {
+        assert!(
+            pass_1_data.only_role.is_none(),
+            "only one `only_role` attribute allowed per method"
+        );
+        pass_1_data.only_role = Some(role_name);
+        true
+    } else {
+        false
+    }
+}
+
 pub fn process_only_user_account_attribute(
     attr: &syn::Attribute,
     pass_1_data: &mut MethodAttributesPass1,
@@ -99,6 +116,39 @@ pub fn process_only_user_account_attribute(
     is_only_user_account
 }
 
+pub fn process_when_not_paused_attribute(
+    attr: &syn::Attribute,
+    pass_1_data: &mut MethodAttributesPass1,
+) -> bool {
+    let is_when_not_paused = is_when_not_paused(attr);
+    if is_when_not_paused {
+        pass_1_data.when_not_paused = true;
+    }
+    is_when_not_paused
+}
+
+pub fn process_allow_when_paused_attribute(
+    attr: &syn::Attribute,
+    pass_1_data: &mut MethodAttributesPass1,
+) -> bool {
+    let is_allow_when_paused = is_allow_when_paused(attr);
+    if is_allow_when_paused {
+        pass_1_data.allow_when_paused = true;
+    }
+    is_allow_when_paused
+}
+
+pub fn process_non_reentrant_attribute(
+    attr: &syn::Attribute,
+    pass_1_data: &mut MethodAttributesPass1,
+) -> bool {
+    let is_non_reentrant = is_non_reentrant(attr);
+    if is_non_reentrant {
+        pass_1_data.non_reentrant = true;
+    }
+    is_non_reentrant
+}
+
 pub fn process_endpoint_attribute(
     attr: &syn::Attribute,
     pass_1_data: &MethodAttributesPass1,
@@ -116,7 +166,11 @@ pub fn process_endpoint_attribute(
                 payable: pass_1_data.payable.clone(),
                 only_owner: pass_1_data.only_owner,
                 only_admin: pass_1_data.only_admin,
+                only_role: pass_1_data.only_role.clone(),
                 only_user_account: pass_1_data.only_user_account,
+                when_not_paused: pass_1_data.when_not_paused
+                    || (pass_1_data.trait_when_not_paused && !pass_1_data.allow_when_paused),
+                non_reentrant: pass_1_data.non_reentrant,
                 mutability: EndpointMutabilityMetadata::Mutable,
                 allow_multiple_var_args: pass_1_data.allow_multiple_var_args,
             });
@@ -141,7 +195,10 @@ pub fn process_view_attribute(
                 payable: pass_1_data.payable.clone(),
                 only_owner: pass_1_data.only_owner,
                 only_admin: pass_1_data.only_admin,
+                only_role: pass_1_data.only_role.clone(),
                 only_user_account: pass_1_data.only_user_account,
+                when_not_paused: pass_1_data.when_not_paused,
+                non_reentrant: pass_1_data.non_reentrant,
                 mutability: EndpointMutabilityMetadata::Readonly,
                 allow_multiple_var_args: pass_1_data.allow_multiple_var_args,
             });
@@ -166,7 +223,10 @@ pub fn process_external_view_attribute(
                 payable: pass_1_data.payable.clone(),
                 only_owner: pass_1_data.only_owner,
                 only_admin: pass_1_data.only_admin,
+                only_role: pass_1_data.only_role.clone(),
                 only_user_account: pass_1_data.only_user_account,
+                when_not_paused: pass_1_data.when_not_paused,
+                non_reentrant: pass_1_data.non_reentrant,
                 mutability: EndpointMutabilityMetadata::Readonly,
                 allow_multiple_var_args: pass_1_data.allow_multiple_var_args,
             });
@@ -184,7 +244,11 @@ pub fn process_callback_raw_attribute(attr: &syn::Attribute, method: &mut Method
     }
 }
 
-pub fn process_callback_attribute(attr: &syn::Attribute, method: &mut Method) -> bool {
+pub fn process_callback_attribute(
+    attr: &syn::Attribute,
+    pass_1_data: &MethodAttributesPass1,
+    method: &mut Method,
+) -> bool {
     CallbackAttribute::parse(attr)
         .map(|callback_attr| {
             check_single_role(&*method);
@@ -195,12 +259,17 @@ pub fn process_callback_attribute(attr: &syn::Attribute, method: &mut Method) ->
             method.public_role = PublicRole::Callback(CallbackMetadata {
                 callback_name: callback_ident,
                 allow_multiple_var_args: method.is_allow_multiple_var_args(),
+                non_reentrant: pass_1_data.non_reentrant,
             });
         })
         .is_some()
 }
 
-pub fn process_promises_callback_attribute(attr: &syn::Attribute, method: &mut Method) -> bool {
+pub fn process_promises_callback_attribute(
+    attr: &syn::Attribute,
+    pass_1_data: &MethodAttributesPass1,
+    method: &mut Method,
+) -> bool {
     PromisesCallbackAttribute::parse(attr)
         .map(|callback_attr| {
             check_single_role(&*method);
@@ -211,6 +280,7 @@ pub fn process_promises_callback_attribute(attr: &syn::Attribute, method: &mut M
             method.public_role = PublicRole::CallbackPromise(CallbackMetadata {
                 callback_name: callback_ident,
                 allow_multiple_var_args: method.is_allow_multiple_var_args(),
+                non_reentrant: pass_1_data.non_reentrant,
             });
         })
         .is_some()
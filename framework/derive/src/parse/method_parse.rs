@@ -1,4 +1,7 @@
-use crate::model::{Method, MethodImpl, MethodPayableMetadata, PublicRole, TraitProperties};
+use crate::model::{
+    EndpointMutabilityMetadata, Method, MethodImpl, MethodPayableMetadata, PublicRole,
+    TraitProperties,
+};
 
 use super::{
     attributes::extract_doc,
@@ -7,19 +10,26 @@ use super::{
         process_storage_get_attribute, process_storage_is_empty_attribute,
         process_storage_mapper_attribute, process_storage_set_attribute,
     },
-    extract_method_args, process_allow_multiple_var_args_attribute, process_callback_attribute,
+    extract_method_args, process_allow_multiple_var_args_attribute,
+    process_allow_when_paused_attribute, process_callback_attribute,
     process_callback_raw_attribute, process_endpoint_attribute, process_external_view_attribute,
-    process_init_attribute, process_label_names_attribute, process_only_admin_attribute,
-    process_only_owner_attribute, process_only_user_account_attribute,
-    process_output_names_attribute, process_payable_attribute, process_promises_callback_attribute,
-    process_upgrade_attribute, process_view_attribute,
+    process_init_attribute, process_label_names_attribute, process_non_reentrant_attribute,
+    process_only_admin_attribute, process_only_owner_attribute, process_only_role_attribute,
+    process_only_user_account_attribute, process_output_names_attribute, process_payable_attribute,
+    process_promises_callback_attribute, process_upgrade_attribute, process_view_attribute,
+    process_when_not_paused_attribute,
 };
 pub struct MethodAttributesPass1 {
     pub method_name: String,
     pub payable: MethodPayableMetadata,
     pub only_owner: bool,
     pub only_admin: bool,
+    pub only_role: Option<String>,
     pub only_user_account: bool,
+    pub when_not_paused: bool,
+    pub allow_when_paused: bool,
+    pub trait_when_not_paused: bool,
+    pub non_reentrant: bool,
     pub allow_multiple_var_args: bool,
 }
 
@@ -37,7 +47,12 @@ pub fn process_method(m: &syn::TraitItemFn, trait_attributes: &TraitProperties)
         payable: MethodPayableMetadata::NotPayable,
         only_owner: trait_attributes.only_owner,
         only_admin: trait_attributes.only_admin,
+        only_role: None,
         only_user_account: trait_attributes.only_user_account,
+        when_not_paused: false,
+        allow_when_paused: false,
+        trait_when_not_paused: trait_attributes.when_not_paused,
+        non_reentrant: false,
         allow_multiple_var_args: trait_attributes.allow_multiple_var_args,
     };
     let mut first_pass_unprocessed_attributes = Vec::new();
@@ -68,6 +83,8 @@ pub fn process_method(m: &syn::TraitItemFn, trait_attributes: &TraitProperties)
     );
 
     validate_method(&method);
+    validate_non_reentrant(&method, &first_pass_data);
+    validate_when_not_paused(&first_pass_data);
 
     method
 }
@@ -92,7 +109,11 @@ fn process_attribute_first_pass(
     process_payable_attribute(attr, first_pass_data)
         || process_only_owner_attribute(attr, first_pass_data)
         || process_only_admin_attribute(attr, first_pass_data)
+        || process_only_role_attribute(attr, first_pass_data)
         || process_only_user_account_attribute(attr, first_pass_data)
+        || process_when_not_paused_attribute(attr, first_pass_data)
+        || process_allow_when_paused_attribute(attr, first_pass_data)
+        || process_non_reentrant_attribute(attr, first_pass_data)
         || process_allow_multiple_var_args_attribute(attr, first_pass_data)
 }
 
@@ -120,8 +141,8 @@ fn process_attribute_second_pass(
         || process_view_attribute(attr, first_pass_data, method)
         || process_external_view_attribute(attr, first_pass_data, method)
         || process_callback_raw_attribute(attr, method)
-        || process_callback_attribute(attr, method)
-        || process_promises_callback_attribute(attr, method)
+        || process_callback_attribute(attr, first_pass_data, method)
+        || process_promises_callback_attribute(attr, first_pass_data, method)
         || process_event_attribute(attr, method)
         || process_proxy_attribute(attr, method)
         || process_storage_get_attribute(attr, method)
@@ -133,6 +154,36 @@ fn process_attribute_second_pass(
         || process_label_names_attribute(attr, method)
 }
 
+fn validate_non_reentrant(method: &Method, first_pass_data: &MethodAttributesPass1) {
+    if !first_pass_data.non_reentrant {
+        return;
+    }
+
+    match &method.public_role {
+        PublicRole::Endpoint(endpoint_metadata) => assert!(
+            matches!(
+                endpoint_metadata.mutability,
+                EndpointMutabilityMetadata::Mutable
+            ),
+            "`#[non_reentrant]` cannot be placed on views. Method '{}' is a view.",
+            &method.name.to_string()
+        ),
+        PublicRole::Callback(_) | PublicRole::CallbackPromise(_) => {},
+        _ => panic!(
+            "`#[non_reentrant]` can only be placed on endpoints and callbacks. Method '{}' is neither.",
+            &method.name.to_string()
+        ),
+    }
+}
+
+fn validate_when_not_paused(first_pass_data: &MethodAttributesPass1) {
+    assert!(
+        !(first_pass_data.when_not_paused && first_pass_data.allow_when_paused),
+        "`#[when_not_paused]` and `#[allow_when_paused]` cannot be used together. Method '{}' has both.",
+        &first_pass_data.method_name
+    )
+}
+
 fn validate_method(method: &Method) {
     assert!(
         matches!(
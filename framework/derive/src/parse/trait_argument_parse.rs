@@ -20,6 +20,7 @@ fn process_trait_attribute(
 ) -> bool {
     process_only_owner_argument(attr, trait_arg_metadata)
         || process_only_admin_argument(attr, trait_arg_metadata)
+        || process_when_not_paused_argument(attr, trait_arg_metadata)
         || process_dcdt_attribute_argument(attr, trait_arg_metadata)
 }
 
@@ -39,6 +40,17 @@ fn process_only_admin_argument(attr: &syn::Attribute, arg_metadata: &mut TraitPr
     has_attr
 }
 
+fn process_when_not_paused_argument(
+    attr: &syn::Attribute,
+    arg_metadata: &mut TraitProperties,
+) -> bool {
+    let has_attr = is_when_not_paused_prop(attr);
+    if has_attr {
+        arg_metadata.when_not_paused = true;
+    }
+    has_attr
+}
+
 fn process_dcdt_attribute_argument(
     attr: &syn::Attribute,
     arg_metadata: &mut TraitProperties,
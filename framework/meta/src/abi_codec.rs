@@ -0,0 +1,12 @@
+mod abi_codec_error;
+mod abi_type;
+mod abi_value;
+mod abi_value_decode;
+mod abi_value_encode;
+mod abi_value_from_json;
+mod contract_abi_codec;
+
+pub use abi_codec_error::AbiCodecError;
+pub use abi_type::AbiType;
+pub use abi_value::AbiValue;
+pub use contract_abi_codec::{AbiCodec, DecodedEvent};
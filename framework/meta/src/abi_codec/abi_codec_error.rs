@@ -0,0 +1,37 @@
+use std::fmt::Display;
+
+use dharitri_sc::codec::{DecodeError, EncodeError};
+
+/// Error produced when converting values based on the ABI alone.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AbiCodecError(pub String);
+
+impl AbiCodecError {
+    pub fn new(message: impl Into<String>) -> Self {
+        AbiCodecError(message.into())
+    }
+
+    pub fn message(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl Display for AbiCodecError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+impl std::error::Error for AbiCodecError {}
+
+impl From<DecodeError> for AbiCodecError {
+    fn from(err: DecodeError) -> Self {
+        AbiCodecError::new(err.message_str())
+    }
+}
+
+impl From<EncodeError> for AbiCodecError {
+    fn from(err: EncodeError) -> Self {
+        AbiCodecError::new(err.message_str())
+    }
+}
@@ -0,0 +1,184 @@
+use super::AbiCodecError;
+
+/// The structure of a type, as described by its ABI type name (e.g. `List<tuple<u32,Option<bytes>>>`).
+///
+/// Types that are not built into the framework are kept by name,
+/// and resolved using the type descriptions in the contract ABI.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AbiType {
+    Bool,
+    U8,
+    U16,
+    U32,
+    U64,
+    I8,
+    I16,
+    I32,
+    I64,
+    NonZeroUsize,
+    BigUint,
+    BigInt,
+    /// Arbitrary length bytes, e.g. `ManagedBuffer`.
+    Bytes,
+    /// Arbitrary length UTF-8 text, e.g. `String`, `TokenIdentifier`.
+    Text,
+    /// Bytes of a fixed length, e.g. `Address` (32), `CodeMetadata` (2).
+    FixedBytes(usize),
+    Option(Box<AbiType>),
+    List(Box<AbiType>),
+    Array(usize, Box<AbiType>),
+    Tuple(Vec<AbiType>),
+    Multi(Vec<AbiType>),
+    Variadic(Box<AbiType>),
+    CountedVariadic(Box<AbiType>),
+    Optional(Box<AbiType>),
+    /// A struct or enum, described in the contract ABI.
+    Custom(String),
+}
+
+impl AbiType {
+    pub fn parse(type_name: &str) -> Result<Self, AbiCodecError> {
+        let type_name = type_name.trim();
+        let Some(generic_start) = type_name.find('<') else {
+            return Ok(Self::parse_simple(type_name));
+        };
+        if !type_name.ends_with('>') {
+            return Err(AbiCodecError::new(format!(
+                "invalid type name: {type_name}"
+            )));
+        }
+
+        let generic_name = &type_name[..generic_start];
+        let type_args = split_type_args(&type_name[generic_start + 1..type_name.len() - 1])?
+            .into_iter()
+            .map(AbiType::parse)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        match generic_name {
+            "Option" => Ok(AbiType::Option(single_type_arg(type_name, type_args)?)),
+            "List" => Ok(AbiType::List(single_type_arg(type_name, type_args)?)),
+            "tuple" => Ok(AbiType::Tuple(type_args)),
+            "multi" => Ok(AbiType::Multi(type_args)),
+            "variadic" => Ok(AbiType::Variadic(single_type_arg(type_name, type_args)?)),
+            "counted-variadic" => Ok(AbiType::CountedVariadic(single_type_arg(
+                type_name, type_args,
+            )?)),
+            "optional" => Ok(AbiType::Optional(single_type_arg(type_name, type_args)?)),
+            _ => {
+                if let Some(Ok(length)) = generic_name.strip_prefix("array").map(str::parse) {
+                    Ok(AbiType::Array(
+                        length,
+                        single_type_arg(type_name, type_args)?,
+                    ))
+                } else {
+                    Ok(AbiType::Custom(type_name.to_string()))
+                }
+            },
+        }
+    }
+
+    fn parse_simple(type_name: &str) -> Self {
+        match type_name {
+            "bool" => AbiType::Bool,
+            "u8" => AbiType::U8,
+            "u16" => AbiType::U16,
+            "u32" | "usize" => AbiType::U32,
+            "u64" => AbiType::U64,
+            "i8" => AbiType::I8,
+            "i16" => AbiType::I16,
+            "i32" | "isize" => AbiType::I32,
+            "i64" => AbiType::I64,
+            "NonZeroUsize" => AbiType::NonZeroUsize,
+            "BigUint" => AbiType::BigUint,
+            "BigInt" => AbiType::BigInt,
+            "bytes" => AbiType::Bytes,
+            "utf-8 string" | "TokenIdentifier" | "RewaOrDcdtTokenIdentifier" => AbiType::Text,
+            "Address" | "H256" => AbiType::FixedBytes(32),
+            "CodeMetadata" => AbiType::FixedBytes(2),
+            _ => AbiType::Custom(type_name.to_string()),
+        }
+    }
+
+    /// Multi-value types can only appear at the top level of endpoint arguments and results,
+    /// or as part of other multi-values.
+    pub fn is_multi(&self) -> bool {
+        matches!(
+            self,
+            AbiType::Multi(_)
+                | AbiType::Variadic(_)
+                | AbiType::CountedVariadic(_)
+                | AbiType::Optional(_)
+        )
+    }
+}
+
+fn single_type_arg(
+    type_name: &str,
+    mut type_args: Vec<AbiType>,
+) -> Result<Box<AbiType>, AbiCodecError> {
+    if type_args.len() != 1 {
+        return Err(AbiCodecError::new(format!(
+            "expected exactly one type argument: {type_name}"
+        )));
+    }
+    Ok(Box::new(type_args.remove(0)))
+}
+
+/// Splits by the commas that are not nested in other type arguments.
+fn split_type_args(type_args: &str) -> Result<Vec<&str>, AbiCodecError> {
+    let mut result = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (index, c) in type_args.char_indices() {
+        match c {
+            '<' => depth += 1,
+            '>' => {
+                depth = depth.checked_sub(1).ok_or_else(|| {
+                    AbiCodecError::new(format!("unbalanced type arguments: {type_args}"))
+                })?;
+            },
+            ',' if depth == 0 => {
+                result.push(&type_args[start..index]);
+                start = index + 1;
+            },
+            _ => {},
+        }
+    }
+    if depth != 0 {
+        return Err(AbiCodecError::new(format!(
+            "unbalanced type arguments: {type_args}"
+        )));
+    }
+    result.push(&type_args[start..]);
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_abi_type_test() {
+        assert_eq!(AbiType::parse("usize"), Ok(AbiType::U32));
+        assert_eq!(
+            AbiType::parse("List<tuple<u32,Option<bytes>>>"),
+            Ok(AbiType::List(Box::new(AbiType::Tuple(vec![
+                AbiType::U32,
+                AbiType::Option(Box::new(AbiType::Bytes)),
+            ]))))
+        );
+        assert_eq!(
+            AbiType::parse("variadic<multi<Address,array32<u8>>>"),
+            Ok(AbiType::Variadic(Box::new(AbiType::Multi(vec![
+                AbiType::FixedBytes(32),
+                AbiType::Array(32, Box::new(AbiType::U8)),
+            ]))))
+        );
+        assert_eq!(
+            AbiType::parse("MyStruct"),
+            Ok(AbiType::Custom("MyStruct".to_string()))
+        );
+        assert!(AbiType::parse("List<u8,u16>").is_err());
+        assert!(AbiType::parse("List<Option<u8>").is_err());
+    }
+}
@@ -0,0 +1,92 @@
+use dharitri_sc::codec::num_bigint::BigInt;
+use serde::{ser::SerializeMap, Serialize, Serializer};
+
+/// Largest integer that can be represented exactly in a JSON number by most consumers (e.g. JavaScript).
+const MAX_SAFE_JSON_INTEGER: i64 = (1 << 53) - 1;
+
+/// A value of any type described in a contract ABI, not backed by a compiled Rust type.
+///
+/// Values are produced from JSON and decoded from raw bytes by the [`AbiCodec`](super::AbiCodec),
+/// based on the ABI type descriptions.
+///
+/// Serializes to JSON as follows:
+/// - numbers as JSON numbers if they are safe integers, as decimal strings otherwise;
+/// - bytes as hex strings;
+/// - `None` as `null`;
+/// - lists, arrays, tuples and multi-values as JSON arrays;
+/// - structs as JSON objects;
+/// - enum variants as their name if they have no fields, as `{ "<name>": { <fields> } }` otherwise.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AbiValue {
+    Bool(bool),
+    Number(BigInt),
+    Bytes(Vec<u8>),
+    Text(String),
+    Option(Option<Box<AbiValue>>),
+    List(Vec<AbiValue>),
+    Struct(Vec<(String, AbiValue)>),
+    Enum {
+        variant: String,
+        fields: Vec<(String, AbiValue)>,
+    },
+}
+
+impl AbiValue {
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).expect("AbiValue JSON serialization cannot fail")
+    }
+}
+
+impl Serialize for AbiValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            AbiValue::Bool(value) => serializer.serialize_bool(*value),
+            AbiValue::Number(value) => match i64::try_from(value) {
+                Ok(small) if small.abs() <= MAX_SAFE_JSON_INTEGER => {
+                    serializer.serialize_i64(small)
+                },
+                _ => serializer.serialize_str(&value.to_string()),
+            },
+            AbiValue::Bytes(bytes) => serializer.serialize_str(&hex::encode(bytes)),
+            AbiValue::Text(text) => serializer.serialize_str(text),
+            AbiValue::Option(None) => serializer.serialize_none(),
+            AbiValue::Option(Some(value)) => value.serialize(serializer),
+            AbiValue::List(items) => serializer.collect_seq(items),
+            AbiValue::Struct(fields) => serialize_fields(fields, serializer),
+            AbiValue::Enum { variant, fields } => {
+                if fields.is_empty() {
+                    serializer.serialize_str(variant)
+                } else {
+                    let mut map = serializer.serialize_map(Some(1))?;
+                    map.serialize_entry(variant, &SerializedFields(fields))?;
+                    map.end()
+                }
+            },
+        }
+    }
+}
+
+struct SerializedFields<'a>(&'a [(String, AbiValue)]);
+
+impl Serialize for SerializedFields<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_fields(self.0, serializer)
+    }
+}
+
+fn serialize_fields<S>(fields: &[(String, AbiValue)], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let mut map = serializer.serialize_map(Some(fields.len()))?;
+    for (name, value) in fields {
+        map.serialize_entry(name, value)?;
+    }
+    map.end()
+}
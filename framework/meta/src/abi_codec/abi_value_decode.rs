@@ -0,0 +1,314 @@
+use std::num::NonZeroUsize;
+
+use dharitri_sc::codec::{
+    num_bigint::{BigInt, BigUint},
+    DefaultErrorHandler, NestedDecode, NestedDecodeInput, TopDecode,
+};
+
+use crate::abi_json::{
+    EnumVariantDescriptionJson, StructFieldDescriptionJson, TYPE_DESCRIPTION_JSON_TYPE_ENUM,
+    TYPE_DESCRIPTION_JSON_TYPE_EXPLICIT_ENUM, TYPE_DESCRIPTION_JSON_TYPE_STRUCT,
+};
+
+use super::{abi_value_encode::multi_not_allowed, AbiCodec, AbiCodecError, AbiType, AbiValue};
+
+impl AbiCodec<'_> {
+    /// Decodes a multi-value from as many raw arguments as needed, consuming them from the input.
+    /// Regular values are top-decoded from a single raw argument.
+    pub fn multi_decode(
+        &self,
+        abi_type: &AbiType,
+        input: &mut &[Vec<u8>],
+    ) -> Result<AbiValue, AbiCodecError> {
+        match abi_type {
+            AbiType::Multi(item_types) => {
+                let items = item_types
+                    .iter()
+                    .map(|item_type| self.multi_decode(item_type, input))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(AbiValue::List(items))
+            },
+            AbiType::Variadic(item_type) => {
+                let mut items = Vec::new();
+                while !input.is_empty() {
+                    items.push(self.multi_decode(item_type, input)?);
+                }
+                Ok(AbiValue::List(items))
+            },
+            AbiType::CountedVariadic(item_type) => {
+                let count = usize::top_decode(next_raw_arg(input)?.clone())?;
+                let items = (0..count)
+                    .map(|_| self.multi_decode(item_type, input))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(AbiValue::List(items))
+            },
+            AbiType::Optional(item_type) => {
+                if input.is_empty() {
+                    Ok(AbiValue::Option(None))
+                } else {
+                    let item = self.multi_decode(item_type, input)?;
+                    Ok(AbiValue::Option(Some(Box::new(item))))
+                }
+            },
+            _ => self.top_decode(abi_type, next_raw_arg(input)?),
+        }
+    }
+
+    pub fn top_decode(&self, abi_type: &AbiType, bytes: &[u8]) -> Result<AbiValue, AbiCodecError> {
+        match abi_type {
+            AbiType::Bool => Ok(AbiValue::Bool(bool::top_decode(bytes.to_vec())?)),
+            AbiType::U8 => top_decode_number::<u8>(bytes),
+            AbiType::U16 => top_decode_number::<u16>(bytes),
+            AbiType::U32 => top_decode_number::<u32>(bytes),
+            AbiType::U64 => top_decode_number::<u64>(bytes),
+            AbiType::I8 => top_decode_number::<i8>(bytes),
+            AbiType::I16 => top_decode_number::<i16>(bytes),
+            AbiType::I32 => top_decode_number::<i32>(bytes),
+            AbiType::I64 => top_decode_number::<i64>(bytes),
+            AbiType::NonZeroUsize => {
+                let value = NonZeroUsize::top_decode(bytes.to_vec())?;
+                Ok(AbiValue::Number(BigInt::from(value.get())))
+            },
+            AbiType::BigUint => top_decode_number::<BigUint>(bytes),
+            AbiType::BigInt => top_decode_number::<BigInt>(bytes),
+            AbiType::Bytes => Ok(AbiValue::Bytes(bytes.to_vec())),
+            AbiType::Text => Ok(AbiValue::Text(String::top_decode(bytes.to_vec())?)),
+            AbiType::FixedBytes(length) => {
+                if bytes.len() != *length {
+                    return Err(AbiCodecError::new(format!(
+                        "expected {length} bytes, got {}",
+                        bytes.len()
+                    )));
+                }
+                Ok(AbiValue::Bytes(bytes.to_vec()))
+            },
+            AbiType::Option(_) => {
+                // "0x00" is also accepted as `None`, like in the codec
+                if bytes.is_empty() {
+                    Ok(AbiValue::Option(None))
+                } else {
+                    self.nested_decode_all(abi_type, bytes)
+                }
+            },
+            AbiType::List(item_type) => {
+                let mut input = bytes;
+                let mut items = Vec::new();
+                while !input.is_empty() {
+                    items.push(self.nested_decode(item_type, &mut input)?);
+                }
+                Ok(AbiValue::List(items))
+            },
+            AbiType::Array(..) | AbiType::Tuple(_) => self.nested_decode_all(abi_type, bytes),
+            AbiType::Custom(type_name) => self.custom_top_decode(type_name, bytes),
+            _ => Err(multi_not_allowed(abi_type)),
+        }
+    }
+
+    pub fn nested_decode(
+        &self,
+        abi_type: &AbiType,
+        input: &mut &[u8],
+    ) -> Result<AbiValue, AbiCodecError> {
+        match abi_type {
+            AbiType::Bool => Ok(AbiValue::Bool(bool::dep_decode(input)?)),
+            AbiType::U8 => nested_decode_number::<u8>(input),
+            AbiType::U16 => nested_decode_number::<u16>(input),
+            AbiType::U32 => nested_decode_number::<u32>(input),
+            AbiType::U64 => nested_decode_number::<u64>(input),
+            AbiType::I8 => nested_decode_number::<i8>(input),
+            AbiType::I16 => nested_decode_number::<i16>(input),
+            AbiType::I32 => nested_decode_number::<i32>(input),
+            AbiType::I64 => nested_decode_number::<i64>(input),
+            AbiType::NonZeroUsize => {
+                let value = NonZeroUsize::dep_decode(input)?;
+                Ok(AbiValue::Number(BigInt::from(value.get())))
+            },
+            AbiType::BigUint => nested_decode_number::<BigUint>(input),
+            AbiType::BigInt => nested_decode_number::<BigInt>(input),
+            AbiType::Bytes => Ok(AbiValue::Bytes(Vec::<u8>::dep_decode(input)?)),
+            AbiType::Text => Ok(AbiValue::Text(String::dep_decode(input)?)),
+            AbiType::FixedBytes(length) => {
+                let mut bytes = vec![0u8; *length];
+                input.read_into(&mut bytes[..], DefaultErrorHandler)?;
+                Ok(AbiValue::Bytes(bytes))
+            },
+            AbiType::Option(item_type) => match u8::dep_decode(input)? {
+                0 => Ok(AbiValue::Option(None)),
+                1 => {
+                    let item = self.nested_decode(item_type, input)?;
+                    Ok(AbiValue::Option(Some(Box::new(item))))
+                },
+                _ => Err(AbiCodecError::new("invalid option discriminant")),
+            },
+            AbiType::List(item_type) => {
+                let length = usize::dep_decode(input)?;
+                let items = (0..length)
+                    .map(|_| self.nested_decode(item_type, input))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(AbiValue::List(items))
+            },
+            AbiType::Array(length, item_type) => {
+                let items = (0..*length)
+                    .map(|_| self.nested_decode(item_type, input))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(AbiValue::List(items))
+            },
+            AbiType::Tuple(item_types) => {
+                let items = item_types
+                    .iter()
+                    .map(|item_type| self.nested_decode(item_type, input))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(AbiValue::List(items))
+            },
+            AbiType::Custom(type_name) => self.custom_nested_decode(type_name, input),
+            _ => Err(multi_not_allowed(abi_type)),
+        }
+    }
+
+    /// Nested-decodes a value that must occupy the entire input.
+    fn nested_decode_all(
+        &self,
+        abi_type: &AbiType,
+        bytes: &[u8],
+    ) -> Result<AbiValue, AbiCodecError> {
+        let mut input = bytes;
+        let value = self.nested_decode(abi_type, &mut input)?;
+        if !input.is_empty() {
+            return Err(AbiCodecError::new("input too long"));
+        }
+        Ok(value)
+    }
+
+    fn custom_top_decode(&self, type_name: &str, bytes: &[u8]) -> Result<AbiValue, AbiCodecError> {
+        let type_description = self.type_description(type_name)?;
+        match type_description.content_type.as_str() {
+            TYPE_DESCRIPTION_JSON_TYPE_ENUM => {
+                let variants = &type_description.variants;
+                if variants.iter().all(|variant| variant.fields.is_empty()) {
+                    // fieldless enums are top-encoded as their discriminant alone
+                    let discriminant = u8::top_decode(bytes.to_vec())?;
+                    let variant = find_variant(type_name, variants, discriminant)?;
+                    return Ok(AbiValue::Enum {
+                        variant: variant.name.clone(),
+                        fields: Vec::new(),
+                    });
+                }
+                match variants.first() {
+                    Some(first_variant) if bytes.is_empty() && first_variant.fields.is_empty() => {
+                        Ok(AbiValue::Enum {
+                            variant: first_variant.name.clone(),
+                            fields: Vec::new(),
+                        })
+                    },
+                    _ => self.nested_decode_all(&AbiType::Custom(type_name.to_string()), bytes),
+                }
+            },
+            TYPE_DESCRIPTION_JSON_TYPE_EXPLICIT_ENUM => {
+                let variant_name = String::top_decode(bytes.to_vec())?;
+                check_explicit_variant(type_name, &type_description.variants, &variant_name)?;
+                Ok(AbiValue::Text(variant_name))
+            },
+            _ => self.nested_decode_all(&AbiType::Custom(type_name.to_string()), bytes),
+        }
+    }
+
+    fn custom_nested_decode(
+        &self,
+        type_name: &str,
+        input: &mut &[u8],
+    ) -> Result<AbiValue, AbiCodecError> {
+        let type_description = self.type_description(type_name)?;
+        match type_description.content_type.as_str() {
+            TYPE_DESCRIPTION_JSON_TYPE_STRUCT => {
+                let fields = self.nested_decode_fields(&type_description.fields, input)?;
+                Ok(AbiValue::Struct(fields))
+            },
+            TYPE_DESCRIPTION_JSON_TYPE_ENUM => {
+                let discriminant = u8::dep_decode(input)?;
+                let variant = find_variant(type_name, &type_description.variants, discriminant)?;
+                let fields = self.nested_decode_fields(&variant.fields, input)?;
+                Ok(AbiValue::Enum {
+                    variant: variant.name.clone(),
+                    fields,
+                })
+            },
+            TYPE_DESCRIPTION_JSON_TYPE_EXPLICIT_ENUM => {
+                let variant_name = String::dep_decode(input)?;
+                check_explicit_variant(type_name, &type_description.variants, &variant_name)?;
+                Ok(AbiValue::Text(variant_name))
+            },
+            _ => Err(AbiCodecError::new(format!(
+                "type not described in the ABI: {type_name}"
+            ))),
+        }
+    }
+
+    fn nested_decode_fields(
+        &self,
+        field_descriptions: &[StructFieldDescriptionJson],
+        input: &mut &[u8],
+    ) -> Result<Vec<(String, AbiValue)>, AbiCodecError> {
+        field_descriptions
+            .iter()
+            .map(|field| {
+                let value = self.nested_decode(&AbiType::parse(&field.field_type)?, input)?;
+                Ok((field.name.clone(), value))
+            })
+            .collect()
+    }
+}
+
+fn next_raw_arg<'r>(input: &mut &'r [Vec<u8>]) -> Result<&'r Vec<u8>, AbiCodecError> {
+    let (first, rest) = input
+        .split_first()
+        .ok_or_else(|| AbiCodecError::new("too few arguments"))?;
+    *input = rest;
+    Ok(first)
+}
+
+fn top_decode_number<T>(bytes: &[u8]) -> Result<AbiValue, AbiCodecError>
+where
+    T: TopDecode,
+    BigInt: From<T>,
+{
+    Ok(AbiValue::Number(BigInt::from(T::top_decode(
+        bytes.to_vec(),
+    )?)))
+}
+
+fn nested_decode_number<T>(input: &mut &[u8]) -> Result<AbiValue, AbiCodecError>
+where
+    T: NestedDecode,
+    BigInt: From<T>,
+{
+    Ok(AbiValue::Number(BigInt::from(T::dep_decode(input)?)))
+}
+
+fn find_variant<'v>(
+    type_name: &str,
+    variants: &'v [EnumVariantDescriptionJson],
+    discriminant: u8,
+) -> Result<&'v EnumVariantDescriptionJson, AbiCodecError> {
+    variants
+        .iter()
+        .find(|variant| variant.discriminant.unwrap_or_default() == discriminant as usize)
+        .ok_or_else(|| {
+            AbiCodecError::new(format!(
+                "invalid discriminant for {type_name}: {discriminant}"
+            ))
+        })
+}
+
+fn check_explicit_variant(
+    type_name: &str,
+    variants: &[EnumVariantDescriptionJson],
+    variant_name: &str,
+) -> Result<(), AbiCodecError> {
+    if variants.iter().any(|variant| variant.name == variant_name) {
+        Ok(())
+    } else {
+        Err(AbiCodecError::new(format!(
+            "unknown variant of {type_name}: {variant_name}"
+        )))
+    }
+}
@@ -0,0 +1,376 @@
+use std::num::NonZeroUsize;
+
+use dharitri_sc::codec::{
+    num_bigint::{BigInt, BigUint},
+    top_encode_to_vec_u8, NestedEncode, TopEncode,
+};
+
+use crate::abi_json::{
+    EnumVariantDescriptionJson, StructFieldDescriptionJson, TYPE_DESCRIPTION_JSON_TYPE_ENUM,
+    TYPE_DESCRIPTION_JSON_TYPE_EXPLICIT_ENUM, TYPE_DESCRIPTION_JSON_TYPE_STRUCT,
+};
+
+use super::{AbiCodec, AbiCodecError, AbiType, AbiValue};
+
+impl AbiCodec<'_> {
+    /// Encodes a multi-value into as many raw arguments as needed.
+    /// Regular values are top-encoded into a single raw argument.
+    pub fn multi_encode(
+        &self,
+        abi_type: &AbiType,
+        value: &AbiValue,
+        output: &mut Vec<Vec<u8>>,
+    ) -> Result<(), AbiCodecError> {
+        match abi_type {
+            AbiType::Multi(item_types) => {
+                let items = expect_list(value, Some(item_types.len()))?;
+                for (item_type, item) in item_types.iter().zip(items) {
+                    self.multi_encode(item_type, item, output)?;
+                }
+                Ok(())
+            },
+            AbiType::Variadic(item_type) => {
+                for item in expect_list(value, None)? {
+                    self.multi_encode(item_type, item, output)?;
+                }
+                Ok(())
+            },
+            AbiType::CountedVariadic(item_type) => {
+                let items = expect_list(value, None)?;
+                output.push(top_encode_to_vec_u8(&items.len())?);
+                for item in items {
+                    self.multi_encode(item_type, item, output)?;
+                }
+                Ok(())
+            },
+            AbiType::Optional(item_type) => match expect_option(value)? {
+                Some(item) => self.multi_encode(item_type, item, output),
+                None => Ok(()),
+            },
+            _ => {
+                output.push(self.top_encode(abi_type, value)?);
+                Ok(())
+            },
+        }
+    }
+
+    pub fn top_encode(
+        &self,
+        abi_type: &AbiType,
+        value: &AbiValue,
+    ) -> Result<Vec<u8>, AbiCodecError> {
+        match abi_type {
+            AbiType::Bool => codec_top_encode(&expect_bool(value)?),
+            AbiType::U8 => codec_top_encode(&expect_number::<u8>(value)?),
+            AbiType::U16 => codec_top_encode(&expect_number::<u16>(value)?),
+            AbiType::U32 => codec_top_encode(&expect_number::<u32>(value)?),
+            AbiType::U64 => codec_top_encode(&expect_number::<u64>(value)?),
+            AbiType::I8 => codec_top_encode(&expect_number::<i8>(value)?),
+            AbiType::I16 => codec_top_encode(&expect_number::<i16>(value)?),
+            AbiType::I32 => codec_top_encode(&expect_number::<i32>(value)?),
+            AbiType::I64 => codec_top_encode(&expect_number::<i64>(value)?),
+            AbiType::NonZeroUsize => codec_top_encode(&expect_non_zero_usize(value)?),
+            AbiType::BigUint => codec_top_encode(&expect_big_uint(value)?),
+            AbiType::BigInt => codec_top_encode(&expect_big_int(value)?),
+            AbiType::Bytes => Ok(expect_bytes(value, None)?.to_vec()),
+            AbiType::Text => Ok(expect_text(value)?.as_bytes().to_vec()),
+            AbiType::FixedBytes(length) => Ok(expect_bytes(value, Some(*length))?.to_vec()),
+            AbiType::Option(item_type) => {
+                let mut result = Vec::new();
+                if let Some(item) = expect_option(value)? {
+                    result.push(1u8);
+                    self.nested_encode(item_type, item, &mut result)?;
+                }
+                Ok(result)
+            },
+            AbiType::List(item_type) => {
+                let mut result = Vec::new();
+                for item in expect_list(value, None)? {
+                    self.nested_encode(item_type, item, &mut result)?;
+                }
+                Ok(result)
+            },
+            AbiType::Array(..) | AbiType::Tuple(_) => {
+                let mut result = Vec::new();
+                self.nested_encode(abi_type, value, &mut result)?;
+                Ok(result)
+            },
+            AbiType::Custom(type_name) => self.custom_top_encode(type_name, value),
+            _ => Err(multi_not_allowed(abi_type)),
+        }
+    }
+
+    pub fn nested_encode(
+        &self,
+        abi_type: &AbiType,
+        value: &AbiValue,
+        dest: &mut Vec<u8>,
+    ) -> Result<(), AbiCodecError> {
+        match abi_type {
+            AbiType::Bool => codec_nested_encode(&expect_bool(value)?, dest),
+            AbiType::U8 => codec_nested_encode(&expect_number::<u8>(value)?, dest),
+            AbiType::U16 => codec_nested_encode(&expect_number::<u16>(value)?, dest),
+            AbiType::U32 => codec_nested_encode(&expect_number::<u32>(value)?, dest),
+            AbiType::U64 => codec_nested_encode(&expect_number::<u64>(value)?, dest),
+            AbiType::I8 => codec_nested_encode(&expect_number::<i8>(value)?, dest),
+            AbiType::I16 => codec_nested_encode(&expect_number::<i16>(value)?, dest),
+            AbiType::I32 => codec_nested_encode(&expect_number::<i32>(value)?, dest),
+            AbiType::I64 => codec_nested_encode(&expect_number::<i64>(value)?, dest),
+            AbiType::NonZeroUsize => codec_nested_encode(&expect_non_zero_usize(value)?, dest),
+            AbiType::BigUint => codec_nested_encode(&expect_big_uint(value)?, dest),
+            AbiType::BigInt => codec_nested_encode(&expect_big_int(value)?, dest),
+            AbiType::Bytes => codec_nested_encode(&expect_bytes(value, None)?, dest),
+            AbiType::Text => codec_nested_encode(&expect_text(value)?, dest),
+            AbiType::FixedBytes(length) => {
+                dest.extend_from_slice(expect_bytes(value, Some(*length))?);
+                Ok(())
+            },
+            AbiType::Option(item_type) => match expect_option(value)? {
+                Some(item) => {
+                    dest.push(1u8);
+                    self.nested_encode(item_type, item, dest)
+                },
+                None => {
+                    dest.push(0u8);
+                    Ok(())
+                },
+            },
+            AbiType::List(item_type) => {
+                let items = expect_list(value, None)?;
+                codec_nested_encode(&items.len(), dest)?;
+                for item in items {
+                    self.nested_encode(item_type, item, dest)?;
+                }
+                Ok(())
+            },
+            AbiType::Array(length, item_type) => {
+                for item in expect_list(value, Some(*length))? {
+                    self.nested_encode(item_type, item, dest)?;
+                }
+                Ok(())
+            },
+            AbiType::Tuple(item_types) => {
+                let items = expect_list(value, Some(item_types.len()))?;
+                for (item_type, item) in item_types.iter().zip(items) {
+                    self.nested_encode(item_type, item, dest)?;
+                }
+                Ok(())
+            },
+            AbiType::Custom(type_name) => self.custom_nested_encode(type_name, value, dest),
+            _ => Err(multi_not_allowed(abi_type)),
+        }
+    }
+
+    fn custom_top_encode(
+        &self,
+        type_name: &str,
+        value: &AbiValue,
+    ) -> Result<Vec<u8>, AbiCodecError> {
+        let type_description = self.type_description(type_name)?;
+        match type_description.content_type.as_str() {
+            TYPE_DESCRIPTION_JSON_TYPE_ENUM => {
+                // variants without fields are top-encoded as their discriminant alone
+                let (variant, fields) = expect_enum(type_name, &type_description.variants, value)?;
+                if variant.fields.is_empty() {
+                    codec_top_encode(&variant_discriminant(type_name, variant)?)
+                } else {
+                    let mut result = Vec::new();
+                    codec_nested_encode(&variant_discriminant(type_name, variant)?, &mut result)?;
+                    self.nested_encode_fields(&variant.fields, fields, &mut result)?;
+                    Ok(result)
+                }
+            },
+            TYPE_DESCRIPTION_JSON_TYPE_EXPLICIT_ENUM => Ok(expect_text(value)?.as_bytes().to_vec()),
+            _ => {
+                let mut result = Vec::new();
+                self.custom_nested_encode(type_name, value, &mut result)?;
+                Ok(result)
+            },
+        }
+    }
+
+    fn custom_nested_encode(
+        &self,
+        type_name: &str,
+        value: &AbiValue,
+        dest: &mut Vec<u8>,
+    ) -> Result<(), AbiCodecError> {
+        let type_description = self.type_description(type_name)?;
+        match type_description.content_type.as_str() {
+            TYPE_DESCRIPTION_JSON_TYPE_STRUCT => {
+                let AbiValue::Struct(fields) = value else {
+                    return Err(mismatch(type_name, value));
+                };
+                self.nested_encode_fields(&type_description.fields, fields, dest)
+            },
+            TYPE_DESCRIPTION_JSON_TYPE_ENUM => {
+                let (variant, fields) = expect_enum(type_name, &type_description.variants, value)?;
+                codec_nested_encode(&variant_discriminant(type_name, variant)?, dest)?;
+                self.nested_encode_fields(&variant.fields, fields, dest)
+            },
+            TYPE_DESCRIPTION_JSON_TYPE_EXPLICIT_ENUM => {
+                codec_nested_encode(&expect_text(value)?, dest)
+            },
+            _ => Err(AbiCodecError::new(format!(
+                "type not described in the ABI: {type_name}"
+            ))),
+        }
+    }
+
+    fn nested_encode_fields(
+        &self,
+        field_descriptions: &[StructFieldDescriptionJson],
+        fields: &[(String, AbiValue)],
+        dest: &mut Vec<u8>,
+    ) -> Result<(), AbiCodecError> {
+        for field_description in field_descriptions {
+            let (_, field_value) = fields
+                .iter()
+                .find(|(name, _)| name == &field_description.name)
+                .ok_or_else(|| {
+                    AbiCodecError::new(format!("missing field: {}", field_description.name))
+                })?;
+            self.nested_encode(
+                &AbiType::parse(&field_description.field_type)?,
+                field_value,
+                dest,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+fn codec_top_encode<T: TopEncode>(value: &T) -> Result<Vec<u8>, AbiCodecError> {
+    Ok(top_encode_to_vec_u8(value)?)
+}
+
+fn codec_nested_encode<T: NestedEncode>(
+    value: &T,
+    dest: &mut Vec<u8>,
+) -> Result<(), AbiCodecError> {
+    Ok(value.dep_encode(dest)?)
+}
+
+fn variant_discriminant(
+    type_name: &str,
+    variant: &EnumVariantDescriptionJson,
+) -> Result<u8, AbiCodecError> {
+    u8::try_from(variant.discriminant.unwrap_or_default()).map_err(|_| {
+        AbiCodecError::new(format!(
+            "discriminant of {type_name}::{} does not fit in a byte",
+            variant.name
+        ))
+    })
+}
+
+/// The variant description and the field values of an enum value.
+type EnumValueParts<'v> = (&'v EnumVariantDescriptionJson, &'v [(String, AbiValue)]);
+
+fn expect_enum<'v>(
+    type_name: &str,
+    variants: &'v [EnumVariantDescriptionJson],
+    value: &'v AbiValue,
+) -> Result<EnumValueParts<'v>, AbiCodecError> {
+    let AbiValue::Enum {
+        variant: variant_name,
+        fields,
+    } = value
+    else {
+        return Err(mismatch(type_name, value));
+    };
+    let variant = variants
+        .iter()
+        .find(|variant| &variant.name == variant_name)
+        .ok_or_else(|| {
+            AbiCodecError::new(format!("unknown variant of {type_name}: {variant_name}"))
+        })?;
+    Ok((variant, fields.as_slice()))
+}
+
+fn expect_bool(value: &AbiValue) -> Result<bool, AbiCodecError> {
+    match value {
+        AbiValue::Bool(b) => Ok(*b),
+        _ => Err(mismatch("bool", value)),
+    }
+}
+
+fn expect_big_int(value: &AbiValue) -> Result<BigInt, AbiCodecError> {
+    match value {
+        AbiValue::Number(number) => Ok(number.clone()),
+        _ => Err(mismatch("a number", value)),
+    }
+}
+
+fn expect_big_uint(value: &AbiValue) -> Result<BigUint, AbiCodecError> {
+    expect_big_int(value)?
+        .to_biguint()
+        .ok_or_else(|| mismatch("an unsigned number", value))
+}
+
+fn expect_number<T>(value: &AbiValue) -> Result<T, AbiCodecError>
+where
+    T: for<'b> TryFrom<&'b BigInt>,
+{
+    let number = expect_big_int(value)?;
+    T::try_from(&number).map_err(|_| {
+        AbiCodecError::new(format!(
+            "number out of range for {}: {number}",
+            std::any::type_name::<T>()
+        ))
+    })
+}
+
+fn expect_non_zero_usize(value: &AbiValue) -> Result<NonZeroUsize, AbiCodecError> {
+    NonZeroUsize::new(expect_number::<u32>(value)? as usize)
+        .ok_or_else(|| mismatch("a non-zero number", value))
+}
+
+fn expect_bytes(value: &AbiValue, length: Option<usize>) -> Result<&[u8], AbiCodecError> {
+    match value {
+        AbiValue::Bytes(bytes) => match length {
+            Some(length) if bytes.len() != length => Err(AbiCodecError::new(format!(
+                "expected {length} bytes, got {}",
+                bytes.len()
+            ))),
+            _ => Ok(bytes.as_slice()),
+        },
+        _ => Err(mismatch("bytes", value)),
+    }
+}
+
+fn expect_text(value: &AbiValue) -> Result<&str, AbiCodecError> {
+    match value {
+        AbiValue::Text(text) => Ok(text.as_str()),
+        _ => Err(mismatch("text", value)),
+    }
+}
+
+fn expect_option(value: &AbiValue) -> Result<Option<&AbiValue>, AbiCodecError> {
+    match value {
+        AbiValue::Option(item) => Ok(item.as_deref()),
+        _ => Err(mismatch("an option", value)),
+    }
+}
+
+fn expect_list(value: &AbiValue, length: Option<usize>) -> Result<&[AbiValue], AbiCodecError> {
+    match value {
+        AbiValue::List(items) => match length {
+            Some(length) if items.len() != length => Err(AbiCodecError::new(format!(
+                "expected {length} items, got {}",
+                items.len()
+            ))),
+            _ => Ok(items.as_slice()),
+        },
+        _ => Err(mismatch("a list", value)),
+    }
+}
+
+fn mismatch(expected: &str, value: &AbiValue) -> AbiCodecError {
+    AbiCodecError::new(format!("expected {expected}, got {}", value.to_json()))
+}
+
+pub(super) fn multi_not_allowed(abi_type: &AbiType) -> AbiCodecError {
+    AbiCodecError::new(format!(
+        "multi-value type can only be used at top level: {abi_type:?}"
+    ))
+}
@@ -0,0 +1,205 @@
+use dharitri_sc::codec::num_bigint::BigInt;
+use serde_json::Value as JsonValue;
+
+use crate::abi_json::{
+    StructFieldDescriptionJson, TYPE_DESCRIPTION_JSON_TYPE_ENUM,
+    TYPE_DESCRIPTION_JSON_TYPE_EXPLICIT_ENUM, TYPE_DESCRIPTION_JSON_TYPE_STRUCT,
+};
+
+use super::{AbiCodec, AbiCodecError, AbiType, AbiValue};
+
+impl AbiCodec<'_> {
+    /// Converts a JSON value to an [`AbiValue`] of the given type.
+    ///
+    /// Accepts the same representation that [`AbiValue`] serializes to.
+    /// Additionally, numbers can always be given either as JSON numbers, or as decimal strings.
+    pub fn value_from_json(
+        &self,
+        abi_type: &AbiType,
+        json: &JsonValue,
+    ) -> Result<AbiValue, AbiCodecError> {
+        match abi_type {
+            AbiType::Bool => json
+                .as_bool()
+                .map(AbiValue::Bool)
+                .ok_or_else(|| expected("a boolean", json)),
+            AbiType::U8
+            | AbiType::U16
+            | AbiType::U32
+            | AbiType::U64
+            | AbiType::I8
+            | AbiType::I16
+            | AbiType::I32
+            | AbiType::I64
+            | AbiType::NonZeroUsize
+            | AbiType::BigUint
+            | AbiType::BigInt => number_from_json(json).map(AbiValue::Number),
+            AbiType::Bytes | AbiType::FixedBytes(_) => {
+                let hex_str = json
+                    .as_str()
+                    .ok_or_else(|| expected("a hex string", json))?;
+                hex::decode(hex_str)
+                    .map(AbiValue::Bytes)
+                    .map_err(|_| expected("a hex string", json))
+            },
+            AbiType::Text => json
+                .as_str()
+                .map(|text| AbiValue::Text(text.to_string()))
+                .ok_or_else(|| expected("a string", json)),
+            AbiType::Option(item_type) | AbiType::Optional(item_type) => {
+                if json.is_null() {
+                    Ok(AbiValue::Option(None))
+                } else {
+                    let item = self.value_from_json(item_type, json)?;
+                    Ok(AbiValue::Option(Some(Box::new(item))))
+                }
+            },
+            AbiType::List(item_type)
+            | AbiType::Variadic(item_type)
+            | AbiType::CountedVariadic(item_type) => {
+                let items = json_array(json)?
+                    .iter()
+                    .map(|item| self.value_from_json(item_type, item))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(AbiValue::List(items))
+            },
+            AbiType::Array(length, item_type) => {
+                let items = json_array_of_length(json, *length)?;
+                let items = items
+                    .iter()
+                    .map(|item| self.value_from_json(item_type, item))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(AbiValue::List(items))
+            },
+            AbiType::Tuple(item_types) | AbiType::Multi(item_types) => {
+                let items = json_array_of_length(json, item_types.len())?;
+                let items = item_types
+                    .iter()
+                    .zip(items)
+                    .map(|(item_type, item)| self.value_from_json(item_type, item))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(AbiValue::List(items))
+            },
+            AbiType::Custom(type_name) => self.custom_value_from_json(type_name, json),
+        }
+    }
+
+    fn custom_value_from_json(
+        &self,
+        type_name: &str,
+        json: &JsonValue,
+    ) -> Result<AbiValue, AbiCodecError> {
+        let type_description = self.type_description(type_name)?;
+        match type_description.content_type.as_str() {
+            TYPE_DESCRIPTION_JSON_TYPE_STRUCT => {
+                let fields = self.fields_from_json(&type_description.fields, json)?;
+                Ok(AbiValue::Struct(fields))
+            },
+            TYPE_DESCRIPTION_JSON_TYPE_ENUM => {
+                let (variant_name, fields_json) = match json {
+                    JsonValue::String(variant_name) => (variant_name, None),
+                    JsonValue::Object(object) if object.len() == 1 => {
+                        let (variant_name, fields_json) = object.iter().next().unwrap();
+                        (variant_name, Some(fields_json))
+                    },
+                    _ => return Err(expected("a variant name or a single-key object", json)),
+                };
+                let variant = type_description
+                    .variants
+                    .iter()
+                    .find(|variant| &variant.name == variant_name)
+                    .ok_or_else(|| {
+                        AbiCodecError::new(format!(
+                            "unknown variant of {type_name}: {variant_name}"
+                        ))
+                    })?;
+                let fields = match fields_json {
+                    Some(fields_json) => self.fields_from_json(&variant.fields, fields_json)?,
+                    None if variant.fields.is_empty() => Vec::new(),
+                    None => {
+                        return Err(AbiCodecError::new(format!(
+                            "missing fields of variant {variant_name}"
+                        )))
+                    },
+                };
+                Ok(AbiValue::Enum {
+                    variant: variant.name.clone(),
+                    fields,
+                })
+            },
+            TYPE_DESCRIPTION_JSON_TYPE_EXPLICIT_ENUM => {
+                let variant_name = json
+                    .as_str()
+                    .ok_or_else(|| expected("a variant name", json))?;
+                if !type_description
+                    .variants
+                    .iter()
+                    .any(|variant| variant.name == variant_name)
+                {
+                    return Err(AbiCodecError::new(format!(
+                        "unknown variant of {type_name}: {variant_name}"
+                    )));
+                }
+                Ok(AbiValue::Text(variant_name.to_string()))
+            },
+            _ => Err(AbiCodecError::new(format!(
+                "type not described in the ABI: {type_name}"
+            ))),
+        }
+    }
+
+    fn fields_from_json(
+        &self,
+        field_descriptions: &[StructFieldDescriptionJson],
+        json: &JsonValue,
+    ) -> Result<Vec<(String, AbiValue)>, AbiCodecError> {
+        let object = json
+            .as_object()
+            .ok_or_else(|| expected("an object", json))?;
+        field_descriptions
+            .iter()
+            .map(|field| {
+                let field_json = object
+                    .get(&field.name)
+                    .ok_or_else(|| AbiCodecError::new(format!("missing field: {}", field.name)))?;
+                let value =
+                    self.value_from_json(&AbiType::parse(&field.field_type)?, field_json)?;
+                Ok((field.name.clone(), value))
+            })
+            .collect()
+    }
+}
+
+fn number_from_json(json: &JsonValue) -> Result<BigInt, AbiCodecError> {
+    match json {
+        JsonValue::Number(number) => {
+            if let Some(value) = number.as_u64() {
+                Ok(BigInt::from(value))
+            } else if let Some(value) = number.as_i64() {
+                Ok(BigInt::from(value))
+            } else {
+                Err(expected("an integer", json))
+            }
+        },
+        JsonValue::String(decimal) => decimal
+            .parse::<BigInt>()
+            .map_err(|_| expected("an integer", json)),
+        _ => Err(expected("an integer", json)),
+    }
+}
+
+fn json_array(json: &JsonValue) -> Result<&Vec<JsonValue>, AbiCodecError> {
+    json.as_array().ok_or_else(|| expected("an array", json))
+}
+
+fn json_array_of_length(json: &JsonValue, length: usize) -> Result<&Vec<JsonValue>, AbiCodecError> {
+    let items = json_array(json)?;
+    if items.len() != length {
+        return Err(expected(&format!("an array of length {length}"), json));
+    }
+    Ok(items)
+}
+
+fn expected(what: &str, json: &JsonValue) -> AbiCodecError {
+    AbiCodecError::new(format!("expected {what}, got {json}"))
+}
@@ -0,0 +1,396 @@
+use serde::Serialize;
+
+use crate::abi_json::{ContractAbiJson, InputAbiJson, OutputAbiJson, TypeDescriptionJson};
+
+use super::{AbiCodecError, AbiType, AbiValue};
+
+/// Encodes arguments and decodes results, using only a contract ABI.
+///
+/// Follows the same top-level and nested encoding rules as the contracts.
+/// Arguments are given as JSON, results are decoded into [`AbiValue`]s, which serialize back to JSON.
+pub struct AbiCodec<'a> {
+    pub abi: &'a ContractAbiJson,
+}
+
+/// An event log, decoded based on the event description in the ABI.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct DecodedEvent {
+    pub identifier: String,
+    /// All event inputs, indexed or not, in ABI order, as an [`AbiValue::Struct`].
+    pub inputs: AbiValue,
+}
+
+impl<'a> AbiCodec<'a> {
+    pub fn new(abi: &'a ContractAbiJson) -> Self {
+        AbiCodec { abi }
+    }
+
+    pub(super) fn type_description(
+        &self,
+        type_name: &str,
+    ) -> Result<&'a TypeDescriptionJson, AbiCodecError> {
+        self.abi
+            .types
+            .get(type_name)
+            .ok_or_else(|| AbiCodecError::new(format!("unknown type: {type_name}")))
+    }
+
+    /// Encodes the arguments of an endpoint, given as JSON, one for each endpoint input.
+    ///
+    /// Trailing optional and variadic arguments can be omitted.
+    pub fn encode_endpoint_args(
+        &self,
+        endpoint_name: &str,
+        args: &[serde_json::Value],
+    ) -> Result<Vec<Vec<u8>>, AbiCodecError> {
+        let endpoint = self
+            .abi
+            .endpoints
+            .iter()
+            .find(|endpoint| endpoint.name == endpoint_name)
+            .ok_or_else(|| AbiCodecError::new(format!("unknown endpoint: {endpoint_name}")))?;
+        self.encode_inputs(&endpoint.inputs, args)
+    }
+
+    /// Encodes the constructor arguments, given as JSON.
+    pub fn encode_constructor_args(
+        &self,
+        args: &[serde_json::Value],
+    ) -> Result<Vec<Vec<u8>>, AbiCodecError> {
+        let constructor = self
+            .abi
+            .constructor
+            .as_ref()
+            .ok_or_else(|| AbiCodecError::new("contract has no constructor"))?;
+        self.encode_inputs(&constructor.inputs, args)
+    }
+
+    /// Encodes the upgrade constructor arguments, given as JSON.
+    pub fn encode_upgrade_args(
+        &self,
+        args: &[serde_json::Value],
+    ) -> Result<Vec<Vec<u8>>, AbiCodecError> {
+        let upgrade_constructor = self
+            .abi
+            .upgrade_constructor
+            .as_ref()
+            .ok_or_else(|| AbiCodecError::new("contract has no upgrade constructor"))?;
+        self.encode_inputs(&upgrade_constructor.inputs, args)
+    }
+
+    fn encode_inputs(
+        &self,
+        inputs: &[InputAbiJson],
+        args: &[serde_json::Value],
+    ) -> Result<Vec<Vec<u8>>, AbiCodecError> {
+        if args.len() > inputs.len() {
+            return Err(AbiCodecError::new(format!(
+                "too many arguments: expected at most {}, got {}",
+                inputs.len(),
+                args.len()
+            )));
+        }
+
+        let mut raw_args = Vec::new();
+        for (index, input) in inputs.iter().enumerate() {
+            let abi_type = AbiType::parse(&input.type_name)?;
+            let value = match args.get(index) {
+                Some(arg) => self.value_from_json(&abi_type, arg),
+                None => missing_value(&abi_type),
+            }
+            .map_err(|err| AbiCodecError::new(format!("argument `{}`: {}", input.arg_name, err)))?;
+            self.multi_encode(&abi_type, &value, &mut raw_args)?;
+        }
+        Ok(raw_args)
+    }
+
+    /// Decodes the raw results of an endpoint call or query (e.g. the decoded `returnData` of a VM query).
+    pub fn decode_endpoint_results(
+        &self,
+        endpoint_name: &str,
+        raw_results: &[Vec<u8>],
+    ) -> Result<Vec<AbiValue>, AbiCodecError> {
+        let endpoint = self
+            .abi
+            .endpoints
+            .iter()
+            .find(|endpoint| endpoint.name == endpoint_name)
+            .ok_or_else(|| AbiCodecError::new(format!("unknown endpoint: {endpoint_name}")))?;
+        self.decode_outputs(&endpoint.outputs, raw_results)
+    }
+
+    fn decode_outputs(
+        &self,
+        outputs: &[OutputAbiJson],
+        raw_results: &[Vec<u8>],
+    ) -> Result<Vec<AbiValue>, AbiCodecError> {
+        let mut input = raw_results;
+        let values = outputs
+            .iter()
+            .map(|output| self.multi_decode(&AbiType::parse(&output.type_name)?, &mut input))
+            .collect::<Result<Vec<_>, _>>()?;
+        if !input.is_empty() {
+            return Err(AbiCodecError::new("too many results"));
+        }
+        Ok(values)
+    }
+
+    /// Decodes an event log, e.g. from the base64-decoded `topics` and `data` of a transaction log event.
+    ///
+    /// The first topic is the event identifier, followed by the indexed inputs.
+    /// The data field contains the single non-indexed input, if any.
+    pub fn decode_event(
+        &self,
+        topics: &[Vec<u8>],
+        data: &[u8],
+    ) -> Result<DecodedEvent, AbiCodecError> {
+        let (identifier_topic, mut indexed_topics) = topics
+            .split_first()
+            .ok_or_else(|| AbiCodecError::new("event has no topics"))?;
+        let identifier = String::from_utf8(identifier_topic.clone())
+            .map_err(|_| AbiCodecError::new("invalid event identifier"))?;
+        let event = self
+            .abi
+            .events
+            .iter()
+            .find(|event| event.identifier == identifier)
+            .ok_or_else(|| AbiCodecError::new(format!("unknown event: {identifier}")))?;
+
+        let mut fields = Vec::with_capacity(event.inputs.len());
+        for input in &event.inputs {
+            let abi_type = AbiType::parse(&input.type_name)?;
+            let value = if input.indexed == Some(true) {
+                self.multi_decode(&abi_type, &mut indexed_topics)?
+            } else {
+                self.top_decode(&abi_type, data)?
+            };
+            fields.push((input.arg_name.clone(), value));
+        }
+        if !indexed_topics.is_empty() {
+            return Err(AbiCodecError::new("too many event topics"));
+        }
+
+        Ok(DecodedEvent {
+            identifier,
+            inputs: AbiValue::Struct(fields),
+        })
+    }
+}
+
+fn missing_value(abi_type: &AbiType) -> Result<AbiValue, AbiCodecError> {
+    match abi_type {
+        AbiType::Optional(_) => Ok(AbiValue::Option(None)),
+        AbiType::Variadic(_) | AbiType::CountedVariadic(_) => Ok(AbiValue::List(Vec::new())),
+        _ => Err(AbiCodecError::new("missing argument")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::abi_json::deserialize_abi_from_json;
+
+    const ABI_JSON: &str = r#"{
+        "name": "AbiCodecTest",
+        "constructor": {
+            "inputs": [],
+            "outputs": []
+        },
+        "endpoints": [
+            {
+                "name": "setup",
+                "mutability": "mutable",
+                "inputs": [
+                    { "name": "payments", "type": "List<Payment>" },
+                    { "name": "status", "type": "Status" },
+                    { "name": "label", "type": "Option<bytes>" },
+                    { "name": "extra", "type": "optional<u32>", "multi_arg": true }
+                ],
+                "outputs": []
+            },
+            {
+                "name": "getAction",
+                "mutability": "readonly",
+                "inputs": [],
+                "outputs": [
+                    { "type": "Action" },
+                    { "type": "variadic<multi<u32,BigUint>>", "multi_result": true }
+                ]
+            }
+        ],
+        "events": [
+            {
+                "identifier": "transfer",
+                "inputs": [
+                    { "name": "from", "type": "Address", "indexed": true },
+                    { "name": "amount", "type": "BigUint", "indexed": true },
+                    { "name": "payment", "type": "Payment" }
+                ]
+            }
+        ],
+        "types": {
+            "Payment": {
+                "type": "struct",
+                "fields": [
+                    { "name": "token", "type": "TokenIdentifier" },
+                    { "name": "nonce", "type": "u64" },
+                    { "name": "amount", "type": "BigUint" }
+                ]
+            },
+            "Status": {
+                "type": "enum",
+                "variants": [
+                    { "name": "Inactive", "discriminant": 0 },
+                    { "name": "Active", "discriminant": 1 }
+                ]
+            },
+            "Action": {
+                "type": "enum",
+                "variants": [
+                    { "name": "Nothing", "discriminant": 0 },
+                    {
+                        "name": "Transfer",
+                        "discriminant": 1,
+                        "fields": [
+                            { "name": "to", "type": "Address" },
+                            { "name": "payment", "type": "Payment" }
+                        ]
+                    }
+                ]
+            }
+        }
+    }"#;
+
+    fn payment_nested_hex() -> String {
+        format!(
+            "0000000a{}{}{}",
+            hex::encode("TKN-123456"),
+            "0000000000000005",
+            "0000000203e8"
+        )
+    }
+
+    #[test]
+    fn encode_endpoint_args_test() {
+        let abi = deserialize_abi_from_json(ABI_JSON).unwrap();
+        let codec = AbiCodec::new(&abi);
+
+        let raw_args = codec
+            .encode_endpoint_args(
+                "setup",
+                &[
+                    json!([{ "token": "TKN-123456", "nonce": 5, "amount": "1000" }]),
+                    json!("Active"),
+                    json!("abcd"),
+                ],
+            )
+            .unwrap();
+        let raw_args_hex: Vec<String> = raw_args.iter().map(hex::encode).collect();
+        assert_eq!(
+            raw_args_hex,
+            vec![
+                payment_nested_hex(),
+                "01".to_string(),
+                "0100000002abcd".to_string()
+            ]
+        );
+
+        let raw_args = codec
+            .encode_endpoint_args(
+                "setup",
+                &[json!([]), json!("Inactive"), json!(null), json!(7)],
+            )
+            .unwrap();
+        assert_eq!(raw_args, vec![vec![], vec![], vec![], vec![7u8]]);
+
+        assert!(codec.encode_endpoint_args("setup", &[json!([])]).is_err());
+        assert!(codec
+            .encode_endpoint_args("setup", &[json!([]), json!("Unknown")])
+            .is_err());
+        assert!(codec
+            .encode_endpoint_args(
+                "setup",
+                &[json!([]), json!("Active"), json!(null), json!(-1)]
+            )
+            .is_err());
+        assert!(codec.encode_endpoint_args("missing", &[]).is_err());
+    }
+
+    #[test]
+    fn decode_endpoint_results_test() {
+        let abi = deserialize_abi_from_json(ABI_JSON).unwrap();
+        let codec = AbiCodec::new(&abi);
+
+        let action = hex::decode(format!("01{}{}", "11".repeat(32), payment_nested_hex())).unwrap();
+        let raw_results = vec![
+            action.clone(),
+            vec![5],
+            hex::decode("0f4240").unwrap(),
+            vec![6],
+            u64::MAX.to_be_bytes().to_vec(),
+        ];
+        let results = codec
+            .decode_endpoint_results("getAction", &raw_results)
+            .unwrap();
+        assert_eq!(
+            serde_json::to_value(&results).unwrap(),
+            json!([
+                {
+                    "Transfer": {
+                        "to": "11".repeat(32),
+                        "payment": { "token": "TKN-123456", "nonce": 5, "amount": 1000 }
+                    }
+                },
+                [[5, 1000000], [6, "18446744073709551615"]]
+            ])
+        );
+
+        // the decoded value can be converted back to JSON and re-encoded to the same bytes
+        let action_type = AbiType::parse("Action").unwrap();
+        let action_value = codec
+            .value_from_json(&action_type, &results[0].to_json())
+            .unwrap();
+        assert_eq!(
+            codec.top_encode(&action_type, &action_value).unwrap(),
+            action
+        );
+
+        let results = codec
+            .decode_endpoint_results("getAction", &[vec![]])
+            .unwrap();
+        assert_eq!(
+            serde_json::to_value(&results).unwrap(),
+            json!(["Nothing", []])
+        );
+
+        assert!(codec
+            .decode_endpoint_results("getAction", &[vec![], vec![5]])
+            .is_err());
+    }
+
+    #[test]
+    fn decode_event_test() {
+        let abi = deserialize_abi_from_json(ABI_JSON).unwrap();
+        let codec = AbiCodec::new(&abi);
+
+        let topics = vec![b"transfer".to_vec(), vec![0x22; 32], vec![0x03, 0xe8]];
+        let data = hex::decode(payment_nested_hex()).unwrap();
+        let event = codec.decode_event(&topics, &data).unwrap();
+        assert_eq!(
+            serde_json::to_value(&event).unwrap(),
+            json!({
+                "identifier": "transfer",
+                "inputs": {
+                    "from": "22".repeat(32),
+                    "amount": 1000,
+                    "payment": { "token": "TKN-123456", "nonce": 5, "amount": 1000 }
+                }
+            })
+        );
+
+        assert!(codec.decode_event(&topics[..2], &data).is_err());
+        assert!(codec.decode_event(&[b"unknown".to_vec()], &[]).is_err());
+    }
+}
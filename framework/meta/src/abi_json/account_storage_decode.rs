@@ -0,0 +1,266 @@
+use std::collections::HashMap;
+
+use dharitri_sc::codec::num_bigint::{BigInt, BigUint};
+
+use super::{ContractAbiJson, StorageAbiJson};
+
+/// Types whose top-encoded representation is a big-endian unsigned number.
+const UNSIGNED_TYPES: &[&str] = &[
+    "u8",
+    "u16",
+    "u32",
+    "u64",
+    "usize",
+    "BigUint",
+    "NonZeroUsize",
+];
+
+/// Types whose top-encoded representation is a big-endian signed number.
+const SIGNED_TYPES: &[&str] = &["i8", "i16", "i32", "i64", "isize", "BigInt"];
+
+/// Types whose top-encoded representation is human-readable text.
+const TEXT_TYPES: &[&str] = &[
+    "TokenIdentifier",
+    "RewaOrDcdtTokenIdentifier",
+    "String",
+    "&str",
+];
+
+/// Marks the values of the storage mappers that store several entries under the same base key.
+///
+/// Each entry contains the mapper name, the key suffix and the index of the mapper type argument
+/// describing the stored value. An index of `None` means that the value is a length or an index (`u32`).
+const MAPPER_VALUE_SUFFIXES: &[(&str, &str, Option<usize>)] = &[
+    ("VecMapper", ".len", None),
+    ("VecMapper", ".item", Some(0)),
+    ("UnorderedSetMapper", ".len", None),
+    ("UnorderedSetMapper", ".item", Some(0)),
+    ("UnorderedSetMapper", ".index", None),
+    ("SetMapper", ".value", Some(0)),
+    ("SetMapper", ".node_id", None),
+    ("QueueMapper", ".value", Some(0)),
+    ("MapMapper", ".mapped", Some(1)),
+    ("MapMapper", ".value", Some(0)),
+    ("MapMapper", ".node_id", None),
+];
+
+/// A storage value, decoded based on its type in the ABI.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DecodedStorageValue {
+    Bool(bool),
+    /// Integers and big numbers, in decimal representation.
+    Number(String),
+    Text(String),
+    /// Raw bytes, for types that are not decoded.
+    Bytes(Vec<u8>),
+}
+
+/// An account storage entry, matched to a storage item from the ABI.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DecodedStorageEntry {
+    /// The storage item name, i.e. the Rust method name.
+    pub name: String,
+    /// The storage item key, as declared in the contract.
+    pub key: String,
+    pub mapper: Option<String>,
+    /// The rest of the raw storage key: the encoded key arguments and the mapper-specific suffixes.
+    pub key_suffix: Vec<u8>,
+    /// The type used to decode the value, if known.
+    pub value_type: Option<String>,
+    pub value: DecodedStorageValue,
+}
+
+/// Decodes raw account storage, as retrieved from the gateway (hex keys and values),
+/// into named, typed entries, using the storage section of the contract ABI.
+///
+/// Each raw key is matched to the storage item with the longest key that prefixes it.
+/// Keys not matching any storage item (e.g. system or reserved keys) are skipped.
+///
+/// The result is sorted by raw storage key.
+pub fn decode_account_storage(
+    abi: &ContractAbiJson,
+    raw_storage: &HashMap<String, String>,
+) -> Vec<DecodedStorageEntry> {
+    let mut raw_entries: Vec<(Vec<u8>, Vec<u8>)> = raw_storage
+        .iter()
+        .filter_map(|(key, value)| Some((hex::decode(key).ok()?, hex::decode(value).ok()?)))
+        .collect();
+    raw_entries.sort();
+
+    raw_entries
+        .into_iter()
+        .filter_map(|(raw_key, raw_value)| decode_storage_entry(abi, &raw_key, &raw_value))
+        .collect()
+}
+
+fn decode_storage_entry(
+    abi: &ContractAbiJson,
+    raw_key: &[u8],
+    raw_value: &[u8],
+) -> Option<DecodedStorageEntry> {
+    let storage = abi
+        .storage
+        .iter()
+        .filter(|storage| raw_key.starts_with(storage.key.as_bytes()))
+        .max_by_key(|storage| storage.key.len())?;
+    let key_suffix = &raw_key[storage.key.len()..];
+    let value_type = storage_value_type(storage, key_suffix);
+    let value = match &value_type {
+        Some(type_name) => decode_value(type_name, raw_value),
+        None => DecodedStorageValue::Bytes(raw_value.to_vec()),
+    };
+
+    Some(DecodedStorageEntry {
+        name: storage.name.clone(),
+        key: storage.key.clone(),
+        mapper: storage.mapper.clone(),
+        key_suffix: key_suffix.to_vec(),
+        value_type,
+        value,
+    })
+}
+
+/// Plain storage and single value mappers store the value directly.
+/// For the other known mappers, the value type is determined from the key suffix.
+fn storage_value_type(storage: &StorageAbiJson, key_suffix: &[u8]) -> Option<String> {
+    let mapper = match &storage.mapper {
+        None => return storage.value_types.first().cloned(),
+        Some(mapper) if mapper == "SingleValueMapper" => {
+            return storage.value_types.first().cloned()
+        },
+        Some(mapper) => mapper,
+    };
+
+    MAPPER_VALUE_SUFFIXES
+        .iter()
+        .filter(|(mapper_name, suffix, _)| {
+            mapper_name == mapper && contains_subslice(key_suffix, suffix.as_bytes())
+        })
+        .map(|(_, _, type_arg_index)| match type_arg_index {
+            Some(index) => storage.value_types.get(*index).cloned(),
+            None => Some("u32".to_string()),
+        })
+        .next()
+        .flatten()
+}
+
+fn contains_subslice(haystack: &[u8], needle: &[u8]) -> bool {
+    haystack
+        .windows(needle.len())
+        .any(|window| window == needle)
+}
+
+fn decode_value(type_name: &str, raw_value: &[u8]) -> DecodedStorageValue {
+    if type_name == "bool" && raw_value.len() <= 1 {
+        return DecodedStorageValue::Bool(raw_value == [1u8]);
+    }
+    if UNSIGNED_TYPES.contains(&type_name) {
+        return DecodedStorageValue::Number(BigUint::from_bytes_be(raw_value).to_string());
+    }
+    if SIGNED_TYPES.contains(&type_name) {
+        return DecodedStorageValue::Number(BigInt::from_signed_bytes_be(raw_value).to_string());
+    }
+    if TEXT_TYPES.contains(&type_name) {
+        if let Ok(text) = String::from_utf8(raw_value.to_vec()) {
+            return DecodedStorageValue::Text(text);
+        }
+    }
+
+    DecodedStorageValue::Bytes(raw_value.to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::abi_json::deserialize_abi_from_json;
+
+    const STORAGE_ABI_JSON: &str = r#"{
+        "name": "StorageTest",
+        "storage": [
+            {
+                "name": "counter",
+                "key": "counter",
+                "valueTypes": ["u64"]
+            },
+            {
+                "name": "counter_owner",
+                "key": "counter_owner",
+                "mapper": "SingleValueMapper",
+                "valueTypes": ["ManagedAddress"]
+            },
+            {
+                "name": "balances",
+                "key": "balances",
+                "mapper": "MapMapper",
+                "valueTypes": ["ManagedAddress", "BigUint"]
+            },
+            {
+                "name": "tokens",
+                "key": "tokens",
+                "mapper": "VecMapper",
+                "keyArgs": [
+                    {
+                        "name": "owner",
+                        "type": "ManagedAddress"
+                    }
+                ],
+                "valueTypes": ["TokenIdentifier"]
+            }
+        ]
+    }"#;
+
+    fn raw_storage(entries: &[(&[u8], &[u8])]) -> HashMap<String, String> {
+        entries
+            .iter()
+            .map(|(key, value)| (hex::encode(key), hex::encode(value)))
+            .collect()
+    }
+
+    #[test]
+    fn decode_account_storage_test() {
+        let abi = deserialize_abi_from_json(STORAGE_ABI_JSON).unwrap();
+        let owner = [5u8; 32];
+        let mut tokens_item_key = b"tokens".to_vec();
+        tokens_item_key.extend_from_slice(&owner);
+        tokens_item_key.extend_from_slice(b".item\x00\x00\x00\x01");
+        let mut balances_mapped_key = b"balances.mapped".to_vec();
+        balances_mapped_key.extend_from_slice(&owner);
+
+        let decoded = decode_account_storage(
+            &abi,
+            &raw_storage(&[
+                (b"counter", &[0x01, 0x00]),
+                (b"counter_owner", &owner),
+                (&balances_mapped_key, &[0x0f, 0x42, 0x40]),
+                (&tokens_item_key, b"TKN-123456"),
+                (b"unknown", b"value"),
+            ]),
+        );
+
+        assert_eq!(decoded.len(), 4);
+
+        assert_eq!(decoded[0].name, "balances");
+        assert_eq!(decoded[0].value_type.as_deref(), Some("BigUint"));
+        assert_eq!(
+            decoded[0].value,
+            DecodedStorageValue::Number("1000000".to_string())
+        );
+
+        assert_eq!(decoded[1].name, "counter");
+        assert_eq!(
+            decoded[1].value,
+            DecodedStorageValue::Number("256".to_string())
+        );
+
+        assert_eq!(decoded[2].name, "counter_owner");
+        assert_eq!(decoded[2].key_suffix, Vec::<u8>::new());
+        assert_eq!(decoded[2].value, DecodedStorageValue::Bytes(owner.to_vec()));
+
+        assert_eq!(decoded[3].name, "tokens");
+        assert_eq!(decoded[3].mapper.as_deref(), Some("VecMapper"));
+        assert_eq!(
+            decoded[3].value,
+            DecodedStorageValue::Text("TKN-123456".to_string())
+        );
+    }
+}
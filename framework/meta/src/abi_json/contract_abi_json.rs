@@ -35,9 +35,17 @@ pub struct ContractAbiJson {
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub events: Vec<EventAbiJson>,
 
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub storage: Vec<StorageAbiJson>,
+
     #[serde(default)]
     pub dcdt_attributes: Vec<DcdtAttributeJson>,
 
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<ContractErrorAbiJson>,
+
     #[serde(default)]
     pub has_callback: bool,
 
@@ -63,6 +71,8 @@ impl From<&ContractAbi> for ContractAbiJson {
                 .map(|endpoint| endpoint.name.to_string())
                 .collect(),
             events: abi.events.iter().map(EventAbiJson::from).collect(),
+            storage: abi.storage.iter().map(StorageAbiJson::from).collect(),
+            errors: abi.errors.iter().map(ContractErrorAbiJson::from).collect(),
             has_callback: abi.has_callback,
             types: convert_type_descriptions_to_json(&abi.type_descriptions),
             dcdt_attributes: abi
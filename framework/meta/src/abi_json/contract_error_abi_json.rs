@@ -0,0 +1,28 @@
+use dharitri_sc::abi::ContractErrorAbi;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+pub struct ContractErrorAbiJson {
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub docs: Vec<String>,
+
+    #[serde(rename = "type")]
+    pub error_type: String,
+
+    pub name: String,
+    pub code: u32,
+    pub message: String,
+}
+
+impl From<&ContractErrorAbi> for ContractErrorAbiJson {
+    fn from(abi: &ContractErrorAbi) -> Self {
+        ContractErrorAbiJson {
+            docs: abi.docs.iter().map(|d| d.to_string()).collect(),
+            error_type: abi.error_type.clone(),
+            name: abi.name.clone(),
+            code: abi.code,
+            message: abi.message.clone(),
+        }
+    }
+}
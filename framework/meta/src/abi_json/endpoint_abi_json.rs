@@ -103,6 +103,16 @@ pub struct EndpointAbiJson {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub only_admin: Option<bool>,
 
+    #[serde(rename = "onlyRole")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub only_role: Option<String>,
+
+    #[serde(rename = "whenNotPaused")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub when_not_paused: Option<bool>,
+
     pub mutability: EndpointMutabilityAbiJson,
 
     #[serde(rename = "payableInTokens")]
@@ -130,6 +140,12 @@ impl From<&EndpointAbi> for EndpointAbiJson {
             name: abi.name.to_string(),
             only_owner: if abi.only_owner { Some(true) } else { None },
             only_admin: if abi.only_admin { Some(true) } else { None },
+            only_role: abi.only_role.clone(),
+            when_not_paused: if abi.when_not_paused {
+                Some(true)
+            } else {
+                None
+            },
             mutability: match abi.mutability {
                 EndpointMutabilityAbi::Mutable => EndpointMutabilityAbiJson::Mutable,
                 EndpointMutabilityAbi::Readonly => EndpointMutabilityAbiJson::Readonly,
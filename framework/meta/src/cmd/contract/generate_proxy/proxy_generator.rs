@@ -79,6 +79,7 @@ impl<'a> ProxyGenerator<'a> {
         self.write_struct_tx_proxy_methods();
         self.write_content();
         self.write_types();
+        self.write_errors();
     }
 
     fn write_header(&mut self) {
@@ -174,6 +175,32 @@ where
         }
     }
 
+    /// Custom error types are written as enums with the same codes and messages,
+    /// so that failed transactions can be decoded into them.
+    fn write_errors(&mut self) {
+        let errors = self.contract_abi.errors.clone();
+        let mut error_types: Vec<&str> = Vec::new();
+        for error in &errors {
+            if !error_types.contains(&error.error_type.as_str()) {
+                error_types.push(&error.error_type);
+            }
+        }
+
+        for error_type in error_types {
+            self.writeln("");
+            self.writeln("#[derive(ContractError, Clone, Copy, PartialEq, Eq, Debug)]");
+            self.writeln(format!("pub enum {error_type} {{"));
+            for error in errors.iter().filter(|error| error.error_type == error_type) {
+                for doc in &error.docs {
+                    self.writeln(format!("    /// {doc} "));
+                }
+                self.writeln(format!("    #[error({:?})]", error.message));
+                self.writeln(format!("    {} = {},", error.name, error.code));
+            }
+            self.writeln("}");
+        }
+    }
+
     fn write_constructors(&mut self) {
         let constructors: Vec<EndpointAbi> = self.contract_abi.constructors.clone();
 
@@ -194,9 +194,11 @@ fn build_contract_abi(builder: ContractVariantBuilder, original_abi: &ContractAb
         endpoints,
         promise_callbacks,
         events: original_abi.events.clone(),
+        storage: original_abi.storage.clone(),
         has_callback,
         type_descriptions: original_abi.type_descriptions.clone(),
         dcdt_attributes: original_abi.dcdt_attributes.clone(),
+        errors: original_abi.errors.clone(),
     }
 }
 
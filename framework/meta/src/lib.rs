@@ -1,3 +1,4 @@
+pub mod abi_codec;
 pub mod abi_json;
 mod cargo_toml_contents;
 pub mod cli_args;
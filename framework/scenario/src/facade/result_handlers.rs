@@ -1,17 +1,21 @@
+mod expect_contract_error;
 mod expect_error;
 mod expect_message;
 mod expect_status;
 mod expect_value;
+mod returns_contract_error;
 mod returns_message;
 mod returns_new_bech32_address;
 mod returns_new_token_identifier;
 mod returns_status;
 mod with_tx_raw_response;
 
+pub use expect_contract_error::ExpectContractError;
 pub use expect_error::ExpectError;
 pub use expect_message::ExpectMessage;
 pub use expect_status::ExpectStatus;
 pub use expect_value::ExpectValue;
+pub use returns_contract_error::ReturnsContractError;
 pub use returns_message::ReturnsMessage;
 pub use returns_new_bech32_address::ReturnsNewBech32Address;
 pub use returns_new_token_identifier::ReturnsNewTokenIdentifier;
@@ -0,0 +1,36 @@
+use dharitri_chain_scenario_format::serde_raw::ValueSubTree;
+use dharitri_sc::types::{ContractError, RHListItem, RHListItemExec, TxEnv};
+
+use crate::scenario_model::{BytesValue, CheckValue, TxExpect, TxResponse};
+
+/// Verifies that the transaction failed with the given custom contract error.
+///
+/// Can only be used in tests and interactors, not available in contracts.
+pub struct ExpectContractError<E: ContractError>(pub E);
+
+impl<E, Env, Original> RHListItem<Env, Original> for ExpectContractError<E>
+where
+    E: ContractError,
+    Env: TxEnv,
+{
+    type Returns = ();
+}
+
+impl<E, Env, Original> RHListItemExec<TxResponse, Env, Original> for ExpectContractError<E>
+where
+    E: ContractError,
+    Env: TxEnv<RHExpect = TxExpect>,
+{
+    fn item_tx_expect(&self, mut prev: TxExpect) -> TxExpect {
+        let message = self.0.signaled_message();
+        prev.status = CheckValue::Equal(4u64.into());
+        let expect_message_expr = BytesValue {
+            value: message.as_bytes().to_vec(),
+            original: ValueSubTree::Str(format!("str:{message}")),
+        };
+        prev.message = CheckValue::Equal(expect_message_expr);
+        prev
+    }
+
+    fn item_process_result(self, _: &TxResponse) -> Self::Returns {}
+}
@@ -0,0 +1,58 @@
+use std::marker::PhantomData;
+
+use dharitri_sc::types::{ContractError, RHListItem, RHListItemExec, TxEnv};
+
+use crate::scenario_model::{CheckValue, TxExpect, TxResponse, U64Value};
+
+/// Indicates that the custom contract error will be returned, decoded from the error message.
+///
+/// Returns `None` if the transaction succeeded, or if it failed with a different error.
+///
+/// Can only be used in tests and interactors, not available in contracts.
+pub struct ReturnsContractError<E: ContractError> {
+    _phantom: PhantomData<E>,
+}
+
+impl<E: ContractError> Default for ReturnsContractError<E> {
+    fn default() -> Self {
+        Self {
+            _phantom: Default::default(),
+        }
+    }
+}
+
+impl<E: ContractError> ReturnsContractError<E> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<E, Env, Original> RHListItem<Env, Original> for ReturnsContractError<E>
+where
+    E: ContractError,
+    Env: TxEnv,
+{
+    type Returns = Option<E>;
+}
+
+impl<E, Env, Original> RHListItemExec<TxResponse, Env, Original> for ReturnsContractError<E>
+where
+    E: ContractError,
+    Env: TxEnv<RHExpect = TxExpect>,
+{
+    fn item_tx_expect(&self, mut prev: TxExpect) -> TxExpect {
+        if let CheckValue::Equal(U64Value {
+            value: 0,
+            original: _,
+        }) = prev.status
+        {
+            prev.status = CheckValue::Star;
+        }
+        prev.message = CheckValue::Star;
+        prev
+    }
+
+    fn item_process_result(self, raw_result: &TxResponse) -> Self::Returns {
+        raw_result.tx_error.contract_error()
+    }
+}
@@ -2,7 +2,9 @@ use dharitri_sc::types::{RHListItem, RHListItemExec, TxEnv};
 
 use crate::scenario_model::TxResponse;
 
-/// Indicates that the newly deployed address will be returned after a deploy.
+/// Indicates that the gas used by the transaction will be returned.
+///
+/// In the Rust VM, gas is only metered if a gas schedule was set.
 pub struct ReturnsGasUsed;
 
 impl<Env, Original> RHListItem<Env, Original> for ReturnsGasUsed
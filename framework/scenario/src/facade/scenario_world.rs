@@ -1,11 +1,17 @@
-use dharitri_chain_vm::world_mock::BlockchainState;
+use dharitri_chain_vm::{
+    tx_execution::GasSchedule,
+    world_mock::{BlockchainState, BlockchainStateSnapshot, ForkSource},
+};
 
 use crate::{
     scenario::{run_trace::ScenarioTrace, run_vm::ScenarioVMRunner},
     vm_go_tool::run_drt_scenario_go,
 };
 use dharitri_sc_meta::find_workspace::find_current_workspace;
-use std::path::{Path, PathBuf};
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 
 use super::debugger_backend::DebuggerBackend;
 
@@ -103,6 +109,46 @@ impl ScenarioWorld {
         self
     }
 
+    /// Enables gas metering in the debugger.
+    ///
+    /// From here on, transactions fail when running out of gas, unused gas is refunded,
+    /// and the gas used can be retrieved via `ReturnsGasUsed`.
+    pub fn gas_schedule(&mut self, gas_schedule: GasSchedule) -> &mut Self {
+        self.get_mut_debugger_backend()
+            .vm_runner
+            .blockchain_mock
+            .vm
+            .set_gas_schedule(Some(gas_schedule));
+        self
+    }
+
+    /// Enables fork mode.
+    ///
+    /// Accounts missing from the debugger state are retrieved from the fork source on first access,
+    /// e.g. from a gateway, or from a recorded cache. Their storage is retrieved one key at a time, when read.
+    pub fn fork_source<F>(&mut self, fork_source: F) -> &mut Self
+    where
+        F: ForkSource + 'static,
+    {
+        self.get_mut_state().set_fork_source(Arc::new(fork_source));
+        self
+    }
+
+    /// Saves the entire blockchain state, so tests can later return to this point.
+    ///
+    /// Useful for running several test cases from a common, expensive setup.
+    pub fn snapshot(&self) -> BlockchainStateSnapshot {
+        self.get_state().snapshot()
+    }
+
+    /// Resets the blockchain state to a snapshot taken earlier.
+    ///
+    /// The same snapshot can be restored any number of times.
+    pub fn restore(&mut self, snapshot: &BlockchainStateSnapshot) -> &mut Self {
+        self.get_mut_state().restore(snapshot);
+        self
+    }
+
     /// Older versions of the Rust compiler were setting a wrong path in the environment when debugging.
     /// This method was made as a workaround to avoid this problem.
     ///
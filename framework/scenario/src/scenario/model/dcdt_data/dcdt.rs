@@ -75,6 +75,9 @@ impl InterpretableFrom<DcdtRaw> for Dcdt {
                 frozen: full_dcdt
                     .frozen
                     .map(|b| U64Value::interpret_from(b, context)),
+                paused: full_dcdt
+                    .paused
+                    .map(|b| U64Value::interpret_from(b, context)),
             }),
         }
     }
@@ -96,6 +99,7 @@ impl IntoRaw<DcdtRaw> for Dcdt {
                 last_nonce: eo.last_nonce.map(|ti| ti.original),
                 roles: eo.roles,
                 frozen: eo.frozen.map(|ti| ti.original),
+                paused: eo.paused.map(|ti| ti.original),
             }),
         }
     }
@@ -27,6 +27,7 @@ impl CheckDcdt {
 
             if has_single_fungible_instance
                 && dcdt_check.frozen.is_star()
+                && dcdt_check.paused.is_star()
                 && dcdt_check.last_nonce.is_star()
             {
                 let balance =
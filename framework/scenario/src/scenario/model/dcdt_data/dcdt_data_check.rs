@@ -13,6 +13,7 @@ pub struct CheckDcdtData {
     pub instances: CheckDcdtInstances,
     pub last_nonce: CheckValue<U64Value>,
     pub frozen: CheckValue<U64Value>,
+    pub paused: CheckValue<U64Value>,
 }
 
 impl InterpretableFrom<CheckDcdtDataRaw> for CheckDcdtData {
@@ -21,6 +22,7 @@ impl InterpretableFrom<CheckDcdtDataRaw> for CheckDcdtData {
             instances: CheckDcdtInstances::interpret_from(from.instances, context),
             last_nonce: CheckValue::<U64Value>::interpret_from(from.last_nonce, context),
             frozen: CheckValue::<U64Value>::interpret_from(from.frozen, context),
+            paused: CheckValue::<U64Value>::interpret_from(from.paused, context),
         }
     }
 }
@@ -32,6 +34,7 @@ impl IntoRaw<CheckDcdtDataRaw> for CheckDcdtData {
             last_nonce: self.last_nonce.into_raw(),
             roles: Vec::new(),
             frozen: self.frozen.into_raw(),
+            paused: self.paused.into_raw(),
         }
     }
 }
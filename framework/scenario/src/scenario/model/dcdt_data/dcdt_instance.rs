@@ -11,11 +11,13 @@ use crate::{
 pub struct DcdtInstance {
     pub nonce: Option<U64Value>,
     pub balance: Option<BigUintValue>,
+    pub name: Option<BytesValue>,
     pub creator: Option<AddressValue>,
     pub royalties: Option<U64Value>,
     pub hash: Option<BytesValue>,
     pub uri: Vec<BytesValue>,
     pub attributes: Option<BytesValue>,
+    pub frozen: Option<U64Value>,
 }
 
 impl DcdtInstance {
@@ -27,11 +29,13 @@ impl DcdtInstance {
         };
 
         is_fungible
+            && self.name.is_none()
             && self.creator.is_none()
             && self.royalties.is_none()
             && self.hash.is_none()
             && self.uri.is_empty()
             && self.attributes.is_none()
+            && self.frozen.is_none()
     }
 }
 
@@ -42,6 +46,7 @@ impl InterpretableFrom<DcdtInstanceRaw> for DcdtInstance {
             balance: from
                 .balance
                 .map(|b| BigUintValue::interpret_from(b, context)),
+            name: from.name.map(|b| BytesValue::interpret_from(b, context)),
             creator: from
                 .creator
                 .map(|b| AddressValue::interpret_from(b, context)),
@@ -55,6 +60,7 @@ impl InterpretableFrom<DcdtInstanceRaw> for DcdtInstance {
             attributes: from
                 .attributes
                 .map(|b| BytesValue::interpret_from(b, context)),
+            frozen: from.frozen.map(|b| U64Value::interpret_from(b, context)),
         }
     }
 }
@@ -64,11 +70,13 @@ impl IntoRaw<DcdtInstanceRaw> for DcdtInstance {
         DcdtInstanceRaw {
             nonce: self.nonce.map(|n| n.original),
             balance: self.balance.map(|n| n.original),
+            name: self.name.map(|n| n.original),
             creator: self.creator.map(|n| n.original),
             royalties: self.royalties.map(|n| n.original),
             hash: self.hash.map(|n| n.original),
             uri: self.uri.into_iter().map(|b| b.original).collect(),
             attributes: self.attributes.map(|n| n.original),
+            frozen: self.frozen.map(|n| n.original),
         }
     }
 }
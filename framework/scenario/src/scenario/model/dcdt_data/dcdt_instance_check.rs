@@ -10,11 +10,13 @@ use crate::{
 pub struct CheckDcdtInstance {
     pub nonce: U64Value,
     pub balance: CheckValue<BigUintValue>,
+    pub name: CheckValue<BytesValue>,
     pub creator: CheckValue<BytesValue>,
     pub royalties: CheckValue<U64Value>,
     pub hash: CheckValue<BytesValue>,
     pub uri: CheckValueList,
     pub attributes: CheckValue<BytesValue>,
+    pub frozen: CheckValue<U64Value>,
 }
 
 impl CheckDcdtInstance {
@@ -22,11 +24,13 @@ impl CheckDcdtInstance {
         let is_uri_star = matches!(self.uri, CheckValue::Star);
 
         self.nonce.value == 0
+            && self.name.is_star()
             && self.creator.is_star()
             && self.royalties.is_star()
             && self.hash.is_star()
             && is_uri_star
             && self.attributes.is_star()
+            && self.frozen.is_star()
     }
 }
 
@@ -35,11 +39,13 @@ impl InterpretableFrom<CheckDcdtInstanceRaw> for CheckDcdtInstance {
         CheckDcdtInstance {
             nonce: U64Value::interpret_from(from.nonce, context),
             balance: CheckValue::<BigUintValue>::interpret_from(from.balance, context),
+            name: CheckValue::<BytesValue>::interpret_from(from.name, context),
             creator: CheckValue::<BytesValue>::interpret_from(from.creator, context),
             royalties: CheckValue::<U64Value>::interpret_from(from.royalties, context),
             hash: CheckValue::<BytesValue>::interpret_from(from.hash, context),
             uri: CheckValueList::interpret_from(from.uri, context),
             attributes: CheckValue::<BytesValue>::interpret_from(from.attributes, context),
+            frozen: CheckValue::<U64Value>::interpret_from(from.frozen, context),
         }
     }
 }
@@ -49,11 +55,13 @@ impl IntoRaw<CheckDcdtInstanceRaw> for CheckDcdtInstance {
         CheckDcdtInstanceRaw {
             nonce: self.nonce.into_raw(),
             balance: self.balance.into_raw(),
+            name: self.name.into_raw(),
             creator: self.creator.into_raw(),
             royalties: self.royalties.into_raw(),
             hash: self.hash.into_raw(),
             uri: self.uri.into_raw(),
             attributes: self.attributes.into_raw(),
+            frozen: self.frozen.into_raw(),
         }
     }
 }
@@ -11,6 +11,7 @@ pub struct DcdtObject {
     pub last_nonce: Option<U64Value>,
     pub roles: Vec<String>,
     pub frozen: Option<U64Value>,
+    pub paused: Option<U64Value>,
 }
 
 impl DcdtObject {
@@ -23,6 +24,7 @@ impl DcdtObject {
             && self.last_nonce.is_none()
             && self.roles.is_empty()
             && self.frozen.is_none()
+            && self.paused.is_none()
     }
 
     pub fn set_balance<N, A>(&mut self, token_nonce_expr: N, amount_expr: A)
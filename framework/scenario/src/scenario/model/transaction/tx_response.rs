@@ -26,7 +26,7 @@ pub struct TxResponse {
     /// The logs of the transaction.
     pub logs: Vec<Log>,
     /// The gas used by the transaction.
-    pub gas: u64,
+    pub gas_used: u64,
     /// The refund of the transaction.
     pub refund: u64,
     /// The smart contract results of the transaction.
@@ -44,6 +44,7 @@ impl TxResponse {
                 status: tx_result.result_status,
                 message: tx_result.result_message,
             },
+            gas_used: tx_result.gas_used,
             ..Default::default()
         }
     }
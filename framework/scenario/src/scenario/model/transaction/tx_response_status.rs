@@ -1,3 +1,5 @@
+use dharitri_sc::types::ContractError;
+
 #[derive(Debug, Default, Clone)]
 /// The status of a transaction.
 pub struct TxResponseStatus {
@@ -25,6 +27,17 @@ impl TxResponseStatus {
     pub fn is_success(&self) -> bool {
         self.status == 0
     }
+
+    /// Decodes the error message into a custom contract error, based on the error code it contains.
+    ///
+    /// Returns `None` if the transaction was successful, or if the message does not belong to `E`.
+    pub fn contract_error<E: ContractError>(&self) -> Option<E> {
+        if self.is_success() {
+            return None;
+        }
+
+        E::from_signaled_message(&self.message)
+    }
 }
 
 impl std::fmt::Display for TxResponseStatus {
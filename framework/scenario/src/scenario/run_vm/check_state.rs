@@ -114,7 +114,12 @@ fn execute(state: &BlockchainState, accounts: &CheckAccounts) {
                     }
                 }
             }
-            check_account_dcdt(expected_address, &expected_account.dcdt, &account.dcdt);
+            check_account_dcdt(
+                state,
+                expected_address,
+                &expected_account.dcdt,
+                &account.dcdt,
+            );
         } else {
             assert!(
                 accounts.other_accounts_allowed,
@@ -124,7 +129,12 @@ fn execute(state: &BlockchainState, accounts: &CheckAccounts) {
     }
 }
 
-pub fn check_account_dcdt(address: &AddressKey, expected: &CheckDcdtMap, actual: &AccountDcdt) {
+pub fn check_account_dcdt(
+    state: &BlockchainState,
+    address: &AddressKey,
+    expected: &CheckDcdtMap,
+    actual: &AccountDcdt,
+) {
     match expected {
         CheckDcdtMap::Star => {},
         CheckDcdtMap::Equal(contents) => {
@@ -166,6 +176,7 @@ pub fn check_account_dcdt(address: &AddressKey, expected: &CheckDcdtMap, actual:
                             bytes_to_string(key.value.as_slice()),
                             expected_dcdt,
                             &actual_value,
+                            state.is_dcdt_token_paused(key.value.as_slice()),
                         );
                     },
                 }
@@ -181,6 +192,7 @@ pub fn check_account_dcdt(address: &AddressKey, expected: &CheckDcdtMap, actual:
                         bytes_to_string(token_identifier),
                         &CheckDcdtData::default(),
                         actual_value,
+                        state.is_dcdt_token_paused(token_identifier),
                     );
                 }
             }
@@ -192,6 +204,7 @@ pub fn check_account_dcdt(address: &AddressKey, expected: &CheckDcdtMap, actual:
                     bytes_to_string(token_identifier),
                     &CheckDcdtData::default(),
                     actual_value,
+                    state.is_dcdt_token_paused(token_identifier),
                 );
             }
         },
@@ -203,6 +216,7 @@ pub fn check_dcdt_data(
     token: String,
     expected: &CheckDcdtData,
     actual: &DcdtData,
+    actual_paused: bool,
 ) {
     let mut errors: Vec<String> = vec!["".to_string()];
     check_token_instances(
@@ -221,11 +235,18 @@ pub fn check_dcdt_data(
 
     if !expected.frozen.check(u64::from(actual.frozen)) {
         errors.push(format!(
-            "bad last nonce. Address: {}. Token Name: {}. Want: {}. Have: {}\n",
+            "bad frozen. Address: {}. Token Name: {}. Want: {}. Have: {}\n",
             address, token, expected.frozen, &actual.frozen
         ));
     }
 
+    if !expected.paused.check(u64::from(actual_paused)) {
+        errors.push(format!(
+            "bad paused. Address: {}. Token Name: {}. Want: {}. Have: {}\n",
+            address, token, expected.paused, actual_paused
+        ));
+    }
+
     errors.push("".to_string());
     assert!(errors.len() == 2, "{}", errors.join("\n"));
 }
@@ -291,6 +312,17 @@ pub fn check_token_instance(
             &actual_value.balance,
         ))
     }
+    if !expected_value.name.check(&actual_value.metadata.name) {
+        errors.push(format!(
+            "bad dcdt name. Address: {}. Token {}. Nonce {}. Want: {}. Have: {}",
+            address,
+            token,
+            expected_value.nonce.value,
+            expected_value.name,
+            verbose_hex(&actual_value.metadata.name),
+        ))
+    }
+
     let actual_creator = if let Some(creator) = &actual_value.metadata.creator {
         creator.as_ref()
     } else {
@@ -352,4 +384,11 @@ pub fn check_token_instance(
             verbose_hex(&actual_value.metadata.attributes),
         ))
     }
+
+    if !expected_value.frozen.check(u64::from(actual_value.frozen)) {
+        errors.push(format!(
+            "bad dcdt frozen. Address: {}. Token {}. Nonce {}. Want: {}. Have: {}",
+            address, token, expected_value.nonce.value, expected_value.frozen, actual_value.frozen,
+        ))
+    }
 }
@@ -8,5 +8,6 @@ mod tx_input_util;
 mod tx_output_check;
 mod vm_runner;
 
+pub use set_state::convert_scenario_account_to_world_mock;
 use tx_output_check::*;
 pub use vm_runner::ScenarioVMRunner;
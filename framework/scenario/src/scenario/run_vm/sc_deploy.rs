@@ -32,17 +32,13 @@ impl ScenarioVMRunner {
     {
         let tx_input = tx_input_from_deploy(sc_deploy_step);
         let contract_code = &sc_deploy_step.tx.contract_code.value;
-        let (new_address, tx_result) = self.blockchain_mock.vm.sc_create(
+        let (new_address, tx_result) = self.blockchain_mock.vm.sc_create_with_async_and_callback(
             tx_input,
             contract_code,
             VMCodeMetadata::from(sc_deploy_step.tx.code_metadata.bits()),
             &mut self.blockchain_mock.state,
             f,
         );
-        assert!(
-            tx_result.pending_calls.no_calls(),
-            "Async calls from constructors are currently not supported"
-        );
         (new_address.as_array().into(), tx_result)
     }
 
@@ -1,4 +1,4 @@
-use crate::scenario::model::SetStateStep;
+use crate::scenario::model::{Account, AddressKey, SetStateStep};
 
 use dharitri_chain_vm::{
     types::VMCodeMetadata,
@@ -21,57 +21,14 @@ impl ScenarioVMRunner {
 
 fn execute(state: &mut BlockchainState, set_state_step: &SetStateStep) {
     for (address, account) in set_state_step.accounts.iter() {
-        let storage = account
-            .storage
-            .iter()
-            .map(|(k, v)| (k.value.clone(), v.value.clone()))
-            .collect();
-        let dcdt = AccountDcdt::new_from_raw_map(
-            account
-                .dcdt
-                .iter()
-                .map(|(k, v)| (k.value.clone(), convert_denali_dcdt_to_world_mock(v)))
-                .collect(),
-        );
-
-        state.validate_and_add_account(AccountData {
-            address: address.to_vm_address(),
-            nonce: account
-                .nonce
-                .as_ref()
-                .map(|nonce| nonce.value)
-                .unwrap_or_default(),
-            rewa_balance: account
-                .balance
-                .as_ref()
-                .map(|balance| balance.value.clone())
-                .unwrap_or_default(),
-            dcdt,
-            username: account
-                .username
-                .as_ref()
-                .map(|bytes_value| bytes_value.value.clone())
-                .unwrap_or_default(),
-            storage,
-            contract_path: account
-                .code
-                .as_ref()
-                .map(|bytes_value| bytes_value.value.clone()),
-            code_metadata: account
-                .code_metadata
-                .as_ref()
-                .map(|bytes_value| VMCodeMetadata::from(&bytes_value.value))
-                .unwrap_or(DEFAULT_CODE_METADATA),
-            contract_owner: account
-                .owner
-                .as_ref()
-                .map(|address_value| address_value.to_vm_address()),
-            developer_rewards: account
-                .developer_rewards
-                .as_ref()
-                .map(|rewards| rewards.value.clone())
-                .unwrap_or_default(),
-        });
+        for (token_identifier, dcdt) in account.dcdt.iter() {
+            if let crate::scenario::model::Dcdt::Full(full_dcdt) = dcdt {
+                if let Some(paused) = &full_dcdt.paused {
+                    state.set_dcdt_token_paused(&token_identifier.value, paused.value > 0);
+                }
+            }
+        }
+        state.validate_and_add_account(convert_scenario_account_to_world_mock(address, account));
     }
     for new_address in set_state_step.new_addresses.iter() {
         assert!(
@@ -95,6 +52,64 @@ fn execute(state: &mut BlockchainState, set_state_step: &SetStateStep) {
     }
 }
 
+/// Converts an account from a scenario "setState" step to the debugger representation.
+pub fn convert_scenario_account_to_world_mock(
+    address: &AddressKey,
+    account: &Account,
+) -> AccountData {
+    let storage = account
+        .storage
+        .iter()
+        .map(|(k, v)| (k.value.clone(), v.value.clone()))
+        .collect();
+    let dcdt = AccountDcdt::new_from_raw_map(
+        account
+            .dcdt
+            .iter()
+            .map(|(k, v)| (k.value.clone(), convert_denali_dcdt_to_world_mock(v)))
+            .collect(),
+    );
+
+    AccountData {
+        address: address.to_vm_address(),
+        nonce: account
+            .nonce
+            .as_ref()
+            .map(|nonce| nonce.value)
+            .unwrap_or_default(),
+        rewa_balance: account
+            .balance
+            .as_ref()
+            .map(|balance| balance.value.clone())
+            .unwrap_or_default(),
+        dcdt,
+        username: account
+            .username
+            .as_ref()
+            .map(|bytes_value| bytes_value.value.clone())
+            .unwrap_or_default(),
+        storage,
+        contract_path: account
+            .code
+            .as_ref()
+            .map(|bytes_value| bytes_value.value.clone()),
+        code_metadata: account
+            .code_metadata
+            .as_ref()
+            .map(|bytes_value| VMCodeMetadata::from(&bytes_value.value))
+            .unwrap_or(DEFAULT_CODE_METADATA),
+        contract_owner: account
+            .owner
+            .as_ref()
+            .map(|address_value| address_value.to_vm_address()),
+        developer_rewards: account
+            .developer_rewards
+            .as_ref()
+            .map(|rewards| rewards.value.clone())
+            .unwrap_or_default(),
+    }
+}
+
 fn convert_denali_dcdt_to_world_mock(denali_dcdt: &crate::scenario::model::Dcdt) -> DcdtData {
     match denali_dcdt {
         crate::scenario::model::Dcdt::Short(short_dcdt) => {
@@ -151,7 +166,11 @@ fn convert_scenario_dcdt_instance_to_world_mock(
             .map(|value| value.value.clone())
             .unwrap_or_default(),
         metadata: DcdtInstanceMetadata {
-            name: Vec::new(),
+            name: scenario_dcdt
+                .name
+                .as_ref()
+                .map(|name| name.value.clone())
+                .unwrap_or_default(),
             creator: scenario_dcdt
                 .creator
                 .as_ref()
@@ -173,6 +192,11 @@ fn convert_scenario_dcdt_instance_to_world_mock(
                 .map(|attributes| attributes.value.clone())
                 .unwrap_or_default(),
         },
+        frozen: scenario_dcdt
+            .frozen
+            .as_ref()
+            .map(|frozen| frozen.value > 0)
+            .unwrap_or_default(),
     }
 }
 
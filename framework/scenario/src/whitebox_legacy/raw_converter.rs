@@ -79,9 +79,11 @@ pub(crate) fn dcdt_data_as_raw(dcdt: &DcdtData) -> DcdtRaw {
             balance: Some(rust_biguint_as_raw(&inst.balance)),
             creator: inst.metadata.creator.as_ref().map(vm_address_as_raw),
             hash: inst.metadata.hash.as_ref().map(bytes_as_raw),
+            name: Some(bytes_as_raw(&inst.metadata.name)),
             nonce: Some(u64_as_raw(inst.nonce)),
             royalties: Some(u64_as_raw(inst.metadata.royalties)),
             uri: inst.metadata.uri.iter().map(bytes_as_raw).collect(),
+            frozen: None,
         };
 
         instances_raw.push(inst_raw);
@@ -93,6 +95,7 @@ pub(crate) fn dcdt_data_as_raw(dcdt: &DcdtData) -> DcdtRaw {
         last_nonce: last_nonce_raw,
         roles: roles_raw,
         token_identifier: None,
+        paused: None,
     })
 }
 
@@ -188,6 +191,7 @@ pub(crate) fn account_as_check_state_raw(acc: &AccountData) -> CheckAccountsRaw
                 balance: opt_raw_value_to_check_raw(&inst_raw.balance),
                 creator: opt_raw_value_to_check_raw(&inst_raw.creator),
                 hash: opt_raw_value_to_check_raw(&inst_raw.hash),
+                name: opt_raw_value_to_check_raw(&inst_raw.name),
                 nonce: inst_raw
                     .nonce
                     .clone()
@@ -200,6 +204,7 @@ pub(crate) fn account_as_check_state_raw(acc: &AccountData) -> CheckAccountsRaw
                         .map(|v| CheckBytesValueRaw::Equal(v.clone()))
                         .collect(),
                 ),
+                frozen: CheckBytesValueRaw::Unspecified,
             };
 
             dcdt_instances_check_raw.push(inst_check_raw);
@@ -213,6 +218,7 @@ pub(crate) fn account_as_check_state_raw(acc: &AccountData) -> CheckAccountsRaw
 
         let dcdt_check_raw = CheckDcdtDataRaw {
             frozen: CheckBytesValueRaw::Unspecified,
+            paused: CheckBytesValueRaw::Unspecified,
             last_nonce: last_nonce_check,
             instances: CheckDcdtInstancesRaw::Equal(dcdt_instances_check_raw),
             roles: roles_as_str,
@@ -3,7 +3,7 @@ use dharitri_sc::{
     derive::{debug_const_managed_decimal, debug_managed_decimal},
     types::{
         BigFloat, BigInt, BigUint, ConstDecimals, Decimals, ManagedDecimal, ManagedDecimalSigned,
-        NumDecimals,
+        NumDecimals, RoundingMode,
     },
 };
 use dharitri_sc_scenario::api::StaticApi;
@@ -558,3 +558,267 @@ fn test_managed_decimal_log2() {
 
     assert_eq!(log2_hp_frac.to_string(), "8.823953218");
 }
+
+#[test]
+fn test_managed_decimal_log10() {
+    let fixed =
+        ManagedDecimal::<StaticApi, NumDecimals>::from_raw_units(BigUint::from(1000u64), 0usize);
+    assert_eq!(fixed.log10().unwrap().to_string(), "3.000000000");
+
+    let v_small =
+        ManagedDecimal::<StaticApi, ConstDecimals<2>>::const_decimals_from_raw(BigUint::from(1u64));
+    // 0.01
+    assert_eq!(v_small.log10().unwrap().to_string(), "-2.000000000");
+
+    let frac =
+        ManagedDecimal::<StaticApi, NumDecimals>::from_raw_units(BigUint::from(288221u64), 3usize);
+    // 288.221
+    assert_eq!(frac.log10().unwrap().to_string(), "2.459725621");
+
+    let zero = ManagedDecimal::<StaticApi, ConstDecimals<2>>::from(BigUint::zero());
+    assert!(zero.log10().is_none());
+
+    let negative = ManagedDecimalSigned::<StaticApi, ConstDecimals<1>>::const_decimals_from_raw(
+        BigInt::from(-5i64),
+    );
+    assert!(negative.log10().is_none());
+
+    let half = ManagedDecimalSigned::<StaticApi, ConstDecimals<1>>::const_decimals_from_raw(
+        BigInt::from(5i64),
+    );
+    assert_eq!(half.log10().unwrap().to_string(), "-0.301029996");
+}
+
+#[test]
+fn test_managed_decimal_exp() {
+    let one = ManagedDecimal::<StaticApi, ConstDecimals<18>>::from(BigUint::from(1u64));
+    assert_eq!(one.exp().to_string(), "2.718281828459045235");
+
+    let frac = ManagedDecimal::<StaticApi, NumDecimals>::from_raw_units(
+        BigUint::from(2_500_000_000u64),
+        9usize,
+    );
+    // 2.5
+    assert_eq!(frac.exp().to_string(), "12.182493960");
+
+    let large = ManagedDecimal::<StaticApi, ConstDecimals<6>>::from(BigUint::from(20u64));
+    assert_eq!(large.exp().to_string(), "485165195.409790");
+
+    let zero = ManagedDecimal::<StaticApi, ConstDecimals<4>>::from(BigUint::zero());
+    assert_eq!(zero.exp().to_string(), "1.0000");
+
+    let negative = ManagedDecimalSigned::<StaticApi, ConstDecimals<9>>::const_decimals_from_raw(
+        BigInt::from(-1_000_000_000i64),
+    );
+    assert_eq!(negative.exp().to_string(), "0.367879441");
+}
+
+#[test]
+fn test_managed_decimal_pow() {
+    let base = ManagedDecimal::<StaticApi, ConstDecimals<2>>::const_decimals_from_raw(
+        BigUint::from(105u64),
+    );
+    // 1.05
+    assert_eq!(base.pow(0).to_string(), "1.00");
+    assert_eq!(base.pow(1).to_string(), "1.05");
+    assert_eq!(base.pow(3).to_string(), "1.15");
+
+    let negative = ManagedDecimalSigned::<StaticApi, ConstDecimals<1>>::const_decimals_from_raw(
+        BigInt::from(-15i64),
+    );
+    // -1.5
+    assert_eq!(negative.pow(2).to_string(), "2.2");
+    assert_eq!(negative.pow(3).to_string(), "-3.3");
+}
+
+#[test]
+fn test_managed_decimal_pow_decimal() {
+    let rate = ManagedDecimal::<StaticApi, ConstDecimals<9>>::const_decimals_from_raw(
+        BigUint::from(1_050_000_000u64),
+    );
+    // 1.05
+    let periods = ManagedDecimal::<StaticApi, ConstDecimals<0>>::from(BigUint::from(10u64));
+    assert_eq!(rate.pow_decimal(&periods).to_string(), "1.628894626");
+
+    let periods = ManagedDecimal::<StaticApi, ConstDecimals<1>>::const_decimals_from_raw(
+        BigUint::from(25u64),
+    );
+    // 2.5
+    assert_eq!(rate.pow_decimal(&periods).to_string(), "1.129726321");
+
+    let two = ManagedDecimal::<StaticApi, ConstDecimals<9>>::from(BigUint::from(2u64));
+    let half =
+        ManagedDecimal::<StaticApi, ConstDecimals<1>>::const_decimals_from_raw(BigUint::from(5u64));
+    assert_eq!(two.pow_decimal(&half).to_string(), "1.414213562");
+
+    let small = ManagedDecimal::<StaticApi, NumDecimals>::from_raw_units(
+        BigUint::from(500_000_000u64),
+        9usize,
+    );
+    // 0.5
+    let exponent =
+        ManagedDecimal::<StaticApi, NumDecimals>::from_raw_units(BigUint::from(15u64), 1usize);
+    // 1.5
+    assert_eq!(small.pow_decimal(&exponent).to_string(), "0.353553390");
+
+    let large = ManagedDecimal::<StaticApi, ConstDecimals<4>>::from(BigUint::from(100u64));
+    let exponent = ManagedDecimal::<StaticApi, ConstDecimals<2>>::const_decimals_from_raw(
+        BigUint::from(325u64),
+    );
+    // 3.25
+    assert_eq!(large.pow_decimal(&exponent).to_string(), "3162277.6601");
+
+    let zero_exponent = ManagedDecimal::<StaticApi, ConstDecimals<2>>::from(BigUint::zero());
+    assert_eq!(large.pow_decimal(&zero_exponent).to_string(), "1.0000");
+}
+
+#[test]
+fn test_managed_decimal_roots() {
+    let two = ManagedDecimal::<StaticApi, ConstDecimals<9>>::from(BigUint::from(2u64));
+    assert_eq!(two.sqrt().to_string(), "1.414213562");
+    assert_eq!(two.nth_root(2).to_string(), "1.414213562");
+    assert_eq!(two.nth_root(3).to_string(), "1.259921049");
+    assert_eq!(two.nth_root(1).to_string(), "2.000000000");
+
+    let cube =
+        ManagedDecimal::<StaticApi, NumDecimals>::from_raw_units(BigUint::from(27_000u64), 3usize);
+    assert_eq!(cube.nth_root(3).to_string(), "3.000");
+
+    let half = ManagedDecimal::<StaticApi, NumDecimals>::from_raw_units(
+        BigUint::from(500_000_000u64),
+        9usize,
+    );
+    assert_eq!(half.nth_root(5).to_string(), "0.870550563");
+
+    let zero = ManagedDecimal::<StaticApi, ConstDecimals<2>>::from(BigUint::zero());
+    assert_eq!(zero.sqrt().to_string(), "0.00");
+    assert_eq!(zero.nth_root(4).to_string(), "0.00");
+}
+
+#[test]
+fn test_managed_decimal_rescale_with_rounding() {
+    let cases = [
+        // value with 3 decimals, down, up, half up, half even, all with 1 decimal
+        (1_250u64, "1.2", "1.3", "1.3", "1.2"),
+        (1_350u64, "1.3", "1.4", "1.4", "1.4"),
+        (1_249u64, "1.2", "1.3", "1.2", "1.2"),
+        (1_251u64, "1.2", "1.3", "1.3", "1.3"),
+        (1_200u64, "1.2", "1.2", "1.2", "1.2"),
+    ];
+
+    for (raw, down, up, half_up, half_even) in cases {
+        let dec = ManagedDecimal::<StaticApi, ConstDecimals<3>>::const_decimals_from_raw(
+            BigUint::from(raw),
+        );
+        let rescaled = |rounding| dec.rescale_with_rounding(1usize, rounding).to_string();
+        assert_eq!(rescaled(RoundingMode::Down), down);
+        assert_eq!(rescaled(RoundingMode::Up), up);
+        assert_eq!(rescaled(RoundingMode::HalfUp), half_up);
+        assert_eq!(rescaled(RoundingMode::HalfEven), half_even);
+    }
+
+    let dec = ManagedDecimal::<StaticApi, NumDecimals>::from_raw_units(BigUint::from(5u64), 1usize);
+    let rescaled = dec.rescale_with_rounding(ConstDecimals::<3>, RoundingMode::Up);
+    assert_eq!(rescaled.to_string(), "0.500");
+}
+
+#[test]
+fn test_managed_decimal_signed_rescale_with_rounding() {
+    let dec = ManagedDecimalSigned::<StaticApi, ConstDecimals<3>>::const_decimals_from_raw(
+        BigInt::from(-1_250i64),
+    );
+
+    let rescaled = |rounding| dec.rescale_with_rounding(1usize, rounding).to_string();
+    assert_eq!(rescaled(RoundingMode::Down), "-1.2");
+    assert_eq!(rescaled(RoundingMode::Up), "-1.3");
+    assert_eq!(rescaled(RoundingMode::HalfUp), "-1.3");
+    assert_eq!(rescaled(RoundingMode::HalfEven), "-1.2");
+}
+
+#[test]
+fn test_managed_decimal_mul_div_with_rounding() {
+    let amount = ManagedDecimal::<StaticApi, ConstDecimals<2>>::const_decimals_from_raw(
+        BigUint::from(1001u64),
+    );
+    // 10.01
+    let fee = ManagedDecimal::<StaticApi, NumDecimals>::from_raw_units(BigUint::from(3u64), 2usize);
+    // 0.03 => 0.3003
+
+    assert_eq!(
+        amount
+            .mul_with_rounding(&fee, RoundingMode::Down)
+            .to_string(),
+        "0.30"
+    );
+    assert_eq!(
+        amount.mul_with_rounding(&fee, RoundingMode::Up).to_string(),
+        "0.31"
+    );
+    assert_eq!(
+        amount
+            .mul_with_rounding(&fee, RoundingMode::HalfUp)
+            .to_string(),
+        "0.30"
+    );
+
+    let three = ManagedDecimal::<StaticApi, ConstDecimals<0>>::from(BigUint::from(3u64));
+    // 3.336666...
+    assert_eq!(
+        amount
+            .div_with_rounding(&three, RoundingMode::Down)
+            .to_string(),
+        "3.33"
+    );
+    assert_eq!(
+        amount
+            .div_with_rounding(&three, RoundingMode::Up)
+            .to_string(),
+        "3.34"
+    );
+    assert_eq!(
+        amount
+            .div_with_rounding(&three, RoundingMode::HalfEven)
+            .to_string(),
+        "3.34"
+    );
+
+    let two = ManagedDecimal::<StaticApi, ConstDecimals<0>>::from(BigUint::from(2u64));
+    // 5.005
+    assert_eq!(
+        amount
+            .div_with_rounding(&two, RoundingMode::HalfUp)
+            .to_string(),
+        "5.01"
+    );
+    assert_eq!(
+        amount
+            .div_with_rounding(&two, RoundingMode::HalfEven)
+            .to_string(),
+        "5.00"
+    );
+
+    let negative = ManagedDecimalSigned::<StaticApi, ConstDecimals<2>>::const_decimals_from_raw(
+        BigInt::from(-1001i64),
+    );
+    let two_signed = ManagedDecimalSigned::<StaticApi, ConstDecimals<0>>::const_decimals_from_raw(
+        BigInt::from(2i64),
+    );
+    assert_eq!(
+        negative
+            .div_with_rounding(&two_signed, RoundingMode::Down)
+            .to_string(),
+        "-5.00"
+    );
+    assert_eq!(
+        negative
+            .div_with_rounding(&two_signed, RoundingMode::Up)
+            .to_string(),
+        "-5.01"
+    );
+    assert_eq!(
+        negative
+            .mul_with_rounding(&two_signed, RoundingMode::Up)
+            .to_string(),
+        "-20.02"
+    );
+}
@@ -0,0 +1,192 @@
+use crate::sdk::{blockchain::CommunicationProxy, data::address::Address as SdkAddress};
+use dharitri_sc_scenario::{
+    dharitri_chain_vm::{
+        types::VMAddress,
+        world_mock::{AccountData, AccountStorage, ForkSource},
+    },
+    dharitri_sc::types::Address,
+    scenario::{parse_scenario, run_vm::convert_scenario_account_to_world_mock},
+    scenario_format::interpret_trait::IntoRaw,
+    scenario_model::{Account, AddressKey, BytesKey, BytesValue, Scenario, SetStateStep, Step},
+};
+use std::{
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+use tokio::runtime::Runtime;
+
+/// Loads accounts from a gateway into the debugger, on first access. Used with `ScenarioWorld::fork_source`.
+///
+/// Storage is retrieved one key at a time, only for the keys that are actually read.
+///
+/// All retrieved data is saved to a cache file, as a scenario `setState` step.
+/// Accounts and keys found in the cache are never requested again,
+/// so once recorded, the cache file can also be used on its own, offline.
+///
+/// Contracts are retrieved with their on-chain code,
+/// they need to be registered in the world under this same code, to be executed.
+///
+/// The VM hooks are synchronous, so requests are run to completion on a runtime owned by the fork source.
+/// Because of this, the debugger must not run inside an async runtime in fork mode.
+pub struct GatewayForkSource {
+    proxy: Option<CommunicationProxy>,
+    runtime: Runtime,
+    cache_path: PathBuf,
+    cache: Mutex<SetStateStep>,
+}
+
+impl GatewayForkSource {
+    /// Reads through to the gateway, caching results in the given file.
+    pub fn new<P: AsRef<Path>>(proxy: CommunicationProxy, cache_path: P) -> Self {
+        Self::new_with_optional_proxy(Some(proxy), cache_path)
+    }
+
+    /// Only uses the accounts and keys already recorded in the cache file.
+    pub fn offline<P: AsRef<Path>>(cache_path: P) -> Self {
+        Self::new_with_optional_proxy(None, cache_path)
+    }
+
+    fn new_with_optional_proxy<P: AsRef<Path>>(
+        proxy: Option<CommunicationProxy>,
+        cache_path: P,
+    ) -> Self {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .expect("failed to start runtime for fork source");
+        let cache_path = cache_path.as_ref().to_path_buf();
+        let cache = load_cache(&cache_path);
+        GatewayForkSource {
+            proxy,
+            runtime,
+            cache_path,
+            cache: Mutex::new(cache),
+        }
+    }
+
+    /// Account data, DCDT balances and roles, but no storage.
+    fn retrieve_account(
+        &self,
+        proxy: &CommunicationProxy,
+        address: &SdkAddress,
+    ) -> Option<Account> {
+        self.runtime.block_on(async {
+            let sdk_account = proxy.get_account(address).await.ok()?;
+            let account_dcdt = proxy
+                .get_account_dcdt_tokens(address)
+                .await
+                .unwrap_or_else(|err| {
+                    panic!("failed to retrieve DCDT tokens for address {address}: {err}")
+                });
+            let account_dcdt_roles =
+                proxy
+                    .get_account_dcdt_roles(address)
+                    .await
+                    .unwrap_or_else(|err| {
+                        panic!("failed to retrieve DCDT roles for address {address}: {err}")
+                    });
+
+            let mut account = Account::new()
+                .nonce(sdk_account.nonce)
+                .balance(sdk_account.balance.as_str())
+                .code(BytesValue::from_hex(&sdk_account.code));
+            account.username = Some(format!("str:{}", sdk_account.username).into());
+
+            for dcdt_balance in account_dcdt.values() {
+                let token_id_expr = format!("str:{}", dcdt_balance.token_identifier);
+                account =
+                    account.dcdt_balance(token_id_expr.as_str(), dcdt_balance.balance.as_str());
+            }
+            for (token_id, dcdt_roles) in account_dcdt_roles {
+                let token_id_expr = format!("str:{token_id}");
+                account = account.dcdt_roles(token_id_expr.as_str(), dcdt_roles);
+            }
+
+            Some(account)
+        })
+    }
+
+    fn retrieve_storage(
+        &self,
+        proxy: &CommunicationProxy,
+        address: &SdkAddress,
+        key: &[u8],
+    ) -> Vec<u8> {
+        let value_hex = self
+            .runtime
+            .block_on(proxy.get_account_storage_value(address, &hex::encode(key)))
+            .unwrap_or_else(|err| {
+                panic!("failed to retrieve storage for address {address}: {err}")
+            });
+        hex::decode(value_hex).expect("invalid storage value hex received from gateway")
+    }
+
+    fn save_cache(&self, cache: &SetStateStep) {
+        let scenario = Scenario {
+            name: None,
+            comment: None,
+            check_gas: None,
+            steps: vec![Step::SetState(cache.clone())],
+        };
+        std::fs::write(&self.cache_path, scenario.into_raw().to_json_string())
+            .expect("failed to write fork source cache file");
+    }
+}
+
+impl ForkSource for GatewayForkSource {
+    fn fetch_account(&self, address: &VMAddress) -> Option<AccountData> {
+        let address_key = AddressKey::from(&Address::from(address.as_array()));
+        let mut cache = self.cache.lock().unwrap();
+
+        if !cache.accounts.contains_key(&address_key) {
+            let proxy = self.proxy.as_ref()?;
+            let account =
+                self.retrieve_account(proxy, &SdkAddress::from_bytes(*address.as_array()))?;
+            cache.accounts.insert(address_key.clone(), account);
+            self.save_cache(&cache);
+        }
+
+        let mut account_data =
+            convert_scenario_account_to_world_mock(&address_key, &cache.accounts[&address_key]);
+        account_data.storage = AccountStorage::default();
+        Some(account_data)
+    }
+
+    fn fetch_storage(&self, address: &VMAddress, key: &[u8]) -> Vec<u8> {
+        let address_key = AddressKey::from(&Address::from(address.as_array()));
+        let storage_key = BytesKey::from_hex(&hex::encode(key));
+        let mut cache = self.cache.lock().unwrap();
+
+        let Some(account) = cache.accounts.get_mut(&address_key) else {
+            return Vec::new();
+        };
+        if let Some(value) = account.storage.get(&storage_key) {
+            return value.value.clone();
+        }
+        let Some(proxy) = &self.proxy else {
+            return Vec::new();
+        };
+
+        let value = self.retrieve_storage(proxy, &SdkAddress::from_bytes(*address.as_array()), key);
+        // empty values are also recorded, so that missing keys are not requested again
+        account
+            .storage
+            .insert(storage_key, BytesValue::from(value.clone()));
+        self.save_cache(&cache);
+        value
+    }
+}
+
+fn load_cache(cache_path: &Path) -> SetStateStep {
+    let mut cache = SetStateStep::new();
+    if !cache_path.exists() {
+        return cache;
+    }
+
+    for step in parse_scenario(cache_path).steps {
+        if let Step::SetState(set_state) = step {
+            cache.accounts.extend(set_state.accounts);
+        }
+    }
+    cache
+}
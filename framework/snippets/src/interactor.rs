@@ -1,7 +1,8 @@
 use dharitri_sc_scenario::{
-    imports::{retrieve_account_as_scenario_set_state, Bech32Address, ScenarioRunner},
     denali_system::{run_list::ScenarioRunnerList, run_trace::ScenarioTraceFile},
     dharitri_sc::types::Address,
+    imports::{retrieve_account_as_scenario_set_state, Bech32Address, ScenarioRunner},
+    meta::abi_json::{decode_account_storage, ContractAbiJson, DecodedStorageEntry},
     scenario_model::AddressValue,
 };
 use dharitri_sdk::{
@@ -54,6 +55,8 @@ impl Interactor {
                 address: address.clone(),
                 wallet,
                 current_nonce: None,
+                guardian: None,
+                relayer: None,
             },
         );
         address
@@ -74,6 +77,23 @@ impl Interactor {
         self.pre_runners.run_set_state_step(&set_state);
         self.post_runners.run_set_state_step(&set_state);
     }
+
+    /// Retrieves the storage of a contract and decodes it into named, typed entries,
+    /// based on the storage section of the contract ABI.
+    pub async fn retrieve_decoded_storage(
+        &self,
+        contract_address: &Bech32Address,
+        contract_abi: &ContractAbiJson,
+    ) -> Vec<DecodedStorageEntry> {
+        let raw_storage = self
+            .proxy
+            .get_account_storage_keys(&address_h256_to_drtrs(contract_address.as_address()))
+            .await
+            .unwrap_or_else(|err| {
+                panic!("failed to retrieve storage for address {contract_address}: {err}")
+            });
+        decode_account_storage(contract_abi, &raw_storage)
+    }
 }
 
 pub(crate) fn denali_to_drtrs_address(denali_address: &AddressValue) -> DrtrsAddress {
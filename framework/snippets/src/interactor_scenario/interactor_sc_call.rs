@@ -66,6 +66,10 @@ impl Interactor {
             chain_id: self.network_config.chain_id.clone(),
             version: self.network_config.min_transaction_version,
             options: 0,
+            guardian: None,
+            guardian_signature: None,
+            relayer: None,
+            relayer_signature: None,
         }
     }
 }
@@ -6,6 +6,11 @@ use dharitri_sc_scenario::{
     api::StaticApi,
     denali_system::ScenarioRunner,
     dharitri_sc::{abi::TypeAbiFrom, codec::TopDecodeMulti, types::ContractCall},
+    imports::Bech32Address,
+    meta::{
+        abi_codec::{AbiCodec, AbiCodecError, AbiValue},
+        abi_json::ContractAbiJson,
+    },
     scenario_model::{ScQueryStep, TxResponse},
 };
 use dharitri_sdk::{data::vm::VmValueRequest, utils::base64_decode};
@@ -48,6 +53,37 @@ impl Interactor {
         self.post_runners.run_sc_query_step(step);
     }
 
+    /// Performs a VM query based only on the contract ABI, without a compiled proxy.
+    ///
+    /// The arguments are given as JSON and the results are decoded according to the endpoint outputs in the ABI.
+    pub async fn query_with_abi(
+        &self,
+        contract_address: &Bech32Address,
+        contract_abi: &ContractAbiJson,
+        endpoint_name: &str,
+        args: &[serde_json::Value],
+    ) -> Result<Vec<AbiValue>, AbiCodecError> {
+        let codec = AbiCodec::new(contract_abi);
+        let raw_args = codec.encode_endpoint_args(endpoint_name, args)?;
+
+        let sc_address = address_h256_to_drtrs(contract_address.as_address());
+        let req = VmValueRequest {
+            sc_address: sc_address.clone(),
+            func_name: endpoint_name.to_string(),
+            args: raw_args.iter().map(hex::encode).collect(),
+            caller: sc_address,
+            value: "0".to_string(),
+        };
+        let result = self
+            .proxy
+            .execute_vmquery(&req)
+            .await
+            .expect("error executing VM query");
+
+        let raw_results: Vec<Vec<u8>> = result.data.return_data.iter().map(base64_decode).collect();
+        codec.decode_endpoint_results(endpoint_name, &raw_results)
+    }
+
     #[deprecated(since = "0.42.0", note = "Was renamed to `quick_query`.")]
     pub async fn vm_query<CC, RequestedResult>(&mut self, contract_call: CC) -> RequestedResult
     where
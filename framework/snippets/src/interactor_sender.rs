@@ -9,6 +9,12 @@ pub struct Sender {
     pub address: Address,
     pub wallet: Wallet,
     pub current_nonce: Option<u64>,
+
+    /// If set, all transactions from this sender are guarded, and co-signed by this registered wallet.
+    pub guardian: Option<Address>,
+
+    /// If set, all transactions from this sender are relayed, and co-signed by this registered wallet.
+    pub relayer: Option<Address>,
 }
 
 impl Interactor {
@@ -22,6 +28,34 @@ impl Interactor {
         account.nonce
     }
 
+    /// Transactions from `sender` will be guarded by `guardian`.
+    ///
+    /// Both wallets need to be registered, the guardian also needs to be set on chain beforehand.
+    pub fn set_guardian(&mut self, sender: &Address, guardian: &Address) {
+        assert!(
+            self.sender_map.contains_key(guardian),
+            "the guardian wallet is not registered"
+        );
+        self.sender_mut(sender).guardian = Some(guardian.clone());
+    }
+
+    /// Transactions from `sender` will be relayed by `relayer`, which pays for the gas.
+    ///
+    /// Both wallets need to be registered.
+    pub fn set_relayer(&mut self, sender: &Address, relayer: &Address) {
+        assert!(
+            self.sender_map.contains_key(relayer),
+            "the relayer wallet is not registered"
+        );
+        self.sender_mut(sender).relayer = Some(relayer.clone());
+    }
+
+    fn sender_mut(&mut self, sender_address: &Address) -> &mut Sender {
+        self.sender_map
+            .get_mut(sender_address)
+            .expect("the wallet that was supposed to sign is not registered")
+    }
+
     pub(crate) async fn set_nonce_and_sign_tx(
         &mut self,
         sender_address: &Address,
@@ -48,9 +82,50 @@ impl Interactor {
             .expect("the wallet that was supposed to sign is not registered");
         sender.current_nonce = Some(nonce + 1);
 
+        self.sign_tx(sender_address, transaction);
+    }
+
+    /// Signs the transaction as sender, then co-signs it with the guardian and relayer wallets, if configured.
+    pub(crate) fn sign_tx(&self, sender_address: &Address, transaction: &mut Transaction) {
+        let sender = self
+            .sender_map
+            .get(sender_address)
+            .expect("the wallet that was supposed to sign is not registered");
+
+        // guardian and relayer are part of the signed content
+        if let Some(guardian) = &sender.guardian {
+            transaction.set_guardian(address_h256_to_drtrs(guardian));
+        }
+        if let Some(relayer) = &sender.relayer {
+            transaction.set_relayer(address_h256_to_drtrs(relayer));
+            // the relayer pays an extra base cost
+            transaction.gas_limit += self.network_config.min_gas_limit;
+        }
+
         // sign
         let signature = sender.wallet.sign_tx(transaction);
         transaction.signature = Some(hex::encode(signature));
+
+        // co-sign
+        if let Some(guardian) = &sender.guardian {
+            self.co_signer_wallet(guardian)
+                .sign_tx_as_guardian(transaction)
+                .expect("failed to co-sign transaction as guardian");
+        }
+        if let Some(relayer) = &sender.relayer {
+            self.co_signer_wallet(relayer)
+                .sign_tx_as_relayer(transaction)
+                .expect("failed to co-sign transaction as relayer");
+        }
+
         debug!("transaction {:#?}", transaction);
     }
+
+    fn co_signer_wallet(&self, address: &Address) -> &Wallet {
+        &self
+            .sender_map
+            .get(address)
+            .expect("the co-signer wallet is not registered")
+            .wallet
+    }
 }
@@ -1,3 +1,4 @@
+mod gateway_fork_source;
 mod interactor;
 mod interactor_dns;
 mod interactor_retrieve;
@@ -7,6 +8,7 @@ mod interactor_tx;
 mod multi;
 
 pub use env_logger;
+pub use gateway_fork_source::GatewayForkSource;
 pub use hex;
 pub use interactor::*;
 pub use interactor_dns::*;
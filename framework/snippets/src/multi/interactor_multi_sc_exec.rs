@@ -1,4 +1,4 @@
-use super::interactor_multi_sc_process::{update_nonces_and_sign_tx, SenderSet, Txs};
+use super::interactor_multi_sc_process::{update_nonce, SenderSet, Txs};
 use crate::{Interactor, InteractorStep, StepBuffer};
 use dharitri_sc_scenario::scenario_model::TxResponse;
 use dharitri_sdk::data::transaction::Transaction;
@@ -35,7 +35,8 @@ impl Interactor {
                 .get_mut(sender_address)
                 .expect("sender not registered");
 
-            update_nonces_and_sign_tx(&mut transaction, sender);
+            update_nonce(&mut transaction, sender);
+            self.sign_tx(sender_address, &mut transaction);
             txs.push(transaction);
         }
         txs
@@ -38,10 +38,7 @@ impl Interactor {
     }
 }
 
-pub(crate) fn update_nonces_and_sign_tx(transaction: &mut Transaction, sender: &mut Sender) {
+pub(crate) fn update_nonce(transaction: &mut Transaction, sender: &mut Sender) {
     transaction.nonce = sender.current_nonce.unwrap();
     sender.current_nonce = Some(sender.current_nonce.unwrap() + 1);
-
-    let signature = sender.wallet.sign_tx(&*transaction);
-    transaction.signature = Some(hex::encode(signature));
 }
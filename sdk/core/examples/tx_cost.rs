@@ -21,6 +21,10 @@ async fn main() {
         chain_id: "1".to_string(),
         version: 1,
         options: 0,
+        guardian: None,
+        guardian_signature: None,
+        relayer: None,
+        relayer_signature: None,
         gas_limit: 0,
         gas_price: 0,
         signature: None,
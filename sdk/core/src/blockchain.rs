@@ -2,7 +2,7 @@ use std::collections::HashMap;
 
 use crate::data::{
     account::{Account, AccountResponse},
-    account_storage::AccountStorageResponse,
+    account_storage::{AccountStorageResponse, AccountStorageValueResponse},
     address::Address,
     dcdt::{DcdtBalance, DcdtBalanceResponse, DcdtRolesResponse},
     hyperblock::{HyperBlock, HyperBlockResponse},
@@ -30,6 +30,7 @@ const NETWORK_CONFIG_ENDPOINT: &str = "network/config";
 const NETWORK_ECONOMICS_ENDPOINT: &str = "network/economics";
 const ACCOUNT_ENDPOINT: &str = "address/";
 const KEYS_ENDPOINT: &str = "/keys/";
+const KEY_ENDPOINT: &str = "/key/";
 const COST_TRANSACTION_ENDPOINT: &str = "transaction/cost";
 const SEND_TRANSACTION_ENDPOINT: &str = "transaction/send";
 const SEND_MULTIPLE_TRANSACTIONS_ENDPOINT: &str = "transaction/send-multiple";
@@ -259,6 +260,29 @@ impl CommunicationProxy {
         }
     }
 
+    // get_account_storage_value retrieves the hex encoded value of a single storage key, given in hex
+    pub async fn get_account_storage_value(&self, address: &Address, key: &str) -> Result<String> {
+        if !address.is_valid() {
+            return Err(anyhow!("invalid address"));
+        }
+
+        let endpoint =
+            ACCOUNT_ENDPOINT.to_string() + address.to_string().as_str() + KEY_ENDPOINT + key;
+        let endpoint = self.get_endpoint(endpoint.as_str());
+        let resp = self
+            .client
+            .get(endpoint)
+            .send()
+            .await?
+            .json::<AccountStorageValueResponse>()
+            .await?;
+
+        match resp.data {
+            None => Err(anyhow!("{}", resp.error)),
+            Some(b) => Ok(b.value),
+        }
+    }
+
     async fn get_transaction_info_internal(
         &self,
         hash: &str,
@@ -403,3 +427,59 @@ impl CommunicationProxy {
         }
     }
 }
+
+/// The network requests used to build, send and track transactions, and to check native auth tokens.
+///
+/// Implemented by [`CommunicationProxy`], can be replaced with a custom implementation, e.g. in tests.
+#[allow(async_fn_in_trait)]
+pub trait BlockchainProxy {
+    async fn get_network_config(&self) -> Result<NetworkConfig>;
+
+    async fn get_hyper_block_by_hash(&self, hash: &str) -> Result<HyperBlock>;
+
+    async fn get_hyper_block_by_nonce(&self, nonce: u64) -> Result<HyperBlock>;
+
+    async fn get_latest_hyper_block_nonce(&self, with_metachain: bool) -> Result<u64>;
+
+    async fn request_transaction_cost(&self, tx: &Transaction) -> Result<TxCostResponseData>;
+
+    async fn get_account(&self, address: &Address) -> Result<Account>;
+
+    async fn get_transaction_status(&self, hash: &str) -> Result<String>;
+
+    async fn send_transaction(&self, tx: &Transaction) -> Result<String>;
+}
+
+impl BlockchainProxy for CommunicationProxy {
+    async fn get_network_config(&self) -> Result<NetworkConfig> {
+        CommunicationProxy::get_network_config(self).await
+    }
+
+    async fn get_hyper_block_by_hash(&self, hash: &str) -> Result<HyperBlock> {
+        CommunicationProxy::get_hyper_block_by_hash(self, hash).await
+    }
+
+    async fn get_hyper_block_by_nonce(&self, nonce: u64) -> Result<HyperBlock> {
+        CommunicationProxy::get_hyper_block_by_nonce(self, nonce).await
+    }
+
+    async fn get_latest_hyper_block_nonce(&self, with_metachain: bool) -> Result<u64> {
+        CommunicationProxy::get_latest_hyper_block_nonce(self, with_metachain).await
+    }
+
+    async fn request_transaction_cost(&self, tx: &Transaction) -> Result<TxCostResponseData> {
+        CommunicationProxy::request_transaction_cost(self, tx).await
+    }
+
+    async fn get_account(&self, address: &Address) -> Result<Account> {
+        CommunicationProxy::get_account(self, address).await
+    }
+
+    async fn get_transaction_status(&self, hash: &str) -> Result<String> {
+        CommunicationProxy::get_transaction_status(self, hash).await
+    }
+
+    async fn send_transaction(&self, tx: &Transaction) -> Result<String> {
+        CommunicationProxy::send_transaction(self, tx).await
+    }
+}
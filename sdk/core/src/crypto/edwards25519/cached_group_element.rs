@@ -0,0 +1,9 @@
+use super::field_element::FieldElement;
+
+#[derive(Default, Copy, Clone, Debug)]
+pub struct CachedGroupElement {
+    pub y_plus_x: FieldElement,
+    pub y_minus_x: FieldElement,
+    pub z: FieldElement,
+    pub t2d: FieldElement,
+}
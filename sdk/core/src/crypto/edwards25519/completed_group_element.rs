@@ -1,6 +1,6 @@
 use super::{
-    extended_group_element::ExtendedGroupElement, field_element::FieldElement,
-    pre_computed_group_element::PreComputedGroupElement,
+    cached_group_element::CachedGroupElement, extended_group_element::ExtendedGroupElement,
+    field_element::FieldElement, pre_computed_group_element::PreComputedGroupElement,
     projective_group_element::ProjectiveGroupElement,
 };
 
@@ -27,6 +27,21 @@ impl CompletedGroupElement {
         self.t.fe_sub(&t0, &self.t.clone());
     }
 
+    pub fn ge_add(&mut self, p: &ExtendedGroupElement, q: &CachedGroupElement) {
+        let mut t0 = FieldElement::default();
+        self.x.fe_add(&p.y, &p.x);
+        self.y.fe_sub(&p.y, &p.x);
+        self.z.fe_mul(&self.x, &q.y_plus_x);
+        self.y.fe_mul(&self.y.clone(), &q.y_minus_x);
+        self.t.fe_mul(&q.t2d, &p.t);
+        self.x.fe_mul(&p.z, &q.z);
+        t0.fe_add(&self.x, &self.x);
+        self.x.fe_sub(&self.z, &self.y);
+        self.y.fe_add(&self.z, &self.y.clone());
+        self.z.fe_add(&t0, &self.t);
+        self.t.fe_sub(&t0, &self.t.clone());
+    }
+
     pub fn to_extended(&self, r: &mut ExtendedGroupElement) {
         r.x.fe_mul(&self.x, &self.t);
         r.y.fe_mul(&self.y, &self.z);
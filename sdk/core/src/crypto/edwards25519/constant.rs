@@ -1,5 +1,22 @@
 use super::{field_element::FieldElement, pre_computed_group_element::PreComputedGroupElement};
 
+// d is the curve constant -121665/121666.
+pub const D: FieldElement = FieldElement([
+    -10913610, 13857413, -15372611, 6949391, 114729, -8787816, -6275908, -3247719, -18696448,
+    -12055116,
+]);
+
+// d2 is 2*d.
+pub const D2: FieldElement = FieldElement([
+    -21827239, -5839606, -30745221, 13898782, 229458, 15978800, -12551817, -6495438, 29715968,
+    9444199,
+]);
+
+// sqrt_m1 is a square root of -1 modulo p.
+pub const SQRT_M1: FieldElement = FieldElement([
+    -32595792, -7943725, 9377950, 3500415, 12389472, -272473, -25146209, -2005654, 326686, 11406482,
+]);
+
 // base contains precomputed multiples of the base-point. See the Ed25519 paper
 // for a discussion about how these values are used.
 pub const BASE: [[PreComputedGroupElement; 8]; 32] = [
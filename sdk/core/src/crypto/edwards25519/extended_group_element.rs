@@ -1,5 +1,8 @@
 use super::{
-    completed_group_element::CompletedGroupElement, field_element::FieldElement,
+    cached_group_element::CachedGroupElement,
+    completed_group_element::CompletedGroupElement,
+    constant::{D, D2, SQRT_M1},
+    field_element::FieldElement,
     pre_computed_group_element::PreComputedGroupElement,
     projective_group_element::ProjectiveGroupElement,
 };
@@ -19,6 +22,15 @@ impl ExtendedGroupElement {
         r.z.fe_copy(&self.z);
     }
 
+    pub fn to_cached(self) -> CachedGroupElement {
+        let mut r = CachedGroupElement::default();
+        r.y_plus_x.fe_add(&self.y, &self.x);
+        r.y_minus_x.fe_sub(&self.y, &self.x);
+        r.z.fe_copy(&self.z);
+        r.t2d.fe_mul(&self.t, &D2);
+        r
+    }
+
     pub fn double(&self, r: &mut CompletedGroupElement) {
         let mut q = ProjectiveGroupElement::default();
 
@@ -86,6 +98,86 @@ impl ExtendedGroupElement {
         }
     }
 
+    // ge_double_scalar_mult_vartime sets self = a*A + b*B, where
+    //   a = a[0]+256*a[1]+...+256^31 a[31].
+    //   B is the Ed25519 base point (x,4/5) with x positive.
+    //
+    // Only meant for signature verification, where all inputs are public:
+    // the running time depends on the value of a.
+    //
+    // Preconditions:
+    //   b[31] <= 127
+    pub fn ge_double_scalar_mult_vartime(
+        &mut self,
+        a: [u8; 32],
+        a_point: &ExtendedGroupElement,
+        b: [u8; 32],
+    ) {
+        let a_cached = a_point.to_cached();
+        let mut r = CompletedGroupElement::default();
+
+        self.zero();
+        for i in (0..256).rev() {
+            self.double(&mut r);
+            r.to_extended(self);
+
+            if (a[i >> 3] >> (i & 7)) & 1 == 1 {
+                r.ge_add(self, &a_cached);
+                r.to_extended(self);
+            }
+        }
+
+        let mut b_point = ExtendedGroupElement::default();
+        b_point.ge_scalar_mult_base(b);
+        r.ge_add(self, &b_point.to_cached());
+        r.to_extended(self);
+    }
+
+    // from_bytes decompresses a point, returns None if s is not the encoding of a curve point.
+    pub fn from_bytes(s: &[u8; 32]) -> Option<ExtendedGroupElement> {
+        let mut p = ExtendedGroupElement::default();
+        let mut u = FieldElement::default();
+        let mut v = FieldElement::default();
+        let mut v3 = FieldElement::default();
+        let mut vxx = FieldElement::default();
+        let mut check = FieldElement::default();
+
+        p.y.fe_from_bytes(s);
+        p.z.fe_one();
+        u.fe_square(&p.y);
+        v.fe_mul(&u, &D);
+        u.fe_sub(&u.clone(), &p.z); // y = y^2-1
+        v.fe_add(&v.clone(), &p.z); // v = dy^2+1
+
+        v3.fe_square(&v);
+        v3.fe_mul(&v3.clone(), &v); // v3 = v^3
+        p.x.fe_square(&v3);
+        p.x.fe_mul(&p.x.clone(), &v);
+        p.x.fe_mul(&p.x.clone(), &u); // x = uv^7
+
+        p.x.fe_pow22523(&p.x.clone()); // x = (uv^7)^((q-5)/8)
+        p.x.fe_mul(&p.x.clone(), &v3);
+        p.x.fe_mul(&p.x.clone(), &u); // x = uv^3(uv^7)^((q-5)/8)
+
+        vxx.fe_square(&p.x);
+        vxx.fe_mul(&vxx.clone(), &v);
+        check.fe_sub(&vxx, &u); // vx^2-u
+        if check.fe_is_nonzero() {
+            check.fe_add(&vxx, &u); // vx^2+u
+            if check.fe_is_nonzero() {
+                return None;
+            }
+            p.x.fe_mul(&p.x.clone(), &SQRT_M1);
+        }
+
+        if p.x.fe_is_negative() != (s[31] >> 7) {
+            p.x.fe_neg(&p.x.clone());
+        }
+
+        p.t.fe_mul(&p.x, &p.y);
+        Some(p)
+    }
+
     pub fn to_bytes(self) -> [u8; 32] {
         let mut recip = FieldElement::default();
         let mut x = FieldElement::default();
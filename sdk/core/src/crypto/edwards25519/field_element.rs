@@ -1,5 +1,7 @@
 use std::marker::Copy;
 
+use super::{load3, load4};
+
 #[derive(Default, Copy, Clone, Debug)]
 pub struct FieldElement(pub [i32; 10]);
 
@@ -15,6 +17,22 @@ impl FieldElement {
         self.0[0] = 1;
     }
 
+    // fe_from_bytes unmarshals s, ignoring the most significant bit.
+    pub fn fe_from_bytes(&mut self, s: &[u8; 32]) {
+        let h0 = load4(s[..].to_vec());
+        let h1 = load3(s[4..].to_vec()) << 6;
+        let h2 = load3(s[7..].to_vec()) << 5;
+        let h3 = load3(s[10..].to_vec()) << 3;
+        let h4 = load3(s[13..].to_vec()) << 2;
+        let h5 = load4(s[16..].to_vec());
+        let h6 = load3(s[20..].to_vec()) << 7;
+        let h7 = load3(s[23..].to_vec()) << 5;
+        let h8 = load3(s[26..].to_vec()) << 4;
+        let h9 = (load3(s[29..].to_vec()) & 8388607) << 2;
+
+        self.fe_combine(h0, h1, h2, h3, h4, h5, h6, h7, h8, h9);
+    }
+
     pub fn fe_add(&mut self, a: &FieldElement, b: &FieldElement) {
         self.0[0] = a.0[0] + b.0[0];
         self.0[1] = a.0[1] + b.0[1];
@@ -516,6 +534,74 @@ impl FieldElement {
         self.fe_mul(&t1, &t0); // 254..5,3,1,0
     }
 
+    // fe_pow22523 sets h = z^((p-5)/8) = z^(2^252-3), used to compute square roots.
+    pub fn fe_pow22523(&mut self, z: &FieldElement) {
+        let mut t0 = FieldElement::default();
+        let mut t1 = FieldElement::default();
+        let mut t2 = FieldElement::default();
+
+        t0.fe_square(z);
+        t1.fe_square(&t0);
+        t1.fe_square(&t1.clone());
+        t1.fe_mul(z, &t1.clone());
+        t0.fe_mul(&t0.clone(), &t1);
+        t0.fe_square(&t0.clone());
+        t0.fe_mul(&t1, &t0.clone());
+        t1.fe_square(&t0);
+
+        for _ in 1..5 {
+            t1.fe_square(&t1.clone());
+        }
+
+        t0.fe_mul(&t1, &t0.clone());
+        t1.fe_square(&t0);
+
+        for _ in 1..10 {
+            t1.fe_square(&t1.clone());
+        }
+
+        t1.fe_mul(&t1.clone(), &t0);
+        t2.fe_square(&t1);
+
+        for _ in 1..20 {
+            t2.fe_square(&t2.clone());
+        }
+
+        t1.fe_mul(&t2, &t1.clone());
+        t1.fe_square(&t1.clone());
+
+        for _ in 1..10 {
+            t1.fe_square(&t1.clone());
+        }
+
+        t0.fe_mul(&t1, &t0.clone());
+        t1.fe_square(&t0);
+
+        for _ in 1..50 {
+            t1.fe_square(&t1.clone());
+        }
+
+        t1.fe_mul(&t1.clone(), &t0);
+        t2.fe_square(&t1);
+
+        for _ in 1..100 {
+            t2.fe_square(&t2.clone());
+        }
+
+        t1.fe_mul(&t2, &t1.clone());
+        t1.fe_square(&t1.clone());
+
+        for _ in 1..50 {
+            t1.fe_square(&t1.clone());
+        }
+
+        t0.fe_mul(&t1, &t0.clone());
+        t0.fe_square(&t0.clone());
+        t0.fe_square(&t0.clone());
+
+        self.fe_mul(&t0, z);
+    }
+
     // to_bytes marshals h to s.
     // Preconditions:
     //   |h| bounded by 1.1*2^25,1.1*2^24,1.1*2^25,1.1*2^24,etc.
@@ -634,4 +720,8 @@ impl FieldElement {
         let s = self.to_bytes();
         s[0] & 1
     }
+
+    pub fn fe_is_nonzero(&self) -> bool {
+        self.to_bytes().iter().any(|b| *b != 0)
+    }
 }
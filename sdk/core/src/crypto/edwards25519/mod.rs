@@ -1,5 +1,6 @@
 #![allow(clippy::identity_op)]
 
+pub mod cached_group_element;
 pub mod completed_group_element;
 pub mod constant;
 pub mod extended_group_element;
@@ -521,6 +522,32 @@ pub fn sc_mul_add(a: [u8; 32], b: [u8; 32], c: [u8; 32]) -> [u8; 32] {
     ]
 }
 
+// order is the order of the base point, as little-endian 64-bit words.
+const ORDER: [u64; 4] = [
+    0x5812631a5cf5d3ed,
+    0x14def9dea2f79cd6,
+    0,
+    0x1000000000000000,
+];
+
+// sc_minimal returns true if the given scalar is less than the order of the
+// curve, i.e. if it is in canonical form.
+pub fn sc_minimal(scalar: &[u8; 32]) -> bool {
+    for i in (0..4).rev() {
+        let mut word = [0u8; 8];
+        word.copy_from_slice(&scalar[i * 8..i * 8 + 8]);
+        let v = u64::from_le_bytes(word);
+        if v > ORDER[i] {
+            return false;
+        } else if v < ORDER[i] {
+            return true;
+        }
+    }
+
+    // equal to the order
+    false
+}
+
 // Input:
 //   s[0]+256*s[1]+...+256^63*s[63] = s
 //
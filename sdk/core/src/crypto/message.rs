@@ -0,0 +1,16 @@
+use sha3::{Digest, Keccak256};
+
+/// Prepended to messages before signing, so that a signed message can never be a valid transaction.
+///
+/// The first byte is the length of the rest of the prefix.
+pub const SIGNED_MESSAGE_PREFIX: &[u8] = b"\x19Dharitri Signed Message:\n";
+
+/// The hash that actually gets signed when signing an arbitrary message:
+/// `keccak256(prefix + len(message) + message)`, with the length written in decimal.
+pub fn signed_message_hash(message: &[u8]) -> [u8; 32] {
+    let mut h = Keccak256::new();
+    h.update(SIGNED_MESSAGE_PREFIX);
+    h.update(message.len().to_string().as_bytes());
+    h.update(message);
+    h.finalize().into()
+}
@@ -1,12 +1,17 @@
 use std::fmt::Display;
 
-use super::private_key::PrivateKey;
+use super::{
+    edwards25519::{extended_group_element::ExtendedGroupElement, sc_minimal, sc_reduce},
+    message::signed_message_hash,
+    private_key::{PrivateKey, SIGNATURE_LENGTH},
+};
 use anyhow::Result;
 use bech32::{self, ToBase32, Variant};
 use serde::{
     de::{Deserialize, Deserializer},
     ser::{Serialize, Serializer},
 };
+use sha2::{Digest, Sha512};
 
 pub const PUBLIC_KEY_LENGTH: usize = 32;
 
@@ -14,6 +19,10 @@ pub const PUBLIC_KEY_LENGTH: usize = 32;
 pub struct PublicKey([u8; PUBLIC_KEY_LENGTH]);
 
 impl PublicKey {
+    pub fn from_bytes(bytes: [u8; PUBLIC_KEY_LENGTH]) -> Self {
+        Self(bytes)
+    }
+
     pub fn to_bytes(&self) -> [u8; PUBLIC_KEY_LENGTH] {
         self.0
     }
@@ -33,6 +42,44 @@ impl PublicKey {
         bits.copy_from_slice(&bytes[32..]);
         Ok(Self(bits))
     }
+
+    /// Checks an Ed25519 signature of the message against this public key.
+    pub fn verify(&self, message: &[u8], signature: &[u8]) -> bool {
+        if signature.len() != SIGNATURE_LENGTH || signature[63] & 224 != 0 {
+            return false;
+        }
+
+        let mut a = match ExtendedGroupElement::from_bytes(&self.0) {
+            Some(a) => a,
+            None => return false,
+        };
+        a.x.fe_neg(&a.x.clone());
+        a.t.fe_neg(&a.t.clone());
+
+        let mut h: Sha512 = Sha512::new();
+        let mut hram_digest = [0u8; 64];
+        h.update(&signature[..32]);
+        h.update(self.0);
+        h.update(message);
+        hram_digest.copy_from_slice(h.finalize().as_slice());
+        let hram_digest_reduced = sc_reduce(hram_digest);
+
+        let mut s = [0u8; 32];
+        s.copy_from_slice(&signature[32..]);
+        if !sc_minimal(&s) {
+            return false;
+        }
+
+        let mut r = ExtendedGroupElement::default();
+        r.ge_double_scalar_mult_vartime(hram_digest_reduced, &a, s);
+
+        r.to_bytes()[..] == signature[..32]
+    }
+
+    /// Checks a signature produced by `Wallet::sign_message`.
+    pub fn verify_message(&self, message: &[u8], signature: &[u8]) -> bool {
+        self.verify(&signed_message_hash(message), signature)
+    }
 }
 
 impl<'a> From<&'a PrivateKey> for PublicKey {
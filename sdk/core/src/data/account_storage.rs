@@ -14,3 +14,16 @@ pub struct AccountStorageResponse {
     pub error: String,
     pub code: String,
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountStorageValue {
+    pub value: String,
+}
+
+// AccountStorageValueResponse holds the response of the endpoint for a single storage key
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountStorageValueResponse {
+    pub data: Option<AccountStorageValue>,
+    pub error: String,
+    pub code: String,
+}
@@ -1,7 +1,7 @@
 use std::fmt::{Debug, Display};
 
 use crate::crypto::public_key::PublicKey;
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use bech32::{FromBase32, ToBase32, Variant};
 use serde::{
     de::{Deserialize, Deserializer},
@@ -23,6 +23,9 @@ impl Address {
     pub fn from_bech32_string(bech32: &str) -> Result<Self> {
         let (_, data, _) = bech32::decode(bech32)?;
         let data = Vec::<u8>::from_base32(&data)?;
+        if data.len() != 32 {
+            return Err(anyhow!("invalid address length: {}", data.len()));
+        }
 
         let mut bits: [u8; 32] = [0u8; 32];
         bits.copy_from_slice(&data);
@@ -1,10 +1,23 @@
+use aes::cipher::{KeyIvInit, StreamCipher};
+use hmac::{Hmac, Mac};
+use rand::RngCore;
 use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+
+use super::address::Address;
+
+type Aes128Ctr = ctr::Ctr128BE<aes::Aes128>;
+type HmacSha256 = Hmac<Sha256>;
 
 pub const KDF_N: u32 = 4096;
 pub const KDF_R: u32 = 8;
 pub const KDF_P: u32 = 1;
 pub const KDF_DKLEN: usize = 32;
 pub const KEYSTORE_VERSION: u32 = 4;
+pub const KEYSTORE_KIND_SECRET_KEY: &str = "secretKey";
+pub const KEYSTORE_KIND_MNEMONIC: &str = "mnemonic";
+pub const CIPHER_AES_128_CTR: &str = "aes-128-ctr";
+pub const KDF_SCRYPT: &str = "scrypt";
 
 #[derive(Debug)]
 pub enum WalletError {
@@ -13,6 +26,18 @@ pub enum WalletError {
     InvalidCipher,
 }
 
+impl std::fmt::Display for WalletError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WalletError::InvalidPassword => f.write_str("invalid keystore password"),
+            WalletError::InvalidKdf => f.write_str("unsupported keystore key derivation function"),
+            WalletError::InvalidCipher => f.write_str("unsupported keystore cipher"),
+        }
+    }
+}
+
+impl std::error::Error for WalletError {}
+
 #[derive(Debug)]
 pub enum InsertPassword {
     Plaintext(String),
@@ -48,7 +73,11 @@ pub struct Keystore {
     pub version: u32,
     pub kind: String,
     pub id: String,
+    /// Only present for the `secretKey` kind.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub address: String,
+    /// Only present for the `secretKey` kind.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub bech32: String,
     pub crypto: Crypto,
 }
@@ -59,3 +88,140 @@ pub struct DecryptionParams {
     pub iv: Vec<u8>,
     pub data: Vec<u8>,
 }
+
+impl Keystore {
+    /// Encrypts the data with a key derived from the password, with scrypt.
+    ///
+    /// The data is the 64-byte secret key for the `secretKey` kind, and the mnemonic phrase for the `mnemonic` kind,
+    /// in which case there is no address.
+    pub fn encrypt(kind: &str, data: &[u8], address: Option<&Address>, password: &str) -> Keystore {
+        let mut rng = rand::thread_rng();
+        let mut salt = [0u8; 32];
+        rng.fill_bytes(&mut salt);
+        let mut iv = [0u8; 16];
+        rng.fill_bytes(&mut iv);
+
+        let derived_key = derive_key(password, &salt, KDF_N, KDF_R, KDF_P, KDF_DKLEN)
+            .expect("the default scrypt parameters are valid");
+
+        let mut ciphertext = data.to_vec();
+        let mut cipher = Aes128Ctr::new(derived_key[..16].into(), (&iv).into());
+        cipher.apply_keystream(&mut ciphertext);
+
+        let mac = compute_mac(&derived_key[16..], &ciphertext);
+
+        Keystore {
+            version: KEYSTORE_VERSION,
+            kind: kind.to_string(),
+            id: uuid::Uuid::new_v4().to_string(),
+            address: address
+                .map(|a| hex::encode(a.to_bytes()))
+                .unwrap_or_default(),
+            bech32: address
+                .map(|a| a.to_bech32_string().unwrap())
+                .unwrap_or_default(),
+            crypto: Crypto {
+                ciphertext: hex::encode(ciphertext),
+                cipherparams: CryptoParams {
+                    iv: hex::encode(iv),
+                },
+                cipher: CIPHER_AES_128_CTR.to_string(),
+                kdf: KDF_SCRYPT.to_string(),
+                kdfparams: KdfParams {
+                    dklen: KDF_DKLEN as u32,
+                    salt: hex::encode(salt),
+                    n: KDF_N,
+                    r: KDF_R,
+                    p: KDF_P,
+                },
+                mac: hex::encode(mac),
+            },
+        }
+    }
+
+    /// Derives the key from the password and checks it against the MAC.
+    pub fn decryption_params(&self, password: &str) -> Result<DecryptionParams, WalletError> {
+        if self.crypto.kdf != KDF_SCRYPT {
+            return Err(WalletError::InvalidKdf);
+        }
+        if self.crypto.cipher != CIPHER_AES_128_CTR {
+            return Err(WalletError::InvalidCipher);
+        }
+
+        let kdfparams = &self.crypto.kdfparams;
+        let salt = hex::decode(&kdfparams.salt).map_err(|_| WalletError::InvalidKdf)?;
+        let derived_key = derive_key(
+            password,
+            &salt,
+            kdfparams.n,
+            kdfparams.r,
+            kdfparams.p,
+            kdfparams.dklen as usize,
+        )
+        .ok_or(WalletError::InvalidKdf)?;
+        if derived_key.len() < 32 {
+            return Err(WalletError::InvalidKdf);
+        }
+
+        let ciphertext =
+            hex::decode(&self.crypto.ciphertext).map_err(|_| WalletError::InvalidCipher)?;
+        let iv =
+            hex::decode(&self.crypto.cipherparams.iv).map_err(|_| WalletError::InvalidCipher)?;
+        if iv.len() != 16 {
+            return Err(WalletError::InvalidCipher);
+        }
+
+        let mac = hex::decode(&self.crypto.mac).map_err(|_| WalletError::InvalidPassword)?;
+        let mut hmac = HmacSha256::new_from_slice(&derived_key[16..32])
+            .expect("HMAC can take key of any size");
+        hmac.update(&ciphertext);
+        hmac.verify_slice(&mac)
+            .map_err(|_| WalletError::InvalidPassword)?;
+
+        Ok(DecryptionParams {
+            derived_key_first_half: derived_key[..16].to_vec(),
+            iv,
+            data: ciphertext,
+        })
+    }
+
+    /// Decrypts the keystore data, fails if the password is wrong.
+    pub fn decrypt(&self, password: &str) -> Result<Vec<u8>, WalletError> {
+        Ok(self.decryption_params(password)?.decrypt())
+    }
+}
+
+impl DecryptionParams {
+    pub fn decrypt(&self) -> Vec<u8> {
+        let mut data = self.data.clone();
+        let mut cipher = Aes128Ctr::new(
+            self.derived_key_first_half.as_slice().into(),
+            self.iv.as_slice().into(),
+        );
+        cipher.apply_keystream(&mut data);
+        data
+    }
+}
+
+fn derive_key(
+    password: &str,
+    salt: &[u8],
+    n: u32,
+    r: u32,
+    p: u32,
+    dklen: usize,
+) -> Option<Vec<u8>> {
+    if !n.is_power_of_two() {
+        return None;
+    }
+    let params = scrypt::Params::new(n.trailing_zeros() as u8, r, p, dklen).ok()?;
+    let mut derived_key = vec![0u8; dklen];
+    scrypt::scrypt(password.as_bytes(), salt, &params, &mut derived_key).ok()?;
+    Some(derived_key)
+}
+
+fn compute_mac(key: &[u8], ciphertext: &[u8]) -> Vec<u8> {
+    let mut hmac = HmacSha256::new_from_slice(key).expect("HMAC can take key of any size");
+    hmac.update(ciphertext);
+    hmac.finalize().into_bytes().to_vec()
+}
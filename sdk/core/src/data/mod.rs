@@ -3,6 +3,7 @@ pub mod account_storage;
 pub mod address;
 pub mod dcdt;
 pub mod hyperblock;
+pub mod keystore;
 pub mod network_config;
 pub mod network_economics;
 pub mod network_status;
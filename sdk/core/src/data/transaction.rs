@@ -1,7 +1,19 @@
 use std::collections::HashMap;
 
 use super::{address::Address, vm::CallType};
+use crate::crypto::public_key::PublicKey;
 use serde::{Deserialize, Serialize};
+use serde_json::json;
+use sha3::{Digest, Keccak256};
+
+/// Transaction option bit: the transaction hash is signed, instead of the whole serialized transaction.
+pub const TX_OPTION_SIGN_WITH_HASH: u32 = 0b0001;
+
+/// Transaction option bit: the transaction is co-signed by the guardian of the sender.
+pub const TX_OPTION_GUARDED: u32 = 0b0010;
+
+/// Minimum transaction version that allows transaction options.
+pub const TX_VERSION_WITH_OPTIONS: u32 = 2;
 
 // Transaction holds the fields of a transaction to be broadcasted to the network
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -22,6 +34,78 @@ pub struct Transaction {
     pub version: u32,
     #[serde(skip_serializing_if = "is_zero")]
     pub options: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guardian: Option<Address>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guardian_signature: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relayer: Option<Address>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relayer_signature: Option<String>,
+}
+
+impl Transaction {
+    /// Marks the transaction as guarded, the guardian also needs to sign it.
+    ///
+    /// Must be called before signing, since it changes the signed content.
+    pub fn set_guardian(&mut self, guardian: Address) {
+        self.guardian = Some(guardian);
+        self.options |= TX_OPTION_GUARDED;
+        if self.version < TX_VERSION_WITH_OPTIONS {
+            self.version = TX_VERSION_WITH_OPTIONS;
+        }
+    }
+
+    /// Marks the transaction as relayed, the relayer pays for the gas and also needs to sign it.
+    ///
+    /// Must be called before signing, since it changes the signed content.
+    pub fn set_relayer(&mut self, relayer: Address) {
+        self.relayer = Some(relayer);
+    }
+
+    pub fn is_guarded(&self) -> bool {
+        self.options & TX_OPTION_GUARDED > 0
+    }
+
+    pub fn is_signed_with_hash(&self) -> bool {
+        self.version >= TX_VERSION_WITH_OPTIONS && self.options & TX_OPTION_SIGN_WITH_HASH > 0
+    }
+
+    /// The content signed by the sender, the guardian and the relayer alike.
+    ///
+    /// It is the JSON serialization of the transaction without any of the signatures,
+    /// or the Keccak-256 hash of it, if the transaction is signed with hash.
+    pub fn signable_bytes(&self) -> Vec<u8> {
+        let mut unsign_tx = self.clone();
+        unsign_tx.signature = None;
+        unsign_tx.guardian_signature = None;
+        unsign_tx.relayer_signature = None;
+
+        let tx_bytes = json!(unsign_tx).to_string().into_bytes();
+
+        if unsign_tx.is_signed_with_hash() {
+            let mut h = Keccak256::new();
+            h.update(tx_bytes);
+            h.finalize().to_vec()
+        } else {
+            tx_bytes
+        }
+    }
+
+    /// Checks the sender signature against the sender address.
+    ///
+    /// Returns false if the transaction is not signed, or the signature is not valid hex.
+    pub fn verify_signature(&self) -> bool {
+        let Some(signature) = self
+            .signature
+            .as_ref()
+            .and_then(|signature| hex::decode(signature).ok())
+        else {
+            return false;
+        };
+
+        PublicKey::from_bytes(self.sender.to_bytes()).verify(&self.signable_bytes(), &signature)
+    }
 }
 
 /// This is only used for serialize
@@ -1,5 +1,8 @@
 pub mod blockchain;
 pub mod crypto;
 pub mod data;
+pub mod native_auth;
+pub mod nonce_manager;
+pub mod transaction_builder;
 pub mod utils;
 pub mod wallet;
@@ -0,0 +1,13 @@
+mod native_auth_client;
+mod native_auth_server;
+mod native_auth_token;
+
+pub use native_auth_client::NativeAuthClient;
+pub use native_auth_server::{NativeAuthServer, NativeAuthValidation};
+pub use native_auth_token::NativeAuthToken;
+
+/// Default time to live of the generated tokens, in seconds.
+pub const DEFAULT_TTL_SECONDS: u64 = 86400;
+
+/// Tokens with a longer time to live are rejected by default, in seconds.
+pub const DEFAULT_MAX_EXPIRY_SECONDS: u64 = 86400;
@@ -0,0 +1,69 @@
+use anyhow::Result;
+use serde_json::json;
+
+use crate::{blockchain::BlockchainProxy, wallet::Wallet};
+
+use super::{NativeAuthToken, DEFAULT_TTL_SECONDS};
+
+/// Generates native auth tokens, to authenticate a wallet to a dApp backend.
+#[derive(Debug, Clone)]
+pub struct NativeAuthClient {
+    pub origin: String,
+    pub ttl: u64,
+    pub extra_info: serde_json::Value,
+}
+
+impl NativeAuthClient {
+    pub fn new(origin: &str) -> Self {
+        NativeAuthClient {
+            origin: origin.to_string(),
+            ttl: DEFAULT_TTL_SECONDS,
+            extra_info: json!({}),
+        }
+    }
+
+    pub fn with_ttl(mut self, ttl: u64) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    pub fn with_extra_info(mut self, extra_info: serde_json::Value) -> Self {
+        self.extra_info = extra_info;
+        self
+    }
+
+    /// Retrieves the hash of the latest metachain block, to be included in the token.
+    pub async fn latest_block_hash<Proxy>(proxy: &Proxy) -> Result<String>
+    where
+        Proxy: BlockchainProxy,
+    {
+        let nonce = proxy.get_latest_hyper_block_nonce(true).await?;
+        let hyper_block = proxy.get_hyper_block_by_nonce(nonce).await?;
+        Ok(hyper_block.hash)
+    }
+
+    /// Builds and signs an encoded token.
+    ///
+    /// The token is valid for `ttl` seconds after the timestamp of the given block.
+    pub fn generate_token(&self, wallet: &Wallet, block_hash: &str) -> Result<String> {
+        let mut token = NativeAuthToken {
+            address: wallet.address().to_bech32_string()?,
+            origin: self.origin.clone(),
+            block_hash: block_hash.to_string(),
+            ttl: self.ttl,
+            extra_info: self.extra_info.clone(),
+            signature: String::new(),
+        };
+        token.signature = hex::encode(wallet.sign_message(token.signable_message().as_bytes()));
+        Ok(token.encode())
+    }
+
+    /// Fetches the latest block hash, then builds and signs an encoded token.
+    pub async fn get_token<Proxy>(&self, proxy: &Proxy, wallet: &Wallet) -> Result<String>
+    where
+        Proxy: BlockchainProxy,
+    {
+        let block_hash = Self::latest_block_hash(proxy).await?;
+        self.generate_token(wallet, &block_hash)
+    }
+}
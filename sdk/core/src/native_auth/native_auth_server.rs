@@ -0,0 +1,106 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::{anyhow, Result};
+
+use crate::{blockchain::BlockchainProxy, crypto::public_key::PublicKey, data::address::Address};
+
+use super::{NativeAuthToken, DEFAULT_MAX_EXPIRY_SECONDS};
+
+/// Validates native auth tokens received by a dApp backend.
+#[derive(Debug, Clone)]
+pub struct NativeAuthServer {
+    pub accepted_origins: Vec<String>,
+    pub max_expiry_seconds: u64,
+}
+
+/// The outcome of a successful validation.
+#[derive(Debug, Clone)]
+pub struct NativeAuthValidation {
+    pub address: Address,
+    pub origin: String,
+    /// Timestamp of the block referenced by the token.
+    pub issued: u64,
+    pub expires: u64,
+    pub extra_info: serde_json::Value,
+}
+
+impl NativeAuthServer {
+    pub fn new(accepted_origins: Vec<String>) -> Self {
+        NativeAuthServer {
+            accepted_origins,
+            max_expiry_seconds: DEFAULT_MAX_EXPIRY_SECONDS,
+        }
+    }
+
+    pub fn with_max_expiry_seconds(mut self, max_expiry_seconds: u64) -> Self {
+        self.max_expiry_seconds = max_expiry_seconds;
+        self
+    }
+
+    /// Validates an encoded token against the current time.
+    pub async fn validate<Proxy>(&self, proxy: &Proxy, token: &str) -> Result<NativeAuthValidation>
+    where
+        Proxy: BlockchainProxy,
+    {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+        self.validate_at(proxy, token, now).await
+    }
+
+    /// Validates an encoded token, as of the given timestamp, in seconds.
+    ///
+    /// The block hash is looked up on the gateway: the token expires `ttl` seconds after the block timestamp.
+    pub async fn validate_at<Proxy>(
+        &self,
+        proxy: &Proxy,
+        token: &str,
+        timestamp: u64,
+    ) -> Result<NativeAuthValidation>
+    where
+        Proxy: BlockchainProxy,
+    {
+        let (token, signed_message) = NativeAuthToken::decode_with_signed_message(token)?;
+
+        if token.ttl > self.max_expiry_seconds {
+            return Err(anyhow!(
+                "native auth token ttl {} exceeds the maximum of {} seconds",
+                token.ttl,
+                self.max_expiry_seconds
+            ));
+        }
+
+        if !self.accepted_origins.contains(&token.origin) {
+            return Err(anyhow!(
+                "unhandled native auth token origin: {}",
+                token.origin
+            ));
+        }
+
+        let address = Address::from_bech32_string(&token.address)
+            .map_err(|_| anyhow!("invalid native auth token address: {}", token.address))?;
+        let signature = hex::decode(&token.signature)
+            .map_err(|_| anyhow!("invalid native auth token signature"))?;
+        if !PublicKey::from_bytes(address.to_bytes())
+            .verify_message(signed_message.as_bytes(), &signature)
+        {
+            return Err(anyhow!("invalid native auth token signature"));
+        }
+
+        let hyper_block = proxy
+            .get_hyper_block_by_hash(&token.block_hash)
+            .await
+            .map_err(|err| anyhow!("invalid native auth token block hash: {err}"))?;
+        let issued = hyper_block.timestamp;
+        let expires = issued + token.ttl;
+        if timestamp > expires {
+            return Err(anyhow!("native auth token expired"));
+        }
+
+        Ok(NativeAuthValidation {
+            address,
+            origin: token.origin,
+            issued,
+            expires,
+            extra_info: token.extra_info,
+        })
+    }
+}
@@ -0,0 +1,105 @@
+use anyhow::{anyhow, Result};
+use base64::{
+    alphabet,
+    engine::{general_purpose, DecodePaddingMode, GeneralPurpose, GeneralPurposeConfig},
+    Engine as _,
+};
+
+/// Same as `URL_SAFE_NO_PAD`, but also accepts padded input, which some clients produce.
+const URL_SAFE_ANY_PAD: GeneralPurpose = GeneralPurpose::new(
+    &alphabet::URL_SAFE,
+    GeneralPurposeConfig::new().with_decode_padding_mode(DecodePaddingMode::Indifferent),
+);
+
+/// A native auth token, in decoded form.
+///
+/// Encoded, it looks like `<address>.<body>.<signature>`, where:
+/// - the address is the bech32 address of the signer, in base64;
+/// - the body is `<origin>.<block hash>.<ttl>.<extra info>`, in base64,
+///   with the origin and the extra info JSON also in base64;
+/// - the signature is the hex-encoded signed message of the address followed by the body.
+///
+/// All base64 encodings are URL-safe, without padding. Padded ones are also accepted when decoding.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NativeAuthToken {
+    pub address: String,
+    pub origin: String,
+    pub block_hash: String,
+    pub ttl: u64,
+    pub extra_info: serde_json::Value,
+    pub signature: String,
+}
+
+impl NativeAuthToken {
+    pub fn body(&self) -> String {
+        format!(
+            "{}.{}.{}.{}",
+            base64_url_encode(&self.origin),
+            self.block_hash,
+            self.ttl,
+            base64_url_encode(self.extra_info.to_string()),
+        )
+    }
+
+    /// The message signed with the wallet of the address.
+    pub fn signable_message(&self) -> String {
+        format!("{}{}", self.address, self.body())
+    }
+
+    pub fn encode(&self) -> String {
+        format!(
+            "{}.{}.{}",
+            base64_url_encode(&self.address),
+            base64_url_encode(self.body()),
+            self.signature,
+        )
+    }
+
+    pub fn decode(token: &str) -> Result<Self> {
+        Self::decode_with_signed_message(token).map(|(token, _)| token)
+    }
+
+    /// Decodes a token, together with the message that its signature covers.
+    ///
+    /// The message is made of the address and the body exactly as they were received.
+    /// It can differ from `signable_message`, e.g. when the extra info JSON contains whitespace.
+    pub fn decode_with_signed_message(token: &str) -> Result<(Self, String)> {
+        let parts: Vec<&str> = token.split('.').collect();
+        let [address, body, signature] = parts[..] else {
+            return Err(anyhow!("invalid native auth token format"));
+        };
+
+        let address = base64_url_decode_string(address)?;
+        let body = base64_url_decode_string(body)?;
+        let body_parts: Vec<&str> = body.split('.').collect();
+        let [origin, block_hash, ttl, extra_info] = body_parts[..] else {
+            return Err(anyhow!("invalid native auth token body format"));
+        };
+
+        let decoded = NativeAuthToken {
+            address: address.clone(),
+            origin: base64_url_decode_string(origin)?,
+            block_hash: block_hash.to_string(),
+            ttl: ttl
+                .parse()
+                .map_err(|_| anyhow!("invalid native auth token ttl: {ttl}"))?,
+            extra_info: serde_json::from_str(&base64_url_decode_string(extra_info)?)?,
+            signature: signature.to_string(),
+        };
+        Ok((decoded, format!("{address}{body}")))
+    }
+}
+
+fn base64_url_encode<T>(to_encode: T) -> String
+where
+    T: AsRef<[u8]>,
+{
+    general_purpose::URL_SAFE_NO_PAD.encode(to_encode)
+}
+
+fn base64_url_decode_string(to_decode: &str) -> Result<String> {
+    let bytes = URL_SAFE_ANY_PAD
+        .decode(to_decode)
+        .map_err(|_| anyhow!("invalid base64 in native auth token: {to_decode}"))?;
+    Ok(String::from_utf8(bytes)?)
+}
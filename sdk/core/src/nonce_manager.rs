@@ -0,0 +1,302 @@
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    sync::Mutex,
+};
+
+use anyhow::Result;
+
+use crate::{
+    blockchain::BlockchainProxy,
+    data::{address::Address, transaction::Transaction},
+    wallet::Wallet,
+};
+
+/// Transaction statuses after which the nonce is not going to be consumed.
+const DROPPED_TX_STATUSES: &[&str] = &["invalid", "dropped"];
+
+/// A nonce handed out by the [`NonceManager`], that has not been executed yet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PendingNonce {
+    /// Handed out, but the transaction was not sent yet.
+    Reserved,
+    /// The transaction was sent, with the given hash.
+    Sent(String),
+}
+
+/// Nonce errors reported by the gateway when sending transactions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NonceError {
+    /// The nonce was already used, the local nonce is behind the network.
+    TooLow,
+    /// The nonce is too far ahead of the account nonce, usually because of a gap.
+    TooHigh,
+}
+
+impl NonceError {
+    /// Recognizes the nonce errors in the error message of a failed send.
+    pub fn from_gateway_error(message: &str) -> Option<Self> {
+        let message = message.to_lowercase();
+        if message.contains("nonce too low") || message.contains("lowernonceintx: true") {
+            Some(NonceError::TooLow)
+        } else if message.contains("nonce too high") || message.contains("veryhighnonceintx: true")
+        {
+            Some(NonceError::TooHigh)
+        } else {
+            None
+        }
+    }
+}
+
+/// The outcome of a [`NonceManager::sync`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NonceSync {
+    /// The nonce of the account on the network, i.e. the next nonce to be executed.
+    pub account_nonce: u64,
+    /// Nonces handed out whose transactions are never going to be executed.
+    ///
+    /// They are handed out again, before any new nonce.
+    pub gaps: Vec<u64>,
+    /// Hashes of the transactions the network reports as dropped, or does not know.
+    ///
+    /// They need to be sent again, their nonces are among the gaps.
+    pub discarded: Vec<String>,
+}
+
+#[derive(Debug, Default)]
+struct SenderNonces {
+    /// `None` until retrieved from the network.
+    next_nonce: Option<u64>,
+    pending: BTreeMap<u64, PendingNonce>,
+    /// Nonces below `next_nonce` to be handed out again, lowest first.
+    free: BTreeSet<u64>,
+}
+
+impl SenderNonces {
+    fn take_nonce(&mut self) -> u64 {
+        if let Some(nonce) = self.free.pop_first() {
+            return nonce;
+        }
+        let nonce = self.next_nonce.expect("sender nonce not retrieved");
+        self.next_nonce = Some(nonce + 1);
+        nonce
+    }
+
+    /// Forgets the nonces below the account nonce, and catches up with transactions sent by other tools.
+    fn forget_executed(&mut self, account_nonce: u64) {
+        self.pending = self.pending.split_off(&account_nonce);
+        self.free = self.free.split_off(&account_nonce);
+        let next_nonce = self.next_nonce.unwrap_or(account_nonce).max(account_nonce);
+        self.next_nonce = Some(next_nonce);
+    }
+
+    /// The last nonces handed out go back to `next_nonce`, the others fill the gaps first.
+    fn free_nonce(&mut self, nonce: u64) {
+        self.pending.remove(&nonce);
+        self.free.insert(nonce);
+        while let Some(next_nonce) = self.next_nonce {
+            if next_nonce == 0 || !self.free.remove(&(next_nonce - 1)) {
+                break;
+            }
+            self.next_nonce = Some(next_nonce - 1);
+        }
+    }
+}
+
+/// Hands out nonces to concurrent senders, keeping track of the transactions not yet executed.
+///
+/// Nonces are retrieved from the network once per sender, then incremented locally.
+/// The manager resynchronizes with the network when the gateway reports nonce errors,
+/// or when explicitly synced, detecting nonce gaps caused by dropped transactions.
+///
+/// Can be shared between tasks, e.g. in an `Arc`.
+#[derive(Debug, Default)]
+pub struct NonceManager {
+    senders: Mutex<HashMap<[u8; 32], SenderNonces>>,
+}
+
+impl NonceManager {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reserves the next nonce of the sender.
+    ///
+    /// The nonce needs to be either marked as sent, or released.
+    pub async fn next_nonce<Proxy>(&self, proxy: &Proxy, sender: &Address) -> Result<u64>
+    where
+        Proxy: BlockchainProxy,
+    {
+        if !self.is_known(sender) {
+            let account_nonce = fetch_account_nonce(proxy, sender).await?;
+            let mut senders = self.senders.lock().unwrap();
+            let sender_nonces = senders.entry(sender.to_bytes()).or_default();
+            // another task might have been faster
+            if sender_nonces.next_nonce.is_none() {
+                sender_nonces.next_nonce = Some(account_nonce);
+            }
+        }
+
+        let mut senders = self.senders.lock().unwrap();
+        let sender_nonces = senders.entry(sender.to_bytes()).or_default();
+        let nonce = sender_nonces.take_nonce();
+        sender_nonces.pending.insert(nonce, PendingNonce::Reserved);
+        Ok(nonce)
+    }
+
+    /// Records the hash of the transaction sent with a reserved nonce.
+    pub fn mark_sent(&self, sender: &Address, nonce: u64, tx_hash: &str) {
+        let mut senders = self.senders.lock().unwrap();
+        if let Some(sender_nonces) = senders.get_mut(&sender.to_bytes()) {
+            if let Some(pending) = sender_nonces.pending.get_mut(&nonce) {
+                *pending = PendingNonce::Sent(tx_hash.to_string());
+            }
+        }
+    }
+
+    /// Gives back a reserved nonce, whose transaction could not be sent.
+    ///
+    /// It is handed out again, before any new nonce, so the gap it leaves gets filled.
+    pub fn release(&self, sender: &Address, nonce: u64) {
+        let mut senders = self.senders.lock().unwrap();
+        if let Some(sender_nonces) = senders.get_mut(&sender.to_bytes()) {
+            if sender_nonces.pending.contains_key(&nonce) {
+                sender_nonces.free_nonce(nonce);
+            }
+        }
+    }
+
+    /// The nonces handed out to the sender, that were not yet seen executed.
+    pub fn pending(&self, sender: &Address) -> BTreeMap<u64, PendingNonce> {
+        let senders = self.senders.lock().unwrap();
+        senders
+            .get(&sender.to_bytes())
+            .map(|sender_nonces| sender_nonces.pending.clone())
+            .unwrap_or_default()
+    }
+
+    /// Resynchronizes the sender nonce with the network.
+    ///
+    /// Forgets the executed nonces and detects gaps: nonces released, or whose transactions were dropped.
+    /// The status of every sent transaction is checked, only the dropped ones are discarded.
+    /// The transactions after a gap stay pending, they get executed once the gap is filled.
+    ///
+    /// Transactions that were just sent might not have reached the network yet, and would be reported as dropped.
+    pub async fn sync<Proxy>(&self, proxy: &Proxy, sender: &Address) -> Result<NonceSync>
+    where
+        Proxy: BlockchainProxy,
+    {
+        let account_nonce = fetch_account_nonce(proxy, sender).await?;
+
+        let sent: Vec<(u64, String)> = {
+            let mut senders = self.senders.lock().unwrap();
+            let sender_nonces = senders.entry(sender.to_bytes()).or_default();
+            sender_nonces.forget_executed(account_nonce);
+            sender_nonces
+                .pending
+                .iter()
+                .filter_map(|(nonce, pending)| match pending {
+                    PendingNonce::Sent(tx_hash) => Some((*nonce, tx_hash.clone())),
+                    PendingNonce::Reserved => None,
+                })
+                .collect()
+        };
+
+        let mut dropped = Vec::new();
+        for (nonce, tx_hash) in sent {
+            if is_dropped(proxy, &tx_hash).await? {
+                dropped.push((nonce, tx_hash));
+            }
+        }
+
+        let mut senders = self.senders.lock().unwrap();
+        let sender_nonces = senders.entry(sender.to_bytes()).or_default();
+        sender_nonces.forget_executed(account_nonce);
+
+        let mut discarded = Vec::new();
+        for (nonce, tx_hash) in dropped {
+            // the nonce might have been executed, or handed out again, in the meantime
+            if sender_nonces.pending.get(&nonce) == Some(&PendingNonce::Sent(tx_hash.clone())) {
+                sender_nonces.pending.remove(&nonce);
+                discarded.push(tx_hash);
+            }
+        }
+
+        let next_nonce = sender_nonces
+            .next_nonce
+            .expect("set when forgetting executed nonces");
+        let gaps: Vec<u64> = (account_nonce..next_nonce)
+            .filter(|nonce| !sender_nonces.pending.contains_key(nonce))
+            .collect();
+        for &gap in &gaps {
+            sender_nonces.free_nonce(gap);
+        }
+
+        Ok(NonceSync {
+            account_nonce,
+            gaps,
+            discarded,
+        })
+    }
+
+    /// Sets the nonce, signs and sends the transaction, returning its hash.
+    ///
+    /// On nonce errors from the gateway, resyncs and tries again once, with a new nonce.
+    pub async fn send_tx<Proxy>(
+        &self,
+        proxy: &Proxy,
+        wallet: &Wallet,
+        tx: &mut Transaction,
+    ) -> Result<String>
+    where
+        Proxy: BlockchainProxy,
+    {
+        let sender = wallet.address();
+        let mut retried = false;
+        loop {
+            tx.nonce = self.next_nonce(proxy, &sender).await?;
+            tx.signature = Some(hex::encode(wallet.sign_tx(tx)));
+
+            match proxy.send_transaction(tx).await {
+                Ok(tx_hash) => {
+                    self.mark_sent(&sender, tx.nonce, &tx_hash);
+                    return Ok(tx_hash);
+                },
+                Err(err) => {
+                    self.release(&sender, tx.nonce);
+                    if retried || NonceError::from_gateway_error(&err.to_string()).is_none() {
+                        return Err(err);
+                    }
+                    self.sync(proxy, &sender).await?;
+                    retried = true;
+                },
+            }
+        }
+    }
+
+    fn is_known(&self, sender: &Address) -> bool {
+        let senders = self.senders.lock().unwrap();
+        senders
+            .get(&sender.to_bytes())
+            .is_some_and(|sender_nonces| sender_nonces.next_nonce.is_some())
+    }
+}
+
+async fn fetch_account_nonce<Proxy>(proxy: &Proxy, sender: &Address) -> Result<u64>
+where
+    Proxy: BlockchainProxy,
+{
+    let account = proxy.get_account(sender).await?;
+    Ok(account.nonce)
+}
+
+/// A transaction unknown to the network was dropped.
+async fn is_dropped<Proxy>(proxy: &Proxy, tx_hash: &str) -> Result<bool>
+where
+    Proxy: BlockchainProxy,
+{
+    match proxy.get_transaction_status(tx_hash).await {
+        Ok(status) => Ok(DROPPED_TX_STATUSES.contains(&status.as_str())),
+        Err(err) if err.to_string().contains("not found") => Ok(true),
+        Err(err) => Err(err),
+    }
+}
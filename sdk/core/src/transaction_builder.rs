@@ -0,0 +1,282 @@
+use anyhow::{anyhow, Result};
+
+use crate::{
+    blockchain::BlockchainProxy,
+    data::{
+        address::Address,
+        network_config::NetworkConfig,
+        transaction::{Transaction, TX_VERSION_WITH_OPTIONS},
+    },
+    utils::base64_encode,
+    wallet::Wallet,
+};
+
+/// Transaction version used by the builder, unless options are set.
+pub const DEFAULT_TX_VERSION: u32 = 1;
+
+const DCDT_TRANSFER_FUNC_NAME: &str = "DCDTTransfer";
+const DCDT_NFT_TRANSFER_FUNC_NAME: &str = "DCDTNFTTransfer";
+const DCDT_MULTI_TRANSFER_FUNC_NAME: &str = "MultiDCDTNFTTransfer";
+
+/// Builds transactions, filling in the fields that would otherwise need to be fetched from the network.
+///
+/// Any field set explicitly is left untouched, the others are retrieved on `build`:
+/// - the nonce, from the sender account;
+/// - the chain ID and the gas price, from the network config;
+/// - the gas limit, from the network config for plain REWA transfers,
+///   or estimated by the gateway for DCDT transfers and contract calls.
+#[derive(Debug, Clone)]
+pub struct TransactionBuilder {
+    sender: Address,
+    receiver: Address,
+    value: u128,
+    data: Option<String>,
+    needs_gas_estimation: bool,
+    nonce: Option<u64>,
+    gas_limit: Option<u64>,
+    gas_price: Option<u64>,
+    chain_id: Option<String>,
+    version: u32,
+    options: u32,
+}
+
+impl TransactionBuilder {
+    pub fn new(sender: Address, receiver: Address) -> Self {
+        TransactionBuilder {
+            sender,
+            receiver,
+            value: 0,
+            data: None,
+            needs_gas_estimation: false,
+            nonce: None,
+            gas_limit: None,
+            gas_price: None,
+            chain_id: None,
+            version: DEFAULT_TX_VERSION,
+            options: 0,
+        }
+    }
+
+    /// REWA value, in the smallest denomination.
+    pub fn value(mut self, value: u128) -> Self {
+        self.value = value;
+        self
+    }
+
+    /// Raw data field, not yet base64-encoded.
+    pub fn data(mut self, data: &str) -> Self {
+        self.data = Some(data.to_string());
+        self
+    }
+
+    /// Calls an endpoint of the receiver contract, with the given raw arguments.
+    pub fn function_call(mut self, endpoint_name: &str, args: &[Vec<u8>]) -> Self {
+        self.data = Some(encode_call_data(endpoint_name, args));
+        self.needs_gas_estimation = true;
+        self
+    }
+
+    /// Transfers a fungible DCDT to the receiver.
+    pub fn dcdt_transfer(mut self, token_identifier: &str, amount: u128) -> Self {
+        self.data = Some(encode_call_data(
+            DCDT_TRANSFER_FUNC_NAME,
+            &[
+                token_identifier.as_bytes().to_vec(),
+                top_encode_u128(amount),
+            ],
+        ));
+        self.needs_gas_estimation = true;
+        self
+    }
+
+    /// Transfers a fungible DCDT, then calls an endpoint of the receiver contract with it.
+    pub fn dcdt_transfer_and_call(
+        mut self,
+        token_identifier: &str,
+        amount: u128,
+        endpoint_name: &str,
+        args: &[Vec<u8>],
+    ) -> Self {
+        let mut all_args = vec![
+            token_identifier.as_bytes().to_vec(),
+            top_encode_u128(amount),
+        ];
+        all_args.push(endpoint_name.as_bytes().to_vec());
+        all_args.extend_from_slice(args);
+        self.data = Some(encode_call_data(DCDT_TRANSFER_FUNC_NAME, &all_args));
+        self.needs_gas_estimation = true;
+        self
+    }
+
+    /// Transfers a non-fungible or semi-fungible DCDT to the receiver.
+    ///
+    /// The transaction is sent to self, with the real receiver encoded in the data field.
+    pub fn dcdt_nft_transfer(mut self, token_identifier: &str, nonce: u64, amount: u128) -> Self {
+        self.data = Some(encode_call_data(
+            DCDT_NFT_TRANSFER_FUNC_NAME,
+            &[
+                token_identifier.as_bytes().to_vec(),
+                top_encode_u128(nonce as u128),
+                top_encode_u128(amount),
+                self.receiver.to_bytes().to_vec(),
+            ],
+        ));
+        self.receiver = self.sender.clone();
+        self.needs_gas_estimation = true;
+        self
+    }
+
+    /// Transfers several DCDTs at once, as `(token identifier, nonce, amount)` triples.
+    ///
+    /// The transaction is sent to self, with the real receiver encoded in the data field.
+    pub fn multi_dcdt_transfer(mut self, payments: &[(&str, u64, u128)]) -> Self {
+        let mut args = vec![
+            self.receiver.to_bytes().to_vec(),
+            top_encode_u128(payments.len() as u128),
+        ];
+        for (token_identifier, nonce, amount) in payments {
+            args.push(token_identifier.as_bytes().to_vec());
+            args.push(top_encode_u128(*nonce as u128));
+            args.push(top_encode_u128(*amount));
+        }
+        self.data = Some(encode_call_data(DCDT_MULTI_TRANSFER_FUNC_NAME, &args));
+        self.receiver = self.sender.clone();
+        self.needs_gas_estimation = true;
+        self
+    }
+
+    pub fn nonce(mut self, nonce: u64) -> Self {
+        self.nonce = Some(nonce);
+        self
+    }
+
+    pub fn gas_limit(mut self, gas_limit: u64) -> Self {
+        self.gas_limit = Some(gas_limit);
+        self
+    }
+
+    pub fn gas_price(mut self, gas_price: u64) -> Self {
+        self.gas_price = Some(gas_price);
+        self
+    }
+
+    pub fn chain_id(mut self, chain_id: &str) -> Self {
+        self.chain_id = Some(chain_id.to_string());
+        self
+    }
+
+    pub fn version(mut self, version: u32) -> Self {
+        self.version = version;
+        self
+    }
+
+    /// Also raises the version, if needed, since options are only allowed starting with version 2.
+    pub fn options(mut self, options: u32) -> Self {
+        self.options = options;
+        if self.version < TX_VERSION_WITH_OPTIONS {
+            self.version = TX_VERSION_WITH_OPTIONS;
+        }
+        self
+    }
+
+    /// The minimum gas limit of a transaction with the given raw data field.
+    pub fn data_gas_limit(network_config: &NetworkConfig, data: Option<&str>) -> u64 {
+        let data_len = data.map(str::len).unwrap_or_default() as u64;
+        network_config.min_gas_limit + network_config.gas_per_data_byte * data_len
+    }
+
+    /// Fills in the missing fields from the network, the resulting transaction is not signed.
+    pub async fn build<Proxy>(&self, proxy: &Proxy) -> Result<Transaction>
+    where
+        Proxy: BlockchainProxy,
+    {
+        let network_config =
+            if self.chain_id.is_none() || self.gas_price.is_none() || self.gas_limit.is_none() {
+                Some(proxy.get_network_config().await?)
+            } else {
+                None
+            };
+
+        let nonce = match self.nonce {
+            Some(nonce) => nonce,
+            None => proxy.get_account(&self.sender).await?.nonce,
+        };
+
+        let mut tx = Transaction {
+            nonce,
+            value: self.value.to_string(),
+            receiver: self.receiver.clone(),
+            sender: self.sender.clone(),
+            gas_price: self
+                .gas_price
+                .or_else(|| network_config.as_ref().map(|config| config.min_gas_price))
+                .unwrap_or_default(),
+            gas_limit: self.gas_limit.unwrap_or_default(),
+            data: self.data.as_ref().map(base64_encode),
+            signature: None,
+            chain_id: self
+                .chain_id
+                .clone()
+                .or_else(|| {
+                    network_config
+                        .as_ref()
+                        .map(|config| config.chain_id.clone())
+                })
+                .unwrap_or_default(),
+            version: self.version,
+            options: self.options,
+            guardian: None,
+            guardian_signature: None,
+            relayer: None,
+            relayer_signature: None,
+        };
+
+        if let (None, Some(network_config)) = (self.gas_limit, &network_config) {
+            let data_gas_limit = Self::data_gas_limit(network_config, self.data.as_deref());
+            tx.gas_limit = if self.needs_gas_estimation {
+                let cost = proxy.request_transaction_cost(&tx).await?;
+                if !cost.return_message.is_empty() {
+                    return Err(anyhow!("gas estimation failed: {}", cost.return_message));
+                }
+                cost.tx_gas_units.max(data_gas_limit)
+            } else {
+                data_gas_limit
+            };
+        }
+
+        Ok(tx)
+    }
+
+    /// Fills in the missing fields from the network, then signs the transaction.
+    ///
+    /// The wallet needs to be the sender.
+    pub async fn build_and_sign<Proxy>(&self, proxy: &Proxy, wallet: &Wallet) -> Result<Transaction>
+    where
+        Proxy: BlockchainProxy,
+    {
+        if wallet.address().to_bytes() != self.sender.to_bytes() {
+            return Err(anyhow!("wallet is not the sender of the transaction"));
+        }
+
+        let mut tx = self.build(proxy).await?;
+        tx.signature = Some(hex::encode(wallet.sign_tx(&tx)));
+        Ok(tx)
+    }
+}
+
+/// Encodes a call as `<function>@<hex arg 1>@<hex arg 2>...`.
+fn encode_call_data(function: &str, args: &[Vec<u8>]) -> String {
+    let mut data = function.to_string();
+    for arg in args {
+        data.push('@');
+        data.push_str(&hex::encode(arg));
+    }
+    data
+}
+
+/// Big-endian, without leading zeros, as numbers are encoded in the data field.
+fn top_encode_u128(value: u128) -> Vec<u8> {
+    let bytes = value.to_be_bytes();
+    let first_non_zero = bytes.iter().position(|b| *b != 0).unwrap_or(bytes.len());
+    bytes[first_non_zero..].to_vec()
+}
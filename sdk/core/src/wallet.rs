@@ -1,20 +1,25 @@
 extern crate rand;
 
-use anyhow::Result;
+use std::ops::Range;
+
+use anyhow::{anyhow, Result};
 use bip39::{Language, Mnemonic};
 use hmac::{Hmac, Mac};
 use pbkdf2::pbkdf2;
-use serde_json::json;
-use sha2::{Digest, Sha512};
-use sha3::Keccak256;
+use sha2::Sha512;
 use zeroize::Zeroize;
 
 use crate::{
     crypto::{
+        message::signed_message_hash,
         private_key::{PrivateKey, PRIVATE_KEY_LENGTH},
         public_key::PublicKey,
     },
-    data::{address::Address, transaction::Transaction},
+    data::{
+        address::Address,
+        keystore::{Keystore, KEYSTORE_KIND_MNEMONIC, KEYSTORE_KIND_SECRET_KEY},
+        transaction::Transaction,
+    },
 };
 
 const REWA_COIN_TYPE: u32 = 508;
@@ -58,13 +63,16 @@ impl Wallet {
         address_index: u32,
     ) -> PrivateKey {
         let seed = Self::seed_from_mnemonic(mnemonic, "");
+        Self::private_key_from_seed(&seed, account, address_index)
+    }
 
+    fn private_key_from_seed(seed: &[u8; 64], account: u32, address_index: u32) -> PrivateKey {
         let serialized_key_len = 32;
         let hardened_child_padding: u8 = 0;
 
         let mut digest =
             HmacSha521::new_from_slice(b"ed25519 seed").expect("HMAC can take key of any size");
-        digest.update(&seed);
+        digest.update(seed);
         let intermediary: Vec<u8> = digest.finalize().into_bytes().into_iter().collect();
         let mut key = intermediary[..serialized_key_len].to_vec();
         let mut chain_code = intermediary[serialized_key_len..].to_vec();
@@ -93,6 +101,26 @@ impl Wallet {
         PrivateKey::from_bytes(key.as_slice()).unwrap()
     }
 
+    pub fn from_mnemonic(mnemonic: Mnemonic, account: u32, address_index: u32) -> Self {
+        Self {
+            priv_key: Self::get_private_key_from_mnemonic(mnemonic, account, address_index),
+        }
+    }
+
+    /// Derives the wallets of a range of address indexes of an account, from the same mnemonic.
+    pub fn derive_from_mnemonic(
+        mnemonic: Mnemonic,
+        account: u32,
+        address_indexes: Range<u32>,
+    ) -> Vec<Self> {
+        let seed = Self::seed_from_mnemonic(mnemonic, "");
+        address_indexes
+            .map(|address_index| Self {
+                priv_key: Self::private_key_from_seed(&seed, account, address_index),
+            })
+            .collect()
+    }
+
     pub fn from_private_key(priv_key: &str) -> Result<Self> {
         let pri_key = PrivateKey::from_hex_str(priv_key)?;
         Ok(Self { priv_key: pri_key })
@@ -111,24 +139,111 @@ impl Wallet {
         Ok(Self { priv_key: pri_key })
     }
 
+    /// Loads a wallet from a keystore file, encrypted with the given password.
+    ///
+    /// For mnemonic keystores, the wallet is the first address of the first account.
+    pub fn from_keystore_file(file_path: &str, password: &str) -> Result<Self> {
+        let contents = std::fs::read_to_string(file_path)?;
+        Self::from_keystore_file_contents(&contents, password)
+    }
+
+    pub fn from_keystore_file_contents(contents: &str, password: &str) -> Result<Self> {
+        let keystore: Keystore = serde_json::from_str(contents)?;
+        match keystore.kind.as_str() {
+            KEYSTORE_KIND_SECRET_KEY => {
+                let secret_key = keystore.decrypt(password)?;
+                let priv_key = PrivateKey::from_bytes(&secret_key)?;
+                Ok(Self { priv_key })
+            },
+            KEYSTORE_KIND_MNEMONIC => Ok(Self::from_mnemonic(
+                Self::mnemonic_from_keystore(&keystore, password)?,
+                0,
+                0,
+            )),
+            kind => Err(anyhow!("unsupported keystore kind: {kind}")),
+        }
+    }
+
+    /// Decrypts the mnemonic from a keystore of the `mnemonic` kind.
+    pub fn mnemonic_from_keystore_file(file_path: &str, password: &str) -> Result<Mnemonic> {
+        let contents = std::fs::read_to_string(file_path)?;
+        let keystore: Keystore = serde_json::from_str(&contents)?;
+        Self::mnemonic_from_keystore(&keystore, password)
+    }
+
+    fn mnemonic_from_keystore(keystore: &Keystore, password: &str) -> Result<Mnemonic> {
+        if keystore.kind != KEYSTORE_KIND_MNEMONIC {
+            return Err(anyhow!("not a mnemonic keystore: {}", keystore.kind));
+        }
+        let phrase = String::from_utf8(keystore.decrypt(password)?)?;
+        Ok(Mnemonic::parse_normalized(&phrase)?)
+    }
+
+    /// Encrypts the secret key of the wallet into a keystore of the `secretKey` kind.
+    pub fn to_keystore(&self, password: &str) -> Keystore {
+        Keystore::encrypt(
+            KEYSTORE_KIND_SECRET_KEY,
+            self.priv_key.as_bytes(),
+            Some(&self.address()),
+            password,
+        )
+    }
+
+    /// Encrypts a mnemonic into a keystore of the `mnemonic` kind.
+    pub fn mnemonic_to_keystore(mnemonic: &Mnemonic, password: &str) -> Keystore {
+        Keystore::encrypt(
+            KEYSTORE_KIND_MNEMONIC,
+            mnemonic.to_string().as_bytes(),
+            None,
+            password,
+        )
+    }
+
+    /// Writes the wallet to an encrypted keystore file, of the `secretKey` kind.
+    pub fn to_keystore_file(&self, file_path: &str, password: &str) -> Result<()> {
+        let contents = serde_json::to_string_pretty(&self.to_keystore(password))?;
+        std::fs::write(file_path, contents)?;
+        Ok(())
+    }
+
     pub fn address(&self) -> Address {
         let public_key = PublicKey::from(&self.priv_key);
         Address::from(&public_key)
     }
 
+    /// Signs the transaction as sender.
+    ///
+    /// Guardians and relayers sign the exact same content, none of the signatures are included.
     pub fn sign_tx(&self, unsign_tx: &Transaction) -> [u8; 64] {
-        let mut unsign_tx = unsign_tx.clone();
-        unsign_tx.signature = None;
+        self.priv_key.sign(unsign_tx.signable_bytes())
+    }
+
+    /// Signs an arbitrary message, prefixed so that it cannot be mistaken for a transaction.
+    ///
+    /// Can be checked with `PublicKey::verify_message`.
+    pub fn sign_message(&self, message: &[u8]) -> [u8; 64] {
+        self.priv_key.sign(signed_message_hash(message).to_vec())
+    }
 
-        let mut tx_bytes = json!(unsign_tx).to_string().as_bytes().to_vec();
+    /// Co-signs a guarded transaction. The wallet needs to be the guardian set in the transaction.
+    pub fn sign_tx_as_guardian(&self, tx: &mut Transaction) -> Result<()> {
+        if !self.is_address(tx.guardian.as_ref()) {
+            return Err(anyhow!("wallet is not the guardian of the transaction"));
+        }
+        tx.guardian_signature = Some(hex::encode(self.sign_tx(tx)));
+        Ok(())
+    }
 
-        let should_sign_on_tx_hash = unsign_tx.version >= 2 && unsign_tx.options & 1 > 0;
-        if should_sign_on_tx_hash {
-            let mut h = Keccak256::new();
-            h.update(tx_bytes);
-            tx_bytes = h.finalize().as_slice().to_vec();
+    /// Co-signs a relayed transaction. The wallet needs to be the relayer set in the transaction.
+    pub fn sign_tx_as_relayer(&self, tx: &mut Transaction) -> Result<()> {
+        if !self.is_address(tx.relayer.as_ref()) {
+            return Err(anyhow!("wallet is not the relayer of the transaction"));
         }
+        tx.relayer_signature = Some(hex::encode(self.sign_tx(tx)));
+        Ok(())
+    }
 
-        self.priv_key.sign(tx_bytes)
+    fn is_address(&self, address: Option<&Address>) -> bool {
+        address.is_some_and(|address| address.to_bytes() == self.address().to_bytes())
     }
 }
@@ -0,0 +1,105 @@
+use anyhow::{anyhow, Result};
+use dharitri_sdk::{
+    blockchain::BlockchainProxy,
+    data::{
+        account::{Account, AccountResponse},
+        address::Address,
+        hyperblock::{HyperBlock, HyperBlockResponse},
+        network_config::{NetworkConfig, NetworkConfigResponse},
+        network_status::NetworkStatusResponse,
+        transaction::{
+            ResponseTxCost, SendTransactionResponse, Transaction, TransactionStatus,
+            TxCostResponseData,
+        },
+    },
+};
+use serde::de::DeserializeOwned;
+
+/// Serves canned JSON responses, by endpoint prefix, instead of calling a real gateway.
+#[derive(Default)]
+pub struct MockGateway {
+    responses: Vec<(String, serde_json::Value)>,
+}
+
+impl MockGateway {
+    /// Wraps the data the same way the gateway does.
+    pub fn with_response(mut self, endpoint_prefix: &str, data: serde_json::Value) -> Self {
+        let response = serde_json::json!({
+            "data": data,
+            "error": "",
+            "code": "successful"
+        });
+        self.responses.push((endpoint_prefix.to_string(), response));
+        self
+    }
+
+    fn response<R: DeserializeOwned>(&self, endpoint: &str) -> Result<R> {
+        let (_, response) = self
+            .responses
+            .iter()
+            .find(|(prefix, _)| endpoint.starts_with(prefix.as_str()))
+            .ok_or_else(|| anyhow!("not found: {endpoint}"))?;
+        Ok(serde_json::from_value(response.clone())?)
+    }
+}
+
+impl BlockchainProxy for MockGateway {
+    async fn get_network_config(&self) -> Result<NetworkConfig> {
+        let resp: NetworkConfigResponse = self.response("network/config")?;
+        resp.data
+            .map(|data| data.config)
+            .ok_or_else(|| anyhow!("{}", resp.error))
+    }
+
+    async fn get_hyper_block_by_hash(&self, hash: &str) -> Result<HyperBlock> {
+        let resp: HyperBlockResponse = self.response(&format!("hyperblock/by-hash/{hash}"))?;
+        resp.data
+            .map(|data| data.hyperblock)
+            .ok_or_else(|| anyhow!("{}", resp.error))
+    }
+
+    async fn get_hyper_block_by_nonce(&self, nonce: u64) -> Result<HyperBlock> {
+        let resp: HyperBlockResponse = self.response(&format!("hyperblock/by-nonce/{nonce}"))?;
+        resp.data
+            .map(|data| data.hyperblock)
+            .ok_or_else(|| anyhow!("{}", resp.error))
+    }
+
+    async fn get_latest_hyper_block_nonce(&self, with_metachain: bool) -> Result<u64> {
+        let endpoint = if with_metachain {
+            "network/status/4294967295"
+        } else {
+            "network/status"
+        };
+        let resp: NetworkStatusResponse = self.response(endpoint)?;
+        resp.data
+            .map(|data| data.status.nonce)
+            .ok_or_else(|| anyhow!("{}", resp.error))
+    }
+
+    async fn request_transaction_cost(&self, _tx: &Transaction) -> Result<TxCostResponseData> {
+        let resp: ResponseTxCost = self.response("transaction/cost")?;
+        resp.data.ok_or_else(|| anyhow!("{}", resp.error))
+    }
+
+    async fn get_account(&self, address: &Address) -> Result<Account> {
+        let resp: AccountResponse = self.response(&format!("address/{address}"))?;
+        resp.data
+            .map(|data| data.account)
+            .ok_or_else(|| anyhow!("{}", resp.error))
+    }
+
+    async fn get_transaction_status(&self, hash: &str) -> Result<String> {
+        let resp: TransactionStatus = self.response(&format!("transaction/{hash}/status"))?;
+        resp.data
+            .map(|data| data.status)
+            .ok_or_else(|| anyhow!("{}", resp.error))
+    }
+
+    async fn send_transaction(&self, _tx: &Transaction) -> Result<String> {
+        let resp: SendTransactionResponse = self.response("transaction/send")?;
+        resp.data
+            .map(|data| data.tx_hash)
+            .ok_or_else(|| anyhow!("{}", resp.error))
+    }
+}
@@ -0,0 +1,160 @@
+mod mock_gateway;
+
+use base64::{engine::general_purpose, Engine as _};
+use dharitri_sdk::{
+    native_auth::{NativeAuthClient, NativeAuthServer, NativeAuthToken},
+    wallet::Wallet,
+};
+use mock_gateway::MockGateway;
+use serde_json::json;
+
+const BLOCK_HASH: &str = "4d1f8a4e07a4f5a8c05f4b8e0c2e97a1b0e6f2e3d1c4b5a69788796a5b4c3d2e";
+const BLOCK_NONCE: u64 = 1000;
+const BLOCK_TIMESTAMP: u64 = 1_700_000_000;
+const ORIGIN: &str = "https://dapp.example.com";
+
+fn mock_gateway() -> MockGateway {
+    let hyper_block = json!({
+        "hyperblock": {
+            "nonce": BLOCK_NONCE,
+            "round": BLOCK_NONCE,
+            "hash": BLOCK_HASH,
+            "prevBlockHash": "",
+            "epoch": 1,
+            "numTxs": 0,
+            "shardBlocks": [],
+            "timestamp": BLOCK_TIMESTAMP,
+            "accumulatedFees": "0",
+            "developerFees": "0",
+            "accumulatedFeesInEpoch": "0",
+            "developerFeesInEpoch": "0"
+        }
+    });
+    let status = json!({
+        "status": {
+            "drt_current_round": BLOCK_NONCE,
+            "drt_epoch_number": 1,
+            "drt_nonce": BLOCK_NONCE,
+            "drt_nonce_at_epoch_start": 0,
+            "drt_nonces_passed_in_current_epoch": BLOCK_NONCE,
+            "drt_round_at_epoch_start": 0,
+            "drt_rounds_passed_in_current_epoch": BLOCK_NONCE,
+            "drt_rounds_per_epoch": 14400
+        }
+    });
+
+    MockGateway::default()
+        .with_response(
+            &format!("hyperblock/by-nonce/{BLOCK_NONCE}"),
+            hyper_block.clone(),
+        )
+        .with_response(&format!("hyperblock/by-hash/{BLOCK_HASH}"), hyper_block)
+        .with_response("network/status/4294967295", status)
+}
+
+fn alice() -> Wallet {
+    Wallet::from_pem_file("tests/alice.pem").unwrap()
+}
+
+#[tokio::test]
+async fn test_native_auth_token_roundtrip() {
+    let gateway = mock_gateway();
+    let client = NativeAuthClient::new(ORIGIN)
+        .with_ttl(600)
+        .with_extra_info(json!({ "timestamp": BLOCK_TIMESTAMP }));
+    let token = client.get_token(&gateway, &alice()).await.unwrap();
+
+    let decoded = NativeAuthToken::decode(&token).unwrap();
+    assert_eq!(decoded.origin, ORIGIN);
+    assert_eq!(decoded.block_hash, BLOCK_HASH);
+    assert_eq!(decoded.ttl, 600);
+    assert_eq!(decoded.encode(), token);
+
+    let server = NativeAuthServer::new(vec![ORIGIN.to_string()]);
+    let validation = server
+        .validate_at(&gateway, &token, BLOCK_TIMESTAMP + 600)
+        .await
+        .unwrap();
+    assert_eq!(validation.address.to_bytes(), alice().address().to_bytes());
+    assert_eq!(validation.origin, ORIGIN);
+    assert_eq!(validation.issued, BLOCK_TIMESTAMP);
+    assert_eq!(validation.expires, BLOCK_TIMESTAMP + 600);
+    assert_eq!(validation.extra_info["timestamp"], BLOCK_TIMESTAMP);
+}
+
+#[tokio::test]
+async fn test_native_auth_token_rejected() {
+    let gateway = mock_gateway();
+    let client = NativeAuthClient::new(ORIGIN).with_ttl(600);
+    let token = client.generate_token(&alice(), BLOCK_HASH).unwrap();
+    let server = NativeAuthServer::new(vec![ORIGIN.to_string()]);
+
+    // expired
+    assert!(server
+        .validate_at(&gateway, &token, BLOCK_TIMESTAMP + 601)
+        .await
+        .is_err());
+
+    // origin not accepted
+    let other_server = NativeAuthServer::new(vec!["https://other.example.com".to_string()]);
+    assert!(other_server
+        .validate_at(&gateway, &token, BLOCK_TIMESTAMP)
+        .await
+        .is_err());
+
+    // ttl too long
+    let strict_server = server.clone().with_max_expiry_seconds(60);
+    assert!(strict_server
+        .validate_at(&gateway, &token, BLOCK_TIMESTAMP)
+        .await
+        .is_err());
+
+    // unknown block
+    let unknown_block_token = client.generate_token(&alice(), "00").unwrap();
+    assert!(server
+        .validate_at(&gateway, &unknown_block_token, BLOCK_TIMESTAMP)
+        .await
+        .is_err());
+
+    // tampered
+    let mut tampered = NativeAuthToken::decode(&token).unwrap();
+    tampered.ttl = 500;
+    assert!(server
+        .validate_at(&gateway, &tampered.encode(), BLOCK_TIMESTAMP)
+        .await
+        .is_err());
+
+    assert!(NativeAuthToken::decode("not a token").is_err());
+}
+
+#[tokio::test]
+async fn test_native_auth_token_signed_as_received() {
+    let gateway = mock_gateway();
+    let wallet = alice();
+    let address = wallet.address().to_bech32_string().unwrap();
+
+    // padded base64 and extra info JSON with whitespace, re-encoding would change both
+    let body = format!(
+        "{}.{BLOCK_HASH}.600.{}",
+        general_purpose::URL_SAFE.encode(ORIGIN),
+        general_purpose::URL_SAFE.encode("{ \"timestamp\": 1700000000 }"),
+    );
+    let signature = hex::encode(wallet.sign_message(format!("{address}{body}").as_bytes()));
+    let token = format!(
+        "{}.{}.{signature}",
+        general_purpose::URL_SAFE.encode(&address),
+        general_purpose::URL_SAFE.encode(&body),
+    );
+
+    let decoded = NativeAuthToken::decode(&token).unwrap();
+    assert_ne!(decoded.encode(), token);
+    assert_eq!(decoded.extra_info["timestamp"], BLOCK_TIMESTAMP);
+
+    let server = NativeAuthServer::new(vec![ORIGIN.to_string()]);
+    let validation = server
+        .validate_at(&gateway, &token, BLOCK_TIMESTAMP + 600)
+        .await
+        .unwrap();
+    assert_eq!(validation.origin, ORIGIN);
+    assert_eq!(validation.expires, BLOCK_TIMESTAMP + 600);
+}
@@ -0,0 +1,447 @@
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    sync::{Arc, Mutex},
+};
+
+use anyhow::{anyhow, Result};
+use dharitri_sdk::{
+    blockchain::BlockchainProxy,
+    data::{
+        account::{Account, AccountResponse},
+        address::Address,
+        hyperblock::HyperBlock,
+        network_config::NetworkConfig,
+        transaction::{
+            SendTransactionResponse, Transaction, TransactionStatus, TxCostResponseData,
+        },
+    },
+    nonce_manager::{NonceError, NonceManager, PendingNonce},
+    wallet::Wallet,
+};
+use serde::de::DeserializeOwned;
+use serde_json::json;
+
+const MAX_NONCE_AHEAD: u64 = 100;
+
+#[derive(Default)]
+struct ChainState {
+    account_nonce: u64,
+    mempool: BTreeMap<u64, String>,
+    executed: HashSet<String>,
+    /// Transactions with these nonces are accepted, but never make it into the mempool.
+    drop_nonces: HashSet<u64>,
+    /// Sending transactions with these nonces fails once, with the given error.
+    fail_nonces: HashMap<u64, String>,
+    tx_count: u64,
+}
+
+/// Simulates the nonce handling of the network, for a single account.
+#[derive(Default, Clone)]
+struct SimulatedGateway {
+    state: Arc<Mutex<ChainState>>,
+}
+
+impl SimulatedGateway {
+    fn with_account_nonce(account_nonce: u64) -> Self {
+        let gateway = Self::default();
+        gateway.state.lock().unwrap().account_nonce = account_nonce;
+        gateway
+    }
+
+    fn drop_nonce(&self, nonce: u64) {
+        self.state.lock().unwrap().drop_nonces.insert(nonce);
+    }
+
+    fn fail_nonce(&self, nonce: u64, message: &str) {
+        self.state
+            .lock()
+            .unwrap()
+            .fail_nonces
+            .insert(nonce, message.to_string());
+    }
+
+    fn account_nonce(&self) -> u64 {
+        self.state.lock().unwrap().account_nonce
+    }
+
+    /// Executes all consecutive transactions in the mempool.
+    fn process_block(&self) {
+        let mut state = self.state.lock().unwrap();
+        loop {
+            let nonce = state.account_nonce;
+            let Some(tx_hash) = state.mempool.remove(&nonce) else {
+                break;
+            };
+            state.executed.insert(tx_hash);
+            state.account_nonce += 1;
+        }
+    }
+
+    /// Transactions sent by another tool, unknown to the nonce manager.
+    fn execute_external_txs(&self, count: u64) {
+        self.state.lock().unwrap().account_nonce += count;
+    }
+
+    fn respond(&self, endpoint: &str, payload: Option<serde_json::Value>) -> serde_json::Value {
+        let mut state = self.state.lock().unwrap();
+        if endpoint.starts_with("address/") {
+            return success(json!({
+                "account": {
+                    "address": alice().address().to_bech32_string().unwrap(),
+                    "nonce": state.account_nonce,
+                    "balance": "0",
+                    "username": "",
+                    "code": ""
+                }
+            }));
+        }
+
+        if endpoint == "transaction/send" {
+            let nonce = payload.unwrap()["nonce"].as_u64().unwrap();
+            if let Some(message) = state.fail_nonces.remove(&nonce) {
+                return error(&message);
+            }
+            if nonce < state.account_nonce {
+                return error("transaction generation failed: nonce too low");
+            }
+            if nonce > state.account_nonce + MAX_NONCE_AHEAD {
+                return error("transaction generation failed: nonce too high");
+            }
+            state.tx_count += 1;
+            let tx_hash = format!("{:064x}", state.tx_count);
+            if !state.drop_nonces.remove(&nonce) {
+                state.mempool.insert(nonce, tx_hash.clone());
+            }
+            return success(json!({ "txHash": tx_hash }));
+        }
+
+        if let Some(tx_hash) = endpoint
+            .strip_prefix("transaction/")
+            .and_then(|rest| rest.strip_suffix("/status"))
+        {
+            let tx_hash = tx_hash.to_string();
+            if state.executed.contains(&tx_hash) {
+                return success(json!({ "status": "success" }));
+            }
+            if state.mempool.values().any(|hash| *hash == tx_hash) {
+                return success(json!({ "status": "pending" }));
+            }
+            return error("transaction not found");
+        }
+
+        error(&format!("unknown endpoint: {endpoint}"))
+    }
+}
+
+fn success(data: serde_json::Value) -> serde_json::Value {
+    json!({ "data": data, "error": "", "code": "successful" })
+}
+
+fn error(message: &str) -> serde_json::Value {
+    json!({ "data": null, "error": message, "code": "bad_request" })
+}
+
+impl SimulatedGateway {
+    fn request<R: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        payload: Option<serde_json::Value>,
+    ) -> Result<R> {
+        Ok(serde_json::from_value(self.respond(endpoint, payload))?)
+    }
+}
+
+/// Only the account, sending and status requests are simulated.
+impl BlockchainProxy for SimulatedGateway {
+    async fn get_network_config(&self) -> Result<NetworkConfig> {
+        Err(anyhow!("unknown endpoint: network/config"))
+    }
+
+    async fn get_hyper_block_by_hash(&self, _hash: &str) -> Result<HyperBlock> {
+        Err(anyhow!("unknown endpoint: hyperblock/by-hash"))
+    }
+
+    async fn get_hyper_block_by_nonce(&self, _nonce: u64) -> Result<HyperBlock> {
+        Err(anyhow!("unknown endpoint: hyperblock/by-nonce"))
+    }
+
+    async fn get_latest_hyper_block_nonce(&self, _with_metachain: bool) -> Result<u64> {
+        Err(anyhow!("unknown endpoint: network/status"))
+    }
+
+    async fn request_transaction_cost(&self, _tx: &Transaction) -> Result<TxCostResponseData> {
+        Err(anyhow!("unknown endpoint: transaction/cost"))
+    }
+
+    async fn get_account(&self, address: &Address) -> Result<Account> {
+        let resp: AccountResponse = self.request(&format!("address/{address}"), None)?;
+        resp.data
+            .map(|data| data.account)
+            .ok_or_else(|| anyhow!("{}", resp.error))
+    }
+
+    async fn get_transaction_status(&self, hash: &str) -> Result<String> {
+        let resp: TransactionStatus = self.request(&format!("transaction/{hash}/status"), None)?;
+        resp.data
+            .map(|data| data.status)
+            .ok_or_else(|| anyhow!("{}", resp.error))
+    }
+
+    async fn send_transaction(&self, tx: &Transaction) -> Result<String> {
+        let resp: SendTransactionResponse =
+            self.request("transaction/send", Some(serde_json::to_value(tx)?))?;
+        resp.data
+            .map(|data| data.tx_hash)
+            .ok_or_else(|| anyhow!("{}", resp.error))
+    }
+}
+
+fn alice() -> Wallet {
+    Wallet::from_pem_file("tests/alice.pem").unwrap()
+}
+
+fn transfer(sender: &Address) -> Transaction {
+    Transaction {
+        nonce: 0,
+        value: "1".to_string(),
+        receiver: sender.clone(),
+        sender: sender.clone(),
+        gas_price: 1_000_000_000,
+        gas_limit: 50_000,
+        data: None,
+        signature: None,
+        chain_id: "D".to_string(),
+        version: 1,
+        options: 0,
+        guardian: None,
+        guardian_signature: None,
+        relayer: None,
+        relayer_signature: None,
+    }
+}
+
+#[test]
+fn test_nonce_error_from_gateway_error() {
+    assert_eq!(
+        NonceError::from_gateway_error("transaction generation failed: nonce too low"),
+        Some(NonceError::TooLow)
+    );
+    assert_eq!(
+        NonceError::from_gateway_error("lowerNonceInTx: true, veryHighNonceInTx: false"),
+        Some(NonceError::TooLow)
+    );
+    assert_eq!(
+        NonceError::from_gateway_error("lowerNonceInTx: false, veryHighNonceInTx: true"),
+        Some(NonceError::TooHigh)
+    );
+    assert_eq!(NonceError::from_gateway_error("insufficient funds"), None);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_concurrent_nonces() {
+    let gateway = SimulatedGateway::with_account_nonce(10);
+    let manager = Arc::new(NonceManager::new());
+    let sender = alice().address();
+
+    let handles: Vec<_> = (0..100)
+        .map(|_| {
+            let gateway = gateway.clone();
+            let manager = manager.clone();
+            let sender = sender.clone();
+            tokio::spawn(async move { manager.next_nonce(&gateway, &sender).await.unwrap() })
+        })
+        .collect();
+
+    let mut nonces = Vec::new();
+    for handle in handles {
+        nonces.push(handle.await.unwrap());
+    }
+    nonces.sort();
+    assert_eq!(nonces, (10..110).collect::<Vec<_>>());
+    assert_eq!(manager.pending(&sender).len(), 100);
+}
+
+#[tokio::test]
+async fn test_send_and_execute() {
+    let gateway = SimulatedGateway::with_account_nonce(5);
+    let manager = NonceManager::new();
+    let wallet = alice();
+    let sender = wallet.address();
+
+    let mut hashes = Vec::new();
+    for _ in 0..3 {
+        let mut tx = transfer(&sender);
+        hashes.push(manager.send_tx(&gateway, &wallet, &mut tx).await.unwrap());
+        assert!(tx.verify_signature());
+    }
+    assert_eq!(
+        manager.pending(&sender).into_iter().collect::<Vec<_>>(),
+        vec![
+            (5, PendingNonce::Sent(hashes[0].clone())),
+            (6, PendingNonce::Sent(hashes[1].clone())),
+            (7, PendingNonce::Sent(hashes[2].clone())),
+        ]
+    );
+
+    gateway.process_block();
+    assert_eq!(gateway.account_nonce(), 8);
+
+    let sync = manager.sync(&gateway, &sender).await.unwrap();
+    assert_eq!(sync.account_nonce, 8);
+    assert!(sync.gaps.is_empty());
+    assert!(sync.discarded.is_empty());
+    assert!(manager.pending(&sender).is_empty());
+    assert_eq!(manager.next_nonce(&gateway, &sender).await.unwrap(), 8);
+}
+
+#[tokio::test]
+async fn test_dropped_tx_gap() {
+    let gateway = SimulatedGateway::with_account_nonce(0);
+    let manager = NonceManager::new();
+    let wallet = alice();
+    let sender = wallet.address();
+
+    // the second transaction never reaches the mempool, the ones after it are stuck
+    gateway.drop_nonce(1);
+    let mut hashes = Vec::new();
+    for _ in 0..4 {
+        let mut tx = transfer(&sender);
+        hashes.push(manager.send_tx(&gateway, &wallet, &mut tx).await.unwrap());
+    }
+    gateway.process_block();
+    assert_eq!(gateway.account_nonce(), 1);
+
+    let sync = manager.sync(&gateway, &sender).await.unwrap();
+    assert_eq!(sync.account_nonce, 1);
+    assert_eq!(sync.gaps, vec![1]);
+    assert_eq!(sync.discarded, vec![hashes[1].clone()]);
+    // the ones after the gap are still in the mempool
+    assert_eq!(
+        manager.pending(&sender).into_iter().collect::<Vec<_>>(),
+        vec![
+            (2, PendingNonce::Sent(hashes[2].clone())),
+            (3, PendingNonce::Sent(hashes[3].clone())),
+        ]
+    );
+
+    // sending again fills the gap, then the stuck transactions get executed
+    let mut tx = transfer(&sender);
+    manager.send_tx(&gateway, &wallet, &mut tx).await.unwrap();
+    assert_eq!(tx.nonce, 1);
+    gateway.process_block();
+    assert_eq!(gateway.account_nonce(), 4);
+    assert_eq!(manager.next_nonce(&gateway, &sender).await.unwrap(), 4);
+}
+
+#[tokio::test]
+async fn test_released_nonce_gap() {
+    let gateway = SimulatedGateway::with_account_nonce(0);
+    let manager = NonceManager::new();
+    let sender = alice().address();
+
+    let first = manager.next_nonce(&gateway, &sender).await.unwrap();
+    let second = manager.next_nonce(&gateway, &sender).await.unwrap();
+    manager.mark_sent(&sender, second, "hash");
+
+    // not the last one, it is handed out again first
+    manager.release(&sender, first);
+    assert_eq!(manager.next_nonce(&gateway, &sender).await.unwrap(), 0);
+    assert_eq!(manager.next_nonce(&gateway, &sender).await.unwrap(), 2);
+    manager.release(&sender, 0);
+    manager.release(&sender, 2);
+
+    // the sent transaction never reached the network
+    let sync = manager.sync(&gateway, &sender).await.unwrap();
+    assert_eq!(sync.gaps, vec![0, 1]);
+    assert_eq!(sync.discarded, vec!["hash".to_string()]);
+    assert!(manager.pending(&sender).is_empty());
+    assert_eq!(manager.next_nonce(&gateway, &sender).await.unwrap(), 0);
+}
+
+#[tokio::test]
+async fn test_resync_on_nonce_errors() {
+    let gateway = SimulatedGateway::with_account_nonce(0);
+    let manager = NonceManager::new();
+    let wallet = alice();
+    let sender = wallet.address();
+
+    let mut tx = transfer(&sender);
+    manager.send_tx(&gateway, &wallet, &mut tx).await.unwrap();
+    gateway.process_block();
+
+    // nonce too low: someone else used the account in the meantime
+    gateway.execute_external_txs(5);
+    let mut tx = transfer(&sender);
+    manager.send_tx(&gateway, &wallet, &mut tx).await.unwrap();
+    assert_eq!(tx.nonce, 6);
+    gateway.process_block();
+    assert_eq!(gateway.account_nonce(), 7);
+
+    // nonce too high: far ahead, behind a transaction that never reached the network
+    for _ in 0..=MAX_NONCE_AHEAD {
+        let nonce = manager.next_nonce(&gateway, &sender).await.unwrap();
+        manager.mark_sent(&sender, nonce, "lost");
+    }
+    let mut tx = transfer(&sender);
+    manager.send_tx(&gateway, &wallet, &mut tx).await.unwrap();
+    assert_eq!(tx.nonce, 7);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_concurrent_sends_with_failure() {
+    let gateway = SimulatedGateway::with_account_nonce(0);
+    let manager = Arc::new(NonceManager::new());
+    let wallet = Arc::new(alice());
+    let sender = wallet.address();
+
+    // retried after a sync, which must not discard the transactions around it
+    gateway.fail_nonce(3, "transaction generation failed: nonce too high");
+    // not a nonce error, the send fails
+    gateway.fail_nonce(6, "transaction generation failed: insufficient funds");
+
+    let handles: Vec<_> = (0..10)
+        .map(|_| {
+            let gateway = gateway.clone();
+            let manager = manager.clone();
+            let wallet = wallet.clone();
+            tokio::spawn(async move {
+                let mut tx = transfer(&wallet.address());
+                manager
+                    .send_tx(&gateway, &wallet, &mut tx)
+                    .await
+                    .map(|_| tx.nonce)
+            })
+        })
+        .collect();
+
+    let mut nonces = Vec::new();
+    let mut failures = 0;
+    for handle in handles {
+        match handle.await.unwrap() {
+            Ok(nonce) => nonces.push(nonce),
+            Err(_) => failures += 1,
+        }
+    }
+    assert_eq!(failures, 1);
+    nonces.sort();
+    let missing: Vec<u64> = (0..10).filter(|nonce| !nonces.contains(nonce)).collect();
+    assert_eq!(missing.len(), 1);
+
+    // the failed nonce is the only gap, unless it was the last one, the transactions after it are kept
+    gateway.process_block();
+    assert_eq!(gateway.account_nonce(), missing[0]);
+    let sync = manager.sync(&gateway, &sender).await.unwrap();
+    if missing[0] < 9 {
+        assert_eq!(sync.gaps, missing);
+    } else {
+        assert!(sync.gaps.is_empty());
+    }
+    assert!(sync.discarded.is_empty());
+    assert_eq!(manager.pending(&sender).len() as u64, 9 - missing[0]);
+
+    let mut tx = transfer(&sender);
+    manager.send_tx(&gateway, &wallet, &mut tx).await.unwrap();
+    assert_eq!(tx.nonce, missing[0]);
+    gateway.process_block();
+    assert_eq!(gateway.account_nonce(), 10);
+}
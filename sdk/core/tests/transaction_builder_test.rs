@@ -0,0 +1,172 @@
+mod mock_gateway;
+
+use dharitri_sdk::{
+    data::transaction::Transaction, transaction_builder::TransactionBuilder, utils::base64_decode,
+    wallet::Wallet,
+};
+use mock_gateway::MockGateway;
+use serde_json::json;
+
+const ACCOUNT_NONCE: u64 = 42;
+const CHAIN_ID: &str = "D";
+const MIN_GAS_PRICE: u64 = 1_000_000_000;
+const MIN_GAS_LIMIT: u64 = 50_000;
+const GAS_PER_DATA_BYTE: u64 = 1_500;
+const ESTIMATED_GAS: u64 = 1_234_567;
+
+fn alice() -> Wallet {
+    Wallet::from_pem_file("tests/alice.pem").unwrap()
+}
+
+fn bob() -> Wallet {
+    Wallet::from_private_key("1648ad209d6b157a289884933e3bb30f161ec7113221ec16f87c3578b05830b0")
+        .unwrap()
+}
+
+fn mock_gateway() -> MockGateway {
+    let account = json!({
+        "account": {
+            "address": alice().address().to_bech32_string().unwrap(),
+            "nonce": ACCOUNT_NONCE,
+            "balance": "1000000000000000000",
+            "username": "",
+            "code": ""
+        }
+    });
+    let network_config = json!({
+        "config": {
+            "drt_chain_id": CHAIN_ID,
+            "drt_denomination": 18,
+            "drt_gas_per_data_byte": GAS_PER_DATA_BYTE,
+            "drt_latest_tag_software_version": "v1.0.0",
+            "drt_meta_consensus_group_size": 1,
+            "drt_min_gas_limit": MIN_GAS_LIMIT,
+            "drt_min_gas_price": MIN_GAS_PRICE,
+            "drt_min_transaction_version": 1,
+            "drt_num_metachain_nodes": 1,
+            "drt_num_nodes_in_shard": 1,
+            "drt_num_shards_without_meta": 1,
+            "drt_round_duration": 6000,
+            "drt_shard_consensus_group_size": 1,
+            "drt_start_time": 0
+        }
+    });
+    let tx_cost = json!({
+        "txGasUnits": ESTIMATED_GAS,
+        "returnMessage": ""
+    });
+
+    MockGateway::default()
+        .with_response("address/", account)
+        .with_response("network/config", network_config)
+        .with_response("transaction/cost", tx_cost)
+}
+
+fn raw_data(tx: &Transaction) -> String {
+    String::from_utf8(base64_decode(tx.data.as_ref().unwrap())).unwrap()
+}
+
+#[tokio::test]
+async fn test_build_rewa_transfer() {
+    let tx = TransactionBuilder::new(alice().address(), bob().address())
+        .value(1_000)
+        .data("hello")
+        .build(&mock_gateway())
+        .await
+        .unwrap();
+
+    assert_eq!(tx.nonce, ACCOUNT_NONCE);
+    assert_eq!(tx.value, "1000");
+    assert_eq!(tx.chain_id, CHAIN_ID);
+    assert_eq!(tx.gas_price, MIN_GAS_PRICE);
+    assert_eq!(tx.gas_limit, MIN_GAS_LIMIT + 5 * GAS_PER_DATA_BYTE);
+    assert_eq!(raw_data(&tx), "hello");
+    assert!(tx.signature.is_none());
+}
+
+#[tokio::test]
+async fn test_build_explicit_fields() {
+    // nothing left to fetch, the gateway is not called
+    let tx = TransactionBuilder::new(alice().address(), bob().address())
+        .nonce(7)
+        .gas_limit(100_000)
+        .gas_price(2_000_000_000)
+        .chain_id("T")
+        .options(1)
+        .build(&MockGateway::default())
+        .await
+        .unwrap();
+
+    assert_eq!(tx.nonce, 7);
+    assert_eq!(tx.gas_limit, 100_000);
+    assert_eq!(tx.gas_price, 2_000_000_000);
+    assert_eq!(tx.chain_id, "T");
+    assert_eq!(tx.version, 2);
+    assert!(tx.is_signed_with_hash());
+    assert!(tx.data.is_none());
+}
+
+#[tokio::test]
+async fn test_build_contract_call() {
+    let tx = TransactionBuilder::new(alice().address(), bob().address())
+        .function_call("add", &[vec![5]])
+        .build(&mock_gateway())
+        .await
+        .unwrap();
+    assert_eq!(raw_data(&tx), "add@05");
+    assert_eq!(tx.gas_limit, ESTIMATED_GAS);
+
+    let tx = TransactionBuilder::new(alice().address(), bob().address())
+        .dcdt_transfer_and_call("TOKEN-123456", 256, "deposit", &[])
+        .build(&mock_gateway())
+        .await
+        .unwrap();
+    assert_eq!(
+        raw_data(&tx),
+        "DCDTTransfer@544f4b454e2d313233343536@0100@6465706f736974"
+    );
+}
+
+#[tokio::test]
+async fn test_build_nft_transfers() {
+    let bob_hex = hex::encode(bob().address().to_bytes());
+
+    let tx = TransactionBuilder::new(alice().address(), bob().address())
+        .dcdt_nft_transfer("NFT-123456", 1, 1)
+        .gas_limit(1_000_000)
+        .build(&mock_gateway())
+        .await
+        .unwrap();
+    assert_eq!(tx.receiver.to_bytes(), alice().address().to_bytes());
+    assert_eq!(
+        raw_data(&tx),
+        format!("DCDTNFTTransfer@4e46542d313233343536@01@01@{bob_hex}")
+    );
+
+    let tx = TransactionBuilder::new(alice().address(), bob().address())
+        .multi_dcdt_transfer(&[("TOKEN-123456", 0, 10), ("NFT-123456", 2, 1)])
+        .gas_limit(1_000_000)
+        .build(&mock_gateway())
+        .await
+        .unwrap();
+    assert_eq!(
+        raw_data(&tx),
+        format!("MultiDCDTNFTTransfer@{bob_hex}@02@544f4b454e2d313233343536@@0a@4e46542d313233343536@02@01")
+    );
+}
+
+#[tokio::test]
+async fn test_build_and_sign() {
+    let builder = TransactionBuilder::new(alice().address(), bob().address()).value(1);
+
+    let tx = builder
+        .build_and_sign(&mock_gateway(), &alice())
+        .await
+        .unwrap();
+    assert!(tx.verify_signature());
+
+    assert!(builder
+        .build_and_sign(&mock_gateway(), &bob())
+        .await
+        .is_err());
+}
@@ -1,6 +1,10 @@
 use bip39::Mnemonic;
 
-use dharitri_sdk::{crypto::public_key::PublicKey, data::address::Address, wallet::Wallet};
+use dharitri_sdk::{
+    crypto::public_key::PublicKey,
+    data::{address::Address, transaction::Transaction},
+    wallet::Wallet,
+};
 
 #[test]
 fn test_private_key_from_mnemonic() {
@@ -48,3 +52,167 @@ fn test_load_from_pem() {
         "drt1qyu5wthldzr8wx5c9ucg8kjagg0jfs53s8nr3zpz3hypefsdd8ssey5egf"
     );
 }
+
+fn guarded_relayed_tx(sender: &Wallet, guardian: &Wallet, relayer: &Wallet) -> Transaction {
+    let mut tx = Transaction {
+        nonce: 1,
+        value: "0".to_string(),
+        receiver: sender.address(),
+        sender: sender.address(),
+        gas_price: 1_000_000_000,
+        gas_limit: 100_000,
+        data: None,
+        signature: None,
+        chain_id: "D".to_string(),
+        version: 1,
+        options: 0,
+        guardian: None,
+        guardian_signature: None,
+        relayer: None,
+        relayer_signature: None,
+    };
+    tx.set_guardian(guardian.address());
+    tx.set_relayer(relayer.address());
+    tx
+}
+
+#[test]
+fn test_sign_guarded_relayed_tx() {
+    let sender = Wallet::from_pem_file("tests/alice.pem").unwrap();
+    let co_signer = Wallet::from_private_key(
+        "1648ad209d6b157a289884933e3bb30f161ec7113221ec16f87c3578b05830b0",
+    )
+    .unwrap();
+    let mut tx = guarded_relayed_tx(&sender, &co_signer, &co_signer);
+    assert!(tx.is_guarded());
+    assert_eq!(tx.version, 2);
+    assert_eq!(tx.options, 0b10);
+
+    let signature = sender.sign_tx(&tx);
+    tx.signature = Some(hex::encode(signature));
+    assert!(sender.sign_tx_as_guardian(&mut tx).is_err());
+    co_signer.sign_tx_as_guardian(&mut tx).unwrap();
+    co_signer.sign_tx_as_relayer(&mut tx).unwrap();
+
+    // all parties sign the same content, existing signatures are ignored
+    assert_eq!(sender.sign_tx(&tx), signature);
+    assert_eq!(tx.guardian_signature, tx.relayer_signature);
+
+    let json = serde_json::to_value(&tx).unwrap();
+    assert_eq!(json["options"], 2);
+    assert_eq!(
+        json["guardian"],
+        co_signer.address().to_bech32_string().unwrap()
+    );
+    assert_eq!(json["relayer"], json["guardian"]);
+    assert!(json["guardianSignature"].is_string());
+    assert!(json["relayerSignature"].is_string());
+}
+
+#[test]
+fn test_verify_known_signature() {
+    // RFC 8032, test vector 1
+    let public_key = PublicKey::from_bytes(
+        hex::decode("d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a")
+            .unwrap()
+            .try_into()
+            .unwrap(),
+    );
+    let signature = hex::decode("e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b").unwrap();
+    assert!(public_key.verify(b"", &signature));
+    assert!(!public_key.verify(b"x", &signature));
+    assert!(!public_key.verify(b"", &signature[..63]));
+}
+
+#[test]
+fn test_sign_message() {
+    let wallet = Wallet::from_pem_file("tests/alice.pem").unwrap();
+    let public_key = PublicKey::from_bytes(wallet.address().to_bytes());
+
+    let signature = wallet.sign_message(b"hello");
+    assert!(public_key.verify_message(b"hello", &signature));
+    assert!(!public_key.verify_message(b"hello!", &signature));
+    // the prefix is part of the signed content
+    assert!(!public_key.verify(b"hello", &signature));
+
+    let mut tampered = signature;
+    tampered[0] ^= 1;
+    assert!(!public_key.verify_message(b"hello", &tampered));
+}
+
+#[test]
+fn test_verify_tx_signature() {
+    let sender = Wallet::from_pem_file("tests/alice.pem").unwrap();
+    let other = Wallet::from_private_key(
+        "1648ad209d6b157a289884933e3bb30f161ec7113221ec16f87c3578b05830b0",
+    )
+    .unwrap();
+    let mut tx = guarded_relayed_tx(&sender, &other, &other);
+    assert!(!tx.verify_signature());
+
+    tx.signature = Some(hex::encode(other.sign_tx(&tx)));
+    assert!(!tx.verify_signature());
+
+    tx.signature = Some(hex::encode(sender.sign_tx(&tx)));
+    assert!(tx.verify_signature());
+
+    tx.nonce += 1;
+    assert!(!tx.verify_signature());
+
+    // signed with hash
+    tx.options |= 1;
+    assert!(tx.is_signed_with_hash());
+    tx.signature = Some(hex::encode(sender.sign_tx(&tx)));
+    assert!(tx.verify_signature());
+    assert_eq!(tx.signable_bytes().len(), 32);
+}
+
+const MNEMONIC: &str = "acid twice post genre topic observe valid viable gesture fortune funny dawn around blood enemy page update reduce decline van bundle zebra rookie real";
+
+#[test]
+fn test_derive_from_mnemonic() {
+    let mnemonic = Mnemonic::parse_normalized(MNEMONIC).unwrap();
+    let wallets = Wallet::derive_from_mnemonic(mnemonic.clone(), 0, 0..3);
+    assert_eq!(wallets.len(), 3);
+    for (index, wallet) in wallets.iter().enumerate() {
+        let expected = Wallet::from_mnemonic(mnemonic.clone(), 0, index as u32);
+        assert_eq!(wallet.address().to_bytes(), expected.address().to_bytes());
+    }
+    assert_eq!(
+        hex::encode(wallets[1].address().to_bytes()),
+        "af8fef070a581873912ccbafb6a78bb9eb4e003085ac43dbbdfa3e20eb93cede"
+    );
+}
+
+#[test]
+fn test_secret_key_keystore_roundtrip() {
+    let wallet = Wallet::from_pem_file("tests/alice.pem").unwrap();
+    let keystore = wallet.to_keystore("password");
+    assert_eq!(keystore.kind, "secretKey");
+    assert_eq!(keystore.address, hex::encode(wallet.address().to_bytes()));
+
+    let json = serde_json::to_string(&keystore).unwrap();
+    let loaded = Wallet::from_keystore_file_contents(&json, "password").unwrap();
+    assert_eq!(loaded.address().to_bytes(), wallet.address().to_bytes());
+    assert_eq!(loaded.sign_message(b"test"), wallet.sign_message(b"test"));
+
+    assert!(Wallet::from_keystore_file_contents(&json, "wrong password").is_err());
+}
+
+#[test]
+fn test_mnemonic_keystore_roundtrip() {
+    let mnemonic = Mnemonic::parse_normalized(MNEMONIC).unwrap();
+    let keystore = Wallet::mnemonic_to_keystore(&mnemonic, "password");
+    assert_eq!(keystore.kind, "mnemonic");
+
+    let json = serde_json::to_value(&keystore).unwrap();
+    assert!(json.get("address").is_none());
+    assert!(json.get("bech32").is_none());
+
+    let loaded = Wallet::from_keystore_file_contents(&json.to_string(), "password").unwrap();
+    assert_eq!(
+        loaded.address().to_bytes(),
+        Wallet::from_mnemonic(mnemonic, 0, 0).address().to_bytes()
+    );
+    assert!(Wallet::from_keystore_file_contents(&json.to_string(), "wrong password").is_err());
+}
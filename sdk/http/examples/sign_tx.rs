@@ -31,6 +31,10 @@ async fn main() {
         chain_id: arg.chain_id,
         version: arg.version,
         options: arg.options,
+        guardian: None,
+        guardian_signature: None,
+        relayer: None,
+        relayer_signature: None,
     };
 
     let signature = wl.sign_tx(&unsign_tx);
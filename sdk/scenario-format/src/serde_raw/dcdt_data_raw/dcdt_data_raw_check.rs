@@ -21,4 +21,8 @@ pub struct CheckDcdtDataRaw {
     #[serde(default)]
     #[serde(skip_serializing_if = "CheckBytesValueRaw::is_unspecified")]
     pub frozen: CheckBytesValueRaw,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "CheckBytesValueRaw::is_unspecified")]
+    pub paused: CheckBytesValueRaw,
 }
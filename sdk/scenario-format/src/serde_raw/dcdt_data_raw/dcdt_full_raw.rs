@@ -23,4 +23,9 @@ pub struct DcdtFullRaw {
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub frozen: Option<ValueSubTree>,
+
+    /// Global token state, affects all accounts holding the token.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paused: Option<ValueSubTree>,
 }
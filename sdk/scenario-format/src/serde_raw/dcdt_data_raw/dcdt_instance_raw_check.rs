@@ -11,6 +11,10 @@ pub struct CheckDcdtInstanceRaw {
     #[serde(skip_serializing_if = "CheckBytesValueRaw::is_unspecified")]
     pub balance: CheckBytesValueRaw,
 
+    #[serde(default)]
+    #[serde(skip_serializing_if = "CheckBytesValueRaw::is_unspecified")]
+    pub name: CheckBytesValueRaw,
+
     #[serde(default)]
     #[serde(skip_serializing_if = "CheckBytesValueRaw::is_unspecified")]
     pub creator: CheckBytesValueRaw,
@@ -30,4 +34,8 @@ pub struct CheckDcdtInstanceRaw {
     #[serde(default)]
     #[serde(skip_serializing_if = "CheckBytesValueRaw::is_unspecified")]
     pub attributes: CheckBytesValueRaw,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "CheckBytesValueRaw::is_unspecified")]
+    pub frozen: CheckBytesValueRaw,
 }
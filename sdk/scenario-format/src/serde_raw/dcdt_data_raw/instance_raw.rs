@@ -13,6 +13,10 @@ pub struct DcdtInstanceRaw {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub balance: Option<ValueSubTree>,
 
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<ValueSubTree>,
+
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub creator: Option<ValueSubTree>,
@@ -32,4 +36,8 @@ pub struct DcdtInstanceRaw {
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub attributes: Option<ValueSubTree>,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub frozen: Option<ValueSubTree>,
 }
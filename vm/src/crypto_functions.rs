@@ -1,9 +1,24 @@
+mod bls;
+mod elliptic_curve;
+mod secp256k1;
+
+pub use bls::{
+    public_key_bls, sign_bls, verify_bls, BLS_PUBLIC_KEY_LEN, BLS_SECRET_KEY_LEN, BLS_SIGNATURE_LEN,
+};
+pub use elliptic_curve::{EcPoint, EllipticCurve};
+pub use secp256k1::{
+    encode_secp256k1_der_signature, verify_custom_secp256k1, verify_secp256k1, ECDSA_DOUBLE_SHA256,
+    ECDSA_KECCAK256, ECDSA_PLAIN_MSG, ECDSA_RIPEMD160, ECDSA_SHA256,
+};
+
+use bitcoin_hashes::{ripemd160 as ripemd160_hash, Hash};
 use ed25519_dalek::*;
 use sha2::Sha256;
 use sha3::{Digest, Keccak256};
 
 pub const SHA256_RESULT_LEN: usize = 32;
 pub const KECCAK256_RESULT_LEN: usize = 32;
+pub const RIPEMD160_RESULT_LEN: usize = 20;
 
 pub fn sha256(data: &[u8]) -> [u8; SHA256_RESULT_LEN] {
     let mut hasher = Sha256::new();
@@ -17,6 +32,10 @@ pub fn keccak256(data: &[u8]) -> [u8; KECCAK256_RESULT_LEN] {
     hasher.finalize().into()
 }
 
+pub fn ripemd160(data: &[u8]) -> [u8; RIPEMD160_RESULT_LEN] {
+    ripemd160_hash::Hash::hash(data).to_byte_array()
+}
+
 pub fn verify_ed25519(key: &[u8], message: &[u8], signature: &[u8]) -> bool {
     let public = PublicKey::from_bytes(key);
     if public.is_err() {
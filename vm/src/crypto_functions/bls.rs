@@ -0,0 +1,42 @@
+//! BLS signatures over BLS12-381, with signatures in G1 and public keys in G2.
+//!
+//! The curve arithmetic, hashing to the curve and the pairing all come from `blst`.
+
+use blst::{
+    min_sig::{PublicKey, SecretKey, Signature},
+    BLST_ERROR,
+};
+
+pub const BLS_SIGNATURE_LEN: usize = 48;
+pub const BLS_PUBLIC_KEY_LEN: usize = 96;
+pub const BLS_SECRET_KEY_LEN: usize = 32;
+
+/// Domain separation tag of the IETF basic BLS scheme, with signatures in G1.
+const BLS_DST: &[u8] = b"BLS_SIG_BLS12381G1_XMD:SHA-256_SSWU_RO_NUL_";
+
+/// Keys must be in the G2 subgroup and not the identity.
+fn public_key_from_bytes(key: &[u8]) -> Option<PublicKey> {
+    PublicKey::key_validate(key).ok()
+}
+
+pub fn verify_bls(key: &[u8], message: &[u8], signature: &[u8]) -> bool {
+    let Some(public_key) = public_key_from_bytes(key) else {
+        return false;
+    };
+    let Ok(signature) = Signature::from_bytes(signature) else {
+        return false;
+    };
+    signature.verify(true, message, BLS_DST, &[], &public_key, false) == BLST_ERROR::BLST_SUCCESS
+}
+
+/// The secret key is a big endian scalar. Returns `None` if it is not a valid secret key.
+pub fn public_key_bls(secret_key: &[u8]) -> Option<Vec<u8>> {
+    let secret_key = SecretKey::from_bytes(secret_key).ok()?;
+    Some(secret_key.sk_to_pk().to_bytes().to_vec())
+}
+
+/// Returns `None` if the secret key is invalid.
+pub fn sign_bls(secret_key: &[u8], message: &[u8]) -> Option<Vec<u8>> {
+    let secret_key = SecretKey::from_bytes(secret_key).ok()?;
+    Some(secret_key.sign(message, BLS_DST, &[]).to_bytes().to_vec())
+}
@@ -0,0 +1,329 @@
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+
+/// Affine point, the point at infinity is represented as (0, 0), just like in Go.
+pub type EcPoint = (BigUint, BigUint);
+
+/// Mirrors Go's `elliptic.CurveParams`, which is what the Go VM uses.
+///
+/// The curves are of the form y² = x³ + ax + b (mod p). For the NIST curves a = -3.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EllipticCurve {
+    /// The order of the underlying field.
+    pub p: BigUint,
+    /// The order of the base point.
+    pub n: BigUint,
+    /// The linear coefficient of the curve equation.
+    pub a: BigUint,
+    /// The constant of the curve equation.
+    pub b: BigUint,
+    pub gx: BigUint,
+    pub gy: BigUint,
+    pub bit_size: u32,
+    pub name: &'static str,
+}
+
+fn hex_to_big_uint(hex_str: &str) -> BigUint {
+    BigUint::parse_bytes(hex_str.as_bytes(), 16).expect("invalid curve constant")
+}
+
+impl EllipticCurve {
+    fn from_hex_params(
+        name: &'static str,
+        bit_size: u32,
+        p: &str,
+        n: &str,
+        b: &str,
+        gx: &str,
+        gy: &str,
+    ) -> Self {
+        let p = hex_to_big_uint(p);
+        EllipticCurve {
+            a: &p - 3u32,
+            p,
+            n: hex_to_big_uint(n),
+            b: hex_to_big_uint(b),
+            gx: hex_to_big_uint(gx),
+            gy: hex_to_big_uint(gy),
+            bit_size,
+            name,
+        }
+    }
+
+    pub fn p224() -> Self {
+        Self::from_hex_params(
+            "p224",
+            224,
+            "ffffffffffffffffffffffffffffffff000000000000000000000001",
+            "ffffffffffffffffffffffffffff16a2e0b8f03e13dd29455c5c2a3d",
+            "b4050a850c04b3abf54132565044b0b7d7bfd8ba270b39432355ffb4",
+            "b70e0cbd6bb4bf7f321390b94a03c1d356c21122343280d6115c1d21",
+            "bd376388b5f723fb4c22dfe6cd4375a05a07476444d5819985007e34",
+        )
+    }
+
+    pub fn p256() -> Self {
+        Self::from_hex_params(
+            "p256",
+            256,
+            "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff",
+            "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551",
+            "5ac635d8aa3a93e7b3ebbd55769886bc651d06b0cc53b0f63bce3c3e27d2604b",
+            "6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296",
+            "4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5",
+        )
+    }
+
+    pub fn p384() -> Self {
+        Self::from_hex_params(
+            "p384",
+            384,
+            "fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffeffffffff0000000000000000ffffffff",
+            "ffffffffffffffffffffffffffffffffffffffffffffffffc7634d81f4372ddf581a0db248b0a77aecec196accc52973",
+            "b3312fa7e23ee7e4988e056be3f82d19181d9c6efe8141120314088f5013875ac656398d8a2ed19d2a85c8edd3ec2aef",
+            "aa87ca22be8b05378eb1c71ef320ad746e1d3b628ba79b9859f741e082542a385502f25dbf55296c3a545e3872760ab7",
+            "3617de4a96262c6f5d9e98bf9292dc29f8f41dbd289a147ce9da3113b5f0b8c00a60b1ce1d7e819d7a431d7c90ea0e5f",
+        )
+    }
+
+    pub fn p521() -> Self {
+        Self::from_hex_params(
+            "p521",
+            521,
+            "01ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+            "01fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffa51868783bf2f966b7fcc0148f709a5d03bb5c9b8899c47aebb6fb71e91386409",
+            "0051953eb9618e1c9a1f929a21a0b68540eea2da725b99b315f3b8b489918ef109e156193951ec7e937b1652c0bd3bb1bf073573df883d2c34f1ef451fd46b503f00",
+            "00c6858e06b70404e9cd9e3ecb662395b4429c648139053fb521f828af606b4d3dbaa14b5e77efe75928fe1dc127a2ffa8de3348b3c1856a429bf97e7e31c2e5bd66",
+            "011839296a789a3bc0045c8a5fb42c7d1bd998f54449579b446817afbd17273e662c97ee72995ef42640c550b9013fad0761353c7086a272c24088be94769fd16650",
+        )
+    }
+
+    /// Not available via the elliptic curve VM hooks, only used for signature verification.
+    pub fn secp256k1() -> Self {
+        EllipticCurve {
+            a: BigUint::zero(),
+            ..Self::from_hex_params(
+                "secp256k1",
+                256,
+                "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f",
+                "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141",
+                "07",
+                "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+                "483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8",
+            )
+        }
+    }
+
+    /// The curve names, as used by the Go VM.
+    pub fn from_name(name: &[u8]) -> Option<Self> {
+        match name {
+            b"p224" => Some(Self::p224()),
+            b"p256" => Some(Self::p256()),
+            b"p384" => Some(Self::p384()),
+            b"p521" => Some(Self::p521()),
+            _ => None,
+        }
+    }
+
+    /// Length of the field elements, in bytes.
+    pub fn byte_len(&self) -> usize {
+        (self.bit_size as usize).div_ceil(8)
+    }
+
+    pub fn private_key_byte_len(&self) -> usize {
+        (self.n.bits() as usize).div_ceil(8)
+    }
+
+    fn mod_sub(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        ((a + &self.p) - (b % &self.p)) % &self.p
+    }
+
+    fn mod_inv(&self, a: &BigUint) -> BigUint {
+        a.modinv(&self.p).expect("field element has no inverse")
+    }
+
+    /// x³ + ax + b
+    fn polynomial(&self, x: &BigUint) -> BigUint {
+        (x * x * x + &self.a * x + &self.b) % &self.p
+    }
+
+    pub fn is_on_curve(&self, x: &BigUint, y: &BigUint) -> bool {
+        if x >= &self.p || y >= &self.p {
+            return false;
+        }
+        (y * y) % &self.p == self.polynomial(x)
+    }
+
+    pub fn add(&self, x1: &BigUint, y1: &BigUint, x2: &BigUint, y2: &BigUint) -> EcPoint {
+        if x1.is_zero() && y1.is_zero() {
+            return (x2.clone(), y2.clone());
+        }
+        if x2.is_zero() && y2.is_zero() {
+            return (x1.clone(), y1.clone());
+        }
+        if x1 == x2 {
+            if y1 == y2 {
+                return self.double(x1, y1);
+            }
+            return (BigUint::zero(), BigUint::zero());
+        }
+
+        let lambda = self.mod_sub(y2, y1) * self.mod_inv(&self.mod_sub(x2, x1)) % &self.p;
+        self.line_intersection(&lambda, x1, y1, x2)
+    }
+
+    pub fn double(&self, x: &BigUint, y: &BigUint) -> EcPoint {
+        if y.is_zero() {
+            return (BigUint::zero(), BigUint::zero());
+        }
+
+        let numerator = (x * x * 3u32 + &self.a) % &self.p;
+        let lambda = numerator * self.mod_inv(&((y * 2u32) % &self.p)) % &self.p;
+        self.line_intersection(&lambda, x, y, x)
+    }
+
+    fn line_intersection(
+        &self,
+        lambda: &BigUint,
+        x1: &BigUint,
+        y1: &BigUint,
+        x2: &BigUint,
+    ) -> EcPoint {
+        let x3 = self.mod_sub(&self.mod_sub(&(lambda * lambda), x1), x2);
+        let y3 = self.mod_sub(&(lambda * self.mod_sub(x1, &x3)), y1);
+        (x3, y3)
+    }
+
+    /// The scalar is given as big endian bytes.
+    pub fn scalar_mult(&self, x: &BigUint, y: &BigUint, scalar: &[u8]) -> EcPoint {
+        let mut result = (BigUint::zero(), BigUint::zero());
+        for byte in scalar {
+            for bit_index in (0..8).rev() {
+                result = self.double(&result.0, &result.1);
+                if (byte >> bit_index) & 1 == 1 {
+                    result = self.add(&result.0, &result.1, x, y);
+                }
+            }
+        }
+        result
+    }
+
+    pub fn scalar_base_mult(&self, scalar: &[u8]) -> EcPoint {
+        self.scalar_mult(&self.gx, &self.gy, scalar)
+    }
+
+    /// Uncompressed form: `0x04 || x || y`.
+    pub fn marshal(&self, x: &BigUint, y: &BigUint) -> Vec<u8> {
+        let mut result = vec![4u8];
+        result.extend_from_slice(&self.pad_field_element(x));
+        result.extend_from_slice(&self.pad_field_element(y));
+        result
+    }
+
+    /// Compressed form: `0x02 | parity(y) || x`.
+    pub fn marshal_compressed(&self, x: &BigUint, y: &BigUint) -> Vec<u8> {
+        let mut result = vec![2u8 | (y.bit(0) as u8)];
+        result.extend_from_slice(&self.pad_field_element(x));
+        result
+    }
+
+    pub fn unmarshal(&self, data: &[u8]) -> Option<EcPoint> {
+        let byte_len = self.byte_len();
+        if data.len() != 1 + 2 * byte_len || data[0] != 4 {
+            return None;
+        }
+        let x = BigUint::from_bytes_be(&data[1..1 + byte_len]);
+        let y = BigUint::from_bytes_be(&data[1 + byte_len..]);
+        if !self.is_on_curve(&x, &y) {
+            return None;
+        }
+        Some((x, y))
+    }
+
+    pub fn unmarshal_compressed(&self, data: &[u8]) -> Option<EcPoint> {
+        let byte_len = self.byte_len();
+        if data.len() != 1 + byte_len || (data[0] != 2 && data[0] != 3) {
+            return None;
+        }
+        let x = BigUint::from_bytes_be(&data[1..]);
+        if x >= self.p {
+            return None;
+        }
+        let mut y = self.mod_sqrt(&self.polynomial(&x))?;
+        if y.bit(0) != (data[0] & 1 == 1) {
+            y = self.mod_sub(&BigUint::zero(), &y);
+        }
+        if !self.is_on_curve(&x, &y) {
+            return None;
+        }
+        Some((x, y))
+    }
+
+    /// Same algorithm as the (deprecated) `elliptic.GenerateKey` in Go,
+    /// so that the same randomness produces the same keys.
+    pub fn generate_key<R>(&self, mut random_bytes: R) -> (Vec<u8>, EcPoint)
+    where
+        R: FnMut(usize) -> Vec<u8>,
+    {
+        const MASK: [u8; 8] = [0xff, 0x1, 0x3, 0x7, 0xf, 0x1f, 0x3f, 0x7f];
+        let bit_size = self.n.bits() as usize;
+        loop {
+            let mut private_key = random_bytes(self.private_key_byte_len());
+            private_key[0] &= MASK[bit_size % 8];
+            // this is because, in tests, the randomness source can return all zeros
+            private_key[1] ^= 0x42;
+            if BigUint::from_bytes_be(&private_key) >= self.n {
+                continue;
+            }
+            let public_key = self.scalar_base_mult(&private_key);
+            return (private_key, public_key);
+        }
+    }
+
+    fn pad_field_element(&self, value: &BigUint) -> Vec<u8> {
+        let bytes = value.to_bytes_be();
+        let mut result = vec![0u8; self.byte_len().saturating_sub(bytes.len())];
+        result.extend_from_slice(&bytes);
+        result
+    }
+
+    /// Tonelli-Shanks, since not all supported fields have p ≡ 3 (mod 4).
+    fn mod_sqrt(&self, a: &BigUint) -> Option<BigUint> {
+        let p = &self.p;
+        let one = BigUint::one();
+        let p_minus_one = p - &one;
+        if a.is_zero() {
+            return Some(BigUint::zero());
+        }
+        if a.modpow(&(&p_minus_one >> 1), p) != one {
+            return None;
+        }
+
+        let two_adicity = p_minus_one.trailing_zeros().unwrap_or_default();
+        let odd_factor = &p_minus_one >> two_adicity;
+
+        let mut non_residue = BigUint::from(2u32);
+        while non_residue.modpow(&(&p_minus_one >> 1), p) == one {
+            non_residue += 1u32;
+        }
+
+        let mut m = two_adicity;
+        let mut c = non_residue.modpow(&odd_factor, p);
+        let mut t = a.modpow(&odd_factor, p);
+        let mut r = a.modpow(&((&odd_factor + &one) >> 1), p);
+        while t != one {
+            let mut i = 0;
+            let mut t_pow = t.clone();
+            while t_pow != one {
+                t_pow = &t_pow * &t_pow % p;
+                i += 1;
+            }
+            let b = c.modpow(&(BigUint::one() << (m - i - 1)), p);
+            m = i;
+            c = &b * &b % p;
+            t = t * &c % p;
+            r = r * b % p;
+        }
+        Some(r)
+    }
+}
@@ -0,0 +1,146 @@
+use num_bigint::BigUint;
+use num_traits::Zero;
+use sha2::{Digest, Sha256};
+
+use super::{keccak256, ripemd160, EcPoint, EllipticCurve};
+
+/// Message hash types, same values as `MessageHashType` in the framework.
+pub const ECDSA_PLAIN_MSG: u8 = 0;
+pub const ECDSA_SHA256: u8 = 1;
+pub const ECDSA_DOUBLE_SHA256: u8 = 2;
+pub const ECDSA_KECCAK256: u8 = 3;
+pub const ECDSA_RIPEMD160: u8 = 4;
+
+const DER_SEQUENCE_TAG: u8 = 0x30;
+const DER_INTEGER_TAG: u8 = 0x02;
+
+fn hash_message(message: &[u8], hash_type: u8) -> Option<Vec<u8>> {
+    match hash_type {
+        ECDSA_PLAIN_MSG => Some(message.to_vec()),
+        ECDSA_SHA256 => Some(Sha256::digest(message).to_vec()),
+        ECDSA_DOUBLE_SHA256 => Some(Sha256::digest(Sha256::digest(message)).to_vec()),
+        ECDSA_KECCAK256 => Some(keccak256(message).to_vec()),
+        ECDSA_RIPEMD160 => Some(ripemd160(message).to_vec()),
+        _ => None,
+    }
+}
+
+/// Accepts both compressed (33 bytes) and uncompressed (65 bytes) SEC1 keys.
+fn parse_public_key(curve: &EllipticCurve, key: &[u8]) -> Option<EcPoint> {
+    match key.first() {
+        Some(2) | Some(3) => curve.unmarshal_compressed(key),
+        Some(4) => curve.unmarshal(key),
+        _ => None,
+    }
+}
+
+fn encode_der_integer(value: &[u8]) -> Vec<u8> {
+    let first_non_zero = value.iter().position(|b| *b != 0).unwrap_or(value.len());
+    let mut trimmed = value[first_non_zero..].to_vec();
+    if trimmed.first().is_none_or(|b| b & 0x80 != 0) {
+        // positive numbers with the highest bit set need a leading zero
+        trimmed.insert(0, 0);
+    }
+
+    let mut result = vec![DER_INTEGER_TAG, trimmed.len() as u8];
+    result.extend_from_slice(&trimmed);
+    result
+}
+
+fn decode_der_integer(data: &[u8]) -> Option<(BigUint, &[u8])> {
+    if data.len() < 2 || data[0] != DER_INTEGER_TAG {
+        return None;
+    }
+    let length = data[1] as usize;
+    let value = data.get(2..2 + length)?;
+    if length == 0 || value[0] & 0x80 != 0 {
+        // negative numbers are not allowed
+        return None;
+    }
+    if length > 1 && value[0] == 0 && value[1] & 0x80 == 0 {
+        // not minimally encoded
+        return None;
+    }
+    Some((BigUint::from_bytes_be(value), &data[2 + length..]))
+}
+
+fn decode_der_signature(signature: &[u8]) -> Option<(BigUint, BigUint)> {
+    if signature.len() < 2
+        || signature[0] != DER_SEQUENCE_TAG
+        || signature[1] as usize != signature.len() - 2
+    {
+        return None;
+    }
+    let (r, rest) = decode_der_integer(&signature[2..])?;
+    let (s, rest) = decode_der_integer(rest)?;
+    if !rest.is_empty() {
+        return None;
+    }
+    Some((r, s))
+}
+
+/// DER-encodes an ECDSA signature, given as big-endian r and s.
+///
+/// Just like the Go VM, s is normalized to the lower half of the curve order.
+pub fn encode_secp256k1_der_signature(r: &[u8], s: &[u8]) -> Vec<u8> {
+    let order = EllipticCurve::secp256k1().n;
+    let mut s = BigUint::from_bytes_be(s);
+    if s > &order >> 1 {
+        s = &order - (s % &order);
+    }
+
+    let mut body = encode_der_integer(r);
+    body.extend_from_slice(&encode_der_integer(&s.to_bytes_be()));
+
+    let mut result = vec![DER_SEQUENCE_TAG, body.len() as u8];
+    result.extend_from_slice(&body);
+    result
+}
+
+/// Verifies a DER-encoded ECDSA signature over the secp256k1 curve.
+///
+/// The message is first hashed according to the hash type,
+/// `verify_secp256k1` uses double SHA-256, as in the Go VM.
+pub fn verify_custom_secp256k1(
+    key: &[u8],
+    message: &[u8],
+    signature: &[u8],
+    hash_type: u8,
+) -> bool {
+    let curve = EllipticCurve::secp256k1();
+    let Some(hash) = hash_message(message, hash_type) else {
+        return false;
+    };
+    let Some((x, y)) = parse_public_key(&curve, key) else {
+        return false;
+    };
+    let Some((r, s)) = decode_der_signature(signature) else {
+        return false;
+    };
+    if r.is_zero() || s.is_zero() || r >= curve.n || s >= curve.n {
+        return false;
+    }
+
+    // only the leftmost bits of the hash are used, if it is longer than the curve order
+    let hash_len = hash.len().min(curve.private_key_byte_len());
+    let z = BigUint::from_bytes_be(&hash[..hash_len]);
+
+    let Some(s_inv) = s.modinv(&curve.n) else {
+        return false;
+    };
+    let u1 = (&z * &s_inv) % &curve.n;
+    let u2 = (&r * &s_inv) % &curve.n;
+
+    let (x1, y1) = curve.scalar_base_mult(&u1.to_bytes_be());
+    let (x2, y2) = curve.scalar_mult(&x, &y, &u2.to_bytes_be());
+    let (rx, ry) = curve.add(&x1, &y1, &x2, &y2);
+    if rx.is_zero() && ry.is_zero() {
+        return false;
+    }
+
+    rx % &curve.n == r
+}
+
+pub fn verify_secp256k1(key: &[u8], message: &[u8], signature: &[u8]) -> bool {
+    verify_custom_secp256k1(key, message, signature, ECDSA_DOUBLE_SHA256)
+}
@@ -4,10 +4,12 @@ mod exec_call;
 mod exec_contract_endpoint;
 mod exec_create;
 mod exec_general_tx;
+mod gas_schedule;
 mod system_sc;
 
 pub use blockchain_vm::{BlockchainVM, BlockchainVMRef};
 pub use builtin_function_mocks::*;
 pub use exec_call::*;
 pub(crate) use exec_general_tx::*;
+pub use gas_schedule::GasSchedule;
 pub use system_sc::*;
@@ -2,11 +2,14 @@ use std::{ops::Deref, sync::Arc};
 
 use dharitri_vm_executor::Executor;
 
-use super::BuiltinFunctionContainer;
+use super::{BuiltinFunctionContainer, GasSchedule};
 
 pub struct BlockchainVM {
     pub builtin_functions: BuiltinFunctionContainer,
     pub executor: Box<dyn Executor + Send + Sync>,
+
+    /// Gas metering is only performed if a gas schedule is set.
+    pub gas_schedule: Option<GasSchedule>,
 }
 
 #[derive(Clone)]
@@ -17,6 +20,7 @@ impl BlockchainVM {
         BlockchainVM {
             builtin_functions: BuiltinFunctionContainer,
             executor,
+            gas_schedule: None,
         }
     }
 }
@@ -25,6 +29,15 @@ impl BlockchainVMRef {
     pub fn new(executor: Box<dyn Executor + Send + Sync>) -> Self {
         BlockchainVMRef(Arc::new(BlockchainVM::new(executor)))
     }
+
+    /// Enables or disables gas metering.
+    ///
+    /// Can only be called in between transactions, while no other references to the VM exist.
+    pub fn set_gas_schedule(&mut self, gas_schedule: Option<GasSchedule>) {
+        Arc::get_mut(&mut self.0)
+            .expect("cannot change the gas schedule during execution")
+            .gas_schedule = gas_schedule;
+    }
 }
 
 impl Deref for BlockchainVMRef {
@@ -2,8 +2,9 @@ use super::{
     builtin_func_trait::BuiltinFunction,
     builtin_function_names::*,
     dcdt_nft::{
-        DCDTLocalBurn, DCDTLocalMint, DCDTNftAddQuantity, DCDTNftAddUri, DCDTNftBurn,
-        DCDTNftCreate, DCDTNftUpdateAttributes,
+        DCDTLocalBurn, DCDTLocalMint, DCDTMetaDataRecreate, DCDTMetaDataUpdate, DCDTModifyCreator,
+        DCDTModifyRoyalties, DCDTNftAddQuantity, DCDTNftAddUri, DCDTNftBurn, DCDTNftCreate,
+        DCDTNftUpdateAttributes, DCDTSetNewURIs,
     },
     general::{ChangeOwner, ClaimDeveloperRewards, DeleteUsername, SetUsername, UpgradeContract},
     transfer::{DCDTMultiTransfer, DCDTNftTransfer, DCDTTransfer},
@@ -103,6 +104,21 @@ impl<'a> BuiltinFunctionCall<'a> {
                 DCDTNftUpdateAttributes,
                 f,
             ),
+            DCDT_MODIFY_ROYALTIES_FUNC_NAME => {
+                self.check_role_and_execute(DcdtLocalRole::ModifyRoyalties, DCDTModifyRoyalties, f)
+            },
+            DCDT_SET_NEW_URIS_FUNC_NAME => {
+                self.check_role_and_execute(DcdtLocalRole::SetNewUri, DCDTSetNewURIs, f)
+            },
+            DCDT_MODIFY_CREATOR_FUNC_NAME => {
+                self.check_role_and_execute(DcdtLocalRole::ModifyCreator, DCDTModifyCreator, f)
+            },
+            DCDT_METADATA_RECREATE_FUNC_NAME => {
+                self.check_role_and_execute(DcdtLocalRole::NftRecreate, DCDTMetaDataRecreate, f)
+            },
+            DCDT_METADATA_UPDATE_FUNC_NAME => {
+                self.check_role_and_execute(DcdtLocalRole::NftUpdate, DCDTMetaDataUpdate, f)
+            },
 
             DCDT_MULTI_TRANSFER_FUNC_NAME => self.execute_bf(DCDTMultiTransfer, f),
             DCDT_NFT_TRANSFER_FUNC_NAME => self.execute_bf(DCDTNftTransfer, f),
@@ -0,0 +1,93 @@
+use num_bigint::BigUint;
+use num_traits::ToPrimitive;
+
+use crate::{
+    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxLog, TxResult},
+    types::{top_decode_u64, top_encode_u64},
+    world_mock::DcdtInstanceMetadata,
+};
+
+/// Royalties are expressed in basis points, 10000 means 100%.
+const MAX_ROYALTIES: u64 = 10_000;
+
+/// Number of arguments expected by `DCDTMetaDataRecreate` and `DCDTMetaDataUpdate`,
+/// not counting the URIs.
+pub(super) const NUM_METADATA_ARGS: usize = 6;
+
+pub(super) fn check_num_args(
+    tx_input: &TxInput,
+    func_name: &str,
+    expected: usize,
+) -> Result<(), String> {
+    if tx_input.args.len() != expected {
+        return Err(format!("{func_name} expects {expected} arguments"));
+    }
+    Ok(())
+}
+
+pub(super) fn check_min_num_args(
+    tx_input: &TxInput,
+    func_name: &str,
+    expected: usize,
+) -> Result<(), String> {
+    if tx_input.args.len() < expected {
+        return Err(format!("{func_name} expects at least {expected} arguments"));
+    }
+    Ok(())
+}
+
+pub(super) fn decode_royalties(arg: &[u8]) -> Result<u64, String> {
+    BigUint::from_bytes_be(arg)
+        .to_u64()
+        .filter(|royalties| *royalties <= MAX_ROYALTIES)
+        .ok_or_else(|| "invalid royalties value".to_string())
+}
+
+/// Common logic for the builtin functions that modify the metadata of an existing token instance.
+///
+/// The first 2 arguments are always the token identifier and the nonce,
+/// all arguments after them end up in the log topics.
+pub(super) fn execute_metadata_change<F>(
+    func_name: &str,
+    tx_input: TxInput,
+    tx_cache: TxCache,
+    f: F,
+) -> (TxResult, BlockchainUpdate)
+where
+    F: FnOnce(&mut DcdtInstanceMetadata) -> Result<(), String>,
+{
+    let token_identifier = tx_input.args[0].clone();
+    let nonce = top_decode_u64(tx_input.args[1].as_slice());
+
+    let result = tx_cache.with_account_mut(&tx_input.from, |account| {
+        let metadata = account
+            .dcdt
+            .get_instance_metadata_mut(&token_identifier, nonce)
+            .ok_or_else(|| "invalid token nonce".to_string())?;
+        f(metadata)
+    });
+    if let Err(message) = result {
+        return (TxResult::from_vm_error(message), BlockchainUpdate::empty());
+    }
+
+    let mut topics = vec![
+        token_identifier,
+        top_encode_u64(nonce),
+        Vec::new(), // value = 0
+    ];
+    topics.extend_from_slice(&tx_input.args[2..]);
+    let log = TxLog {
+        address: tx_input.from,
+        endpoint: func_name.into(),
+        topics,
+        data: vec![],
+    };
+
+    let tx_result = TxResult {
+        result_status: 0,
+        result_logs: vec![log],
+        ..Default::default()
+    };
+
+    (tx_result, tx_cache.into_blockchain_updates())
+}
@@ -0,0 +1,61 @@
+use crate::{
+    tx_execution::{builtin_function_names::DCDT_METADATA_RECREATE_FUNC_NAME, BlockchainVMRef},
+    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult},
+    world_mock::DcdtInstanceMetadata,
+};
+
+use super::{
+    super::builtin_func_trait::BuiltinFunction,
+    dcdt_metadata_common::{
+        check_min_num_args, decode_royalties, execute_metadata_change, NUM_METADATA_ARGS,
+    },
+};
+
+/// Overwrites all the metadata of an existing token instance. The caller becomes the new creator.
+///
+/// Arguments: token identifier, nonce, name, royalties, hash, attributes, URIs.
+pub struct DCDTMetaDataRecreate;
+
+impl BuiltinFunction for DCDTMetaDataRecreate {
+    fn name(&self) -> &str {
+        DCDT_METADATA_RECREATE_FUNC_NAME
+    }
+
+    fn execute<F>(
+        &self,
+        tx_input: TxInput,
+        tx_cache: TxCache,
+        _vm: &BlockchainVMRef,
+        _f: F,
+    ) -> (TxResult, BlockchainUpdate)
+    where
+        F: FnOnce(),
+    {
+        if let Err(message) = check_min_num_args(
+            &tx_input,
+            DCDT_METADATA_RECREATE_FUNC_NAME,
+            NUM_METADATA_ARGS + 1,
+        ) {
+            return (TxResult::from_vm_error(message), BlockchainUpdate::empty());
+        }
+
+        let args = tx_input.args.clone();
+        let new_creator = tx_input.from.clone();
+        execute_metadata_change(
+            DCDT_METADATA_RECREATE_FUNC_NAME,
+            tx_input,
+            tx_cache,
+            |metadata| {
+                *metadata = DcdtInstanceMetadata {
+                    name: args[2].clone(),
+                    creator: Some(new_creator),
+                    royalties: decode_royalties(&args[3])?,
+                    hash: Some(args[4].clone()),
+                    attributes: args[5].clone(),
+                    uri: args[NUM_METADATA_ARGS..].to_vec(),
+                };
+                Ok(())
+            },
+        )
+    }
+}
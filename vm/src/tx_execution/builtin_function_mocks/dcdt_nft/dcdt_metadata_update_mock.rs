@@ -0,0 +1,69 @@
+use crate::{
+    tx_execution::{builtin_function_names::DCDT_METADATA_UPDATE_FUNC_NAME, BlockchainVMRef},
+    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult},
+};
+
+use super::{
+    super::builtin_func_trait::BuiltinFunction,
+    dcdt_metadata_common::{
+        check_min_num_args, decode_royalties, execute_metadata_change, NUM_METADATA_ARGS,
+    },
+};
+
+/// Same arguments as `DCDTMetaDataRecreate`, but only the non-empty ones overwrite the existing metadata.
+pub struct DCDTMetaDataUpdate;
+
+impl BuiltinFunction for DCDTMetaDataUpdate {
+    fn name(&self) -> &str {
+        DCDT_METADATA_UPDATE_FUNC_NAME
+    }
+
+    fn execute<F>(
+        &self,
+        tx_input: TxInput,
+        tx_cache: TxCache,
+        _vm: &BlockchainVMRef,
+        _f: F,
+    ) -> (TxResult, BlockchainUpdate)
+    where
+        F: FnOnce(),
+    {
+        if let Err(message) = check_min_num_args(
+            &tx_input,
+            DCDT_METADATA_UPDATE_FUNC_NAME,
+            NUM_METADATA_ARGS + 1,
+        ) {
+            return (TxResult::from_vm_error(message), BlockchainUpdate::empty());
+        }
+
+        let args = tx_input.args.clone();
+        execute_metadata_change(
+            DCDT_METADATA_UPDATE_FUNC_NAME,
+            tx_input,
+            tx_cache,
+            |metadata| {
+                if !args[2].is_empty() {
+                    metadata.name = args[2].clone();
+                }
+                if !args[3].is_empty() {
+                    metadata.royalties = decode_royalties(&args[3])?;
+                }
+                if !args[4].is_empty() {
+                    metadata.hash = Some(args[4].clone());
+                }
+                if !args[5].is_empty() {
+                    metadata.attributes = args[5].clone();
+                }
+                let new_uris: Vec<Vec<u8>> = args[NUM_METADATA_ARGS..]
+                    .iter()
+                    .filter(|uri| !uri.is_empty())
+                    .cloned()
+                    .collect();
+                if !new_uris.is_empty() {
+                    metadata.uri = new_uris;
+                }
+                Ok(())
+            },
+        )
+    }
+}
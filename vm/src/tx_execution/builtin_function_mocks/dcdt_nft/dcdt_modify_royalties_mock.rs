@@ -0,0 +1,44 @@
+use crate::{
+    tx_execution::{builtin_function_names::DCDT_MODIFY_ROYALTIES_FUNC_NAME, BlockchainVMRef},
+    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult},
+};
+
+use super::{
+    super::builtin_func_trait::BuiltinFunction,
+    dcdt_metadata_common::{check_num_args, decode_royalties, execute_metadata_change},
+};
+
+/// Sets new royalties on an existing token instance.
+pub struct DCDTModifyRoyalties;
+
+impl BuiltinFunction for DCDTModifyRoyalties {
+    fn name(&self) -> &str {
+        DCDT_MODIFY_ROYALTIES_FUNC_NAME
+    }
+
+    fn execute<F>(
+        &self,
+        tx_input: TxInput,
+        tx_cache: TxCache,
+        _vm: &BlockchainVMRef,
+        _f: F,
+    ) -> (TxResult, BlockchainUpdate)
+    where
+        F: FnOnce(),
+    {
+        if let Err(message) = check_num_args(&tx_input, DCDT_MODIFY_ROYALTIES_FUNC_NAME, 3) {
+            return (TxResult::from_vm_error(message), BlockchainUpdate::empty());
+        }
+
+        let royalties_arg = tx_input.args[2].clone();
+        execute_metadata_change(
+            DCDT_MODIFY_ROYALTIES_FUNC_NAME,
+            tx_input,
+            tx_cache,
+            |metadata| {
+                metadata.royalties = decode_royalties(&royalties_arg)?;
+                Ok(())
+            },
+        )
+    }
+}
@@ -60,6 +60,7 @@ impl BuiltinFunction for DCDTNftCreate {
                     uri: uris,
                     attributes,
                 },
+                frozen: false,
             });
 
             dcdt_data.last_nonce
@@ -0,0 +1,44 @@
+use crate::{
+    tx_execution::{builtin_function_names::DCDT_SET_NEW_URIS_FUNC_NAME, BlockchainVMRef},
+    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult},
+};
+
+use super::{
+    super::builtin_func_trait::BuiltinFunction,
+    dcdt_metadata_common::{check_min_num_args, execute_metadata_change},
+};
+
+/// Replaces all URIs of an existing token instance, as opposed to `DCDTNFTAddURI`, which appends them.
+pub struct DCDTSetNewURIs;
+
+impl BuiltinFunction for DCDTSetNewURIs {
+    fn name(&self) -> &str {
+        DCDT_SET_NEW_URIS_FUNC_NAME
+    }
+
+    fn execute<F>(
+        &self,
+        tx_input: TxInput,
+        tx_cache: TxCache,
+        _vm: &BlockchainVMRef,
+        _f: F,
+    ) -> (TxResult, BlockchainUpdate)
+    where
+        F: FnOnce(),
+    {
+        if let Err(message) = check_min_num_args(&tx_input, DCDT_SET_NEW_URIS_FUNC_NAME, 3) {
+            return (TxResult::from_vm_error(message), BlockchainUpdate::empty());
+        }
+
+        let new_uris = tx_input.args[2..].to_vec();
+        execute_metadata_change(
+            DCDT_SET_NEW_URIS_FUNC_NAME,
+            tx_input,
+            tx_cache,
+            |metadata| {
+                metadata.uri = new_uris;
+                Ok(())
+            },
+        )
+    }
+}
@@ -1,15 +1,26 @@
 mod dcdt_local_burn;
 mod dcdt_local_mint;
+mod dcdt_metadata_common;
+mod dcdt_metadata_recreate_mock;
+mod dcdt_metadata_update_mock;
+mod dcdt_modify_creator_mock;
+mod dcdt_modify_royalties_mock;
 mod dcdt_nft_add_quantity_mock;
 mod dcdt_nft_add_uri_mock;
 mod dcdt_nft_burn_mock;
 mod dcdt_nft_create_mock;
 mod dcdt_nft_update_attriutes_mock;
+mod dcdt_set_new_uris_mock;
 
 pub use dcdt_local_burn::*;
 pub use dcdt_local_mint::*;
+pub use dcdt_metadata_recreate_mock::*;
+pub use dcdt_metadata_update_mock::*;
+pub use dcdt_modify_creator_mock::*;
+pub use dcdt_modify_royalties_mock::*;
 pub use dcdt_nft_add_quantity_mock::*;
 pub use dcdt_nft_add_uri_mock::*;
 pub use dcdt_nft_burn_mock::*;
 pub use dcdt_nft_create_mock::*;
 pub use dcdt_nft_update_attriutes_mock::*;
+pub use dcdt_set_new_uris_mock::*;
@@ -1,13 +1,14 @@
 use crate::{
     tx_execution::{
         builtin_function_mocks::builtin_func_trait::BuiltinFunctionDcdtTransferInfo,
-        BlockchainVMRef,
+        is_system_sc_address, BlockchainVMRef,
     },
     tx_mock::{
         BlockchainUpdate, CallType, TxCache, TxFunctionName, TxInput, TxLog, TxResult,
         TxTokenTransfer,
     },
     types::{top_decode_u64, VMAddress},
+    vm_err_msg,
 };
 use num_bigint::BigUint;
 use num_traits::Zero;
@@ -82,6 +83,15 @@ where
         ..Default::default()
     };
 
+    if let Err(message) = check_transfers_allowed(
+        &tx_cache,
+        &exec_input.from,
+        &exec_input.to,
+        &exec_input.dcdt_values,
+    ) {
+        return (TxResult::from_vm_error(message), BlockchainUpdate::empty());
+    }
+
     let (mut tx_result, blockchain_updates) = vm.default_execution(exec_input, tx_cache, f);
 
     // prepends dcdt log
@@ -91,6 +101,34 @@ where
     (tx_result, blockchain_updates)
 }
 
+/// Same checks as the node: neither sender nor receiver can have the token instance frozen,
+/// and the token must not be paused. Transfers coming from the system SC are exempt.
+fn check_transfers_allowed(
+    tx_cache: &TxCache,
+    from: &VMAddress,
+    to: &VMAddress,
+    transfers: &[TxTokenTransfer],
+) -> Result<(), &'static str> {
+    if is_system_sc_address(from) {
+        return Ok(());
+    }
+
+    for transfer in transfers {
+        let token_identifier = transfer.token_identifier.as_slice();
+        if tx_cache.is_dcdt_instance_frozen(from, token_identifier, transfer.nonce) {
+            return Err(vm_err_msg::DCDT_IS_FROZEN_FOR_ACCOUNT);
+        }
+        if tx_cache.is_dcdt_token_paused(token_identifier) {
+            return Err(vm_err_msg::DCDT_TOKEN_IS_PAUSED);
+        }
+        if tx_cache.is_dcdt_instance_frozen(to, token_identifier, transfer.nonce) {
+            return Err(vm_err_msg::DCDT_IS_FROZEN_FOR_ACCOUNT);
+        }
+    }
+
+    Ok(())
+}
+
 pub(super) fn adjust_call_type(
     call_type: CallType,
     call: &ParsedTransferBuiltinFunCall,
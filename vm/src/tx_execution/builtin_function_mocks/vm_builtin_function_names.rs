@@ -7,6 +7,11 @@ pub const DCDT_NFT_ADD_QUANTITY_FUNC_NAME: &str = "DCDTNFTAddQuantity";
 pub const DCDT_NFT_ADD_URI_FUNC_NAME: &str = "DCDTNFTAddURI";
 pub const DCDT_NFT_UPDATE_ATTRIBUTES_FUNC_NAME: &str = "DCDTNFTUpdateAttributes";
 pub const DCDT_NFT_BURN_FUNC_NAME: &str = "DCDTNFTBurn";
+pub const DCDT_MODIFY_ROYALTIES_FUNC_NAME: &str = "DCDTModifyRoyalties";
+pub const DCDT_SET_NEW_URIS_FUNC_NAME: &str = "DCDTSetNewURIs";
+pub const DCDT_MODIFY_CREATOR_FUNC_NAME: &str = "DCDTModifyCreator";
+pub const DCDT_METADATA_RECREATE_FUNC_NAME: &str = "DCDTMetaDataRecreate";
+pub const DCDT_METADATA_UPDATE_FUNC_NAME: &str = "DCDTMetaDataUpdate";
 pub const DCDT_TRANSFER_FUNC_NAME: &str = "DCDTTransfer";
 pub const CHANGE_OWNER_BUILTIN_FUNC_NAME: &str = "ChangeOwnerAddress";
 pub const CLAIM_DEVELOPER_REWARDS_FUNC_NAME: &str = "ClaimDeveloperRewards";
@@ -4,7 +4,7 @@ use crate::{
         merge_results, AsyncCallTxData, BlockchainUpdate, CallType, Promise, TxCache, TxContext,
         TxContextStack, TxInput, TxPanic, TxResult, TxResultCalls,
     },
-    types::VMCodeMetadata,
+    types::{VMAddress, VMCodeMetadata},
     with_shared::Shareable,
     world_mock::{AccountData, AccountDcdt, BlockchainState},
 };
@@ -86,8 +86,11 @@ impl BlockchainVMRef {
         F: FnOnce(),
     {
         state.subtract_tx_gas(&tx_input.from, tx_input.gas_limit, tx_input.gas_price);
+        let sender = tx_input.from.clone();
+        let gas_limit = tx_input.gas_limit;
+        let gas_price = tx_input.gas_price;
 
-        let (tx_result, blockchain_updates) = state.with_shared(|state_arc| {
+        let (mut tx_result, blockchain_updates) = state.with_shared(|state_arc| {
             let tx_cache = TxCache::new(state_arc);
             self.execute_builtin_function_or_default(tx_input, tx_cache, f)
         });
@@ -96,34 +99,55 @@ impl BlockchainVMRef {
             blockchain_updates.apply(state);
         }
 
+        self.settle_tx_gas(&sender, gas_limit, gas_price, &mut tx_result, state);
+
         tx_result
     }
 
+    /// When gas metering is enabled, failed transactions consume all their gas,
+    /// and the sender gets refunded for the unused gas.
+    pub(crate) fn settle_tx_gas(
+        &self,
+        sender: &VMAddress,
+        gas_limit: u64,
+        gas_price: u64,
+        tx_result: &mut TxResult,
+        state: &mut BlockchainState,
+    ) {
+        if self.gas_schedule.is_none() {
+            return;
+        }
+
+        if tx_result.result_status != 0 || tx_result.gas_used > gas_limit {
+            tx_result.gas_used = gas_limit;
+        }
+        state.refund_tx_gas(sender, gas_limit - tx_result.gas_used, gas_price);
+    }
+
+    /// Executes a legacy async call, its own pending calls, and then its callback.
+    ///
+    /// The callback gets the results of the async call itself,
+    /// not those of the calls it launched in turn.
     pub fn execute_async_call_and_callback(
         &self,
         async_data: AsyncCallTxData,
         state: &mut Shareable<BlockchainState>,
     ) -> (TxResult, TxResult) {
+        state.load_fork_account_if_missing(&async_data.to);
         if state.accounts.contains_key(&async_data.to) {
             let async_input = async_call_tx_input(&async_data, CallType::AsyncCall);
-
-            let async_result = self.sc_call_with_async_and_callback(
-                async_input,
-                state,
-                execute_current_tx_context_input,
-            );
+            let async_result =
+                self.execute_sc_call_lambda(async_input, state, execute_current_tx_context_input);
 
             let callback_input =
                 async_callback_tx_input(&async_data, &async_result, &self.builtin_functions);
-            let callback_result = self.execute_sc_call_lambda(
+            let async_result = self.execute_pending_calls(async_result, state);
+
+            let callback_result = self.sc_call_with_async_and_callback(
                 callback_input,
                 state,
                 execute_current_tx_context_input,
             );
-            assert!(
-                callback_result.pending_calls.async_call.is_none(),
-                "successive asyncs currently not supported"
-            );
             (async_result, callback_result)
         } else {
             let result = self.insert_ghost_account(&async_data, state);
@@ -137,7 +161,6 @@ impl BlockchainVMRef {
         }
     }
 
-    // TODO: refactor
     pub fn sc_call_with_async_and_callback<F>(
         &self,
         tx_input: TxInput,
@@ -148,33 +171,42 @@ impl BlockchainVMRef {
         F: FnOnce(),
     {
         // main call
-        let mut tx_result = self.execute_sc_call_lambda(tx_input, state, f);
+        let tx_result = self.execute_sc_call_lambda(tx_input, state, f);
 
+        self.execute_pending_calls(tx_result, state)
+    }
+
+    /// Executes all async calls and promises launched by a successful transaction,
+    /// in the order in which they were registered.
+    ///
+    /// Callbacks can launch further calls, these are also executed, recursively.
+    pub fn execute_pending_calls(
+        &self,
+        mut tx_result: TxResult,
+        state: &mut Shareable<BlockchainState>,
+    ) -> TxResult {
         // take & clear pending calls
         let pending_calls = std::mem::replace(&mut tx_result.pending_calls, TxResultCalls::empty());
+        if tx_result.result_status != 0 {
+            return tx_result;
+        }
 
-        // legacy async call
-        // the async call also gets reset
-        if tx_result.result_status == 0 {
-            if let Some(async_data) = pending_calls.async_call {
-                let (async_result, callback_result) =
-                    self.execute_async_call_and_callback(async_data, state);
-
-                tx_result = merge_results(tx_result, async_result);
-                tx_result = merge_results(tx_result, callback_result);
+        // legacy async calls
+        for async_data in pending_calls.async_calls {
+            let (async_result, callback_result) =
+                self.execute_async_call_and_callback(async_data, state);
 
-                return tx_result;
-            }
+            tx_result = merge_results(tx_result, async_result);
+            tx_result = merge_results(tx_result, callback_result);
         }
 
-        // calling all promises
-        // the promises are also reset
+        // promises
         for promise in pending_calls.promises {
             let (async_result, callback_result) =
                 self.execute_promise_call_and_callback(&promise, state);
 
-            tx_result = merge_results(tx_result, async_result.clone());
-            tx_result = merge_results(tx_result, callback_result.clone());
+            tx_result = merge_results(tx_result, async_result);
+            tx_result = merge_results(tx_result, callback_result);
         }
 
         tx_result
@@ -185,14 +217,25 @@ impl BlockchainVMRef {
         promise: &Promise,
         state: &mut Shareable<BlockchainState>,
     ) -> (TxResult, TxResult) {
+        state.load_fork_account_if_missing(&promise.call.to);
         if state.accounts.contains_key(&promise.call.to) {
             let async_input = async_call_tx_input(&promise.call, CallType::AsyncCall);
-            let async_result = self.sc_call_with_async_and_callback(
-                async_input,
-                state,
-                execute_current_tx_context_input,
-            );
-            let callback_result = self.execute_promises_callback(&async_result, promise, state);
+            let async_result =
+                self.execute_sc_call_lambda(async_input, state, execute_current_tx_context_input);
+
+            let callback_input = promise.has_callback().then(|| {
+                async_promise_callback_tx_input(promise, &async_result, &self.builtin_functions)
+            });
+            let async_result = self.execute_pending_calls(async_result, state);
+
+            let callback_result = match callback_input {
+                Some(callback_input) => self.sc_call_with_async_and_callback(
+                    callback_input,
+                    state,
+                    execute_current_tx_context_input,
+                ),
+                None => TxResult::empty(),
+            };
             (async_result, callback_result)
         } else {
             let result = self.insert_ghost_account(&promise.call, state);
@@ -206,26 +249,6 @@ impl BlockchainVMRef {
         }
     }
 
-    fn execute_promises_callback(
-        &self,
-        async_result: &TxResult,
-        promise: &Promise,
-        state: &mut Shareable<BlockchainState>,
-    ) -> TxResult {
-        if !promise.has_callback() {
-            return TxResult::empty();
-        }
-        let callback_input =
-            async_promise_callback_tx_input(promise, async_result, &self.builtin_functions);
-        let callback_result =
-            self.execute_sc_call_lambda(callback_input, state, execute_current_tx_context_input);
-        assert!(
-            callback_result.pending_calls.promises.is_empty(),
-            "successive promises currently not supported"
-        );
-        callback_result
-    }
-
     /// When calling a contract that is unknown to the state, we insert a ghost account.
     fn insert_ghost_account(
         &self,
@@ -23,8 +23,11 @@ impl BlockchainVMRef {
         // must be done after computing the new address
         state.increase_account_nonce(&tx_input.from);
         state.subtract_tx_gas(&tx_input.from, tx_input.gas_limit, tx_input.gas_price);
+        let sender = tx_input.from.clone();
+        let gas_limit = tx_input.gas_limit;
+        let gas_price = tx_input.gas_price;
 
-        let (tx_result, new_address, blockchain_updates) = state.with_shared(|state_arc| {
+        let (mut tx_result, new_address, blockchain_updates) = state.with_shared(|state_arc| {
             let tx_cache = TxCache::new(state_arc);
 
             self.deploy_contract(tx_input, contract_path.to_vec(), code_metadata, tx_cache, f)
@@ -32,6 +35,26 @@ impl BlockchainVMRef {
 
         blockchain_updates.apply(state);
 
+        self.settle_tx_gas(&sender, gas_limit, gas_price, &mut tx_result, state);
+
+        (new_address, tx_result)
+    }
+
+    /// Same as `sc_create`, but also executes the async calls and promises launched by the constructor.
+    pub fn sc_create_with_async_and_callback<F>(
+        &self,
+        tx_input: TxInput,
+        contract_path: &[u8],
+        code_metadata: VMCodeMetadata,
+        state: &mut Shareable<BlockchainState>,
+        f: F,
+    ) -> (VMAddress, TxResult)
+    where
+        F: FnOnce(),
+    {
+        let (new_address, tx_result) =
+            self.sc_create(tx_input, contract_path, code_metadata, state, f);
+        let tx_result = self.execute_pending_calls(tx_result, state);
         (new_address, tx_result)
     }
 }
@@ -0,0 +1,45 @@
+/// Gas costs charged by the debugger VM, when gas metering is enabled.
+///
+/// A simplified version of the Go VM gas schedule: every VM hook call has the same base cost,
+/// storage access is additionally charged per byte.
+///
+/// The default values are taken from the Go VM gas schedule.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GasSchedule {
+    /// Charged for every VM hook call.
+    pub vm_hook_call: u64,
+
+    /// Base cost of a storage read, on top of the VM hook call.
+    pub storage_load: u64,
+
+    /// Base cost of a storage write, on top of the VM hook call.
+    pub storage_store: u64,
+
+    /// Charged for every byte of key and value read from storage.
+    pub data_copy_per_byte: u64,
+
+    /// Charged for every byte of value written to storage.
+    pub persist_per_byte: u64,
+}
+
+impl Default for GasSchedule {
+    fn default() -> Self {
+        GasSchedule {
+            vm_hook_call: 1000,
+            storage_load: 50_000,
+            storage_store: 75_000,
+            data_copy_per_byte: 50,
+            persist_per_byte: 1000,
+        }
+    }
+}
+
+impl GasSchedule {
+    pub fn storage_load_cost(&self, num_bytes: usize) -> u64 {
+        self.storage_load + self.data_copy_per_byte * num_bytes as u64
+    }
+
+    pub fn storage_store_cost(&self, num_bytes: usize) -> u64 {
+        self.storage_store + self.persist_per_byte * num_bytes as u64
+    }
+}
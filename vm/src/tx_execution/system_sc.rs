@@ -1,15 +1,22 @@
+mod system_sc_common;
+mod system_sc_freeze_wipe;
 mod system_sc_issue;
+mod system_sc_mint_burn;
+mod system_sc_pause;
 mod system_sc_special_roles;
-mod system_sc_unimplemented;
+mod system_sc_token_management;
 
 use crate::{
     tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult},
     types::VMAddress,
 };
 use hex_literal::hex;
+use system_sc_freeze_wipe::*;
 use system_sc_issue::*;
+use system_sc_mint_burn::*;
+use system_sc_pause::*;
 use system_sc_special_roles::*;
-use system_sc_unimplemented::*;
+use system_sc_token_management::*;
 
 /// Address of the system smart contract that manages DCDT.
 /// Bech32: drt1yvesqqqqqqqqqqqqqqqqqqqqqqqqyvesqqqqqqqqqqqqqqqzlllsd5j0s2
@@ -0,0 +1,140 @@
+use num_bigint::BigUint;
+
+use crate::{
+    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult},
+    types::{top_decode_u64, VMAddress, VMTokenType},
+    world_mock::{DcdtTokenData, DcdtTokenProperties},
+};
+
+pub(super) const ERR_OWNER_ONLY: &str = "can be called by owner only";
+
+/// Gives mutable access to the token-level data of a token.
+///
+/// Tokens that were not issued through the system SC, e.g. tokens only set up in the scenario state,
+/// are registered on first use by any system SC function, see [`unregistered_token_data`].
+/// The registration is only kept if the call succeeds.
+pub(super) fn with_token_data_or_register<R, F>(
+    tx_cache: &TxCache,
+    tx_input: &TxInput,
+    token_identifier: &[u8],
+    f: F,
+) -> Result<R, &'static str>
+where
+    F: FnOnce(&mut DcdtTokenData) -> Result<R, &'static str>,
+{
+    if tx_cache
+        .with_dcdt_token_data(token_identifier, |_| ())
+        .is_none()
+    {
+        let token_data = unregistered_token_data(tx_cache, tx_input, token_identifier);
+        tx_cache.insert_dcdt_token_data(token_identifier.to_vec(), token_data);
+    }
+
+    tx_cache
+        .with_dcdt_token_data_mut(token_identifier, f)
+        .expect("token data registered above")
+}
+
+/// The token-level data assumed for a token the system SC has no record of.
+///
+/// - the caller becomes the owner;
+/// - all properties are enabled;
+/// - the type is deduced from the existing balances: fungible if there are no NFT instances,
+///   semi-fungible if any instance has a balance greater than 1, non-fungible otherwise;
+/// - the name and ticker are both the ticker part of the identifier, with no decimals.
+fn unregistered_token_data(
+    tx_cache: &TxCache,
+    tx_input: &TxInput,
+    token_identifier: &[u8],
+) -> DcdtTokenData {
+    let mut has_instances = false;
+    let mut has_semi_fungible_instances = false;
+    for address in tx_cache.account_addresses() {
+        tx_cache.with_account(&address, |account| {
+            if let Some(dcdt_data) = account.dcdt.get_by_identifier(token_identifier) {
+                for (nonce, instance) in dcdt_data.instances.get_instances() {
+                    if *nonce > 0 {
+                        has_instances = true;
+                        has_semi_fungible_instances |= instance.balance > BigUint::from(1u32);
+                    }
+                }
+                has_instances |= dcdt_data.last_nonce > 0;
+            }
+        });
+    }
+    let token_type = if has_semi_fungible_instances {
+        VMTokenType::SemiFungible
+    } else if has_instances {
+        VMTokenType::NonFungible
+    } else {
+        VMTokenType::Fungible
+    };
+
+    let ticker = token_identifier
+        .split(|c| *c == b'-')
+        .next()
+        .unwrap_or_default()
+        .to_vec();
+    let mut token_data =
+        DcdtTokenData::new(tx_input.from.clone(), ticker.clone(), ticker, token_type, 0);
+    token_data.properties = DcdtTokenProperties::all_enabled();
+    token_data
+}
+
+pub(super) fn check_token_owner(
+    token_data: &DcdtTokenData,
+    tx_input: &TxInput,
+) -> Result<(), &'static str> {
+    if token_data.owner != tx_input.from {
+        return Err(ERR_OWNER_ONLY);
+    }
+    Ok(())
+}
+
+pub(super) fn check_num_args(tx_input: &TxInput, expected: usize) -> Result<(), &'static str> {
+    if tx_input.args.len() != expected {
+        return Err("invalid number of arguments");
+    }
+    Ok(())
+}
+
+pub(super) fn check_min_num_args(tx_input: &TxInput, min: usize) -> Result<(), &'static str> {
+    if tx_input.args.len() < min {
+        return Err("not enough arguments");
+    }
+    Ok(())
+}
+
+pub(super) fn address_from_arg(arg: &[u8]) -> Result<VMAddress, &'static str> {
+    if arg.len() != 32 {
+        return Err("invalid address");
+    }
+    Ok(VMAddress::from_slice(arg))
+}
+
+pub(super) fn nonce_from_arg(arg: &[u8]) -> Result<u64, &'static str> {
+    let nonce = top_decode_u64(arg);
+    if nonce == 0 {
+        return Err("invalid nonce");
+    }
+    Ok(nonce)
+}
+
+/// Converts the outcome of a system SC function into the result expected by the executor.
+///
+/// On error, all changes are discarded.
+pub(super) fn into_system_sc_result(
+    result: Result<Vec<Vec<u8>>, &'static str>,
+    tx_cache: TxCache,
+) -> (TxResult, BlockchainUpdate) {
+    match result {
+        Ok(result_values) => {
+            let tx_result = TxResult {
+                result_values,
+                ..Default::default()
+            };
+            (tx_result, tx_cache.into_blockchain_updates())
+        },
+        Err(message) => (TxResult::from_vm_error(message), BlockchainUpdate::empty()),
+    }
+}
@@ -0,0 +1,143 @@
+use crate::tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult};
+
+use super::system_sc_common::{
+    address_from_arg, check_num_args, check_token_owner, into_system_sc_result, nonce_from_arg,
+    with_token_data_or_register,
+};
+
+const ERR_CANNOT_FREEZE: &str = "cannot freeze";
+const ERR_CANNOT_WIPE: &str = "cannot wipe";
+const ERR_NOT_FROZEN: &str = "cannot wipe because the account is not frozen for this dcdt token";
+const ERR_FUNGIBLE_PER_NONCE: &str = "only non fungible tokens can be frozen per nonce";
+
+/// Freezes the entire balance of a token, for one account.
+pub fn freeze(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    let result = toggle_freeze(&tx_input, &tx_cache, true);
+    into_system_sc_result(result, tx_cache)
+}
+
+/// Unfreezes the entire balance of a token, for one account.
+pub fn unfreeze(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    let result = toggle_freeze(&tx_input, &tx_cache, false);
+    into_system_sc_result(result, tx_cache)
+}
+
+/// Freezes a single NFT/SFT/META instance, for one account.
+pub fn freeze_single_nft(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    let result = toggle_freeze_single_nft(&tx_input, &tx_cache, true);
+    into_system_sc_result(result, tx_cache)
+}
+
+/// Unfreezes a single NFT/SFT/META instance, for one account.
+pub fn unfreeze_single_nft(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    let result = toggle_freeze_single_nft(&tx_input, &tx_cache, false);
+    into_system_sc_result(result, tx_cache)
+}
+
+/// Clears the fungible balance of a frozen account.
+pub fn wipe(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    let result = wipe_instance(&tx_input, &tx_cache, false);
+    into_system_sc_result(result, tx_cache)
+}
+
+/// Clears the balance of a frozen NFT/SFT/META instance, for one account.
+pub fn wipe_single_nft(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    let result = wipe_instance(&tx_input, &tx_cache, true);
+    into_system_sc_result(result, tx_cache)
+}
+
+fn toggle_freeze(
+    tx_input: &TxInput,
+    tx_cache: &TxCache,
+    frozen: bool,
+) -> Result<Vec<Vec<u8>>, &'static str> {
+    check_num_args(tx_input, 2)?;
+    let token_identifier = tx_input.args[0].as_slice();
+    let address = address_from_arg(&tx_input.args[1])?;
+
+    with_token_data_or_register(tx_cache, tx_input, token_identifier, |token_data| {
+        check_token_owner(token_data, tx_input)?;
+        if !token_data.properties.can_freeze {
+            return Err(ERR_CANNOT_FREEZE);
+        }
+        Ok(())
+    })?;
+
+    tx_cache.with_account_mut(&address, |account| {
+        account.dcdt.set_frozen(token_identifier, frozen);
+    });
+
+    Ok(Vec::new())
+}
+
+fn toggle_freeze_single_nft(
+    tx_input: &TxInput,
+    tx_cache: &TxCache,
+    frozen: bool,
+) -> Result<Vec<Vec<u8>>, &'static str> {
+    check_num_args(tx_input, 3)?;
+    let token_identifier = tx_input.args[0].as_slice();
+    let nonce = nonce_from_arg(&tx_input.args[1])?;
+    let address = address_from_arg(&tx_input.args[2])?;
+
+    with_token_data_or_register(tx_cache, tx_input, token_identifier, |token_data| {
+        check_token_owner(token_data, tx_input)?;
+        if !token_data.properties.can_freeze {
+            return Err(ERR_CANNOT_FREEZE);
+        }
+        if token_data.is_fungible() {
+            return Err(ERR_FUNGIBLE_PER_NONCE);
+        }
+        Ok(())
+    })?;
+
+    tx_cache.with_account_mut(&address, |account| {
+        account
+            .dcdt
+            .set_instance_frozen(token_identifier, nonce, frozen);
+    });
+
+    Ok(Vec::new())
+}
+
+fn wipe_instance(
+    tx_input: &TxInput,
+    tx_cache: &TxCache,
+    single_nft: bool,
+) -> Result<Vec<Vec<u8>>, &'static str> {
+    let (nonce, address) = if single_nft {
+        check_num_args(tx_input, 3)?;
+        (
+            nonce_from_arg(&tx_input.args[1])?,
+            address_from_arg(&tx_input.args[2])?,
+        )
+    } else {
+        check_num_args(tx_input, 2)?;
+        (0, address_from_arg(&tx_input.args[1])?)
+    };
+    let token_identifier = tx_input.args[0].as_slice();
+
+    with_token_data_or_register(tx_cache, tx_input, token_identifier, |token_data| {
+        check_token_owner(token_data, tx_input)?;
+        if !token_data.properties.can_wipe {
+            return Err(ERR_CANNOT_WIPE);
+        }
+        if single_nft == token_data.is_fungible() {
+            return Err(ERR_CANNOT_WIPE);
+        }
+        Ok(())
+    })?;
+
+    let wiped = tx_cache.with_account_mut(&address, |account| {
+        if !account.dcdt.is_instance_frozen(token_identifier, nonce) {
+            return Err(ERR_NOT_FROZEN);
+        }
+        Ok(account.dcdt.wipe(token_identifier, nonce))
+    })?;
+
+    with_token_data_or_register(tx_cache, tx_input, token_identifier, |token_data| {
+        token_data.burnt_value += wiped;
+        token_data.num_wiped += 1;
+        Ok(Vec::new())
+    })
+}
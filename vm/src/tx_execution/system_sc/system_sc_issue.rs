@@ -4,10 +4,14 @@ use crate::{
     crypto_functions::keccak256,
     tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult},
     types::{top_decode_u64, VMTokenType},
+    world_mock::{DcdtTokenData, DcdtTokenProperties},
+};
+
+use super::system_sc_common::{
+    check_token_owner, into_system_sc_result, with_token_data_or_register,
 };
 
 /// Issues a new fungible token.
-#[allow(unused_variables)]
 pub fn issue(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
     if tx_input.args.len() < 4 {
         let tx_result = TxResult::from_vm_error("not enough arguments");
@@ -18,12 +22,36 @@ pub fn issue(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdat
     let total_supply = BigUint::from_bytes_be(tx_input.args[2].clone().as_ref());
     let decimals = top_decode_u64(tx_input.args[3].clone().as_ref()) as u32;
 
-    register_and_set_roles(tx_input, tx_cache, ticker, VMTokenType::Fungible)
+    let mut token_data = DcdtTokenData::new(
+        tx_input.from.clone(),
+        name,
+        ticker,
+        VMTokenType::Fungible,
+        decimals,
+    );
+    token_data.minted_value = total_supply;
+    if let Err(message) = token_data.properties.apply_arguments(&tx_input.args[4..]) {
+        return (TxResult::from_vm_error(message), BlockchainUpdate::empty());
+    }
+
+    register_and_set_roles(tx_input, tx_cache, token_data)
 }
 
 /// Issues a new semi-fungible token.
-#[allow(unused_variables)]
 pub fn issue_semi_fungible(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    issue_non_fungible_of_type(tx_input, tx_cache, VMTokenType::SemiFungible)
+}
+
+/// Issues a new non-fungible token.
+pub fn issue_non_fungible(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    issue_non_fungible_of_type(tx_input, tx_cache, VMTokenType::NonFungible)
+}
+
+fn issue_non_fungible_of_type(
+    tx_input: TxInput,
+    tx_cache: TxCache,
+    token_type: VMTokenType,
+) -> (TxResult, BlockchainUpdate) {
     if tx_input.args.len() < 2 {
         let tx_result = TxResult::from_vm_error("not enough arguments");
         return (tx_result, BlockchainUpdate::empty());
@@ -31,24 +59,39 @@ pub fn issue_semi_fungible(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, B
     let name = tx_input.args[0].clone();
     let ticker = tx_input.args[1].clone();
 
-    register_and_set_roles(tx_input, tx_cache, ticker, VMTokenType::SemiFungible)
+    let mut token_data = DcdtTokenData::new(tx_input.from.clone(), name, ticker, token_type, 0);
+    if let Err(message) = token_data.properties.apply_arguments(&tx_input.args[2..]) {
+        return (TxResult::from_vm_error(message), BlockchainUpdate::empty());
+    }
+
+    register_and_set_roles(tx_input, tx_cache, token_data)
 }
 
-/// Issues a new non-fungible token.
-#[allow(unused_variables)]
-pub fn issue_non_fungible(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
-    if tx_input.args.len() < 2 {
+/// Issues a new meta token.
+pub fn register_meta_dcdt(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    if tx_input.args.len() < 3 {
         let tx_result = TxResult::from_vm_error("not enough arguments");
         return (tx_result, BlockchainUpdate::empty());
     }
     let name = tx_input.args[0].clone();
     let ticker = tx_input.args[1].clone();
+    let decimals = top_decode_u64(tx_input.args[2].clone().as_ref()) as u32;
+
+    let mut token_data = DcdtTokenData::new(
+        tx_input.from.clone(),
+        name,
+        ticker,
+        VMTokenType::Meta,
+        decimals,
+    );
+    if let Err(message) = token_data.properties.apply_arguments(&tx_input.args[3..]) {
+        return (TxResult::from_vm_error(message), BlockchainUpdate::empty());
+    }
 
-    register_and_set_roles(tx_input, tx_cache, ticker, VMTokenType::NonFungible)
+    register_and_set_roles(tx_input, tx_cache, token_data)
 }
 
 // Issues a new token and sets all roles for its type.
-#[allow(unused_variables)]
 pub fn register_and_set_all_roles(
     tx_input: TxInput,
     tx_cache: TxCache,
@@ -63,32 +106,65 @@ pub fn register_and_set_all_roles(
     let token_type = VMTokenType::from_system_sc_arg(&tx_input.args[2]);
     let decimals = top_decode_u64(tx_input.args[3].clone().as_ref()) as u32;
 
-    register_and_set_roles(tx_input, tx_cache, ticker, token_type)
+    let mut token_data =
+        DcdtTokenData::new(tx_input.from.clone(), name, ticker, token_type, decimals);
+    token_data.properties = DcdtTokenProperties::all_roles_defaults();
+
+    register_and_set_roles(tx_input, tx_cache, token_data)
+}
+
+/// Converts a semi-fungible token into a meta token, with the given number of decimals.
+pub fn change_sft_to_meta_dcdt(
+    tx_input: TxInput,
+    tx_cache: TxCache,
+) -> (TxResult, BlockchainUpdate) {
+    if tx_input.args.len() != 2 {
+        let tx_result = TxResult::from_vm_error("invalid number of arguments");
+        return (tx_result, BlockchainUpdate::empty());
+    }
+
+    let token_identifier = tx_input.args[0].as_slice();
+    let decimals = top_decode_u64(tx_input.args[1].as_slice()) as u32;
+
+    let result =
+        with_token_data_or_register(&tx_cache, &tx_input, token_identifier, |token_data| {
+            check_token_owner(token_data, &tx_input)?;
+            if token_data.token_type != VMTokenType::SemiFungible {
+                return Err("change can happen to semi fungible tokens only");
+            }
+
+            token_data.token_type = VMTokenType::Meta;
+            token_data.num_decimals = decimals;
+            Ok(Vec::new())
+        });
+
+    into_system_sc_result(result, tx_cache)
 }
 
 fn register_and_set_roles(
     tx_input: TxInput,
     tx_cache: TxCache,
-    ticker: Vec<u8>,
-    token_type: VMTokenType,
+    token_data: DcdtTokenData,
 ) -> (TxResult, BlockchainUpdate) {
     let mut new_token_identifiers = tx_cache.get_new_token_identifiers();
 
     let token_identifier = if let Some((i, ti)) =
-        first_token_identifier_with_ticker(&new_token_identifiers, &ticker)
+        first_token_identifier_with_ticker(&new_token_identifiers, &token_data.ticker)
     {
         new_token_identifiers.remove(i);
         ti.into_bytes()
     } else {
-        generate_token_identifier_from_ticker(&tx_input, &tx_cache, &ticker)
+        generate_token_identifier_from_ticker(&tx_input, &tx_cache, &token_data.ticker)
     };
 
+    let token_type = token_data.token_type;
     tx_cache.with_account_mut(&tx_input.from, |account| {
         account
             .dcdt
             .register_and_set_roles(&token_identifier, token_type);
     });
     tx_cache.set_new_token_identifiers(new_token_identifiers);
+    tx_cache.insert_dcdt_token_data(token_identifier.clone(), token_data);
 
     let tx_result = TxResult {
         result_values: vec![token_identifier],
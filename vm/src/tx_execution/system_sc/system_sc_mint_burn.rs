@@ -0,0 +1,91 @@
+use num_bigint::BigUint;
+use num_traits::Zero;
+
+use crate::{
+    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult},
+    world_mock::DcdtInstanceMetadata,
+};
+
+use super::system_sc_common::{
+    address_from_arg, check_min_num_args, check_num_args, check_token_owner, into_system_sc_result,
+    with_token_data_or_register,
+};
+
+/// Mints new fungible tokens. They go to the owner, unless a destination is given.
+pub fn mint(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    let result = try_mint(&tx_input, &tx_cache);
+    into_system_sc_result(result, tx_cache)
+}
+
+/// Burns fungible tokens, which were sent to the system SC.
+pub fn dcdt_burn(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    let result = try_burn(&tx_input, &tx_cache);
+    into_system_sc_result(result, tx_cache)
+}
+
+fn try_mint(tx_input: &TxInput, tx_cache: &TxCache) -> Result<Vec<Vec<u8>>, &'static str> {
+    check_min_num_args(tx_input, 2)?;
+    if tx_input.args.len() > 3 {
+        return Err("invalid number of arguments");
+    }
+
+    let token_identifier = tx_input.args[0].as_slice();
+    let value = BigUint::from_bytes_be(tx_input.args[1].as_slice());
+    if value.is_zero() {
+        return Err("negative or zero mint value");
+    }
+    let destination = if tx_input.args.len() == 3 {
+        address_from_arg(&tx_input.args[2])?
+    } else {
+        tx_input.from.clone()
+    };
+
+    with_token_data_or_register(tx_cache, tx_input, token_identifier, |token_data| {
+        check_token_owner(token_data, tx_input)?;
+        if !token_data.properties.can_mint {
+            return Err("token is not mintable");
+        }
+        if !token_data.is_fungible() {
+            return Err("mint is possible only for fungible tokens");
+        }
+
+        token_data.minted_value += &value;
+        Ok(())
+    })?;
+
+    tx_cache.increase_dcdt_balance(
+        &destination,
+        token_identifier,
+        0,
+        &value,
+        DcdtInstanceMetadata::default(),
+    );
+
+    Ok(Vec::new())
+}
+
+fn try_burn(tx_input: &TxInput, tx_cache: &TxCache) -> Result<Vec<Vec<u8>>, &'static str> {
+    check_num_args(tx_input, 2)?;
+
+    let token_identifier = tx_input.args[0].as_slice();
+    let value = BigUint::from_bytes_be(tx_input.args[1].as_slice());
+    if value.is_zero() {
+        return Err("negative or zero value");
+    }
+
+    with_token_data_or_register(tx_cache, tx_input, token_identifier, |token_data| {
+        if !token_data.properties.can_burn && !token_data.burn_role_globally {
+            return Err("token is not burnable");
+        }
+
+        token_data.burnt_value += &value;
+        Ok(())
+    })?;
+
+    // the transfer to the system SC leaves the balance in place, it is only removed here
+    tx_cache
+        .subtract_dcdt_balance(&tx_input.from, token_identifier, 0, &value)
+        .map_err(|_| "insufficient funds")?;
+
+    Ok(Vec::new())
+}
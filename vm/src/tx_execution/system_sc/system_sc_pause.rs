@@ -0,0 +1,43 @@
+use crate::tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult};
+
+use super::system_sc_common::{
+    check_num_args, check_token_owner, into_system_sc_result, with_token_data_or_register,
+};
+
+/// Pauses all transfers of a token.
+pub fn pause(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    let result = toggle_pause(&tx_input, &tx_cache, true);
+    into_system_sc_result(result, tx_cache)
+}
+
+/// Resumes all transfers of a token.
+pub fn unpause(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    let result = toggle_pause(&tx_input, &tx_cache, false);
+    into_system_sc_result(result, tx_cache)
+}
+
+fn toggle_pause(
+    tx_input: &TxInput,
+    tx_cache: &TxCache,
+    paused: bool,
+) -> Result<Vec<Vec<u8>>, &'static str> {
+    check_num_args(tx_input, 1)?;
+    let token_identifier = tx_input.args[0].as_slice();
+
+    with_token_data_or_register(tx_cache, tx_input, token_identifier, |token_data| {
+        check_token_owner(token_data, tx_input)?;
+        if !token_data.properties.can_pause {
+            return Err("cannot pause/un-pause");
+        }
+        if tx_cache.is_dcdt_token_paused(token_identifier) == paused {
+            return Err(if paused {
+                "cannot pause an already paused contract"
+            } else {
+                "cannot unPause an already un-paused contract"
+            });
+        }
+
+        tx_cache.set_dcdt_token_paused(token_identifier, paused);
+        Ok(Vec::new())
+    })
+}
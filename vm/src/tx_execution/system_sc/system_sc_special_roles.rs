@@ -1,6 +1,13 @@
 use crate::{
+    display_util::address_hex,
     tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult},
-    types::VMAddress,
+    types::{DcdtLocalRole, VMAddress},
+    world_mock::DcdtTokenData,
+};
+
+use super::system_sc_common::{
+    address_from_arg, check_min_num_args, check_num_args, check_token_owner, into_system_sc_result,
+    with_token_data_or_register,
 };
 
 pub fn set_special_role(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
@@ -21,3 +28,243 @@ pub fn set_special_role(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, Bloc
 
     (TxResult::empty(), tx_cache.into_blockchain_updates())
 }
+
+/// Removes roles from an address. Only the token owner can do it.
+pub fn unset_special_role(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    let result = try_unset_special_role(&tx_input, &tx_cache);
+    into_system_sc_result(result, tx_cache)
+}
+
+/// View function, lists all addresses with roles, in the `address:Role1,Role2` format.
+///
+/// Addresses are hex-encoded, the Rust VM does not perform bech32 encoding.
+pub fn get_special_roles(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    let result = check_num_args(&tx_input, 1).map(|()| {
+        let token_identifier = tx_input.args[0].as_slice();
+        addresses_with_roles(&tx_cache, token_identifier)
+            .into_iter()
+            .map(|(address, roles)| {
+                let role_names: Vec<String> = roles
+                    .iter()
+                    .map(|role| String::from_utf8_lossy(role).into_owned())
+                    .collect();
+                format!("{}:{}", address_hex(&address), role_names.join(",")).into_bytes()
+            })
+            .collect()
+    });
+    into_system_sc_result(result, tx_cache)
+}
+
+/// View function, lists all addresses with roles, each followed by its role names.
+pub fn get_all_addresses_and_roles(
+    tx_input: TxInput,
+    tx_cache: TxCache,
+) -> (TxResult, BlockchainUpdate) {
+    let result = check_num_args(&tx_input, 1).map(|()| {
+        let token_identifier = tx_input.args[0].as_slice();
+        let mut result_values = Vec::new();
+        for (address, mut roles) in addresses_with_roles(&tx_cache, token_identifier) {
+            result_values.push(address.to_vec());
+            result_values.append(&mut roles);
+        }
+        result_values
+    });
+    into_system_sc_result(result, tx_cache)
+}
+
+/// Moves the NFT create role from one address to another.
+pub fn transfer_nft_create_role(
+    tx_input: TxInput,
+    tx_cache: TxCache,
+) -> (TxResult, BlockchainUpdate) {
+    let result = try_transfer_nft_create_role(&tx_input, &tx_cache);
+    into_system_sc_result(result, tx_cache)
+}
+
+/// Permanently disables NFT creation, the NFT create role is removed from all addresses.
+pub fn stop_nft_create(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    let result = try_stop_nft_create(&tx_input, &tx_cache);
+    into_system_sc_result(result, tx_cache)
+}
+
+/// Allows the NFT create role to be set on several addresses at once.
+pub fn change_to_multi_shard_create(
+    tx_input: TxInput,
+    tx_cache: TxCache,
+) -> (TxResult, BlockchainUpdate) {
+    let result = update_token_flag(&tx_input, &tx_cache, |token_data| {
+        if token_data.properties.can_create_multi_shard {
+            return Err("it is already multi shard create");
+        }
+        token_data.properties.can_create_multi_shard = true;
+        Ok(())
+    });
+    into_system_sc_result(result, tx_cache)
+}
+
+/// Allows anybody to burn the token.
+pub fn set_burn_role_globally(
+    tx_input: TxInput,
+    tx_cache: TxCache,
+) -> (TxResult, BlockchainUpdate) {
+    let result = update_token_flag(&tx_input, &tx_cache, |token_data| {
+        token_data.burn_role_globally = true;
+        Ok(())
+    });
+    into_system_sc_result(result, tx_cache)
+}
+
+/// Reverts `setBurnRoleGlobally`.
+pub fn unset_burn_role_globally(
+    tx_input: TxInput,
+    tx_cache: TxCache,
+) -> (TxResult, BlockchainUpdate) {
+    let result = update_token_flag(&tx_input, &tx_cache, |token_data| {
+        token_data.burn_role_globally = false;
+        Ok(())
+    });
+    into_system_sc_result(result, tx_cache)
+}
+
+/// Only relevant for cross-shard propagation, so it does nothing in the Rust VM, apart from the checks.
+pub fn send_all_transfer_role_addresses(
+    tx_input: TxInput,
+    tx_cache: TxCache,
+) -> (TxResult, BlockchainUpdate) {
+    let result = update_token_flag(&tx_input, &tx_cache, |_| Ok(()));
+    into_system_sc_result(result, tx_cache)
+}
+
+fn try_unset_special_role(
+    tx_input: &TxInput,
+    tx_cache: &TxCache,
+) -> Result<Vec<Vec<u8>>, &'static str> {
+    check_min_num_args(tx_input, 3)?;
+    let token_identifier = tx_input.args[0].as_slice();
+    let address = address_from_arg(&tx_input.args[1])?;
+
+    with_token_data_or_register(tx_cache, tx_input, token_identifier, |token_data| {
+        check_token_owner(token_data, tx_input)
+    })?;
+
+    tx_cache.with_account_mut(&address, |account| {
+        for role in &tx_input.args[2..] {
+            account.dcdt.unset_special_role(token_identifier, role);
+        }
+    });
+
+    Ok(Vec::new())
+}
+
+fn try_transfer_nft_create_role(
+    tx_input: &TxInput,
+    tx_cache: &TxCache,
+) -> Result<Vec<Vec<u8>>, &'static str> {
+    check_num_args(tx_input, 3)?;
+    let token_identifier = tx_input.args[0].as_slice();
+    let old_creator = address_from_arg(&tx_input.args[1])?;
+    let new_creator = address_from_arg(&tx_input.args[2])?;
+
+    with_token_data_or_register(tx_cache, tx_input, token_identifier, |token_data| {
+        check_token_owner(token_data, tx_input)?;
+        if !token_data.properties.can_transfer_nft_create_role {
+            return Err("cannot transfer NFT create role");
+        }
+        Ok(())
+    })?;
+
+    let nft_create_role = DcdtLocalRole::NftCreate.name().as_bytes();
+    let had_role = tx_cache.with_account_mut(&old_creator, |account| {
+        let had_role = account
+            .dcdt
+            .get_roles(token_identifier)
+            .iter()
+            .any(|role| role.as_slice() == nft_create_role);
+        account
+            .dcdt
+            .unset_special_role(token_identifier, nft_create_role);
+        had_role
+    });
+    if !had_role {
+        return Err("address does not have the NFT create role");
+    }
+
+    tx_cache.with_account_mut(&new_creator, |account| {
+        account
+            .dcdt
+            .set_special_role(token_identifier, nft_create_role);
+    });
+
+    Ok(Vec::new())
+}
+
+fn try_stop_nft_create(
+    tx_input: &TxInput,
+    tx_cache: &TxCache,
+) -> Result<Vec<Vec<u8>>, &'static str> {
+    update_token_flag(tx_input, tx_cache, |token_data| {
+        if token_data.is_fungible() {
+            return Err("cannot stop NFT create for fungible tokens");
+        }
+        if token_data.nft_create_stopped {
+            return Err("NFT create was already stopped");
+        }
+        token_data.nft_create_stopped = true;
+        Ok(())
+    })?;
+
+    let token_identifier = tx_input.args[0].as_slice();
+    let nft_create_role = DcdtLocalRole::NftCreate.name().as_bytes();
+    for (address, _) in addresses_with_roles(tx_cache, token_identifier) {
+        tx_cache.with_account_mut(&address, |account| {
+            account
+                .dcdt
+                .unset_special_role(token_identifier, nft_create_role);
+        });
+    }
+
+    Ok(Vec::new())
+}
+
+/// Common logic for owner-only functions that only take the token identifier as argument.
+fn update_token_flag<F>(
+    tx_input: &TxInput,
+    tx_cache: &TxCache,
+    f: F,
+) -> Result<Vec<Vec<u8>>, &'static str>
+where
+    F: FnOnce(&mut DcdtTokenData) -> Result<(), &'static str>,
+{
+    check_num_args(tx_input, 1)?;
+    let token_identifier = tx_input.args[0].as_slice();
+
+    with_token_data_or_register(tx_cache, tx_input, token_identifier, |token_data| {
+        check_token_owner(token_data, tx_input)?;
+        f(token_data)?;
+        Ok(Vec::new())
+    })
+}
+
+/// All addresses that hold at least one role for the given token, sorted.
+///
+/// Also includes the accounts created or changed in the current transaction and its parents.
+fn addresses_with_roles(
+    tx_cache: &TxCache,
+    token_identifier: &[u8],
+) -> Vec<(VMAddress, Vec<Vec<u8>>)> {
+    let mut addresses = tx_cache.account_addresses();
+    addresses.sort_by(|a, b| a.as_bytes().cmp(b.as_bytes()));
+
+    addresses
+        .into_iter()
+        .filter_map(|address| {
+            let roles =
+                tx_cache.with_account(&address, |account| account.dcdt.get_roles(token_identifier));
+            if roles.is_empty() {
+                None
+            } else {
+                Some((address, roles))
+            }
+        })
+        .collect()
+}
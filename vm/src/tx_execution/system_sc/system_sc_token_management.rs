@@ -0,0 +1,136 @@
+use crate::{
+    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult},
+    types::{top_encode_u64, VMAddress},
+};
+
+use super::system_sc_common::{
+    address_from_arg, check_min_num_args, check_num_args, check_token_owner, into_system_sc_result,
+    with_token_data_or_register,
+};
+
+/// Same as the default in the node config: 0.05 REWA.
+const BASE_ISSUING_COST: u64 = 50_000_000_000_000_000;
+const MIN_TOKEN_NAME_LENGTH: u64 = 3;
+const MAX_TOKEN_NAME_LENGTH: u64 = 20;
+
+const ERR_SYSTEM_SC_OWNER_ONLY: &str = "only system SC owner can call this function";
+
+/// Hands over token management to a new owner.
+pub fn transfer_ownership(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    let result = try_transfer_ownership(&tx_input, &tx_cache);
+    into_system_sc_result(result, tx_cache)
+}
+
+/// Upgrades the token properties.
+pub fn control_changes(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    let result = try_control_changes(&tx_input, &tx_cache);
+    into_system_sc_result(result, tx_cache)
+}
+
+/// View function, lists all token-level data.
+pub fn get_token_properties(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    let result = try_get_token_properties(&tx_input, &tx_cache);
+    into_system_sc_result(result, tx_cache)
+}
+
+/// View function, lists the system SC configuration.
+///
+/// There is no system SC owner in the Rust VM, the zero address is returned instead.
+pub fn get_contract_config(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    let result = check_num_args(&tx_input, 0).map(|()| {
+        vec![
+            VMAddress::zero().to_vec(),
+            top_encode_u64(BASE_ISSUING_COST),
+            top_encode_u64(MIN_TOKEN_NAME_LENGTH),
+            top_encode_u64(MAX_TOKEN_NAME_LENGTH),
+        ]
+    });
+    into_system_sc_result(result, tx_cache)
+}
+
+/// Reserved for the system SC owner, which the Rust VM does not model.
+pub fn claim(_tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    into_system_sc_result(Err(ERR_SYSTEM_SC_OWNER_ONLY), tx_cache)
+}
+
+/// Reserved for the system SC owner, which the Rust VM does not model.
+pub fn config_change(_tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    into_system_sc_result(Err(ERR_SYSTEM_SC_OWNER_ONLY), tx_cache)
+}
+
+fn try_transfer_ownership(
+    tx_input: &TxInput,
+    tx_cache: &TxCache,
+) -> Result<Vec<Vec<u8>>, &'static str> {
+    check_num_args(tx_input, 2)?;
+    let token_identifier = tx_input.args[0].as_slice();
+    let new_owner = address_from_arg(&tx_input.args[1])?;
+
+    with_token_data_or_register(tx_cache, tx_input, token_identifier, |token_data| {
+        check_token_owner(token_data, tx_input)?;
+        if !token_data.properties.can_change_owner {
+            return Err("cannot change owner of the token");
+        }
+
+        token_data.owner = new_owner;
+        Ok(Vec::new())
+    })
+}
+
+fn try_control_changes(
+    tx_input: &TxInput,
+    tx_cache: &TxCache,
+) -> Result<Vec<Vec<u8>>, &'static str> {
+    check_min_num_args(tx_input, 1)?;
+    let token_identifier = tx_input.args[0].as_slice();
+
+    with_token_data_or_register(tx_cache, tx_input, token_identifier, |token_data| {
+        check_token_owner(token_data, tx_input)?;
+        if !token_data.properties.can_upgrade {
+            return Err("token is not upgradable");
+        }
+
+        token_data.properties.apply_arguments(&tx_input.args[1..])?;
+        Ok(Vec::new())
+    })
+}
+
+fn try_get_token_properties(
+    tx_input: &TxInput,
+    tx_cache: &TxCache,
+) -> Result<Vec<Vec<u8>>, &'static str> {
+    check_num_args(tx_input, 1)?;
+    let token_identifier = tx_input.args[0].as_slice();
+
+    let paused = tx_cache.is_dcdt_token_paused(token_identifier);
+    with_token_data_or_register(tx_cache, tx_input, token_identifier, |token_data| {
+        let properties = &token_data.properties;
+        Ok(vec![
+            token_data.name.clone(),
+            token_data.token_type_name().as_bytes().to_vec(),
+            token_data.owner.to_vec(),
+            token_data.minted_value.to_string().into_bytes(),
+            token_data.burnt_value.to_string().into_bytes(),
+            format!("NumDecimals-{}", token_data.num_decimals).into_bytes(),
+            bool_property("IsPaused", paused),
+            bool_property("CanUpgrade", properties.can_upgrade),
+            bool_property("CanMint", properties.can_mint),
+            bool_property("CanBurn", properties.can_burn),
+            bool_property("CanChangeOwner", properties.can_change_owner),
+            bool_property("CanPause", properties.can_pause),
+            bool_property("CanFreeze", properties.can_freeze),
+            bool_property("CanWipe", properties.can_wipe),
+            bool_property("CanAddSpecialRoles", properties.can_add_special_roles),
+            bool_property(
+                "CanTransferNFTCreateRole",
+                properties.can_transfer_nft_create_role,
+            ),
+            bool_property("NFTCreateStopped", token_data.nft_create_stopped),
+            format!("NumWiped-{}", token_data.num_wiped).into_bytes(),
+        ])
+    })
+}
+
+fn bool_property(name: &str, value: bool) -> Vec<u8> {
+    format!("{name}-{value}").into_bytes()
+}
@@ -2,13 +2,15 @@ use std::collections::HashMap;
 
 use crate::{
     types::VMAddress,
-    world_mock::{AccountData, BlockchainState},
+    world_mock::{AccountData, BlockchainState, DcdtTokenData},
 };
 
 #[derive(Default)]
 pub struct BlockchainUpdate {
     pub accounts: HashMap<VMAddress, AccountData>,
     pub new_token_identifiers: Option<Vec<String>>,
+    pub dcdt_tokens: HashMap<Vec<u8>, DcdtTokenData>,
+    pub paused_dcdt_tokens: HashMap<Vec<u8>, bool>,
 }
 
 impl BlockchainUpdate {
@@ -18,6 +20,8 @@ impl BlockchainUpdate {
 
     pub fn apply(self, blockchain: &mut BlockchainState) {
         blockchain.update_accounts(self.accounts);
+        blockchain.update_dcdt_tokens(self.dcdt_tokens);
+        blockchain.update_paused_dcdt_tokens(self.paused_dcdt_tokens);
 
         if let Some(token_identifiers) = self.new_token_identifiers {
             blockchain.update_new_token_identifiers(token_identifiers);
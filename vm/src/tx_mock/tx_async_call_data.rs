@@ -16,6 +16,7 @@ pub struct AsyncCallTxData {
     pub endpoint_name: TxFunctionName,
     pub arguments: Vec<Vec<u8>>,
     pub tx_hash: H256,
+    pub gas_limit: u64,
 }
 
 pub fn async_call_tx_input(async_call: &AsyncCallTxData, call_type: CallType) -> TxInput {
@@ -27,7 +28,7 @@ pub fn async_call_tx_input(async_call: &AsyncCallTxData, call_type: CallType) ->
         func_name: async_call.endpoint_name.clone(),
         args: async_call.arguments.clone(),
         call_type,
-        gas_limit: 1000,
+        gas_limit: async_call.gas_limit,
         gas_price: 0,
         tx_hash: async_call.tx_hash.clone(),
         ..Default::default()
@@ -72,7 +73,7 @@ pub fn async_callback_tx_input(
         func_name: TxFunctionName::CALLBACK,
         args,
         call_type: CallType::AsyncCallback,
-        gas_limit: 1000,
+        gas_limit: async_data.gas_limit,
         gas_price: 0,
         tx_hash: async_data.tx_hash.clone(),
         callback_payments,
@@ -122,12 +123,15 @@ pub fn async_promise_callback_tx_input(
 }
 
 pub fn merge_results(mut original: TxResult, mut new: TxResult) -> TxResult {
+    let gas_used = original.gas_used + new.gas_used;
     if original.result_status == 0 {
         original.result_values.append(&mut new.result_values);
         original.result_logs.append(&mut new.result_logs);
         original.result_message = new.result_message;
+        original.gas_used = gas_used;
         original
     } else {
+        new.gas_used = gas_used;
         new
     }
 }
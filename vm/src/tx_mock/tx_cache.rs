@@ -9,7 +9,7 @@ use colored::Colorize;
 use crate::{
     display_util::address_hex,
     types::VMAddress,
-    world_mock::{AccountData, BlockchainState},
+    world_mock::{AccountData, BlockchainState, DcdtTokenData},
 };
 
 use super::{BlockchainUpdate, TxCacheSource};
@@ -18,6 +18,8 @@ pub struct TxCache {
     source_ref: Arc<dyn TxCacheSource>,
     pub(super) accounts: Mutex<HashMap<VMAddress, AccountData>>,
     pub(super) new_token_identifiers: Mutex<Option<Vec<String>>>,
+    pub(super) dcdt_tokens: Mutex<HashMap<Vec<u8>, DcdtTokenData>>,
+    pub(super) paused_dcdt_tokens: Mutex<HashMap<Vec<u8>, bool>>,
 }
 
 impl fmt::Debug for TxCache {
@@ -34,6 +36,8 @@ impl TxCache {
             source_ref,
             accounts: Mutex::new(HashMap::new()),
             new_token_identifiers: Mutex::new(None),
+            dcdt_tokens: Mutex::new(HashMap::new()),
+            paused_dcdt_tokens: Mutex::new(HashMap::new()),
         }
     }
 
@@ -85,6 +89,18 @@ impl TxCache {
         f(account)
     }
 
+    /// All accounts visible to the transaction: the ones in the state,
+    /// plus the ones created in this cache or in its parents.
+    pub fn account_addresses(&self) -> Vec<VMAddress> {
+        let mut addresses = self.source_ref.account_addresses();
+        for address in self.accounts.lock().unwrap().keys() {
+            if !addresses.contains(address) {
+                addresses.push(address.clone());
+            }
+        }
+        addresses
+    }
+
     pub fn insert_account(&self, account_data: AccountData) {
         self.accounts
             .lock()
@@ -127,14 +143,90 @@ impl TxCache {
         *self.new_token_identifiers.lock().unwrap() = Some(token_identifiers);
     }
 
+    fn load_dcdt_token_data_if_necessary(&self, token_identifier: &[u8]) {
+        let mut dcdt_tokens_mut = self.dcdt_tokens.lock().unwrap();
+        if !dcdt_tokens_mut.contains_key(token_identifier) {
+            if let Some(token_data) = self.source_ref.load_dcdt_token_data(token_identifier) {
+                dcdt_tokens_mut.insert(token_identifier.to_vec(), token_data);
+            }
+        }
+    }
+
+    /// Token-level data, as registered in the DCDT system SC. Returns `None` for unknown tokens.
+    pub fn with_dcdt_token_data<R, F>(&self, token_identifier: &[u8], f: F) -> Option<R>
+    where
+        F: FnOnce(&DcdtTokenData) -> R,
+    {
+        self.load_dcdt_token_data_if_necessary(token_identifier);
+        let dcdt_tokens = self.dcdt_tokens.lock().unwrap();
+        dcdt_tokens.get(token_identifier).map(f)
+    }
+
+    pub fn with_dcdt_token_data_mut<R, F>(&self, token_identifier: &[u8], f: F) -> Option<R>
+    where
+        F: FnOnce(&mut DcdtTokenData) -> R,
+    {
+        self.load_dcdt_token_data_if_necessary(token_identifier);
+        let mut dcdt_tokens = self.dcdt_tokens.lock().unwrap();
+        dcdt_tokens.get_mut(token_identifier).map(f)
+    }
+
+    pub fn insert_dcdt_token_data(&self, token_identifier: Vec<u8>, token_data: DcdtTokenData) {
+        self.dcdt_tokens
+            .lock()
+            .unwrap()
+            .insert(token_identifier, token_data);
+    }
+
+    /// Pauses changed in the current transaction take precedence over the parent cache or state.
+    pub fn is_dcdt_token_paused(&self, token_identifier: &[u8]) -> bool {
+        if let Some(paused) = self
+            .paused_dcdt_tokens
+            .lock()
+            .unwrap()
+            .get(token_identifier)
+        {
+            return *paused;
+        }
+        self.source_ref.is_dcdt_token_paused(token_identifier)
+    }
+
+    pub fn set_dcdt_token_paused(&self, token_identifier: &[u8], paused: bool) {
+        self.paused_dcdt_tokens
+            .lock()
+            .unwrap()
+            .insert(token_identifier.to_vec(), paused);
+    }
+
+    /// Missing accounts are considered not frozen.
+    pub fn is_dcdt_instance_frozen(
+        &self,
+        address: &VMAddress,
+        token_identifier: &[u8],
+        nonce: u64,
+    ) -> bool {
+        self.with_account_or_else(
+            address,
+            |account| account.dcdt.is_instance_frozen(token_identifier, nonce),
+            || false,
+        )
+    }
+
     pub fn into_blockchain_updates(self) -> BlockchainUpdate {
         BlockchainUpdate {
             accounts: self.accounts.into_inner().unwrap(),
             new_token_identifiers: self.new_token_identifiers.into_inner().unwrap(),
+            dcdt_tokens: self.dcdt_tokens.into_inner().unwrap(),
+            paused_dcdt_tokens: self.paused_dcdt_tokens.into_inner().unwrap(),
         }
     }
 
     pub fn commit_updates(&self, updates: BlockchainUpdate) {
         self.accounts.lock().unwrap().extend(updates.accounts);
+        self.dcdt_tokens.lock().unwrap().extend(updates.dcdt_tokens);
+        self.paused_dcdt_tokens
+            .lock()
+            .unwrap()
+            .extend(updates.paused_dcdt_tokens);
     }
 }
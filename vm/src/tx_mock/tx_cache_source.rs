@@ -1,6 +1,6 @@
 use crate::{
     types::VMAddress,
-    world_mock::{AccountData, BlockchainState},
+    world_mock::{AccountData, BlockchainState, DcdtTokenData},
 };
 
 use super::TxCache;
@@ -8,6 +8,13 @@ use super::TxCache;
 pub trait TxCacheSource: Send + Sync {
     fn load_account(&self, address: &VMAddress) -> Option<AccountData>;
 
+    /// The addresses of all accounts known to the source, without duplicates.
+    fn account_addresses(&self) -> Vec<VMAddress>;
+
+    fn load_dcdt_token_data(&self, token_identifier: &[u8]) -> Option<DcdtTokenData>;
+
+    fn is_dcdt_token_paused(&self, token_identifier: &[u8]) -> bool;
+
     fn blockchain_ref(&self) -> &BlockchainState;
 }
 
@@ -16,6 +23,18 @@ impl TxCacheSource for TxCache {
         Some(self.with_account(address, AccountData::clone))
     }
 
+    fn account_addresses(&self) -> Vec<VMAddress> {
+        self.account_addresses()
+    }
+
+    fn load_dcdt_token_data(&self, token_identifier: &[u8]) -> Option<DcdtTokenData> {
+        self.with_dcdt_token_data(token_identifier, DcdtTokenData::clone)
+    }
+
+    fn is_dcdt_token_paused(&self, token_identifier: &[u8]) -> bool {
+        self.is_dcdt_token_paused(token_identifier)
+    }
+
     fn blockchain_ref(&self) -> &BlockchainState {
         self.blockchain_ref()
     }
@@ -23,7 +42,22 @@ impl TxCacheSource for TxCache {
 
 impl TxCacheSource for BlockchainState {
     fn load_account(&self, address: &VMAddress) -> Option<AccountData> {
-        self.accounts.get(address).cloned()
+        self.accounts
+            .get(address)
+            .cloned()
+            .or_else(|| self.fetch_fork_account(address))
+    }
+
+    fn account_addresses(&self) -> Vec<VMAddress> {
+        self.accounts.keys().cloned().collect()
+    }
+
+    fn load_dcdt_token_data(&self, token_identifier: &[u8]) -> Option<DcdtTokenData> {
+        self.get_dcdt_token_data(token_identifier).cloned()
+    }
+
+    fn is_dcdt_token_paused(&self, token_identifier: &[u8]) -> bool {
+        self.is_dcdt_token_paused(token_identifier)
     }
 
     fn blockchain_ref(&self) -> &BlockchainState {
@@ -1,11 +1,14 @@
 mod handle_map;
 mod tx_big_float;
 mod tx_big_int;
+mod tx_elliptic_curve;
 mod tx_managed_buffer;
 mod tx_managed_map;
 
 pub use handle_map::HandleMap;
 use num_bigint::BigInt;
+
+use crate::crypto_functions::EllipticCurve;
 pub use tx_big_int::big_int_to_i64;
 
 use std::collections::HashMap;
@@ -19,6 +22,7 @@ pub struct TxManagedTypes {
     pub(crate) big_float_map: HandleMap<f64>,
     pub(crate) managed_buffer_map: HandleMap<ManagedBufferImpl>,
     pub(crate) managed_map_map: HandleMap<ManagedMapImpl>,
+    pub(crate) elliptic_curve_map: HandleMap<EllipticCurve>,
 }
 
 impl TxManagedTypes {
@@ -28,6 +32,7 @@ impl TxManagedTypes {
             big_float_map: HandleMap::new(),
             managed_buffer_map: HandleMap::new(),
             managed_map_map: HandleMap::new(),
+            elliptic_curve_map: HandleMap::new(),
         }
     }
 }
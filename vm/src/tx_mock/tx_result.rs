@@ -19,6 +19,9 @@ pub struct TxResult {
     ///
     /// Is never cleared of its contents.
     pub all_calls: Vec<AsyncCallTxData>,
+
+    /// Only counted when gas metering is enabled.
+    pub gas_used: u64,
 }
 
 impl Default for TxResult {
@@ -30,6 +33,7 @@ impl Default for TxResult {
             result_logs: Vec::new(),
             pending_calls: TxResultCalls::empty(),
             all_calls: Vec::new(),
+            gas_used: 0,
         }
     }
 }
@@ -83,13 +87,13 @@ impl TxResult {
             .extend_from_slice(sync_call_result.result_values.as_slice());
         self.result_logs
             .extend_from_slice(sync_call_result.result_logs.as_slice());
-        if let Some(sync_result_async) = &sync_call_result.pending_calls.async_call {
-            assert!(
-                self.pending_calls.async_call.is_none(),
-                "Multiple async calls not supported"
-            );
-            self.pending_calls.async_call = Some(sync_result_async.clone());
-        }
+        self.gas_used += sync_call_result.gas_used;
+        self.pending_calls
+            .async_calls
+            .extend_from_slice(&sync_call_result.pending_calls.async_calls);
+        self.pending_calls
+            .promises
+            .extend_from_slice(&sync_call_result.pending_calls.promises);
     }
 
     pub fn assert_ok(&self) {
@@ -2,19 +2,22 @@ use super::{AsyncCallTxData, Promise};
 
 #[derive(Clone, Default, Debug)]
 pub struct TxResultCalls {
-    pub async_call: Option<AsyncCallTxData>,
+    /// Legacy async calls, in the order they were launched.
+    ///
+    /// A contract can only launch one itself, but more can be collected from sync calls.
+    pub async_calls: Vec<AsyncCallTxData>,
     pub promises: Vec<Promise>,
 }
 
 impl TxResultCalls {
     pub fn empty() -> Self {
         TxResultCalls {
-            async_call: None,
+            async_calls: Vec::new(),
             promises: Vec::new(),
         }
     }
 
     pub fn no_calls(&self) -> bool {
-        self.async_call.is_none() && self.promises.is_empty()
+        self.async_calls.is_empty() && self.promises.is_empty()
     }
 }
@@ -9,6 +9,11 @@ const DCDT_ROLE_NFT_BURN: &str = "DCDTRoleNFTBurn";
 const DCDT_ROLE_NFT_ADD_URI: &str = "DCDTRoleNFTAddURI";
 const DCDT_ROLE_NFT_UPDATE_ATTRIBUTES: &str = "DCDTRoleNFTUpdateAttributes";
 const DCDT_ROLE_TRANSFER: &str = "DCDTTransferRole";
+const DCDT_ROLE_SET_NEW_URI: &str = "DCDTRoleSetNewURI";
+const DCDT_ROLE_MODIFY_ROYALTIES: &str = "DCDTRoleModifyRoyalties";
+const DCDT_ROLE_MODIFY_CREATOR: &str = "DCDTRoleModifyCreator";
+const DCDT_ROLE_NFT_RECREATE: &str = "DCDTRoleNFTRecreate";
+const DCDT_ROLE_NFT_UPDATE: &str = "DCDTRoleNFTUpdate";
 
 /// The VM implementation for DcdtLocalRole, used internally in builtin functions.
 ///
@@ -26,6 +31,11 @@ pub enum DcdtLocalRole {
     NftAddUri,
     NftUpdateAttributes,
     Transfer,
+    SetNewUri,
+    ModifyRoyalties,
+    ModifyCreator,
+    NftRecreate,
+    NftUpdate,
 }
 
 impl DcdtLocalRole {
@@ -40,6 +50,11 @@ impl DcdtLocalRole {
             Self::NftAddUri => 6,
             Self::NftUpdateAttributes => 7,
             Self::Transfer => 8,
+            Self::SetNewUri => 9,
+            Self::ModifyRoyalties => 10,
+            Self::ModifyCreator => 11,
+            Self::NftRecreate => 12,
+            Self::NftUpdate => 13,
         }
     }
 
@@ -54,6 +69,11 @@ impl DcdtLocalRole {
             Self::NftAddUri => DCDT_ROLE_NFT_ADD_URI,
             Self::NftUpdateAttributes => DCDT_ROLE_NFT_UPDATE_ATTRIBUTES,
             Self::Transfer => DCDT_ROLE_TRANSFER,
+            Self::SetNewUri => DCDT_ROLE_SET_NEW_URI,
+            Self::ModifyRoyalties => DCDT_ROLE_MODIFY_ROYALTIES,
+            Self::ModifyCreator => DCDT_ROLE_MODIFY_CREATOR,
+            Self::NftRecreate => DCDT_ROLE_NFT_RECREATE,
+            Self::NftUpdate => DCDT_ROLE_NFT_UPDATE,
         }
     }
 
@@ -68,13 +88,18 @@ impl DcdtLocalRole {
             Self::NftAddUri => DcdtLocalRoleFlags::NFT_ADD_URI,
             Self::NftUpdateAttributes => DcdtLocalRoleFlags::NFT_UPDATE_ATTRIBUTES,
             Self::Transfer => DcdtLocalRoleFlags::TRANSFER,
+            Self::SetNewUri => DcdtLocalRoleFlags::SET_NEW_URI,
+            Self::ModifyRoyalties => DcdtLocalRoleFlags::MODIFY_ROYALTIES,
+            Self::ModifyCreator => DcdtLocalRoleFlags::MODIFY_CREATOR,
+            Self::NftRecreate => DcdtLocalRoleFlags::NFT_RECREATE,
+            Self::NftUpdate => DcdtLocalRoleFlags::NFT_UPDATE,
         }
     }
 }
 
 // TODO: can be done with macros, but I didn't find a public library that does it and is no_std
 // we can implement it, it's easy
-const ALL_ROLES: [DcdtLocalRole; 8] = [
+const ALL_ROLES: [DcdtLocalRole; 13] = [
     DcdtLocalRole::Mint,
     DcdtLocalRole::Burn,
     DcdtLocalRole::NftCreate,
@@ -83,6 +108,11 @@ const ALL_ROLES: [DcdtLocalRole; 8] = [
     DcdtLocalRole::NftAddUri,
     DcdtLocalRole::NftUpdateAttributes,
     DcdtLocalRole::Transfer,
+    DcdtLocalRole::SetNewUri,
+    DcdtLocalRole::ModifyRoyalties,
+    DcdtLocalRole::ModifyCreator,
+    DcdtLocalRole::NftRecreate,
+    DcdtLocalRole::NftUpdate,
 ];
 
 impl DcdtLocalRole {
@@ -103,6 +133,11 @@ impl From<u16> for DcdtLocalRole {
             6 => Self::NftAddUri,
             7 => Self::NftUpdateAttributes,
             8 => Self::Transfer,
+            9 => Self::SetNewUri,
+            10 => Self::ModifyRoyalties,
+            11 => Self::ModifyCreator,
+            12 => Self::NftRecreate,
+            13 => Self::NftUpdate,
             _ => Self::None,
         }
     }
@@ -127,6 +162,16 @@ impl<'a> From<&'a [u8]> for DcdtLocalRole {
             Self::NftUpdateAttributes
         } else if byte_slice == DCDT_ROLE_TRANSFER.as_bytes() {
             Self::Transfer
+        } else if byte_slice == DCDT_ROLE_SET_NEW_URI.as_bytes() {
+            Self::SetNewUri
+        } else if byte_slice == DCDT_ROLE_MODIFY_ROYALTIES.as_bytes() {
+            Self::ModifyRoyalties
+        } else if byte_slice == DCDT_ROLE_MODIFY_CREATOR.as_bytes() {
+            Self::ModifyCreator
+        } else if byte_slice == DCDT_ROLE_NFT_RECREATE.as_bytes() {
+            Self::NftRecreate
+        } else if byte_slice == DCDT_ROLE_NFT_UPDATE.as_bytes() {
+            Self::NftUpdate
         } else {
             Self::None
         }
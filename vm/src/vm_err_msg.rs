@@ -14,3 +14,14 @@ pub const ERROR_SIGNALLED_BY_SMARTCONTRACT: &str = "error signalled by smartcont
 
 pub const ERROR_NO_CALLBACK_CLOSURE: &str =
     "no callback for closure, cannot call callback directly";
+
+pub const ASYNC_CALL_NOT_ALLOWED_IN_INIT: &str = "async call is not allowed at this location";
+
+pub const DCDT_IS_FROZEN_FOR_ACCOUNT: &str = "account is frozen for this dcdt token";
+pub const DCDT_TOKEN_IS_PAUSED: &str = "dcdt token is paused";
+
+pub const NOT_ENOUGH_GAS: &str = "not enough gas";
+
+pub const EC_CURVE_NOT_SUPPORTED: &str = "the curve is not supported";
+pub const EC_POINT_NOT_ON_CURVE: &str = "point is not on curve";
+pub const EC_INVALID_POINT_ENCODING: &str = "invalid elliptic curve point encoding";
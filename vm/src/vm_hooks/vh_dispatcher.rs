@@ -16,6 +16,12 @@ impl VMHooksDispatcher {
     pub fn new(handler: Box<dyn VMHooksHandler>) -> Self {
         VMHooksDispatcher { handler }
     }
+
+    /// Every VM hook call goes through here, so this is where the base gas cost gets charged.
+    fn handler(&self) -> &dyn VMHooksHandler {
+        self.handler.use_gas_for_vm_hook();
+        self.handler.as_ref()
+    }
 }
 
 fn bool_to_i32(b: bool) -> i32 {
@@ -31,7 +37,7 @@ impl VMHooks for VMHooksDispatcher {
     fn set_vm_hooks_ptr(&mut self, _vm_hooks_ptr: *mut c_void) {}
 
     fn get_gas_left(&self) -> i64 {
-        self.handler.get_gas_left() as i64
+        self.handler().get_gas_left() as i64
     }
 
     fn get_sc_address(&self, result_offset: MemPtr) {
@@ -45,7 +51,7 @@ impl VMHooks for VMHooksDispatcher {
     fn get_shard_of_address(&self, address_offset: MemPtr) -> i32 {
         unsafe {
             mem_conv::with_bytes(address_offset, 32, |address_bytes| {
-                self.handler.get_shard_of_address(address_bytes)
+                self.handler().get_shard_of_address(address_bytes)
             })
         }
     }
@@ -53,7 +59,7 @@ impl VMHooks for VMHooksDispatcher {
     fn is_smart_contract(&self, address_offset: MemPtr) -> i32 {
         unsafe {
             bool_to_i32(mem_conv::with_bytes(address_offset, 32, |address_bytes| {
-                self.handler.is_smart_contract(address_bytes)
+                self.handler().is_smart_contract(address_bytes)
             }))
         }
     }
@@ -61,7 +67,7 @@ impl VMHooks for VMHooksDispatcher {
     fn signal_error(&self, message_offset: MemPtr, message_length: MemLength) {
         unsafe {
             mem_conv::with_bytes(message_offset, message_length, |message| {
-                self.handler.signal_error(message);
+                self.handler().signal_error(message);
             });
         }
     }
@@ -134,7 +140,7 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn get_dcdt_local_roles(&self, token_id_handle: i32) -> i64 {
-        self.handler.get_dcdt_local_roles_bits(token_id_handle) as i64
+        self.handler().get_dcdt_local_roles_bits(token_id_handle) as i64
     }
 
     fn validate_token_identifier(&self, token_id_handle: i32) -> i32 {
@@ -304,7 +310,7 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn get_num_arguments(&self) -> i32 {
-        self.handler.get_num_arguments()
+        self.handler().get_num_arguments()
     }
 
     fn storage_store(
@@ -361,7 +367,7 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn check_no_payment(&self) {
-        self.handler.check_not_payable();
+        self.handler().check_not_payable();
     }
 
     fn get_call_value(&self, result_offset: MemPtr) -> i32 {
@@ -401,7 +407,7 @@ impl VMHooks for VMHooksDispatcher {
         unsafe {
             mem_conv::with_bytes(address_offset, 32, |address_bytes| {
                 mem_conv::with_bytes(token_id_offset, token_id_len, |token_id_bytes| {
-                    self.handler
+                    self.handler()
                         .get_current_dcdt_nft_nonce(address_bytes, token_id_bytes)
                         as i64
                 })
@@ -418,7 +424,7 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn get_num_dcdt_transfers(&self) -> i32 {
-        self.handler.dcdt_num_transfers() as i32
+        self.handler().dcdt_num_transfers() as i32
     }
 
     fn get_call_value_token_name(
@@ -460,19 +466,19 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn get_block_timestamp(&self) -> i64 {
-        self.handler.get_block_timestamp() as i64
+        self.handler().get_block_timestamp() as i64
     }
 
     fn get_block_nonce(&self) -> i64 {
-        self.handler.get_block_nonce() as i64
+        self.handler().get_block_nonce() as i64
     }
 
     fn get_block_round(&self) -> i64 {
-        self.handler.get_block_round() as i64
+        self.handler().get_block_round() as i64
     }
 
     fn get_block_epoch(&self) -> i64 {
-        self.handler.get_block_epoch() as i64
+        self.handler().get_block_epoch() as i64
     }
 
     fn get_block_random_seed(&self, pointer: MemPtr) {
@@ -484,19 +490,19 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn get_prev_block_timestamp(&self) -> i64 {
-        self.handler.get_prev_block_timestamp() as i64
+        self.handler().get_prev_block_timestamp() as i64
     }
 
     fn get_prev_block_nonce(&self) -> i64 {
-        self.handler.get_prev_block_nonce() as i64
+        self.handler().get_prev_block_nonce() as i64
     }
 
     fn get_prev_block_round(&self) -> i64 {
-        self.handler.get_prev_block_round() as i64
+        self.handler().get_prev_block_round() as i64
     }
 
     fn get_prev_block_epoch(&self) -> i64 {
-        self.handler.get_prev_block_epoch() as i64
+        self.handler().get_prev_block_epoch() as i64
     }
 
     fn get_prev_block_random_seed(&self, pointer: MemPtr) {
@@ -506,7 +512,7 @@ impl VMHooks for VMHooksDispatcher {
     fn finish(&self, pointer: MemPtr, length: MemLength) {
         unsafe {
             mem_conv::with_bytes(pointer, length, |bytes| {
-                self.handler.finish_slice_u8(bytes);
+                self.handler().finish_slice_u8(bytes);
             })
         }
     }
@@ -594,11 +600,11 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn clean_return_data(&self) {
-        self.handler.clean_return_data();
+        self.handler().clean_return_data();
     }
 
     fn delete_from_return_data(&self, result_id: i32) {
-        self.handler.delete_from_return_data(result_id as usize);
+        self.handler().delete_from_return_data(result_id as usize);
     }
 
     fn get_original_tx_hash(&self, data_offset: MemPtr) {
@@ -614,27 +620,27 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn managed_sc_address(&self, destination_handle: i32) {
-        self.handler.managed_sc_address(destination_handle);
+        self.handler().managed_sc_address(destination_handle);
     }
 
     fn managed_owner_address(&self, destination_handle: i32) {
-        self.handler.managed_owner_address(destination_handle);
+        self.handler().managed_owner_address(destination_handle);
     }
 
     fn managed_caller(&self, destination_handle: i32) {
-        self.handler.managed_caller(destination_handle);
+        self.handler().managed_caller(destination_handle);
     }
 
     fn managed_signal_error(&self, err_handle: i32) {
-        self.handler.signal_error_from_buffer(err_handle);
+        self.handler().signal_error_from_buffer(err_handle);
     }
 
     fn managed_write_log(&self, topics_handle: i32, data_handle: i32) {
-        self.handler.managed_write_log(topics_handle, data_handle);
+        self.handler().managed_write_log(topics_handle, data_handle);
     }
 
     fn managed_get_original_tx_hash(&self, result_handle: i32) {
-        self.handler.get_tx_hash(result_handle);
+        self.handler().get_tx_hash(result_handle);
     }
 
     fn managed_get_state_root_hash(&self, result_handle: i32) {
@@ -642,11 +648,11 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn managed_get_block_random_seed(&self, result_handle: i32) {
-        self.handler.get_block_random_seed(result_handle);
+        self.handler().get_block_random_seed(result_handle);
     }
 
     fn managed_get_prev_block_random_seed(&self, result_handle: i32) {
-        self.handler.get_prev_block_random_seed(result_handle);
+        self.handler().get_prev_block_random_seed(result_handle);
     }
 
     fn managed_get_return_data(&self, result_id: i32, result_handle: i32) {
@@ -654,7 +660,7 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn managed_get_multi_dcdt_call_value(&self, multi_call_value_handle: i32) {
-        self.handler
+        self.handler()
             .load_all_dcdt_transfers(multi_call_value_handle)
     }
 
@@ -682,7 +688,7 @@ impl VMHooks for VMHooksDispatcher {
         royalties_handle: i32,
         uris_handle: i32,
     ) {
-        self.handler.managed_get_dcdt_token_data(
+        self.handler().managed_get_dcdt_token_data(
             address_handle,
             token_id_handle,
             nonce as u64,
@@ -698,7 +704,7 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn managed_get_back_transfers(&self, dcdt_transfer_value_handle: i32, call_value_handle: i32) {
-        self.handler
+        self.handler()
             .managed_get_back_transfers(dcdt_transfer_value_handle, call_value_handle);
     }
 
@@ -709,7 +715,7 @@ impl VMHooks for VMHooksDispatcher {
         function_handle: i32,
         arguments_handle: i32,
     ) {
-        self.handler
+        self.handler()
             .async_call_raw(dest_handle, value_handle, function_handle, arguments_handle)
     }
 
@@ -730,7 +736,7 @@ impl VMHooks for VMHooksDispatcher {
         unsafe {
             mem_conv::with_bytes(success_offset, success_length, |success_callback| {
                 mem_conv::with_bytes(error_offset, error_length, |error_callback| {
-                    self.handler.create_async_call_raw(
+                    self.handler().create_async_call_raw(
                         dest_handle,
                         value_handle,
                         function_handle,
@@ -748,7 +754,7 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn managed_get_callback_closure(&self, callback_closure_handle: i32) {
-        self.handler
+        self.handler()
             .load_callback_closure_buffer(callback_closure_handle)
     }
 
@@ -762,7 +768,7 @@ impl VMHooks for VMHooksDispatcher {
         arguments_handle: i32,
         _result_handle: i32,
     ) {
-        self.handler.upgrade_from_source_contract(
+        self.handler().upgrade_from_source_contract(
             dest_handle,
             gas as u64,
             value_handle,
@@ -782,7 +788,7 @@ impl VMHooks for VMHooksDispatcher {
         arguments_handle: i32,
         _result_handle: i32,
     ) {
-        self.handler.upgrade_contract(
+        self.handler().upgrade_contract(
             dest_handle,
             gas as u64,
             value_handle,
@@ -806,7 +812,7 @@ impl VMHooks for VMHooksDispatcher {
         result_address_handle: i32,
         result_handle: i32,
     ) -> i32 {
-        self.handler.deploy_from_source_contract(
+        self.handler().deploy_from_source_contract(
             gas as u64,
             value_handle,
             address_handle,
@@ -828,7 +834,7 @@ impl VMHooks for VMHooksDispatcher {
         result_address_handle: i32,
         result_handle: i32,
     ) -> i32 {
-        self.handler.deploy_contract(
+        self.handler().deploy_contract(
             gas as u64,
             value_handle,
             code_handle,
@@ -872,7 +878,7 @@ impl VMHooks for VMHooksDispatcher {
         arguments_handle: i32,
         result_handle: i32,
     ) -> i32 {
-        self.handler.execute_on_dest_context_raw(
+        self.handler().execute_on_dest_context_raw(
             gas as u64,
             address_handle,
             value_handle,
@@ -891,7 +897,7 @@ impl VMHooks for VMHooksDispatcher {
         function_handle: i32,
         arguments_handle: i32,
     ) -> i32 {
-        self.handler.multi_transfer_dcdt_nft_execute(
+        self.handler().multi_transfer_dcdt_nft_execute(
             dst_handle,
             token_transfers_handle,
             gas_limit as u64,
@@ -909,7 +915,7 @@ impl VMHooks for VMHooksDispatcher {
         function_handle: i32,
         arguments_handle: i32,
     ) -> i32 {
-        self.handler.transfer_value_execute(
+        self.handler().transfer_value_execute(
             dst_handle,
             value_handle,
             gas_limit as u64,
@@ -921,7 +927,7 @@ impl VMHooks for VMHooksDispatcher {
 
     fn managed_is_dcdt_frozen(&self, address_handle: i32, token_id_handle: i32, nonce: i64) -> i32 {
         bool_to_i32(
-            self.handler
+            self.handler()
                 .check_dcdt_frozen(address_handle, token_id_handle, nonce as u64),
         )
     }
@@ -930,22 +936,22 @@ impl VMHooks for VMHooksDispatcher {
         bool_to_i32(false)
     }
 
-    fn managed_is_dcdt_paused(&self, _token_id_handle: i32) -> i32 {
-        bool_to_i32(false)
+    fn managed_is_dcdt_paused(&self, token_id_handle: i32) -> i32 {
+        bool_to_i32(self.handler().check_dcdt_paused(token_id_handle))
     }
 
     fn managed_buffer_to_hex(&self, source_handle: i32, dest_handle: i32) {
-        self.handler.mb_to_hex(source_handle, dest_handle);
+        self.handler().mb_to_hex(source_handle, dest_handle);
     }
 
     fn managed_get_code_metadata(&self, address_handle: i32, response_handle: i32) {
-        self.handler
+        self.handler()
             .managed_get_code_metadata(address_handle, response_handle);
     }
 
     fn managed_is_builtin_function(&self, function_name_handle: i32) -> i32 {
         bool_to_i32(
-            self.handler
+            self.handler()
                 .managed_is_builtin_function(function_name_handle),
         )
     }
@@ -956,96 +962,97 @@ impl VMHooks for VMHooksDispatcher {
         fractional_part: i32,
         exponent: i32,
     ) -> i32 {
-        self.handler
+        self.handler()
             .bf_from_parts(integral_part, fractional_part, exponent)
     }
 
     fn big_float_new_from_frac(&self, numerator: i64, denominator: i64) -> i32 {
-        self.handler.bf_from_frac(numerator, denominator)
+        self.handler().bf_from_frac(numerator, denominator)
     }
 
     fn big_float_new_from_sci(&self, significand: i64, exponent: i64) -> i32 {
-        self.handler.bf_from_sci(significand, exponent)
+        self.handler().bf_from_sci(significand, exponent)
     }
 
     fn big_float_add(&self, destination_handle: i32, op1_handle: i32, op2_handle: i32) {
-        self.handler
+        self.handler()
             .bf_add(destination_handle, op1_handle, op2_handle);
     }
 
     fn big_float_sub(&self, destination_handle: i32, op1_handle: i32, op2_handle: i32) {
-        self.handler
+        self.handler()
             .bf_sub(destination_handle, op1_handle, op2_handle);
     }
 
     fn big_float_mul(&self, destination_handle: i32, op1_handle: i32, op2_handle: i32) {
-        self.handler
+        self.handler()
             .bf_mul(destination_handle, op1_handle, op2_handle);
     }
 
     fn big_float_div(&self, destination_handle: i32, op1_handle: i32, op2_handle: i32) {
-        self.handler
+        self.handler()
             .bf_div(destination_handle, op1_handle, op2_handle);
     }
 
     fn big_float_neg(&self, destination_handle: i32, op_handle: i32) {
-        self.handler.bf_neg(destination_handle, op_handle);
+        self.handler().bf_neg(destination_handle, op_handle);
     }
 
     fn big_float_clone(&self, destination_handle: i32, op_handle: i32) {
-        self.handler.bf_clone(destination_handle, op_handle);
+        self.handler().bf_clone(destination_handle, op_handle);
     }
 
     fn big_float_cmp(&self, op1_handle: i32, op2_handle: i32) -> i32 {
-        self.handler.bf_cmp(op1_handle, op2_handle)
+        self.handler().bf_cmp(op1_handle, op2_handle)
     }
 
     fn big_float_abs(&self, destination_handle: i32, op_handle: i32) {
-        self.handler.bf_abs(destination_handle, op_handle);
+        self.handler().bf_abs(destination_handle, op_handle);
     }
 
     fn big_float_sign(&self, op_handle: i32) -> i32 {
-        self.handler.bf_sign(op_handle)
+        self.handler().bf_sign(op_handle)
     }
 
     fn big_float_sqrt(&self, destination_handle: i32, op_handle: i32) {
-        self.handler.bf_sqrt(destination_handle, op_handle);
+        self.handler().bf_sqrt(destination_handle, op_handle);
     }
 
     fn big_float_pow(&self, destination_handle: i32, op_handle: i32, exponent: i32) {
-        self.handler.bf_pow(destination_handle, op_handle, exponent);
+        self.handler()
+            .bf_pow(destination_handle, op_handle, exponent);
     }
 
     fn big_float_floor(&self, dest_big_int_handle: i32, op_handle: i32) {
-        self.handler.bf_floor(dest_big_int_handle, op_handle);
+        self.handler().bf_floor(dest_big_int_handle, op_handle);
     }
 
     fn big_float_ceil(&self, dest_big_int_handle: i32, op_handle: i32) {
-        self.handler.bf_ceil(dest_big_int_handle, op_handle);
+        self.handler().bf_ceil(dest_big_int_handle, op_handle);
     }
 
     fn big_float_truncate(&self, dest_big_int_handle: i32, op_handle: i32) {
-        self.handler.bf_trunc(dest_big_int_handle, op_handle);
+        self.handler().bf_trunc(dest_big_int_handle, op_handle);
     }
 
     fn big_float_set_int64(&self, destination_handle: i32, value: i64) {
-        self.handler.bf_set_i64(destination_handle, value);
+        self.handler().bf_set_i64(destination_handle, value);
     }
 
     fn big_float_is_int(&self, op_handle: i32) -> i32 {
-        bool_to_i32(self.handler.bf_is_bi(op_handle))
+        bool_to_i32(self.handler().bf_is_bi(op_handle))
     }
 
     fn big_float_set_big_int(&self, destination_handle: i32, big_int_handle: i32) {
-        self.handler.bf_set_bi(destination_handle, big_int_handle);
+        self.handler().bf_set_bi(destination_handle, big_int_handle);
     }
 
     fn big_float_get_const_pi(&self, destination_handle: i32) {
-        self.handler.bf_get_const_pi(destination_handle);
+        self.handler().bf_get_const_pi(destination_handle);
     }
 
     fn big_float_get_const_e(&self, destination_handle: i32) {
-        self.handler.bf_get_const_e(destination_handle);
+        self.handler().bf_get_const_e(destination_handle);
     }
 
     fn big_int_get_unsigned_argument(&self, id: i32, destination_handle: i32) {
@@ -1075,7 +1082,7 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn big_int_get_call_value(&self, destination_handle: i32) {
-        self.handler.load_rewa_value(destination_handle);
+        self.handler().load_rewa_value(destination_handle);
     }
 
     fn big_int_get_dcdt_call_value(&self, destination: i32) {
@@ -1089,7 +1096,7 @@ impl VMHooks for VMHooksDispatcher {
     fn big_int_get_external_balance(&self, address_offset: MemPtr, result: i32) {
         unsafe {
             mem_conv::with_bytes(address_offset, 32, |address_bytes| {
-                self.handler.load_balance(address_bytes, result);
+                self.handler().load_balance(address_bytes, result);
             })
         }
     }
@@ -1105,7 +1112,7 @@ impl VMHooks for VMHooksDispatcher {
         unsafe {
             mem_conv::with_bytes(address_offset, 32, |address_bytes| {
                 mem_conv::with_bytes(token_id_offset, token_id_len, |token_id_bytes| {
-                    self.handler.big_int_get_dcdt_external_balance(
+                    self.handler().big_int_get_dcdt_external_balance(
                         address_bytes,
                         token_id_bytes,
                         nonce as u64,
@@ -1117,7 +1124,7 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn big_int_new(&self, small_value: i64) -> i32 {
-        self.handler.bi_new(small_value)
+        self.handler().bi_new(small_value)
     }
 
     fn big_int_unsigned_byte_length(&self, reference_handle: i32) -> i32 {
@@ -1144,7 +1151,7 @@ impl VMHooks for VMHooksDispatcher {
     ) {
         unsafe {
             mem_conv::with_bytes_mut(byte_offset, byte_length, |bytes| {
-                self.handler
+                self.handler()
                     .bi_set_unsigned_bytes(destination_handle, bytes);
             })
         }
@@ -1158,45 +1165,46 @@ impl VMHooks for VMHooksDispatcher {
     ) {
         unsafe {
             mem_conv::with_bytes_mut(byte_offset, byte_length, |bytes| {
-                self.handler.bi_set_signed_bytes(destination_handle, bytes);
+                self.handler()
+                    .bi_set_signed_bytes(destination_handle, bytes);
             })
         }
     }
 
     fn big_int_is_int64(&self, destination_handle: i32) -> i32 {
-        self.handler.bi_is_int64(destination_handle)
+        self.handler().bi_is_int64(destination_handle)
     }
 
     fn big_int_get_int64(&self, destination_handle: i32) -> i64 {
-        self.handler.bi_get_int64(destination_handle)
+        self.handler().bi_get_int64(destination_handle)
     }
 
     fn big_int_set_int64(&self, destination_handle: i32, value: i64) {
-        self.handler.bi_set_int64(destination_handle, value);
+        self.handler().bi_set_int64(destination_handle, value);
     }
 
     fn big_int_add(&self, destination_handle: i32, op1_handle: i32, op2_handle: i32) {
-        self.handler
+        self.handler()
             .bi_add(destination_handle, op1_handle, op2_handle);
     }
 
     fn big_int_sub(&self, destination_handle: i32, op1_handle: i32, op2_handle: i32) {
-        self.handler
+        self.handler()
             .bi_sub(destination_handle, op1_handle, op2_handle);
     }
 
     fn big_int_mul(&self, destination_handle: i32, op1_handle: i32, op2_handle: i32) {
-        self.handler
+        self.handler()
             .bi_mul(destination_handle, op1_handle, op2_handle);
     }
 
     fn big_int_tdiv(&self, destination_handle: i32, op1_handle: i32, op2_handle: i32) {
-        self.handler
+        self.handler()
             .bi_t_div(destination_handle, op1_handle, op2_handle);
     }
 
     fn big_int_tmod(&self, destination_handle: i32, op1_handle: i32, op2_handle: i32) {
-        self.handler
+        self.handler()
             .bi_t_mod(destination_handle, op1_handle, op2_handle);
     }
 
@@ -1209,32 +1217,32 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn big_int_sqrt(&self, destination_handle: i32, op_handle: i32) {
-        self.handler.bi_sqrt(destination_handle, op_handle);
+        self.handler().bi_sqrt(destination_handle, op_handle);
     }
 
     fn big_int_pow(&self, destination_handle: i32, op1_handle: i32, op2_handle: i32) {
-        self.handler
+        self.handler()
             .bi_pow(destination_handle, op1_handle, op2_handle);
     }
 
     fn big_int_log2(&self, op_handle: i32) -> i32 {
-        self.handler.bi_log2(op_handle)
+        self.handler().bi_log2(op_handle)
     }
 
     fn big_int_abs(&self, destination_handle: i32, op_handle: i32) {
-        self.handler.bi_abs(destination_handle, op_handle);
+        self.handler().bi_abs(destination_handle, op_handle);
     }
 
     fn big_int_neg(&self, destination_handle: i32, op_handle: i32) {
-        self.handler.bi_neg(destination_handle, op_handle);
+        self.handler().bi_neg(destination_handle, op_handle);
     }
 
     fn big_int_sign(&self, op_handle: i32) -> i32 {
-        self.handler.bi_sign(op_handle)
+        self.handler().bi_sign(op_handle)
     }
 
     fn big_int_cmp(&self, op1_handle: i32, op2_handle: i32) -> i32 {
-        self.handler.bi_cmp(op1_handle, op2_handle)
+        self.handler().bi_cmp(op1_handle, op2_handle)
     }
 
     fn big_int_not(&self, destination_handle: i32, op_handle: i32) {
@@ -1242,62 +1250,62 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn big_int_and(&self, destination_handle: i32, op1_handle: i32, op2_handle: i32) {
-        self.handler
+        self.handler()
             .bi_and(destination_handle, op1_handle, op2_handle);
     }
 
     fn big_int_or(&self, destination_handle: i32, op1_handle: i32, op2_handle: i32) {
-        self.handler
+        self.handler()
             .bi_or(destination_handle, op1_handle, op2_handle);
     }
 
     fn big_int_xor(&self, destination_handle: i32, op1_handle: i32, op2_handle: i32) {
-        self.handler
+        self.handler()
             .bi_xor(destination_handle, op1_handle, op2_handle);
     }
 
     fn big_int_shr(&self, destination_handle: i32, op_handle: i32, bits: i32) {
-        self.handler
+        self.handler()
             .bi_shr(destination_handle, op_handle, bits as usize);
     }
 
     fn big_int_shl(&self, destination_handle: i32, op_handle: i32, bits: i32) {
-        self.handler
+        self.handler()
             .bi_shl(destination_handle, op_handle, bits as usize);
     }
 
     fn big_int_finish_unsigned(&self, reference_handle: i32) {
-        self.handler.finish_big_uint_raw(reference_handle);
+        self.handler().finish_big_uint_raw(reference_handle);
     }
 
     fn big_int_finish_signed(&self, reference_handle: i32) {
-        self.handler.finish_big_int_raw(reference_handle);
+        self.handler().finish_big_int_raw(reference_handle);
     }
 
     fn big_int_to_string(&self, big_int_handle: i32, destination_handle: i32) {
-        self.handler
+        self.handler()
             .bi_to_string(big_int_handle, destination_handle);
     }
 
     fn mbuffer_new(&self) -> i32 {
-        self.handler.mb_new_empty()
+        self.handler().mb_new_empty()
     }
 
     fn mbuffer_new_from_bytes(&self, data_offset: MemPtr, data_length: MemLength) -> i32 {
         unsafe {
             mem_conv::with_bytes_mut(data_offset, data_length, |bytes| {
-                self.handler.mb_new_from_bytes(bytes)
+                self.handler().mb_new_from_bytes(bytes)
             })
         }
     }
 
     fn mbuffer_get_length(&self, m_buffer_handle: i32) -> i32 {
-        self.handler.mb_len(m_buffer_handle) as i32
+        self.handler().mb_len(m_buffer_handle) as i32
     }
 
     fn mbuffer_get_bytes(&self, m_buffer_handle: i32, result_offset: MemPtr) -> i32 {
         unsafe {
-            self.handler
+            self.handler()
                 .mb_copy_bytes(m_buffer_handle, result_offset as *mut u8) as i32
         }
     }
@@ -1311,7 +1319,7 @@ impl VMHooks for VMHooksDispatcher {
     ) -> i32 {
         unsafe {
             mem_conv::with_bytes_mut(result_offset, slice_length as isize, |bytes| {
-                self.handler
+                self.handler()
                     .mb_load_slice(source_handle, starting_position as usize, bytes)
             })
         }
@@ -1324,7 +1332,7 @@ impl VMHooks for VMHooksDispatcher {
         slice_length: i32,
         destination_handle: i32,
     ) -> i32 {
-        self.handler.mb_copy_slice(
+        self.handler().mb_copy_slice(
             source_handle,
             starting_position as usize,
             slice_length as usize,
@@ -1333,7 +1341,7 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn mbuffer_eq(&self, m_buffer_handle1: i32, m_buffer_handle2: i32) -> i32 {
-        self.handler.mb_eq(m_buffer_handle1, m_buffer_handle2)
+        self.handler().mb_eq(m_buffer_handle1, m_buffer_handle2)
     }
 
     fn mbuffer_set_bytes(
@@ -1344,7 +1352,7 @@ impl VMHooks for VMHooksDispatcher {
     ) -> i32 {
         unsafe {
             mem_conv::with_bytes(data_offset, data_length, |bytes| {
-                self.handler.mb_set(m_buffer_handle, bytes);
+                self.handler().mb_set(m_buffer_handle, bytes);
             });
         }
         0
@@ -1359,14 +1367,14 @@ impl VMHooks for VMHooksDispatcher {
     ) -> i32 {
         unsafe {
             mem_conv::with_bytes(data_offset, data_length, |bytes| {
-                self.handler
+                self.handler()
                     .mb_set_slice(m_buffer_handle, starting_position as usize, bytes)
             })
         }
     }
 
     fn mbuffer_append(&self, accumulator_handle: i32, data_handle: i32) -> i32 {
-        self.handler.mb_append(accumulator_handle, data_handle);
+        self.handler().mb_append(accumulator_handle, data_handle);
         0
     }
 
@@ -1378,32 +1386,32 @@ impl VMHooks for VMHooksDispatcher {
     ) -> i32 {
         unsafe {
             mem_conv::with_bytes(data_offset, data_length, |bytes| {
-                self.handler.mb_append_bytes(accumulator_handle, bytes);
+                self.handler().mb_append_bytes(accumulator_handle, bytes);
             });
         }
         0
     }
 
     fn mbuffer_to_big_int_unsigned(&self, m_buffer_handle: i32, big_int_handle: i32) -> i32 {
-        self.handler
+        self.handler()
             .mb_to_big_int_unsigned(m_buffer_handle, big_int_handle);
         0
     }
 
     fn mbuffer_to_big_int_signed(&self, m_buffer_handle: i32, big_int_handle: i32) -> i32 {
-        self.handler
+        self.handler()
             .mb_to_big_int_signed(m_buffer_handle, big_int_handle);
         0
     }
 
     fn mbuffer_from_big_int_unsigned(&self, m_buffer_handle: i32, big_int_handle: i32) -> i32 {
-        self.handler
+        self.handler()
             .mb_from_big_int_unsigned(m_buffer_handle, big_int_handle);
         0
     }
 
     fn mbuffer_from_big_int_signed(&self, m_buffer_handle: i32, big_int_handle: i32) -> i32 {
-        self.handler
+        self.handler()
             .mb_from_big_int_signed(m_buffer_handle, big_int_handle);
         0
     }
@@ -1417,13 +1425,13 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn mbuffer_storage_store(&self, key_handle: i32, source_handle: i32) -> i32 {
-        self.handler
+        self.handler()
             .storage_store_managed_buffer_raw(key_handle, source_handle);
         0
     }
 
     fn mbuffer_storage_load(&self, key_handle: i32, destination_handle: i32) -> i32 {
-        self.handler
+        self.handler()
             .storage_load_managed_buffer_raw(key_handle, destination_handle);
         0
     }
@@ -1434,66 +1442,66 @@ impl VMHooks for VMHooksDispatcher {
         key_handle: i32,
         destination_handle: i32,
     ) {
-        self.handler
+        self.handler()
             .storage_load_from_address(address_handle, key_handle, destination_handle);
     }
 
     fn mbuffer_get_argument(&self, id: i32, destination_handle: i32) -> i32 {
-        self.handler
+        self.handler()
             .load_argument_managed_buffer(id, destination_handle);
         0
     }
 
     fn mbuffer_finish(&self, source_handle: i32) -> i32 {
-        self.handler.finish_managed_buffer_raw(source_handle);
+        self.handler().finish_managed_buffer_raw(source_handle);
         0
     }
 
     fn mbuffer_set_random(&self, destination_handle: i32, length: i32) -> i32 {
-        self.handler
+        self.handler()
             .mb_set_random(destination_handle, length as usize);
         0
     }
 
     fn managed_map_new(&self) -> i32 {
-        self.handler.mm_new()
+        self.handler().mm_new()
     }
 
     fn managed_map_put(&self, map_handle: i32, key_handle: i32, value_handle: i32) -> i32 {
-        self.handler.mm_put(map_handle, key_handle, value_handle);
+        self.handler().mm_put(map_handle, key_handle, value_handle);
         0
     }
 
     fn managed_map_get(&self, map_handle: i32, key_handle: i32, out_value_handle: i32) -> i32 {
-        self.handler
+        self.handler()
             .mm_get(map_handle, key_handle, out_value_handle);
         0
     }
 
     fn managed_map_remove(&self, map_handle: i32, key_handle: i32, out_value_handle: i32) -> i32 {
-        self.handler
+        self.handler()
             .mm_remove(map_handle, key_handle, out_value_handle);
         0
     }
 
     fn managed_map_contains(&self, map_handle: i32, key_handle: i32) -> i32 {
-        bool_to_i32(self.handler.mm_contains(map_handle, key_handle))
+        bool_to_i32(self.handler().mm_contains(map_handle, key_handle))
     }
 
     fn small_int_get_unsigned_argument(&self, id: i32) -> i64 {
-        self.handler.get_argument_u64(id) as i64
+        self.handler().get_argument_u64(id) as i64
     }
 
     fn small_int_get_signed_argument(&self, id: i32) -> i64 {
-        self.handler.get_argument_i64(id)
+        self.handler().get_argument_i64(id)
     }
 
     fn small_int_finish_unsigned(&self, value: i64) {
-        self.handler.finish_u64(value as u64);
+        self.handler().finish_u64(value as u64);
     }
 
     fn small_int_finish_signed(&self, value: i64) {
-        self.handler.finish_i64(value);
+        self.handler().finish_i64(value);
     }
 
     fn small_int_storage_store_unsigned(
@@ -1543,7 +1551,7 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn managed_sha256(&self, input_handle: i32, output_handle: i32) -> i32 {
-        self.handler.sha256_managed(output_handle, input_handle);
+        self.handler().sha256_managed(output_handle, input_handle);
         0
     }
 
@@ -1552,7 +1560,8 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn managed_keccak256(&self, input_handle: i32, output_handle: i32) -> i32 {
-        self.handler.keccak256_managed(output_handle, input_handle);
+        self.handler()
+            .keccak256_managed(output_handle, input_handle);
         0
     }
 
@@ -1561,7 +1570,9 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn managed_ripemd160(&self, input_handle: i32, output_handle: i32) -> i32 {
-        panic!("Unavailable: managed_ripemd160")
+        self.handler()
+            .ripemd160_managed(output_handle, input_handle);
+        0
     }
 
     fn verify_bls(
@@ -1575,7 +1586,9 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn managed_verify_bls(&self, key_handle: i32, message_handle: i32, sig_handle: i32) -> i32 {
-        panic!("Unavailable: managed_verify_bls")
+        self.handler()
+            .verify_bls_managed(key_handle, message_handle, sig_handle);
+        0
     }
 
     fn verify_ed25519(
@@ -1589,7 +1602,7 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn managed_verify_ed25519(&self, key_handle: i32, message_handle: i32, sig_handle: i32) -> i32 {
-        self.handler
+        self.handler()
             .verify_ed25519_managed(key_handle, message_handle, sig_handle);
         0
     }
@@ -1613,7 +1626,13 @@ impl VMHooks for VMHooksDispatcher {
         sig_handle: i32,
         hash_type: i32,
     ) -> i32 {
-        panic!("Unavailable: managed_verify_custom_secp256k1")
+        self.handler().verify_custom_secp256k1_managed(
+            key_handle,
+            message_handle,
+            sig_handle,
+            hash_type,
+        );
+        0
     }
 
     fn verify_secp256k1(
@@ -1633,7 +1652,9 @@ impl VMHooks for VMHooksDispatcher {
         message_handle: i32,
         sig_handle: i32,
     ) -> i32 {
-        panic!("Unavailable: managed_verify_secp256k1")
+        self.handler()
+            .verify_secp256k1_managed(key_handle, message_handle, sig_handle);
+        0
     }
 
     fn encode_secp256k1_der_signature(
@@ -1653,7 +1674,9 @@ impl VMHooks for VMHooksDispatcher {
         s_handle: i32,
         sig_handle: i32,
     ) -> i32 {
-        panic!("Unavailable: managed_encode_secp256k1_der_signature")
+        self.handler()
+            .encode_secp256k1_der_signature_managed(r_handle, s_handle, sig_handle);
+        0
     }
 
     fn add_ec(
@@ -1666,7 +1689,15 @@ impl VMHooks for VMHooksDispatcher {
         snd_point_xhandle: i32,
         snd_point_yhandle: i32,
     ) {
-        panic!("Unavailable: add_ec");
+        self.handler().ec_add(
+            x_result_handle,
+            y_result_handle,
+            ec_handle,
+            fst_point_xhandle,
+            fst_point_yhandle,
+            snd_point_xhandle,
+            snd_point_yhandle,
+        );
     }
 
     fn double_ec(
@@ -1677,11 +1708,20 @@ impl VMHooks for VMHooksDispatcher {
         point_xhandle: i32,
         point_yhandle: i32,
     ) {
-        panic!("Unavailable: double_ec");
+        self.handler().ec_double(
+            x_result_handle,
+            y_result_handle,
+            ec_handle,
+            point_xhandle,
+            point_yhandle,
+        );
     }
 
     fn is_on_curve_ec(&self, ec_handle: i32, point_xhandle: i32, point_yhandle: i32) -> i32 {
-        panic!("Unavailable: is_on_curve_ec")
+        bool_to_i32(
+            self.handler()
+                .ec_is_on_curve(ec_handle, point_xhandle, point_yhandle),
+        )
     }
 
     fn scalar_base_mult_ec(
@@ -1692,7 +1732,17 @@ impl VMHooks for VMHooksDispatcher {
         data_offset: MemPtr,
         length: MemLength,
     ) -> i32 {
-        panic!("Unavailable: scalar_base_mult_ec")
+        unsafe {
+            mem_conv::with_bytes(data_offset, length, |scalar| {
+                self.handler().ec_scalar_base_mult(
+                    x_result_handle,
+                    y_result_handle,
+                    ec_handle,
+                    scalar,
+                );
+            })
+        }
+        0
     }
 
     fn managed_scalar_base_mult_ec(
@@ -1702,7 +1752,10 @@ impl VMHooks for VMHooksDispatcher {
         ec_handle: i32,
         data_handle: i32,
     ) -> i32 {
-        panic!("Unavailable: managed_scalar_base_mult_ec")
+        let scalar = self.handler().m_types_lock().mb_get(data_handle).to_vec();
+        self.handler()
+            .ec_scalar_base_mult(x_result_handle, y_result_handle, ec_handle, &scalar);
+        0
     }
 
     fn scalar_mult_ec(
@@ -1715,7 +1768,19 @@ impl VMHooks for VMHooksDispatcher {
         data_offset: MemPtr,
         length: MemLength,
     ) -> i32 {
-        panic!("Unavailable: scalar_mult_ec")
+        unsafe {
+            mem_conv::with_bytes(data_offset, length, |scalar| {
+                self.handler().ec_scalar_mult(
+                    x_result_handle,
+                    y_result_handle,
+                    ec_handle,
+                    point_xhandle,
+                    point_yhandle,
+                    scalar,
+                );
+            })
+        }
+        0
     }
 
     fn managed_scalar_mult_ec(
@@ -1727,7 +1792,16 @@ impl VMHooks for VMHooksDispatcher {
         point_yhandle: i32,
         data_handle: i32,
     ) -> i32 {
-        panic!("Unavailable: managed_scalar_mult_ec")
+        let scalar = self.handler().m_types_lock().mb_get(data_handle).to_vec();
+        self.handler().ec_scalar_mult(
+            x_result_handle,
+            y_result_handle,
+            ec_handle,
+            point_xhandle,
+            point_yhandle,
+            &scalar,
+        );
+        0
     }
 
     fn marshal_ec(
@@ -1737,7 +1811,15 @@ impl VMHooks for VMHooksDispatcher {
         ec_handle: i32,
         result_offset: MemPtr,
     ) -> i32 {
-        panic!("Unavailable: marshal_ec")
+        let result = self
+            .handler()
+            .ec_marshal(ec_handle, x_pair_handle, y_pair_handle);
+        unsafe {
+            mem_conv::with_bytes_mut(result_offset, result.len() as MemLength, |dest| {
+                dest.copy_from_slice(&result);
+            })
+        }
+        result.len() as i32
     }
 
     fn managed_marshal_ec(
@@ -1747,7 +1829,11 @@ impl VMHooks for VMHooksDispatcher {
         ec_handle: i32,
         result_handle: i32,
     ) -> i32 {
-        panic!("Unavailable: managed_marshal_ec")
+        let result = self
+            .handler()
+            .ec_marshal(ec_handle, x_pair_handle, y_pair_handle);
+        self.handler().mb_set(result_handle, &result);
+        result.len() as i32
     }
 
     fn marshal_compressed_ec(
@@ -1757,7 +1843,15 @@ impl VMHooks for VMHooksDispatcher {
         ec_handle: i32,
         result_offset: MemPtr,
     ) -> i32 {
-        panic!("Unavailable: marshal_compressed_ec")
+        let result = self
+            .handler()
+            .ec_marshal_compressed(ec_handle, x_pair_handle, y_pair_handle);
+        unsafe {
+            mem_conv::with_bytes_mut(result_offset, result.len() as MemLength, |dest| {
+                dest.copy_from_slice(&result);
+            })
+        }
+        result.len() as i32
     }
 
     fn managed_marshal_compressed_ec(
@@ -1767,7 +1861,11 @@ impl VMHooks for VMHooksDispatcher {
         ec_handle: i32,
         result_handle: i32,
     ) -> i32 {
-        panic!("Unavailable: managed_marshal_compressed_ec")
+        let result = self
+            .handler()
+            .ec_marshal_compressed(ec_handle, x_pair_handle, y_pair_handle);
+        self.handler().mb_set(result_handle, &result);
+        result.len() as i32
     }
 
     fn unmarshal_ec(
@@ -1778,7 +1876,13 @@ impl VMHooks for VMHooksDispatcher {
         data_offset: MemPtr,
         length: MemLength,
     ) -> i32 {
-        panic!("Unavailable: unmarshal_ec")
+        unsafe {
+            mem_conv::with_bytes(data_offset, length, |data| {
+                self.handler()
+                    .ec_unmarshal(x_result_handle, y_result_handle, ec_handle, data);
+            })
+        }
+        0
     }
 
     fn managed_unmarshal_ec(
@@ -1788,7 +1892,10 @@ impl VMHooks for VMHooksDispatcher {
         ec_handle: i32,
         data_handle: i32,
     ) -> i32 {
-        panic!("Unavailable: managed_unmarshal_ec")
+        let data = self.handler().m_types_lock().mb_get(data_handle).to_vec();
+        self.handler()
+            .ec_unmarshal(x_result_handle, y_result_handle, ec_handle, &data);
+        0
     }
 
     fn unmarshal_compressed_ec(
@@ -1799,7 +1906,17 @@ impl VMHooks for VMHooksDispatcher {
         data_offset: MemPtr,
         length: MemLength,
     ) -> i32 {
-        panic!("Unavailable: unmarshal_compressed_ec")
+        unsafe {
+            mem_conv::with_bytes(data_offset, length, |data| {
+                self.handler().ec_unmarshal_compressed(
+                    x_result_handle,
+                    y_result_handle,
+                    ec_handle,
+                    data,
+                );
+            })
+        }
+        0
     }
 
     fn managed_unmarshal_compressed_ec(
@@ -1809,7 +1926,10 @@ impl VMHooks for VMHooksDispatcher {
         ec_handle: i32,
         data_handle: i32,
     ) -> i32 {
-        panic!("Unavailable: managed_unmarshal_compressed_ec")
+        let data = self.handler().m_types_lock().mb_get(data_handle).to_vec();
+        self.handler()
+            .ec_unmarshal_compressed(x_result_handle, y_result_handle, ec_handle, &data);
+        0
     }
 
     fn generate_key_ec(
@@ -1819,7 +1939,15 @@ impl VMHooks for VMHooksDispatcher {
         ec_handle: i32,
         result_offset: MemPtr,
     ) -> i32 {
-        panic!("Unavailable: generate_key_ec")
+        let private_key =
+            self.handler()
+                .ec_generate_key(x_pub_key_handle, y_pub_key_handle, ec_handle);
+        unsafe {
+            mem_conv::with_bytes_mut(result_offset, private_key.len() as MemLength, |dest| {
+                dest.copy_from_slice(&private_key);
+            })
+        }
+        0
     }
 
     fn managed_generate_key_ec(
@@ -1829,23 +1957,31 @@ impl VMHooks for VMHooksDispatcher {
         ec_handle: i32,
         result_handle: i32,
     ) -> i32 {
-        panic!("Unavailable: managed_generate_key_ec")
+        let private_key =
+            self.handler()
+                .ec_generate_key(x_pub_key_handle, y_pub_key_handle, ec_handle);
+        self.handler().mb_set(result_handle, &private_key);
+        0
     }
 
     fn create_ec(&self, data_offset: MemPtr, data_length: MemLength) -> i32 {
-        panic!("Unavailable: create_ec")
+        unsafe {
+            mem_conv::with_bytes(data_offset, data_length, |name| {
+                self.handler().ec_create_from_name_bytes(name)
+            })
+        }
     }
 
     fn managed_create_ec(&self, data_handle: i32) -> i32 {
-        panic!("Unavailable: managed_create_ec")
+        self.handler().ec_create_from_name_mb(data_handle)
     }
 
     fn get_curve_length_ec(&self, ec_handle: i32) -> i32 {
-        panic!("Unavailable: get_curve_length_ec")
+        self.handler().ec_curve_length(ec_handle) as i32
     }
 
     fn get_priv_key_byte_length_ec(&self, ec_handle: i32) -> i32 {
-        panic!("Unavailable: get_priv_key_byte_length_ec")
+        self.handler().ec_private_key_byte_length(ec_handle) as i32
     }
 
     fn elliptic_curve_get_values(
@@ -1857,6 +1993,14 @@ impl VMHooks for VMHooksDispatcher {
         x_base_point_handle: i32,
         y_base_point_handle: i32,
     ) -> i32 {
-        panic!("Unavailable: elliptic_curve_get_values")
+        self.handler().ec_get_values(
+            ec_handle,
+            field_order_handle,
+            base_point_order_handle,
+            eq_constant_handle,
+            x_base_point_handle,
+            y_base_point_handle,
+        );
+        0
     }
 }
@@ -4,6 +4,7 @@ mod vh_crypto;
 mod vh_endpoint_arg;
 mod vh_endpoint_finish;
 mod vh_error;
+mod vh_gas;
 mod vh_log;
 mod vh_managed_types;
 mod vh_send;
@@ -15,9 +16,11 @@ pub use vh_crypto::VMHooksCrypto;
 pub use vh_endpoint_arg::VMHooksEndpointArgument;
 pub use vh_endpoint_finish::VMHooksEndpointFinish;
 pub use vh_error::{VMHooksError, VMHooksErrorManaged};
+pub use vh_gas::VMHooksGas;
 pub use vh_log::VMHooksLog;
 pub use vh_managed_types::{
-    VMHooksBigFloat, VMHooksBigInt, VMHooksManagedBuffer, VMHooksManagedMap, VMHooksManagedTypes,
+    VMHooksBigFloat, VMHooksBigInt, VMHooksEllipticCurve, VMHooksManagedBuffer, VMHooksManagedMap,
+    VMHooksManagedTypes,
 };
 pub use vh_send::VMHooksSend;
 pub use vh_storage::{VMHooksStorageRead, VMHooksStorageWrite};
@@ -36,5 +39,6 @@ pub trait VMHooksHandler:
     + VMHooksBlockchain
     + VMHooksLog
     + VMHooksSend
+    + VMHooksGas
 {
 }
@@ -1,7 +1,7 @@
 use crate::{
     tx_execution::vm_builtin_function_names::*,
     types::{DcdtLocalRole, DcdtLocalRoleFlags, RawHandle, VMAddress},
-    vm_hooks::VMHooksHandlerSource,
+    vm_hooks::{VMHooksGas, VMHooksHandlerSource},
     world_mock::{DcdtData, DcdtInstance},
 };
 use num_bigint::BigInt;
@@ -28,7 +28,7 @@ const VM_BUILTIN_FUNCTION_NAMES: [&str; 16] = [
     UPGRADE_CONTRACT_FUNC_NAME,
 ];
 
-pub trait VMHooksBlockchain: VMHooksHandlerSource {
+pub trait VMHooksBlockchain: VMHooksHandlerSource + VMHooksGas {
     fn is_contract_address(&self, address_bytes: &[u8]) -> bool {
         let address = VMAddress::from_slice(address_bytes);
         &address == self.current_address()
@@ -77,7 +77,7 @@ pub trait VMHooksBlockchain: VMHooksHandlerSource {
     }
 
     fn get_gas_left(&self) -> u64 {
-        self.input_ref().gas_limit
+        self.gas_left()
     }
 
     fn get_block_timestamp(&self) -> u64 {
@@ -248,19 +248,24 @@ pub trait VMHooksBlockchain: VMHooksHandlerSource {
         &self,
         address_handle: RawHandle,
         token_id_handle: RawHandle,
-        _nonce: u64,
+        nonce: u64,
     ) -> bool {
         let address = VMAddress::from_slice(self.m_types_lock().mb_get(address_handle));
         let token_id_bytes = self.m_types_lock().mb_get(token_id_handle).to_vec();
         if let Some(account) = self.account_data(&address) {
-            if let Some(dcdt_data) = account.dcdt.get_by_identifier(token_id_bytes.as_slice()) {
-                return dcdt_data.frozen;
-            }
+            return account
+                .dcdt
+                .is_instance_frozen(token_id_bytes.as_slice(), nonce);
         }
 
         false
     }
 
+    fn check_dcdt_paused(&self, token_id_handle: RawHandle) -> bool {
+        let token_id_bytes = self.m_types_lock().mb_get(token_id_handle).to_vec();
+        self.dcdt_token_paused(token_id_bytes.as_slice())
+    }
+
     fn get_dcdt_local_roles_bits(&self, token_id_handle: RawHandle) -> u64 {
         let token_id_bytes = self.m_types_lock().mb_get(token_id_handle).to_vec();
         let account = self.current_account_data();
@@ -289,7 +294,7 @@ pub trait VMHooksBlockchain: VMHooksHandlerSource {
     ) {
         let mut m_types = self.m_types_lock();
         m_types.bi_overwrite(value_handle, instance.balance.clone().into());
-        if dcdt_data.frozen {
+        if dcdt_data.is_instance_frozen(instance.nonce) {
             m_types.mb_set(properties_handle, vec![1, 0]);
         } else {
             m_types.mb_set(properties_handle, vec![0, 0]);
@@ -19,6 +19,13 @@ pub trait VMHooksCrypto: VMHooksHandlerSource {
         types.mb_set(dest, result_bytes[..].to_vec());
     }
 
+    fn ripemd160_managed(&self, dest: RawHandle, data_handle: RawHandle) {
+        let mut types = self.m_types_lock();
+        let data = types.mb_get(data_handle);
+        let result_bytes = crypto_functions::ripemd160(data);
+        types.mb_set(dest, result_bytes[..].to_vec());
+    }
+
     /// Should crash if the signature is invalid.
     fn verify_ed25519_managed(&self, key: RawHandle, message: RawHandle, signature: RawHandle) {
         let types = self.m_types_lock();
@@ -30,4 +37,68 @@ pub trait VMHooksCrypto: VMHooksHandlerSource {
             self.vm_error("invalid signature");
         }
     }
+
+    /// Should crash if the signature is invalid.
+    fn verify_bls_managed(&self, key: RawHandle, message: RawHandle, signature: RawHandle) {
+        let types = self.m_types_lock();
+        let key = types.mb_get(key);
+        let message = types.mb_get(message);
+        let signature = types.mb_get(signature);
+        let sig_valid = crypto_functions::verify_bls(key, message, signature);
+        if !sig_valid {
+            self.vm_error("signature is invalid");
+        }
+    }
+
+    /// A signature share is verified just like a regular BLS signature,
+    /// against the public key of the signer.
+    ///
+    /// Should crash if the signature is invalid.
+    fn verify_bls_signature_share_managed(
+        &self,
+        key: RawHandle,
+        message: RawHandle,
+        signature: RawHandle,
+    ) {
+        self.verify_bls_managed(key, message, signature)
+    }
+
+    /// Should crash if the signature is invalid.
+    fn verify_secp256k1_managed(&self, key: RawHandle, message: RawHandle, signature: RawHandle) {
+        let types = self.m_types_lock();
+        let key = types.mb_get(key);
+        let message = types.mb_get(message);
+        let signature = types.mb_get(signature);
+        let sig_valid = crypto_functions::verify_secp256k1(key, message, signature);
+        if !sig_valid {
+            self.vm_error("invalid signature");
+        }
+    }
+
+    /// Should crash if the signature is invalid, or if the hash type is unknown.
+    fn verify_custom_secp256k1_managed(
+        &self,
+        key: RawHandle,
+        message: RawHandle,
+        signature: RawHandle,
+        hash_type: i32,
+    ) {
+        let sig_valid = u8::try_from(hash_type).is_ok_and(|hash_type| {
+            let types = self.m_types_lock();
+            let key = types.mb_get(key);
+            let message = types.mb_get(message);
+            let signature = types.mb_get(signature);
+            crypto_functions::verify_custom_secp256k1(key, message, signature, hash_type)
+        });
+        if !sig_valid {
+            self.vm_error("invalid signature");
+        }
+    }
+
+    fn encode_secp256k1_der_signature_managed(&self, r: RawHandle, s: RawHandle, dest: RawHandle) {
+        let mut types = self.m_types_lock();
+        let signature =
+            crypto_functions::encode_secp256k1_der_signature(types.mb_get(r), types.mb_get(s));
+        types.mb_set(dest, signature);
+    }
 }
@@ -0,0 +1,45 @@
+use crate::{vm_err_msg, vm_hooks::VMHooksHandlerSource};
+
+/// Gas metering, only active when the VM was configured with a gas schedule.
+pub trait VMHooksGas: VMHooksHandlerSource {
+    fn gas_used(&self) -> u64 {
+        if self.gas_schedule().is_none() {
+            return 0;
+        }
+        self.result_lock().gas_used
+    }
+
+    fn gas_left(&self) -> u64 {
+        self.input_ref().gas_limit.saturating_sub(self.gas_used())
+    }
+
+    /// Charges gas for the current transaction, halts execution if the gas limit gets exceeded.
+    fn use_gas(&self, gas: u64) {
+        let mut tx_result = self.result_lock();
+        tx_result.gas_used += gas;
+        let out_of_gas = tx_result.gas_used > self.input_ref().gas_limit;
+        drop(tx_result); // halting also needs the lock
+
+        if out_of_gas {
+            self.halt_with_error(5, vm_err_msg::NOT_ENOUGH_GAS);
+        }
+    }
+
+    fn use_gas_for_vm_hook(&self) {
+        if let Some(gas_schedule) = self.gas_schedule() {
+            self.use_gas(gas_schedule.vm_hook_call);
+        }
+    }
+
+    fn use_gas_for_storage_load(&self, num_bytes: usize) {
+        if let Some(gas_schedule) = self.gas_schedule() {
+            self.use_gas(gas_schedule.storage_load_cost(num_bytes));
+        }
+    }
+
+    fn use_gas_for_storage_store(&self, num_bytes: usize) {
+        if let Some(gas_schedule) = self.gas_schedule() {
+            self.use_gas(gas_schedule.storage_store_cost(num_bytes));
+        }
+    }
+}
@@ -0,0 +1,218 @@
+use num_bigint::BigInt;
+
+use crate::{
+    crypto_functions::{EcPoint, EllipticCurve},
+    types::RawHandle,
+    vm_err_msg,
+    vm_hooks::VMHooksHandlerSource,
+};
+
+/// Elliptic curve operations, the curves are identified by handles, just like any other managed type.
+///
+/// Supports the same curves as the Go VM: P-224, P-256, P-384 and P-521.
+pub trait VMHooksEllipticCurve: VMHooksHandlerSource {
+    fn ec_create_from_name_bytes(&self, name: &[u8]) -> RawHandle {
+        let Some(curve) = EllipticCurve::from_name(name) else {
+            self.vm_error(vm_err_msg::EC_CURVE_NOT_SUPPORTED);
+        };
+        self.m_types_lock().ec_new(curve)
+    }
+
+    fn ec_create_from_name_mb(&self, name_handle: RawHandle) -> RawHandle {
+        let name = self.m_types_lock().mb_get(name_handle).to_vec();
+        self.ec_create_from_name_bytes(&name)
+    }
+
+    fn ec_get_values(
+        &self,
+        ec_handle: RawHandle,
+        field_order_handle: RawHandle,
+        base_point_order_handle: RawHandle,
+        eq_constant_handle: RawHandle,
+        x_base_point_handle: RawHandle,
+        y_base_point_handle: RawHandle,
+    ) {
+        let curve = self.ec_get(ec_handle);
+        let mut types = self.m_types_lock();
+        types.bi_overwrite(field_order_handle, BigInt::from(curve.p));
+        types.bi_overwrite(base_point_order_handle, BigInt::from(curve.n));
+        types.bi_overwrite(eq_constant_handle, BigInt::from(curve.b));
+        types.bi_overwrite(x_base_point_handle, BigInt::from(curve.gx));
+        types.bi_overwrite(y_base_point_handle, BigInt::from(curve.gy));
+    }
+
+    fn ec_curve_length(&self, ec_handle: RawHandle) -> u32 {
+        self.ec_get(ec_handle).bit_size
+    }
+
+    fn ec_private_key_byte_length(&self, ec_handle: RawHandle) -> u32 {
+        self.ec_get(ec_handle).private_key_byte_len() as u32
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn ec_add(
+        &self,
+        x_result_handle: RawHandle,
+        y_result_handle: RawHandle,
+        ec_handle: RawHandle,
+        first_point_x_handle: RawHandle,
+        first_point_y_handle: RawHandle,
+        second_point_x_handle: RawHandle,
+        second_point_y_handle: RawHandle,
+    ) {
+        let curve = self.ec_get(ec_handle);
+        let (x1, y1) =
+            self.ec_get_point_on_curve(&curve, first_point_x_handle, first_point_y_handle);
+        let (x2, y2) =
+            self.ec_get_point_on_curve(&curve, second_point_x_handle, second_point_y_handle);
+        let result = curve.add(&x1, &y1, &x2, &y2);
+        self.ec_set_point(x_result_handle, y_result_handle, result);
+    }
+
+    fn ec_double(
+        &self,
+        x_result_handle: RawHandle,
+        y_result_handle: RawHandle,
+        ec_handle: RawHandle,
+        point_x_handle: RawHandle,
+        point_y_handle: RawHandle,
+    ) {
+        let curve = self.ec_get(ec_handle);
+        let (x, y) = self.ec_get_point_on_curve(&curve, point_x_handle, point_y_handle);
+        let result = curve.double(&x, &y);
+        self.ec_set_point(x_result_handle, y_result_handle, result);
+    }
+
+    fn ec_is_on_curve(
+        &self,
+        ec_handle: RawHandle,
+        point_x_handle: RawHandle,
+        point_y_handle: RawHandle,
+    ) -> bool {
+        let curve = self.ec_get(ec_handle);
+        let types = self.m_types_lock();
+        let x = types.bi_get(point_x_handle);
+        let y = types.bi_get(point_y_handle);
+        match (x.to_biguint(), y.to_biguint()) {
+            (Some(x), Some(y)) => curve.is_on_curve(&x, &y),
+            _ => false,
+        }
+    }
+
+    fn ec_scalar_base_mult(
+        &self,
+        x_result_handle: RawHandle,
+        y_result_handle: RawHandle,
+        ec_handle: RawHandle,
+        scalar: &[u8],
+    ) {
+        let curve = self.ec_get(ec_handle);
+        let result = curve.scalar_base_mult(scalar);
+        self.ec_set_point(x_result_handle, y_result_handle, result);
+    }
+
+    fn ec_scalar_mult(
+        &self,
+        x_result_handle: RawHandle,
+        y_result_handle: RawHandle,
+        ec_handle: RawHandle,
+        point_x_handle: RawHandle,
+        point_y_handle: RawHandle,
+        scalar: &[u8],
+    ) {
+        let curve = self.ec_get(ec_handle);
+        let (x, y) = self.ec_get_point_on_curve(&curve, point_x_handle, point_y_handle);
+        let result = curve.scalar_mult(&x, &y, scalar);
+        self.ec_set_point(x_result_handle, y_result_handle, result);
+    }
+
+    fn ec_marshal(
+        &self,
+        ec_handle: RawHandle,
+        point_x_handle: RawHandle,
+        point_y_handle: RawHandle,
+    ) -> Vec<u8> {
+        let curve = self.ec_get(ec_handle);
+        let (x, y) = self.ec_get_point_on_curve(&curve, point_x_handle, point_y_handle);
+        curve.marshal(&x, &y)
+    }
+
+    fn ec_marshal_compressed(
+        &self,
+        ec_handle: RawHandle,
+        point_x_handle: RawHandle,
+        point_y_handle: RawHandle,
+    ) -> Vec<u8> {
+        let curve = self.ec_get(ec_handle);
+        let (x, y) = self.ec_get_point_on_curve(&curve, point_x_handle, point_y_handle);
+        curve.marshal_compressed(&x, &y)
+    }
+
+    fn ec_unmarshal(
+        &self,
+        x_result_handle: RawHandle,
+        y_result_handle: RawHandle,
+        ec_handle: RawHandle,
+        data: &[u8],
+    ) {
+        let curve = self.ec_get(ec_handle);
+        let Some(point) = curve.unmarshal(data) else {
+            self.vm_error(vm_err_msg::EC_INVALID_POINT_ENCODING);
+        };
+        self.ec_set_point(x_result_handle, y_result_handle, point);
+    }
+
+    fn ec_unmarshal_compressed(
+        &self,
+        x_result_handle: RawHandle,
+        y_result_handle: RawHandle,
+        ec_handle: RawHandle,
+        data: &[u8],
+    ) {
+        let curve = self.ec_get(ec_handle);
+        let Some(point) = curve.unmarshal_compressed(data) else {
+            self.vm_error(vm_err_msg::EC_INVALID_POINT_ENCODING);
+        };
+        self.ec_set_point(x_result_handle, y_result_handle, point);
+    }
+
+    /// Saves the public key in the given handles and returns the private key bytes.
+    fn ec_generate_key(
+        &self,
+        x_pub_key_handle: RawHandle,
+        y_pub_key_handle: RawHandle,
+        ec_handle: RawHandle,
+    ) -> Vec<u8> {
+        let curve = self.ec_get(ec_handle);
+        let (private_key, public_key) = curve.generate_key(|length| self.random_next_bytes(length));
+        self.ec_set_point(x_pub_key_handle, y_pub_key_handle, public_key);
+        private_key
+    }
+
+    fn ec_get(&self, ec_handle: RawHandle) -> EllipticCurve {
+        self.m_types_lock().ec_get(ec_handle).clone()
+    }
+
+    fn ec_get_point_on_curve(
+        &self,
+        curve: &EllipticCurve,
+        point_x_handle: RawHandle,
+        point_y_handle: RawHandle,
+    ) -> EcPoint {
+        let types = self.m_types_lock();
+        let x = types.bi_get(point_x_handle).to_biguint();
+        let y = types.bi_get(point_y_handle).to_biguint();
+        drop(types); // halting also needs the lock
+
+        match (x, y) {
+            (Some(x), Some(y)) if curve.is_on_curve(&x, &y) => (x, y),
+            _ => self.vm_error(vm_err_msg::EC_POINT_NOT_ON_CURVE),
+        }
+    }
+
+    fn ec_set_point(&self, x_handle: RawHandle, y_handle: RawHandle, (x, y): EcPoint) {
+        let mut types = self.m_types_lock();
+        types.bi_overwrite(x_handle, BigInt::from(x));
+        types.bi_overwrite(y_handle, BigInt::from(y));
+    }
+}
@@ -5,7 +5,7 @@ use crate::{
     },
     tx_mock::{AsyncCallTxData, Promise, TxFunctionName, TxTokenTransfer},
     types::{top_encode_big_uint, top_encode_u64, RawHandle, VMAddress, VMCodeMetadata},
-    vm_hooks::VMHooksHandlerSource,
+    vm_hooks::{VMHooksGas, VMHooksHandlerSource},
 };
 use num_traits::Zero;
 
@@ -20,7 +20,7 @@ fn append_endpoint_name_and_args(
     }
 }
 
-pub trait VMHooksSend: VMHooksHandlerSource {
+pub trait VMHooksSend: VMHooksHandlerSource + VMHooksGas {
     fn perform_transfer_execute_dcdt(
         &self,
         to: VMAddress,
@@ -226,6 +226,7 @@ pub trait VMHooksSend: VMHooksHandlerSource {
             endpoint_name,
             arguments: arg_buffer,
             tx_hash,
+            gas_limit: self.gas_left(),
         };
 
         let promise = Promise {
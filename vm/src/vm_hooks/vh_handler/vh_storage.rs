@@ -3,11 +3,13 @@ use crate::{
     vm_hooks::VMHooksHandlerSource,
 };
 
-use super::VMHooksManagedTypes;
+use super::{VMHooksGas, VMHooksManagedTypes};
 
-pub trait VMHooksStorageRead: VMHooksHandlerSource {
+pub trait VMHooksStorageRead: VMHooksHandlerSource + VMHooksGas {
     fn storage_load_managed_buffer_raw(&self, key_handle: RawHandle, dest: RawHandle) {
-        let value = self.storage_read(self.m_types_lock().mb_get(key_handle));
+        let key = self.m_types_lock().mb_get(key_handle).to_vec();
+        let value = self.storage_read(&key);
+        self.use_gas_for_storage_load(key.len() + value.len());
         self.m_types_lock().mb_set(dest, value);
     }
 
@@ -18,16 +20,20 @@ pub trait VMHooksStorageRead: VMHooksHandlerSource {
         dest: RawHandle,
     ) {
         let address = VMAddress::from_slice(self.m_types_lock().mb_get(address_handle));
-        let value = self.storage_read_any_address(&address, self.m_types_lock().mb_get(key_handle));
+        let key = self.m_types_lock().mb_get(key_handle).to_vec();
+        let value = self.storage_read_any_address(&address, &key);
+        self.use_gas_for_storage_load(key.len() + value.len());
         self.m_types_lock().mb_set(dest, value);
     }
 }
 
-pub trait VMHooksStorageWrite: VMHooksHandlerSource + VMHooksManagedTypes {
+pub trait VMHooksStorageWrite: VMHooksHandlerSource + VMHooksManagedTypes + VMHooksGas {
     fn storage_store_managed_buffer_raw(&self, key_handle: RawHandle, value_handle: RawHandle) {
         let types = self.m_types_lock();
-        let key_bytes = types.mb_get(key_handle);
-        let value_bytes = types.mb_get(value_handle);
-        self.storage_write(key_bytes, value_bytes);
+        let key_bytes = types.mb_get(key_handle).to_vec();
+        let value_bytes = types.mb_get(value_handle).to_vec();
+        drop(types); // charging gas also needs the lock when halting
+        self.use_gas_for_storage_store(value_bytes.len());
+        self.storage_write(&key_bytes, &value_bytes);
     }
 }
@@ -3,7 +3,7 @@ use std::sync::{Arc, MutexGuard};
 use dharitri_vm_executor::BreakpointValue;
 
 use crate::{
-    tx_execution::execute_current_tx_context_input,
+    tx_execution::{execute_current_tx_context_input, GasSchedule},
     tx_mock::{
         async_call_tx_input, AsyncCallTxData, BackTransfers, BlockchainUpdate, CallType, TxCache,
         TxContext, TxFunctionName, TxInput, TxManagedTypes, TxPanic, TxResult,
@@ -12,9 +12,10 @@ use crate::{
     vm_err_msg,
     vm_hooks::{
         VMHooksBigFloat, VMHooksBigInt, VMHooksBlockchain, VMHooksCallValue, VMHooksCrypto,
-        VMHooksEndpointArgument, VMHooksEndpointFinish, VMHooksError, VMHooksErrorManaged,
-        VMHooksHandler, VMHooksHandlerSource, VMHooksLog, VMHooksManagedBuffer, VMHooksManagedMap,
-        VMHooksManagedTypes, VMHooksSend, VMHooksStorageRead, VMHooksStorageWrite,
+        VMHooksEllipticCurve, VMHooksEndpointArgument, VMHooksEndpointFinish, VMHooksError,
+        VMHooksErrorManaged, VMHooksGas, VMHooksHandler, VMHooksHandlerSource, VMHooksLog,
+        VMHooksManagedBuffer, VMHooksManagedMap, VMHooksManagedTypes, VMHooksSend,
+        VMHooksStorageRead, VMHooksStorageWrite,
     },
     world_mock::{reserved::STORAGE_RESERVED_PREFIX, AccountData, BlockInfo},
 };
@@ -40,6 +41,7 @@ impl VMHooksHandlerSource for DebugApiVMHooksHandler {
         *self.0.result_lock() = TxResult::from_panic_obj(&TxPanic::new(status, message));
         let breakpoint = match status {
             4 => BreakpointValue::SignalError,
+            5 => BreakpointValue::OutOfGas,
             _ => BreakpointValue::ExecutionFailed,
         };
         std::panic::panic_any(breakpoint);
@@ -49,6 +51,10 @@ impl VMHooksHandlerSource for DebugApiVMHooksHandler {
         self.0.input_ref()
     }
 
+    fn gas_schedule(&self) -> Option<&GasSchedule> {
+        self.0.vm_ref.gas_schedule.as_ref()
+    }
+
     fn random_next_bytes(&self, length: usize) -> Vec<u8> {
         self.0.rng_lock().next_bytes(length)
     }
@@ -59,7 +65,11 @@ impl VMHooksHandlerSource for DebugApiVMHooksHandler {
 
     fn storage_read_any_address(&self, address: &VMAddress, key: &[u8]) -> Vec<u8> {
         self.0.with_account_mut(address, |account| {
-            account.storage.get(key).cloned().unwrap_or_default()
+            account
+                .storage
+                .get(key)
+                .cloned()
+                .unwrap_or_else(|| self.0.blockchain_ref().fetch_fork_storage(address, key))
         })
     }
 
@@ -95,6 +105,12 @@ impl VMHooksHandlerSource for DebugApiVMHooksHandler {
             .unwrap_or_else(|| panic!("Account is not a smart contract, it has no code"))
     }
 
+    fn dcdt_token_paused(&self, token_identifier: &[u8]) -> bool {
+        self.0
+            .blockchain_cache()
+            .is_dcdt_token_paused(token_identifier)
+    }
+
     fn perform_async_call(
         &self,
         to: VMAddress,
@@ -102,11 +118,15 @@ impl VMHooksHandlerSource for DebugApiVMHooksHandler {
         func_name: TxFunctionName,
         arguments: Vec<Vec<u8>>,
     ) -> ! {
+        if self.input_ref().func_name == TxFunctionName::INIT {
+            self.vm_error(vm_err_msg::ASYNC_CALL_NOT_ALLOWED_IN_INIT);
+        }
+
         let async_call_data = self.create_async_call_data(to, rewa_value, func_name, arguments);
         // the cell is no longer needed, since we end in a panic
         let mut tx_result = self.result_lock();
         tx_result.all_calls.push(async_call_data.clone());
-        tx_result.pending_calls.async_call = Some(async_call_data);
+        tx_result.pending_calls.async_calls.push(async_call_data);
         drop(tx_result); // this avoid to poison the mutex
         std::panic::panic_any(BreakpointValue::AsyncCall);
     }
@@ -151,7 +171,7 @@ impl VMHooksHandlerSource for DebugApiVMHooksHandler {
             dcdt_values: Vec::new(),
             func_name: TxFunctionName::EMPTY,
             args,
-            gas_limit: 1000,
+            gas_limit: self.gas_left(),
             gas_price: 0,
             tx_hash,
             ..Default::default()
@@ -226,6 +246,7 @@ impl DebugApiVMHooksHandler {
             endpoint_name: func_name,
             arguments,
             tx_hash,
+            gas_limit: self.gas_left(),
         }
     }
 
@@ -268,6 +289,7 @@ impl VMHooksBigInt for DebugApiVMHooksHandler {}
 impl VMHooksManagedBuffer for DebugApiVMHooksHandler {}
 impl VMHooksManagedMap for DebugApiVMHooksHandler {}
 impl VMHooksBigFloat for DebugApiVMHooksHandler {}
+impl VMHooksEllipticCurve for DebugApiVMHooksHandler {}
 impl VMHooksManagedTypes for DebugApiVMHooksHandler {}
 
 impl VMHooksCallValue for DebugApiVMHooksHandler {}
@@ -281,5 +303,6 @@ impl VMHooksCrypto for DebugApiVMHooksHandler {}
 impl VMHooksBlockchain for DebugApiVMHooksHandler {}
 impl VMHooksLog for DebugApiVMHooksHandler {}
 impl VMHooksSend for DebugApiVMHooksHandler {}
+impl VMHooksGas for DebugApiVMHooksHandler {}
 
 impl VMHooksHandler for DebugApiVMHooksHandler {}
@@ -4,13 +4,15 @@ use std::{
 };
 
 use crate::{
+    tx_execution::GasSchedule,
     tx_mock::{BackTransfers, TxFunctionName, TxInput, TxManagedTypes, TxResult},
     types::{VMAddress, VMCodeMetadata},
     vm_hooks::{
         VMHooksBigFloat, VMHooksBigInt, VMHooksBlockchain, VMHooksCallValue, VMHooksCrypto,
-        VMHooksEndpointArgument, VMHooksEndpointFinish, VMHooksError, VMHooksErrorManaged,
-        VMHooksHandler, VMHooksHandlerSource, VMHooksLog, VMHooksManagedBuffer, VMHooksManagedMap,
-        VMHooksManagedTypes, VMHooksSend, VMHooksStorageRead, VMHooksStorageWrite,
+        VMHooksEllipticCurve, VMHooksEndpointArgument, VMHooksEndpointFinish, VMHooksError,
+        VMHooksErrorManaged, VMHooksGas, VMHooksHandler, VMHooksHandlerSource, VMHooksLog,
+        VMHooksManagedBuffer, VMHooksManagedMap, VMHooksManagedTypes, VMHooksSend,
+        VMHooksStorageRead, VMHooksStorageWrite,
     },
     world_mock::{AccountData, BlockInfo},
 };
@@ -65,6 +67,10 @@ impl VMHooksHandlerSource for SingleTxApiVMHooksHandler {
         &self.0.tx_input_box
     }
 
+    fn gas_schedule(&self) -> Option<&GasSchedule> {
+        None
+    }
+
     fn random_next_bytes(&self, _length: usize) -> Vec<u8> {
         panic!("cannot access the random bytes generator in the SingleTxApi")
     }
@@ -105,6 +111,10 @@ impl VMHooksHandlerSource for SingleTxApiVMHooksHandler {
         vec![]
     }
 
+    fn dcdt_token_paused(&self, _token_identifier: &[u8]) -> bool {
+        false
+    }
+
     fn perform_async_call(
         &self,
         _to: VMAddress,
@@ -150,6 +160,7 @@ impl VMHooksBigInt for SingleTxApiVMHooksHandler {}
 impl VMHooksManagedBuffer for SingleTxApiVMHooksHandler {}
 impl VMHooksManagedMap for SingleTxApiVMHooksHandler {}
 impl VMHooksBigFloat for SingleTxApiVMHooksHandler {}
+impl VMHooksEllipticCurve for SingleTxApiVMHooksHandler {}
 impl VMHooksManagedTypes for SingleTxApiVMHooksHandler {}
 
 impl VMHooksCallValue for SingleTxApiVMHooksHandler {}
@@ -163,5 +174,6 @@ impl VMHooksCrypto for SingleTxApiVMHooksHandler {}
 impl VMHooksBlockchain for SingleTxApiVMHooksHandler {}
 impl VMHooksLog for SingleTxApiVMHooksHandler {}
 impl VMHooksSend for SingleTxApiVMHooksHandler {}
+impl VMHooksGas for SingleTxApiVMHooksHandler {}
 
 impl VMHooksHandler for SingleTxApiVMHooksHandler {}
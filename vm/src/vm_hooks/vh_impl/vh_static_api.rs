@@ -1,13 +1,15 @@
 use std::sync::{Mutex, MutexGuard};
 
 use crate::{
+    tx_execution::GasSchedule,
     tx_mock::{BackTransfers, TxFunctionName, TxInput, TxLog, TxManagedTypes, TxResult},
     types::{VMAddress, VMCodeMetadata},
     vm_hooks::{
         VMHooksBigFloat, VMHooksBigInt, VMHooksBlockchain, VMHooksCallValue, VMHooksCrypto,
-        VMHooksEndpointArgument, VMHooksEndpointFinish, VMHooksError, VMHooksErrorManaged,
-        VMHooksHandler, VMHooksHandlerSource, VMHooksLog, VMHooksManagedBuffer, VMHooksManagedMap,
-        VMHooksManagedTypes, VMHooksSend, VMHooksStorageRead, VMHooksStorageWrite,
+        VMHooksEllipticCurve, VMHooksEndpointArgument, VMHooksEndpointFinish, VMHooksError,
+        VMHooksErrorManaged, VMHooksGas, VMHooksHandler, VMHooksHandlerSource, VMHooksLog,
+        VMHooksManagedBuffer, VMHooksManagedMap, VMHooksManagedTypes, VMHooksSend,
+        VMHooksStorageRead, VMHooksStorageWrite,
     },
     world_mock::{AccountData, BlockInfo},
 };
@@ -36,6 +38,10 @@ impl VMHooksHandlerSource for StaticApiVMHooksHandler {
         panic!("cannot access tx inputs in the StaticApi")
     }
 
+    fn gas_schedule(&self) -> Option<&GasSchedule> {
+        None
+    }
+
     fn current_address(&self) -> &VMAddress {
         &Self::CURRENT_ADDRESS_PLACEHOLDER
     }
@@ -80,6 +86,10 @@ impl VMHooksHandlerSource for StaticApiVMHooksHandler {
         panic!("cannot access account data in the StaticApi")
     }
 
+    fn dcdt_token_paused(&self, _token_identifier: &[u8]) -> bool {
+        panic!("cannot access token data in the StaticApi")
+    }
+
     fn perform_async_call(
         &self,
         _to: VMAddress,
@@ -125,6 +135,7 @@ impl VMHooksBigInt for StaticApiVMHooksHandler {}
 impl VMHooksManagedBuffer for StaticApiVMHooksHandler {}
 impl VMHooksManagedMap for StaticApiVMHooksHandler {}
 impl VMHooksBigFloat for StaticApiVMHooksHandler {}
+impl VMHooksEllipticCurve for StaticApiVMHooksHandler {}
 impl VMHooksManagedTypes for StaticApiVMHooksHandler {}
 
 impl VMHooksCallValue for StaticApiVMHooksHandler {}
@@ -138,5 +149,6 @@ impl VMHooksCrypto for StaticApiVMHooksHandler {}
 impl VMHooksBlockchain for StaticApiVMHooksHandler {}
 impl VMHooksLog for StaticApiVMHooksHandler {}
 impl VMHooksSend for StaticApiVMHooksHandler {}
+impl VMHooksGas for StaticApiVMHooksHandler {}
 
 impl VMHooksHandler for StaticApiVMHooksHandler {}
@@ -1,6 +1,7 @@
 use std::{fmt::Debug, sync::MutexGuard};
 
 use crate::{
+    tx_execution::GasSchedule,
     tx_mock::{BackTransfers, TxFunctionName, TxInput, TxLog, TxManagedTypes, TxResult},
     types::{VMAddress, VMCodeMetadata, H256},
     world_mock::{AccountData, BlockInfo},
@@ -18,6 +19,9 @@ pub trait VMHooksHandlerSource: Debug {
 
     fn input_ref(&self) -> &TxInput;
 
+    /// Gas is only metered if a gas schedule is configured.
+    fn gas_schedule(&self) -> Option<&GasSchedule>;
+
     fn current_address(&self) -> &VMAddress {
         &self.input_ref().to
     }
@@ -64,6 +68,9 @@ pub trait VMHooksHandlerSource: Debug {
 
     fn account_code(&self, address: &VMAddress) -> Vec<u8>;
 
+    /// Global token state, as kept by the DCDT system SC.
+    fn dcdt_token_paused(&self, token_identifier: &[u8]) -> bool;
+
     fn perform_async_call(
         &self,
         to: VMAddress,
@@ -8,16 +8,22 @@ mod dcdt_instance;
 mod dcdt_instance_metadata;
 mod dcdt_instances;
 mod dcdt_roles;
+mod dcdt_token_data;
+mod dcdt_token_properties;
 mod failing_executor;
+mod fork_source;
 pub mod reserved;
 
 pub use account_data::*;
 pub use block_info::*;
 pub use blockchain_mock::*;
-pub use blockchain_state::BlockchainState;
+pub use blockchain_state::{BlockchainState, BlockchainStateSnapshot};
 pub use dcdt_data::*;
 pub use dcdt_instance::*;
 pub use dcdt_instance_metadata::*;
 pub use dcdt_instances::*;
 pub use dcdt_roles::*;
+pub use dcdt_token_data::*;
+pub use dcdt_token_properties::*;
 pub use failing_executor::FailingExecutor;
+pub use fork_source::{ForkCache, ForkSource};
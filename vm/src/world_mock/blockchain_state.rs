@@ -1,10 +1,16 @@
 use num_bigint::BigUint;
 use num_traits::Zero;
-use std::{collections::HashMap, fmt::Debug};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Debug,
+    sync::Arc,
+};
 
 use crate::{tx_mock::BlockchainUpdate, types::VMAddress};
 
-use super::{reserved::STORAGE_REWARD_KEY, AccountData, BlockInfo};
+use super::{
+    reserved::STORAGE_REWARD_KEY, AccountData, BlockInfo, DcdtTokenData, ForkCache, ForkSource,
+};
 
 #[derive(Default, Clone)]
 pub struct BlockchainState {
@@ -13,18 +19,70 @@ pub struct BlockchainState {
     pub previous_block_info: BlockInfo,
     pub current_block_info: BlockInfo,
     pub new_token_identifiers: Vec<String>,
+    pub paused_dcdt_tokens: HashSet<Vec<u8>>,
+    pub dcdt_tokens: HashMap<Vec<u8>, DcdtTokenData>,
+    /// In fork mode, missing accounts and storage are loaded from here.
+    pub fork_cache: Option<Arc<ForkCache>>,
 }
 
+/// A full copy of the blockchain state, taken at some point during a test.
+///
+/// The random number generator is seeded from the block info and the tx hash,
+/// so restoring a snapshot also restores the random sequence.
+#[derive(Clone, Debug)]
+pub struct BlockchainStateSnapshot(BlockchainState);
+
 impl BlockchainState {
+    pub fn snapshot(&self) -> BlockchainStateSnapshot {
+        BlockchainStateSnapshot(self.clone())
+    }
+
+    /// Resets accounts, DCDT data, block info and new address expectations to the snapshot.
+    pub fn restore(&mut self, snapshot: &BlockchainStateSnapshot) {
+        self.clone_from(&snapshot.0);
+    }
+
     pub fn commit_updates(&mut self, updates: BlockchainUpdate) {
         updates.apply(self);
     }
 
+    pub fn set_fork_source(&mut self, fork_source: Arc<dyn ForkSource>) {
+        self.fork_cache = Some(Arc::new(ForkCache::new(fork_source)));
+    }
+
+    /// Retrieves the account from the fork source, only the first time it is requested.
+    ///
+    /// Does not add it to the local state.
+    pub fn fetch_fork_account(&self, address: &VMAddress) -> Option<AccountData> {
+        self.fork_cache
+            .as_ref()
+            .and_then(|fork_cache| fork_cache.account(address))
+    }
+
+    /// Storage value of an account loaded from the fork source, empty if not in fork mode.
+    pub fn fetch_fork_storage(&self, address: &VMAddress, key: &[u8]) -> Vec<u8> {
+        self.fork_cache
+            .as_ref()
+            .map(|fork_cache| fork_cache.storage(address, key))
+            .unwrap_or_default()
+    }
+
+    /// In fork mode, loads the account into the local state, if not already present.
+    pub fn load_fork_account_if_missing(&mut self, address: &VMAddress) {
+        if self.accounts.contains_key(address) {
+            return;
+        }
+        if let Some(account) = self.fetch_fork_account(address) {
+            self.accounts.insert(address.clone(), account);
+        }
+    }
+
     pub fn account_exists(&self, address: &VMAddress) -> bool {
         self.accounts.contains_key(address)
     }
 
     pub fn increase_account_nonce(&mut self, address: &VMAddress) {
+        self.load_fork_account_if_missing(address);
         let account = self.accounts.get_mut(address).unwrap_or_else(|| {
             panic!(
                 "Account not found: {}",
@@ -35,6 +93,7 @@ impl BlockchainState {
     }
 
     pub fn subtract_tx_gas(&mut self, address: &VMAddress, gas_limit: u64, gas_price: u64) {
+        self.load_fork_account_if_missing(address);
         let account = self.accounts.get_mut(address).unwrap_or_else(|| {
             panic!(
                 "Account not found: {}",
@@ -49,7 +108,14 @@ impl BlockchainState {
         account.rewa_balance -= &gas_cost;
     }
 
+    pub fn refund_tx_gas(&mut self, address: &VMAddress, gas_refund: u64, gas_price: u64) {
+        if let Some(account) = self.accounts.get_mut(address) {
+            account.rewa_balance += BigUint::from(gas_refund) * BigUint::from(gas_price);
+        }
+    }
+
     pub fn increase_validator_reward(&mut self, address: &VMAddress, amount: &BigUint) {
+        self.load_fork_account_if_missing(address);
         let account = self.accounts.get_mut(address).unwrap_or_else(|| {
             panic!(
                 "Account not found: {}",
@@ -80,6 +146,32 @@ impl BlockchainState {
     pub fn update_new_token_identifiers(&mut self, token_identifiers: Vec<String>) {
         self.new_token_identifiers = token_identifiers;
     }
+
+    pub fn set_dcdt_token_paused(&mut self, token_identifier: &[u8], paused: bool) {
+        if paused {
+            self.paused_dcdt_tokens.insert(token_identifier.to_vec());
+        } else {
+            self.paused_dcdt_tokens.remove(token_identifier);
+        }
+    }
+
+    pub fn is_dcdt_token_paused(&self, token_identifier: &[u8]) -> bool {
+        self.paused_dcdt_tokens.contains(token_identifier)
+    }
+
+    pub fn update_paused_dcdt_tokens(&mut self, paused_dcdt_tokens: HashMap<Vec<u8>, bool>) {
+        for (token_identifier, paused) in paused_dcdt_tokens {
+            self.set_dcdt_token_paused(&token_identifier, paused);
+        }
+    }
+
+    pub fn get_dcdt_token_data(&self, token_identifier: &[u8]) -> Option<&DcdtTokenData> {
+        self.dcdt_tokens.get(token_identifier)
+    }
+
+    pub fn update_dcdt_tokens(&mut self, dcdt_tokens: HashMap<Vec<u8>, DcdtTokenData>) {
+        self.dcdt_tokens.extend(dcdt_tokens);
+    }
 }
 
 impl Debug for BlockchainState {
@@ -88,6 +180,8 @@ impl Debug for BlockchainState {
             .field("accounts", &self.accounts)
             .field("new_addresses", &self.new_addresses)
             .field("current_block_info", &self.current_block_info)
+            .field("paused_dcdt_tokens", &self.paused_dcdt_tokens)
+            .field("dcdt_tokens", &self.dcdt_tokens)
             .finish()
     }
 }
@@ -28,6 +28,12 @@ impl DcdtData {
     pub fn get_roles(&self) -> Vec<Vec<u8>> {
         self.roles.get()
     }
+
+    /// An instance is frozen either if the whole token is frozen for the account,
+    /// or if that particular nonce was frozen.
+    pub fn is_instance_frozen(&self, nonce: u64) -> bool {
+        self.frozen || self.instances.is_frozen(nonce)
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -146,18 +152,64 @@ impl AccountDcdt {
             .attributes = new_attribute_bytes;
     }
 
+    /// Metadata of an existing token instance, `None` if the instance is not in the account.
+    pub fn get_instance_metadata_mut(
+        &mut self,
+        token_identifier: &[u8],
+        nonce: u64,
+    ) -> Option<&mut DcdtInstanceMetadata> {
+        self.get_mut_by_identifier(token_identifier)
+            .and_then(|dcdt_data| dcdt_data.instances.get_mut_by_nonce(nonce))
+            .map(|instance| &mut instance.metadata)
+    }
+
+    pub fn set_frozen(&mut self, token_identifier: &[u8], frozen: bool) {
+        let dcdt_data = self.0.entry(token_identifier.to_vec()).or_default();
+        dcdt_data.frozen = frozen;
+    }
+
+    pub fn set_instance_frozen(&mut self, token_identifier: &[u8], nonce: u64, frozen: bool) {
+        let dcdt_data = self.0.entry(token_identifier.to_vec()).or_default();
+        dcdt_data.instances.set_frozen(nonce, frozen);
+    }
+
+    pub fn is_instance_frozen(&self, token_identifier: &[u8], nonce: u64) -> bool {
+        self.get_by_identifier(token_identifier)
+            .map(|dcdt_data| dcdt_data.is_instance_frozen(nonce))
+            .unwrap_or_default()
+    }
+
+    /// Clears the balance of a token instance. Returns the amount that was wiped.
+    pub fn wipe(&mut self, token_identifier: &[u8], nonce: u64) -> BigUint {
+        if let Some(instance) = self
+            .get_mut_by_identifier(token_identifier)
+            .and_then(|dcdt_data| dcdt_data.instances.get_mut_by_nonce(nonce))
+        {
+            core::mem::take(&mut instance.balance)
+        } else {
+            BigUint::zero()
+        }
+    }
+
     pub fn iter(&self) -> Iter<Vec<u8>, DcdtData> {
         self.0.iter()
     }
 
     pub fn set_special_role(&mut self, token_identifier: &[u8], role: &[u8]) {
+        let dcdt_data = self.0.entry(token_identifier.to_vec()).or_default();
+        let roles = dcdt_data.roles.get();
+        if !roles.contains(role.to_vec().as_ref()) {
+            let mut new_roles = roles;
+            new_roles.push(role.to_vec());
+            dcdt_data.roles = DcdtRoles::new(new_roles);
+        }
+    }
+
+    pub fn unset_special_role(&mut self, token_identifier: &[u8], role: &[u8]) {
         if let Some(dcdt_data) = self.get_mut_by_identifier(token_identifier) {
-            let roles = dcdt_data.roles.get();
-            if !roles.contains(role.to_vec().as_ref()) {
-                let mut new_roles = roles;
-                new_roles.push(role.to_vec());
-                dcdt_data.roles = DcdtRoles::new(new_roles);
-            }
+            let mut roles = dcdt_data.roles.get();
+            roles.retain(|existing_role| existing_role.as_slice() != role);
+            dcdt_data.roles = DcdtRoles::new(roles);
         }
     }
 
@@ -9,6 +9,7 @@ pub struct DcdtInstance {
     pub nonce: u64,
     pub balance: BigUint,
     pub metadata: DcdtInstanceMetadata,
+    pub frozen: bool,
 }
 
 impl DcdtInstance {
@@ -17,6 +18,7 @@ impl DcdtInstance {
             nonce,
             balance: BigUint::zero(),
             metadata: DcdtInstanceMetadata::default(),
+            frozen: false,
         }
     }
 
@@ -25,10 +27,11 @@ impl DcdtInstance {
             nonce: 0,
             balance,
             metadata: DcdtInstanceMetadata::default(),
+            frozen: false,
         }
     }
 
     pub fn is_empty_dcdt(&self) -> bool {
-        self.balance.is_zero()
+        self.balance.is_zero() && !self.frozen
     }
 }
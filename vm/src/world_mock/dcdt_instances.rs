@@ -44,6 +44,7 @@ impl DcdtInstances {
             nonce,
             balance: BigUint::zero(),
             metadata: metadata.clone(),
+            frozen: false,
         });
         if instance.balance.is_zero() {
             instance.metadata = metadata;
@@ -65,9 +66,24 @@ impl DcdtInstances {
                 nonce,
                 balance: value.clone(),
                 metadata,
+                frozen: false,
             });
     }
 
+    pub fn set_frozen(&mut self, nonce: u64, frozen: bool) {
+        self.0
+            .entry(nonce)
+            .or_insert_with(|| DcdtInstance::default(nonce))
+            .frozen = frozen;
+    }
+
+    pub fn is_frozen(&self, nonce: u64) -> bool {
+        self.0
+            .get(&nonce)
+            .map(|instance| instance.frozen)
+            .unwrap_or_default()
+    }
+
     pub fn get_by_nonce(&self, nonce: u64) -> Option<&DcdtInstance> {
         self.0.get(&nonce)
     }
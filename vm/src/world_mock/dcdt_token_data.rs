@@ -0,0 +1,62 @@
+use num_bigint::BigUint;
+use num_traits::Zero;
+
+use crate::types::{VMAddress, VMTokenType};
+
+use super::DcdtTokenProperties;
+
+/// Token-level data, as kept by the DCDT system SC.
+///
+/// Unlike `DcdtData`, which is kept per account, there is a single instance per token identifier.
+#[derive(Clone, Debug)]
+pub struct DcdtTokenData {
+    pub owner: VMAddress,
+    pub name: Vec<u8>,
+    pub ticker: Vec<u8>,
+    pub token_type: VMTokenType,
+    pub num_decimals: u32,
+    pub properties: DcdtTokenProperties,
+    pub nft_create_stopped: bool,
+    pub burn_role_globally: bool,
+    pub minted_value: BigUint,
+    pub burnt_value: BigUint,
+    pub num_wiped: u64,
+}
+
+impl DcdtTokenData {
+    pub fn new(
+        owner: VMAddress,
+        name: Vec<u8>,
+        ticker: Vec<u8>,
+        token_type: VMTokenType,
+        num_decimals: u32,
+    ) -> Self {
+        DcdtTokenData {
+            owner,
+            name,
+            ticker,
+            token_type,
+            num_decimals,
+            properties: DcdtTokenProperties::default(),
+            nft_create_stopped: false,
+            burn_role_globally: false,
+            minted_value: BigUint::zero(),
+            burnt_value: BigUint::zero(),
+            num_wiped: 0,
+        }
+    }
+
+    pub fn is_fungible(&self) -> bool {
+        self.token_type == VMTokenType::Fungible
+    }
+
+    /// The token type name, as returned by `getTokenProperties`.
+    pub fn token_type_name(&self) -> &'static str {
+        match self.token_type {
+            VMTokenType::Fungible => "FungibleDCDT",
+            VMTokenType::SemiFungible => "SemiFungibleDCDT",
+            VMTokenType::Meta => "MetaDCDT",
+            VMTokenType::NonFungible => "NonFungibleDCDT",
+        }
+    }
+}
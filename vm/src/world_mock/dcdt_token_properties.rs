@@ -0,0 +1,135 @@
+const TRUE_STR: &[u8] = b"true";
+const FALSE_STR: &[u8] = b"false";
+
+/// The configurable properties of a DCDT token, as kept by the DCDT system SC.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DcdtTokenProperties {
+    pub can_freeze: bool,
+    pub can_wipe: bool,
+    pub can_pause: bool,
+    pub can_mint: bool,
+    pub can_burn: bool,
+    pub can_change_owner: bool,
+    pub can_upgrade: bool,
+    pub can_add_special_roles: bool,
+    pub can_transfer_nft_create_role: bool,
+    pub can_create_multi_shard: bool,
+}
+
+impl Default for DcdtTokenProperties {
+    /// Same defaults as the system SC, when no property arguments are given on issue.
+    fn default() -> Self {
+        DcdtTokenProperties {
+            can_freeze: false,
+            can_wipe: false,
+            can_pause: false,
+            can_mint: false,
+            can_burn: false,
+            can_change_owner: false,
+            can_upgrade: true,
+            can_add_special_roles: true,
+            can_transfer_nft_create_role: false,
+            can_create_multi_shard: false,
+        }
+    }
+}
+
+impl DcdtTokenProperties {
+    /// Properties of tokens issued via `registerAndSetAllRoles`.
+    pub fn all_roles_defaults() -> Self {
+        DcdtTokenProperties {
+            can_freeze: true,
+            can_wipe: true,
+            can_pause: true,
+            can_change_owner: true,
+            can_transfer_nft_create_role: true,
+            ..Default::default()
+        }
+    }
+
+    /// Assumed for tokens unknown to the system SC, which were never issued through it.
+    pub fn all_enabled() -> Self {
+        DcdtTokenProperties {
+            can_freeze: true,
+            can_wipe: true,
+            can_pause: true,
+            can_mint: true,
+            can_burn: true,
+            can_change_owner: true,
+            can_upgrade: true,
+            can_add_special_roles: true,
+            can_transfer_nft_create_role: true,
+            can_create_multi_shard: true,
+        }
+    }
+
+    /// Applies property arguments, in the `name@value` pair format used by the system SC.
+    ///
+    /// Values can only be `true` or `false`.
+    pub fn apply_arguments(&mut self, args: &[Vec<u8>]) -> Result<(), &'static str> {
+        if !args.len().is_multiple_of(2) {
+            return Err("invalid number of arguments");
+        }
+
+        for pair in args.chunks(2) {
+            let value = match pair[1].as_slice() {
+                TRUE_STR => true,
+                FALSE_STR => false,
+                _ => return Err("invalid argument"),
+            };
+            let property = match pair[0].as_slice() {
+                b"canFreeze" => &mut self.can_freeze,
+                b"canWipe" => &mut self.can_wipe,
+                b"canPause" => &mut self.can_pause,
+                b"canMint" => &mut self.can_mint,
+                b"canBurn" => &mut self.can_burn,
+                b"canChangeOwner" => &mut self.can_change_owner,
+                b"canUpgrade" => &mut self.can_upgrade,
+                b"canAddSpecialRoles" => &mut self.can_add_special_roles,
+                b"canTransferNFTCreateRole" => &mut self.can_transfer_nft_create_role,
+                b"canCreateMultiShard" => &mut self.can_create_multi_shard,
+                _ => return Err("invalid argument"),
+            };
+            *property = value;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_arguments_ok() {
+        let mut properties = DcdtTokenProperties::default();
+        let args = vec![
+            b"canFreeze".to_vec(),
+            b"true".to_vec(),
+            b"canUpgrade".to_vec(),
+            b"false".to_vec(),
+        ];
+        properties.apply_arguments(&args).unwrap();
+        assert!(properties.can_freeze);
+        assert!(!properties.can_upgrade);
+        assert!(properties.can_add_special_roles);
+    }
+
+    #[test]
+    fn test_apply_arguments_err() {
+        let mut properties = DcdtTokenProperties::default();
+        assert_eq!(
+            properties.apply_arguments(&[b"canFreeze".to_vec()]),
+            Err("invalid number of arguments")
+        );
+        assert_eq!(
+            properties.apply_arguments(&[b"canFreeze".to_vec(), b"yes".to_vec()]),
+            Err("invalid argument")
+        );
+        assert_eq!(
+            properties.apply_arguments(&[b"canFly".to_vec(), b"true".to_vec()]),
+            Err("invalid argument")
+        );
+    }
+}
@@ -0,0 +1,68 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use crate::types::VMAddress;
+
+use super::{AccountData, AccountStorage};
+
+/// Provides accounts that are missing from the local blockchain state, e.g. from a real network.
+///
+/// Enables the fork mode of the debugger: accounts are loaded on first access,
+/// their storage is loaded one key at a time, on first read.
+pub trait ForkSource: Send + Sync {
+    /// Retrieves the account data, without its storage, or `None` if the account cannot be found.
+    fn fetch_account(&self, address: &VMAddress) -> Option<AccountData>;
+
+    /// Retrieves a single storage value of an account. Missing keys yield an empty value.
+    fn fetch_storage(&self, address: &VMAddress, key: &[u8]) -> Vec<u8>;
+}
+
+/// Keeps everything retrieved from the fork source, so that nothing is requested twice.
+///
+/// Only holds remote data, which does not change during a test,
+/// so it is shared between the blockchain state and all of its snapshots.
+pub struct ForkCache {
+    source: Arc<dyn ForkSource>,
+    accounts: Mutex<HashMap<VMAddress, Option<AccountData>>>,
+    storage: Mutex<HashMap<VMAddress, AccountStorage>>,
+}
+
+impl ForkCache {
+    pub fn new(source: Arc<dyn ForkSource>) -> Self {
+        ForkCache {
+            source,
+            accounts: Mutex::new(HashMap::new()),
+            storage: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// The account, as retrieved from the fork source, at most once per address.
+    pub fn account(&self, address: &VMAddress) -> Option<AccountData> {
+        self.accounts
+            .lock()
+            .unwrap()
+            .entry(address.clone())
+            .or_insert_with(|| self.source.fetch_account(address))
+            .clone()
+    }
+
+    /// Storage is only requested for accounts that were themselves loaded from the fork source,
+    /// accounts created locally only have local storage.
+    pub fn storage(&self, address: &VMAddress, key: &[u8]) -> Vec<u8> {
+        let is_forked_account = matches!(self.accounts.lock().unwrap().get(address), Some(Some(_)));
+        if !is_forked_account {
+            return Vec::new();
+        }
+
+        self.storage
+            .lock()
+            .unwrap()
+            .entry(address.clone())
+            .or_default()
+            .entry(key.to_vec())
+            .or_insert_with(|| self.source.fetch_storage(address, key))
+            .clone()
+    }
+}
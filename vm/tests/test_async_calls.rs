@@ -0,0 +1,187 @@
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use dharitri_chain_vm::{
+    tx_execution::BlockchainVMRef,
+    tx_mock::{AsyncCallTxData, Promise, TxContextStack, TxFunctionName, TxInput, TxResult},
+    types::{VMAddress, VMCodeMetadata, H256},
+    vm_hooks::{DebugApiVMHooksHandler, VMHooksHandlerSource, VMHooksSend},
+    with_shared::Shareable,
+    world_mock::{AccountData, BlockchainState, FailingExecutor},
+};
+use num_bigint::BigUint;
+
+const OWNER: [u8; 32] = [4u8; 32];
+const SC: [u8; 32] = *b"\x00\x00\x00\x00\x00\x00\x00\x00sc______________________";
+const RECEIVER_1: [u8; 32] = [1u8; 32];
+const RECEIVER_2: [u8; 32] = [2u8; 32];
+const RECEIVER_3: [u8; 32] = [3u8; 32];
+const SC_FROM_INIT: [u8; 32] = *b"\x00\x00\x00\x00\x00\x00\x00\x00sc_from_init____________";
+const INITIAL_BALANCE: u32 = 1_000;
+
+fn new_state() -> Shareable<BlockchainState> {
+    let mut state = BlockchainState::default();
+    let mut sc = AccountData::new_empty(VMAddress::from(SC));
+    sc.rewa_balance = BigUint::from(INITIAL_BALANCE);
+    state.accounts.insert(VMAddress::from(SC), sc);
+    state.accounts.insert(
+        VMAddress::from(OWNER),
+        AccountData::new_empty(VMAddress::from(OWNER)),
+    );
+    Shareable::new(state)
+}
+
+fn async_call_data(to: [u8; 32], call_value: u32) -> AsyncCallTxData {
+    AsyncCallTxData {
+        from: VMAddress::from(SC),
+        to: VMAddress::from(to),
+        call_value: BigUint::from(call_value),
+        endpoint_name: TxFunctionName::EMPTY,
+        arguments: Vec::new(),
+        tx_hash: H256::zero(),
+        gas_limit: 0,
+    }
+}
+
+fn promise(to: [u8; 32], call_value: u32) -> Promise {
+    Promise {
+        call: async_call_data(to, call_value),
+        success_callback: TxFunctionName::EMPTY,
+        error_callback: TxFunctionName::EMPTY,
+        callback_closure_data: Vec::new(),
+    }
+}
+
+fn balance(state: &BlockchainState, address: [u8; 32]) -> BigUint {
+    state.accounts[&VMAddress::from(address)]
+        .rewa_balance
+        .clone()
+}
+
+#[test]
+fn test_multiple_promises() {
+    let vm = BlockchainVMRef::new(Box::new(FailingExecutor));
+    let mut state = new_state();
+
+    let mut tx_result = TxResult::empty();
+    tx_result
+        .pending_calls
+        .promises
+        .extend([promise(RECEIVER_1, 100), promise(RECEIVER_2, 200)]);
+
+    let tx_result = vm.execute_pending_calls(tx_result, &mut state);
+    tx_result.assert_ok();
+    assert!(tx_result.pending_calls.no_calls());
+
+    assert_eq!(balance(&state, SC), BigUint::from(INITIAL_BALANCE - 300));
+    assert_eq!(balance(&state, RECEIVER_1), BigUint::from(100u32));
+    assert_eq!(balance(&state, RECEIVER_2), BigUint::from(200u32));
+}
+
+#[test]
+fn test_multiple_async_calls_from_sync_calls() {
+    let vm = BlockchainVMRef::new(Box::new(FailingExecutor));
+    let mut state = new_state();
+
+    let mut tx_result = TxResult::empty();
+    for (to, call_value) in [(RECEIVER_1, 100), (RECEIVER_2, 200)] {
+        let mut sync_call_result = TxResult::empty();
+        sync_call_result
+            .pending_calls
+            .async_calls
+            .push(async_call_data(to, call_value));
+        tx_result.merge_after_sync_call(&sync_call_result);
+    }
+    tx_result
+        .pending_calls
+        .promises
+        .push(promise(RECEIVER_3, 300));
+    assert_eq!(tx_result.pending_calls.async_calls.len(), 2);
+
+    let tx_result = vm.execute_pending_calls(tx_result, &mut state);
+    tx_result.assert_ok();
+
+    assert_eq!(balance(&state, SC), BigUint::from(INITIAL_BALANCE - 600));
+    assert_eq!(balance(&state, RECEIVER_1), BigUint::from(100u32));
+    assert_eq!(balance(&state, RECEIVER_2), BigUint::from(200u32));
+    assert_eq!(balance(&state, RECEIVER_3), BigUint::from(300u32));
+}
+
+#[test]
+fn test_pending_calls_skipped_on_error() {
+    let vm = BlockchainVMRef::new(Box::new(FailingExecutor));
+    let mut state = new_state();
+
+    let mut tx_result = TxResult::from_panic_string("error");
+    tx_result
+        .pending_calls
+        .promises
+        .push(promise(RECEIVER_1, 100));
+
+    let tx_result = vm.execute_pending_calls(tx_result, &mut state);
+    tx_result.assert_user_error("error");
+    assert!(tx_result.pending_calls.no_calls());
+    assert!(!state.accounts.contains_key(&VMAddress::from(RECEIVER_1)));
+}
+
+/// Sends 100 to the first receiver via a legacy async call, from the current contract.
+fn perform_legacy_async_call() {
+    let handler = DebugApiVMHooksHandler::new(TxContextStack::static_peek());
+    {
+        let mut types = handler.m_types_lock();
+        types.mb_set(1, RECEIVER_1.to_vec());
+        types.bi_overwrite(2, 100.into());
+        types.mb_set(3, Vec::new());
+        types.mb_set_vec_of_bytes(4, Vec::new());
+    }
+    // halting panics, just like in a real contract call
+    let _ = catch_unwind(AssertUnwindSafe(|| handler.async_call_raw(1, 2, 3, 4)));
+}
+
+#[test]
+fn test_legacy_async_call_from_endpoint() {
+    let vm = BlockchainVMRef::new(Box::new(FailingExecutor));
+    let mut state = new_state();
+
+    let tx_input = TxInput {
+        from: VMAddress::from(OWNER),
+        to: VMAddress::from(SC),
+        func_name: TxFunctionName::from("endpoint"),
+        ..Default::default()
+    };
+    let tx_result = vm.execute_sc_call_lambda(tx_input, &mut state, perform_legacy_async_call);
+    tx_result.assert_ok();
+    assert_eq!(tx_result.pending_calls.async_calls.len(), 1);
+    assert!(tx_result.pending_calls.promises.is_empty());
+
+    let tx_result = vm.execute_pending_calls(tx_result, &mut state);
+    tx_result.assert_ok();
+    assert_eq!(balance(&state, SC), BigUint::from(INITIAL_BALANCE - 100));
+    assert_eq!(balance(&state, RECEIVER_1), BigUint::from(100u32));
+}
+
+#[test]
+fn test_legacy_async_call_from_init() {
+    let vm = BlockchainVMRef::new(Box::new(FailingExecutor));
+    let mut state = new_state();
+    state.put_new_address(VMAddress::from(OWNER), 0, VMAddress::from(SC_FROM_INIT));
+
+    let tx_input = TxInput {
+        from: VMAddress::from(OWNER),
+        to: VMAddress::zero(),
+        ..Default::default()
+    };
+    let (_, tx_result) = vm.sc_create_with_async_and_callback(
+        tx_input,
+        b"contract",
+        VMCodeMetadata::DEFAULT,
+        &mut state,
+        perform_legacy_async_call,
+    );
+    assert_eq!(tx_result.result_status, 10);
+    assert_eq!(
+        tx_result.result_message,
+        "async call is not allowed at this location"
+    );
+    assert!(tx_result.pending_calls.no_calls());
+    assert!(!state.accounts.contains_key(&VMAddress::from(RECEIVER_1)));
+}
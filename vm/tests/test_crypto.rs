@@ -1,5 +1,17 @@
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use dharitri_chain_vm::{
+    crypto_functions::{self, EllipticCurve},
+    tx_execution::BlockchainVMRef,
+    tx_mock::{TxContextStack, TxFunctionName, TxInput, TxResult},
+    types::VMAddress,
+    vm_hooks::{DebugApiVMHooksHandler, VMHooksCrypto, VMHooksHandlerSource},
+    with_shared::Shareable,
+    world_mock::{AccountData, BlockchainState, FailingExecutor},
+};
 use hex::FromHex;
-use dharitri_chain_vm::crypto_functions;
+use num_bigint::BigUint;
+use sha2::{Digest, Sha256};
 
 #[test]
 fn test_verify_ed25519_basic() {
@@ -42,3 +54,185 @@ fn test_verify_ed25519_invalid_args() {
     let success = crypto_functions::verify_ed25519(&pub_bytes, &msg_bytes, &sig_bytes);
     assert!(!success);
 }
+
+#[test]
+fn test_ripemd160() {
+    assert_eq!(
+        hex::encode(crypto_functions::ripemd160(b"")),
+        "9c1185a5c5e9fc54612808977ee8f548b2258d31"
+    );
+    assert_eq!(
+        hex::encode(crypto_functions::ripemd160(b"abc")),
+        "8eb208f7e05d987a9b044a8e98c6b087f15a0bfc"
+    );
+}
+
+/// Plain ECDSA signing, with a fixed nonce, only used to produce test signatures.
+fn sign_secp256k1(private_key: &BigUint, hash: &[u8], nonce: &BigUint) -> Vec<u8> {
+    let curve = EllipticCurve::secp256k1();
+    let (kx, _) = curve.scalar_base_mult(&nonce.to_bytes_be());
+    let r = kx % &curve.n;
+    let z = BigUint::from_bytes_be(hash);
+    let s = (nonce.modinv(&curve.n).unwrap() * (z + &r * private_key)) % &curve.n;
+    crypto_functions::encode_secp256k1_der_signature(&r.to_bytes_be(), &s.to_bytes_be())
+}
+
+#[test]
+fn test_verify_secp256k1() {
+    let curve = EllipticCurve::secp256k1();
+    let private_key = BigUint::from_bytes_be(&[0x5a; 32]);
+    let (x, y) = curve.scalar_base_mult(&private_key.to_bytes_be());
+    let uncompressed_key = curve.marshal(&x, &y);
+    let compressed_key = curve.marshal_compressed(&x, &y);
+
+    let message = b"message to sign";
+    let hash = Sha256::digest(Sha256::digest(message));
+    let signature = sign_secp256k1(&private_key, &hash, &BigUint::from(123456789u64));
+
+    assert!(crypto_functions::verify_secp256k1(
+        &uncompressed_key,
+        message,
+        &signature
+    ));
+    assert!(crypto_functions::verify_secp256k1(
+        &compressed_key,
+        message,
+        &signature
+    ));
+    assert!(crypto_functions::verify_custom_secp256k1(
+        &compressed_key,
+        &hash,
+        &signature,
+        crypto_functions::ECDSA_PLAIN_MSG
+    ));
+
+    assert!(!crypto_functions::verify_secp256k1(
+        &compressed_key,
+        b"other message",
+        &signature
+    ));
+    assert!(!crypto_functions::verify_custom_secp256k1(
+        &compressed_key,
+        message,
+        &signature,
+        crypto_functions::ECDSA_KECCAK256
+    ));
+    assert!(!crypto_functions::verify_secp256k1(
+        &compressed_key,
+        message,
+        &signature[1..]
+    ));
+}
+
+#[test]
+fn test_encode_secp256k1_der_signature() {
+    let signature = crypto_functions::encode_secp256k1_der_signature(&[0x80], &[0x01]);
+    assert_eq!(
+        signature,
+        vec![0x30, 0x07, 0x02, 0x02, 0x00, 0x80, 0x02, 0x01, 0x01]
+    );
+}
+
+/// Known-answer vector for the basic scheme ciphersuite, also produced by the `bls12_381` crate.
+const BLS_SECRET_KEY: [u8; crypto_functions::BLS_SECRET_KEY_LEN] = [0x17; 32];
+const BLS_PUBLIC_KEY: &str = "90c441bdb7f2b1d2cb551fe704bd18cc0c2498b2675716f017a0bd3ec6028f476dceab2aa6d6abc17b9dbcecc4d335ad080780f6ef852f039abf0ac3bcb061de5a39ae586ce6ade6ac7cf60f49fb486d7bb3e6951ee83fa95f5a3c9c2d9a9f17";
+const BLS_SIGNATURE: &str = "a09db23cbf6ae27775e0e613e18c6564bb3697f71e4d32f05732765cbacb91b627277cb3b7435476e97fbc2b2d03e2db";
+
+#[test]
+fn test_verify_bls() {
+    let public_key = crypto_functions::public_key_bls(&BLS_SECRET_KEY).unwrap();
+    let signature = crypto_functions::sign_bls(&BLS_SECRET_KEY, b"message").unwrap();
+    assert_eq!(hex::encode(&public_key), BLS_PUBLIC_KEY);
+    assert_eq!(hex::encode(&signature), BLS_SIGNATURE);
+
+    assert!(crypto_functions::verify_bls(
+        &public_key,
+        b"message",
+        &signature
+    ));
+    assert!(!crypto_functions::verify_bls(
+        &public_key,
+        b"other message",
+        &signature
+    ));
+    assert!(!crypto_functions::verify_bls(
+        &public_key,
+        b"message",
+        &signature[1..]
+    ));
+    assert!(!crypto_functions::verify_bls(
+        &[0u8; crypto_functions::BLS_PUBLIC_KEY_LEN],
+        b"message",
+        &signature
+    ));
+}
+
+#[test]
+fn test_bls_invalid_secret_key() {
+    assert!(crypto_functions::public_key_bls(&[0u8; 32]).is_none());
+    assert!(crypto_functions::sign_bls(&[0xff; 32], b"message").is_none());
+}
+
+const SENDER: [u8; 32] = [1u8; 32];
+const SC: [u8; 32] = *b"\x00\x00\x00\x00\x00\x00\x00\x00sc______________________";
+
+/// Runs some VM hooks in the context of a smart contract call.
+fn run_in_sc_call<F>(f: F) -> TxResult
+where
+    F: FnOnce(&DebugApiVMHooksHandler),
+{
+    let mut state = BlockchainState::default();
+    for address in [SENDER, SC] {
+        let address = VMAddress::from(address);
+        state
+            .accounts
+            .insert(address.clone(), AccountData::new_empty(address));
+    }
+    let mut state = Shareable::new(state);
+    let vm = BlockchainVMRef::new(Box::new(FailingExecutor));
+    let tx_input = TxInput {
+        from: VMAddress::from(SENDER),
+        to: VMAddress::from(SC),
+        func_name: TxFunctionName::from("verify"),
+        ..Default::default()
+    };
+    vm.execute_sc_call_lambda(tx_input, &mut state, || {
+        let handler = DebugApiVMHooksHandler::new(TxContextStack::static_peek());
+        // halting panics, just like in a real contract call
+        let _ = catch_unwind(AssertUnwindSafe(|| f(&handler)));
+    })
+}
+
+/// Invalid signatures halt the execution, just like for ed25519.
+#[test]
+fn test_verify_hooks_failure() {
+    let public_key = crypto_functions::public_key_bls(&BLS_SECRET_KEY).unwrap();
+    let signature = crypto_functions::sign_bls(&BLS_SECRET_KEY, b"message").unwrap();
+    let set_buffers = |handler: &DebugApiVMHooksHandler, message: &[u8]| {
+        let mut types = handler.m_types_lock();
+        types.mb_set(1, public_key.clone());
+        types.mb_set(2, message.to_vec());
+        types.mb_set(3, signature.clone());
+    };
+
+    run_in_sc_call(|handler| {
+        set_buffers(handler, b"message");
+        handler.verify_bls_managed(1, 2, 3);
+        handler.verify_bls_signature_share_managed(1, 2, 3);
+    })
+    .assert_ok();
+
+    let result = run_in_sc_call(|handler| {
+        set_buffers(handler, b"other message");
+        handler.verify_bls_managed(1, 2, 3);
+    });
+    assert_eq!(result.result_status, 10);
+    assert_eq!(result.result_message, "signature is invalid");
+
+    let result = run_in_sc_call(|handler| {
+        set_buffers(handler, b"message");
+        handler.verify_secp256k1_managed(1, 2, 3);
+    });
+    assert_eq!(result.result_status, 10);
+    assert_eq!(result.result_message, "invalid signature");
+}
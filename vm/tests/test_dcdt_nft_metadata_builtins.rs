@@ -0,0 +1,165 @@
+use std::sync::Arc;
+
+use dharitri_chain_vm::{
+    tx_execution::BlockchainVMRef,
+    tx_mock::{TxCache, TxFunctionName, TxInput, TxResult},
+    types::VMAddress,
+    world_mock::{AccountData, BlockchainState, DcdtInstanceMetadata, FailingExecutor},
+};
+use num_bigint::BigUint;
+
+const OWNER: [u8; 32] = [1u8; 32];
+const NFT: &[u8] = b"NFT-123456";
+
+fn new_state(roles: &[&[u8]]) -> BlockchainState {
+    let mut state = BlockchainState::default();
+    let address = VMAddress::from(OWNER);
+    let mut account = AccountData::new_empty(address.clone());
+    account.dcdt.set_dcdt_balance(
+        NFT.to_vec(),
+        1,
+        &BigUint::from(1u32),
+        DcdtInstanceMetadata {
+            name: b"old name".to_vec(),
+            royalties: 100,
+            hash: Some(b"old hash".to_vec()),
+            uri: vec![b"old uri".to_vec()],
+            attributes: b"old attributes".to_vec(),
+            ..Default::default()
+        },
+    );
+    for role in roles {
+        account.dcdt.set_special_role(NFT, role);
+    }
+    state.accounts.insert(address, account);
+    state
+}
+
+fn execute(state: &mut BlockchainState, func: &str, args: &[&[u8]]) -> TxResult {
+    let tx_input = TxInput {
+        from: VMAddress::from(OWNER),
+        to: VMAddress::from(OWNER),
+        func_name: TxFunctionName::from(func),
+        args: args.iter().map(|arg| arg.to_vec()).collect(),
+        ..Default::default()
+    };
+    let tx_cache = TxCache::new(Arc::new(state.clone()));
+    let vm = BlockchainVMRef::new(Box::new(FailingExecutor));
+    let (tx_result, blockchain_update) =
+        vm.execute_builtin_function_or_default(tx_input, tx_cache, || {});
+    if tx_result.result_status == 0 {
+        state.commit_updates(blockchain_update);
+    }
+    tx_result
+}
+
+fn metadata(state: &BlockchainState) -> DcdtInstanceMetadata {
+    state.accounts[&VMAddress::from(OWNER)]
+        .dcdt
+        .get_by_identifier(NFT)
+        .unwrap()
+        .instances
+        .get_by_nonce(1)
+        .unwrap()
+        .metadata
+        .clone()
+}
+
+#[test]
+fn test_modify_royalties_and_creator() {
+    let mut state = new_state(&[b"DCDTRoleModifyRoyalties"]);
+
+    execute(
+        &mut state,
+        "DCDTModifyRoyalties",
+        &[NFT, &[1], &[0x03, 0xe8]],
+    )
+    .assert_ok();
+    assert_eq!(metadata(&state).royalties, 1000);
+
+    let result = execute(
+        &mut state,
+        "DCDTModifyRoyalties",
+        &[NFT, &[1], &[0x27, 0x11]],
+    );
+    assert_eq!(result.result_message, "invalid royalties value");
+
+    let result = execute(&mut state, "DCDTModifyCreator", &[NFT, &[1]]);
+    assert_eq!(result.result_message, "action is not allowed");
+    assert_eq!(metadata(&state).creator, None);
+
+    let mut state = new_state(&[b"DCDTRoleModifyCreator"]);
+    execute(&mut state, "DCDTModifyCreator", &[NFT, &[1]]).assert_ok();
+    assert_eq!(metadata(&state).creator, Some(VMAddress::from(OWNER)));
+}
+
+#[test]
+fn test_set_new_uris() {
+    let mut state = new_state(&[b"DCDTRoleSetNewURI"]);
+
+    let result = execute(&mut state, "DCDTSetNewURIs", &[NFT, &[1], b"uri1", b"uri2"]);
+    result.assert_ok();
+    assert_eq!(
+        metadata(&state).uri,
+        vec![b"uri1".to_vec(), b"uri2".to_vec()]
+    );
+    assert_eq!(result.result_logs[0].topics.len(), 5);
+
+    let result = execute(&mut state, "DCDTSetNewURIs", &[NFT, &[2], b"uri3"]);
+    assert_eq!(result.result_message, "invalid token nonce");
+}
+
+#[test]
+fn test_metadata_recreate() {
+    let mut state = new_state(&[b"DCDTRoleNFTRecreate"]);
+
+    execute(
+        &mut state,
+        "DCDTMetaDataRecreate",
+        &[
+            NFT,
+            &[1],
+            b"new name",
+            &[],
+            &[],
+            b"new attributes",
+            b"new uri",
+        ],
+    )
+    .assert_ok();
+
+    let metadata = metadata(&state);
+    assert_eq!(metadata.name, b"new name".to_vec());
+    assert_eq!(metadata.creator, Some(VMAddress::from(OWNER)));
+    assert_eq!(metadata.royalties, 0);
+    assert_eq!(metadata.hash, Some(Vec::new()));
+    assert_eq!(metadata.attributes, b"new attributes".to_vec());
+    assert_eq!(metadata.uri, vec![b"new uri".to_vec()]);
+}
+
+#[test]
+fn test_metadata_update() {
+    let mut state = new_state(&[b"DCDTRoleNFTUpdate"]);
+
+    let result = execute(&mut state, "DCDTMetaDataUpdate", &[NFT, &[1], b"new name"]);
+    assert_eq!(
+        result.result_message,
+        "DCDTMetaDataUpdate expects at least 7 arguments"
+    );
+
+    execute(
+        &mut state,
+        "DCDTMetaDataUpdate",
+        &[NFT, &[1], b"new name", &[], &[], &[], &[]],
+    )
+    .assert_ok();
+
+    // empty arguments leave the existing values unchanged
+    let metadata = metadata(&state);
+    assert_eq!(metadata.name, b"new name".to_vec());
+    assert_eq!(metadata.creator, None);
+    assert_eq!(metadata.royalties, 100);
+    assert_eq!(metadata.hash, Some(b"old hash".to_vec()));
+    assert_eq!(metadata.attributes, b"old attributes".to_vec());
+    assert_eq!(metadata.uri, vec![b"old uri".to_vec()]);
+}
@@ -0,0 +1,99 @@
+use dharitri_chain_vm::crypto_functions::EllipticCurve;
+use num_bigint::BigUint;
+use num_traits::Zero;
+
+fn all_curves() -> Vec<EllipticCurve> {
+    [b"p224".as_slice(), b"p256", b"p384", b"p521"]
+        .iter()
+        .map(|name| EllipticCurve::from_name(name).unwrap())
+        .collect()
+}
+
+fn hex_big_uint(hex_str: &str) -> BigUint {
+    BigUint::parse_bytes(hex_str.as_bytes(), 16).unwrap()
+}
+
+#[test]
+fn test_ec_unknown_curve() {
+    assert!(EllipticCurve::from_name(b"p255").is_none());
+    assert!(EllipticCurve::from_name(b"").is_none());
+}
+
+#[test]
+fn test_ec_base_point_order() {
+    for curve in all_curves() {
+        assert!(curve.is_on_curve(&curve.gx, &curve.gy), "{}", curve.name);
+
+        let (x, y) = curve.scalar_base_mult(&curve.n.to_bytes_be());
+        assert!(x.is_zero() && y.is_zero(), "{}", curve.name);
+    }
+}
+
+#[test]
+fn test_ec_p256_double() {
+    let curve = EllipticCurve::p256();
+    let expected = (
+        hex_big_uint("7CF27B188D034F7E8A52380304B51AC3C08969E277F21B35A60B48FC47669978"),
+        hex_big_uint("07775510DB8ED040293D9AC69F7430DBBA7DADE63CE982299E04B79D227873D1"),
+    );
+
+    assert_eq!(curve.double(&curve.gx, &curve.gy), expected);
+    assert_eq!(curve.scalar_base_mult(&[2]), expected);
+
+    let (x3, y3) = curve.add(&expected.0, &expected.1, &curve.gx, &curve.gy);
+    assert_eq!((x3, y3), curve.scalar_base_mult(&[3]));
+}
+
+#[test]
+fn test_ec_marshal_round_trip() {
+    for curve in all_curves() {
+        let (x, y) = curve.scalar_base_mult(&[7, 5, 3]);
+
+        let marshalled = curve.marshal(&x, &y);
+        assert_eq!(marshalled.len(), 1 + 2 * curve.byte_len());
+        assert_eq!(curve.unmarshal(&marshalled), Some((x.clone(), y.clone())));
+
+        let compressed = curve.marshal_compressed(&x, &y);
+        assert_eq!(compressed.len(), 1 + curve.byte_len());
+        assert_eq!(
+            curve.unmarshal_compressed(&compressed),
+            Some((x.clone(), y.clone())),
+            "{}",
+            curve.name
+        );
+    }
+}
+
+#[test]
+fn test_ec_unmarshal_invalid() {
+    let curve = EllipticCurve::p256();
+    let mut marshalled = curve.marshal(&curve.gx, &curve.gy);
+    assert!(curve.unmarshal(&marshalled[1..]).is_none());
+
+    let last = marshalled.len() - 1;
+    marshalled[last] ^= 1;
+    assert!(curve.unmarshal(&marshalled).is_none());
+}
+
+#[test]
+fn test_ec_generate_key() {
+    for curve in all_curves() {
+        let (private_key, (x, y)) = curve.generate_key(|length| vec![0x11; length]);
+        assert_eq!(private_key.len(), curve.private_key_byte_len());
+        assert!(curve.is_on_curve(&x, &y));
+        assert_eq!(curve.scalar_base_mult(&private_key), (x, y));
+    }
+}
+
+#[test]
+fn test_ec_secp256k1_double() {
+    let curve = EllipticCurve::secp256k1();
+    let expected = (
+        hex_big_uint("C6047F9441ED7D6D3045406E95C07CD85C778E4B8CEF3CA7ABAC09B95C709EE5"),
+        hex_big_uint("1AE168FEA63DC339A3C58419466CEAEEF7F632653266D0E1236431A950CFE52A"),
+    );
+
+    assert!(curve.is_on_curve(&curve.gx, &curve.gy));
+    assert_eq!(curve.double(&curve.gx, &curve.gy), expected);
+    assert_eq!(curve.scalar_base_mult(&[2]), expected);
+}
@@ -0,0 +1,170 @@
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+use dharitri_chain_vm::{
+    tx_execution::BlockchainVMRef,
+    tx_mock::{TxCache, TxCacheSource, TxContextStack, TxFunctionName, TxInput},
+    types::VMAddress,
+    vm_hooks::{DebugApiVMHooksHandler, VMHooksHandlerSource},
+    with_shared::Shareable,
+    world_mock::{AccountData, BlockchainState, FailingExecutor, ForkSource},
+};
+use num_bigint::BigUint;
+
+const LOCAL_ACCOUNT: [u8; 32] = [1u8; 32];
+const REMOTE_ACCOUNT: [u8; 32] = [2u8; 32];
+const UNKNOWN_ACCOUNT: [u8; 32] = [3u8; 32];
+const LOCAL_SC: [u8; 32] = *b"\x00\x00\x00\x00\x00\x00\x00\x00sc______________________";
+
+/// Only knows a single account, counts the requests.
+#[derive(Default)]
+struct MockForkSource {
+    account_fetch_count: AtomicUsize,
+    storage_fetch_count: AtomicUsize,
+}
+
+impl MockForkSource {
+    fn account_fetch_count(&self) -> usize {
+        self.account_fetch_count.load(Ordering::Relaxed)
+    }
+
+    fn storage_fetch_count(&self) -> usize {
+        self.storage_fetch_count.load(Ordering::Relaxed)
+    }
+}
+
+impl ForkSource for MockForkSource {
+    fn fetch_account(&self, address: &VMAddress) -> Option<AccountData> {
+        self.account_fetch_count.fetch_add(1, Ordering::Relaxed);
+        if address != &VMAddress::from(REMOTE_ACCOUNT) {
+            return None;
+        }
+
+        let mut account = AccountData::new_empty(address.clone());
+        account.nonce = 10;
+        account.rewa_balance = BigUint::from(500u32);
+        Some(account)
+    }
+
+    fn fetch_storage(&self, address: &VMAddress, key: &[u8]) -> Vec<u8> {
+        self.storage_fetch_count.fetch_add(1, Ordering::Relaxed);
+        assert_eq!(address, &VMAddress::from(REMOTE_ACCOUNT));
+        if key == b"key" {
+            b"value".to_vec()
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+fn forked_state(fork_source: Arc<MockForkSource>) -> BlockchainState {
+    let mut state = BlockchainState::default();
+    state.accounts.insert(
+        VMAddress::from(LOCAL_ACCOUNT),
+        AccountData::new_empty(VMAddress::from(LOCAL_ACCOUNT)),
+    );
+    let mut local_sc = AccountData::new_empty(VMAddress::from(LOCAL_SC));
+    local_sc
+        .storage
+        .insert(b"local-key".to_vec(), b"local-value".to_vec());
+    state.accounts.insert(VMAddress::from(LOCAL_SC), local_sc);
+    state.set_fork_source(fork_source);
+    state
+}
+
+#[test]
+fn test_fork_load_account() {
+    let fork_source = Arc::new(MockForkSource::default());
+    let mut state = forked_state(fork_source.clone());
+
+    state.load_fork_account_if_missing(&VMAddress::from(LOCAL_ACCOUNT));
+    assert_eq!(fork_source.account_fetch_count(), 0);
+
+    state.increase_account_nonce(&VMAddress::from(REMOTE_ACCOUNT));
+    let account = &state.accounts[&VMAddress::from(REMOTE_ACCOUNT)];
+    assert_eq!(account.nonce, 11);
+    assert_eq!(account.rewa_balance, BigUint::from(500u32));
+    assert!(account.storage.is_empty());
+
+    // already loaded, no new request
+    state.load_fork_account_if_missing(&VMAddress::from(REMOTE_ACCOUNT));
+    assert_eq!(fork_source.account_fetch_count(), 1);
+
+    state.load_fork_account_if_missing(&VMAddress::from(UNKNOWN_ACCOUNT));
+    assert!(!state.account_exists(&VMAddress::from(UNKNOWN_ACCOUNT)));
+}
+
+#[test]
+fn test_fork_accounts_fetched_once() {
+    let fork_source = Arc::new(MockForkSource::default());
+    let state = forked_state(fork_source.clone());
+
+    // the state itself is not modified, but the fetched accounts are kept
+    for _ in 0..3 {
+        let account = state
+            .load_account(&VMAddress::from(REMOTE_ACCOUNT))
+            .unwrap();
+        assert_eq!(account.nonce, 10);
+        assert!(state
+            .load_account(&VMAddress::from(UNKNOWN_ACCOUNT))
+            .is_none());
+    }
+    assert_eq!(fork_source.account_fetch_count(), 2);
+
+    // snapshots share the retrieved data
+    let snapshot = state.snapshot();
+    let mut restored = BlockchainState::default();
+    restored.restore(&snapshot);
+    restored.load_fork_account_if_missing(&VMAddress::from(REMOTE_ACCOUNT));
+    assert!(restored.account_exists(&VMAddress::from(REMOTE_ACCOUNT)));
+    assert_eq!(fork_source.account_fetch_count(), 2);
+}
+
+#[test]
+fn test_fork_tx_cache_read_through() {
+    let fork_source = Arc::new(MockForkSource::default());
+    let state = Arc::new(forked_state(fork_source.clone()));
+
+    let tx_cache = TxCache::new(state.clone());
+    let nonce = tx_cache.with_account(&VMAddress::from(REMOTE_ACCOUNT), |account| account.nonce);
+    assert_eq!(nonce, 10);
+
+    let updates = tx_cache.into_blockchain_updates();
+    let mut state = Arc::into_inner(state).unwrap();
+    updates.apply(&mut state);
+    assert!(state.account_exists(&VMAddress::from(REMOTE_ACCOUNT)));
+    assert_eq!(fork_source.account_fetch_count(), 1);
+}
+
+#[test]
+fn test_fork_storage_loaded_per_key() {
+    let fork_source = Arc::new(MockForkSource::default());
+    let mut state = Shareable::new(forked_state(fork_source.clone()));
+    let vm = BlockchainVMRef::new(Box::new(FailingExecutor));
+
+    let tx_input = TxInput {
+        from: VMAddress::from(LOCAL_ACCOUNT),
+        to: VMAddress::from(LOCAL_SC),
+        func_name: TxFunctionName::from("read"),
+        ..Default::default()
+    };
+    let result = vm.execute_sc_call_lambda(tx_input, &mut state, || {
+        let handler = DebugApiVMHooksHandler::new(TxContextStack::static_peek());
+        let remote = VMAddress::from(REMOTE_ACCOUNT);
+
+        assert_eq!(handler.storage_read_any_address(&remote, b"key"), b"value");
+        assert_eq!(handler.storage_read_any_address(&remote, b"key"), b"value");
+        assert!(handler
+            .storage_read_any_address(&remote, b"missing-key")
+            .is_empty());
+        assert_eq!(handler.storage_read(b"local-key"), b"local-value");
+        assert!(handler.storage_read(b"missing-key").is_empty());
+    });
+    result.assert_ok();
+
+    // one request per remote key, none for the local account
+    assert_eq!(fork_source.storage_fetch_count(), 2);
+    assert_eq!(fork_source.account_fetch_count(), 1);
+}
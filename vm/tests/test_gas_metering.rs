@@ -0,0 +1,122 @@
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use dharitri_chain_vm::{
+    tx_execution::{BlockchainVMRef, GasSchedule},
+    tx_mock::{TxContextStack, TxFunctionName, TxInput, TxResult},
+    types::VMAddress,
+    vm_hooks::{DebugApiVMHooksHandler, VMHooksGas, VMHooksHandlerSource, VMHooksStorageWrite},
+    with_shared::Shareable,
+    world_mock::{AccountData, BlockchainState, FailingExecutor},
+};
+use num_bigint::BigUint;
+
+const SENDER: [u8; 32] = [1u8; 32];
+const SC: [u8; 32] = *b"\x00\x00\x00\x00\x00\x00\x00\x00sc______________________";
+const INITIAL_BALANCE: u32 = 1_000_000;
+
+fn new_state() -> Shareable<BlockchainState> {
+    let mut state = BlockchainState::default();
+    let mut sender = AccountData::new_empty(VMAddress::from(SENDER));
+    sender.rewa_balance = BigUint::from(INITIAL_BALANCE);
+    state.accounts.insert(VMAddress::from(SENDER), sender);
+    state.accounts.insert(
+        VMAddress::from(SC),
+        AccountData::new_empty(VMAddress::from(SC)),
+    );
+    Shareable::new(state)
+}
+
+fn new_vm(gas_schedule: Option<GasSchedule>) -> BlockchainVMRef {
+    let mut vm = BlockchainVMRef::new(Box::new(FailingExecutor));
+    vm.set_gas_schedule(gas_schedule);
+    vm
+}
+
+/// Calls a VM hook and writes 5 bytes to storage.
+fn call_and_store(
+    vm: &BlockchainVMRef,
+    state: &mut Shareable<BlockchainState>,
+    gas_limit: u64,
+) -> TxResult {
+    let tx_input = TxInput {
+        from: VMAddress::from(SENDER),
+        to: VMAddress::from(SC),
+        func_name: TxFunctionName::from("store"),
+        gas_limit,
+        gas_price: 1,
+        ..Default::default()
+    };
+    vm.execute_sc_call_lambda(tx_input, state, || {
+        let handler = DebugApiVMHooksHandler::new(TxContextStack::static_peek());
+        // halting panics, just like in a real contract call
+        let _ = catch_unwind(AssertUnwindSafe(|| {
+            handler.use_gas_for_vm_hook();
+            handler.m_types_lock().mb_set(1, b"key".to_vec());
+            handler.m_types_lock().mb_set(2, b"value".to_vec());
+            handler.storage_store_managed_buffer_raw(1, 2);
+        }));
+    })
+}
+
+fn sender_balance(state: &BlockchainState) -> BigUint {
+    state.accounts[&VMAddress::from(SENDER)]
+        .rewa_balance
+        .clone()
+}
+
+fn sc_storage(state: &BlockchainState) -> Option<Vec<u8>> {
+    state.accounts[&VMAddress::from(SC)]
+        .storage
+        .get(b"key".as_slice())
+        .cloned()
+}
+
+#[test]
+fn test_gas_metering_disabled() {
+    let vm = new_vm(None);
+    let mut state = new_state();
+
+    let result = call_and_store(&vm, &mut state, 50_000);
+    result.assert_ok();
+    assert_eq!(result.gas_used, 0);
+    assert_eq!(sc_storage(&state), Some(b"value".to_vec()));
+    assert_eq!(
+        sender_balance(&state),
+        BigUint::from(INITIAL_BALANCE - 50_000)
+    );
+}
+
+#[test]
+fn test_gas_metering_refund() {
+    let vm = new_vm(Some(GasSchedule::default()));
+    let mut state = new_state();
+
+    let result = call_and_store(&vm, &mut state, 100_000);
+    result.assert_ok();
+
+    // vm hook + storage store base + 5 bytes
+    let expected_gas_used = 1000 + 75_000 + 5 * 1000;
+    assert_eq!(result.gas_used, expected_gas_used);
+    assert_eq!(sc_storage(&state), Some(b"value".to_vec()));
+    assert_eq!(
+        sender_balance(&state),
+        BigUint::from(INITIAL_BALANCE - expected_gas_used as u32)
+    );
+}
+
+#[test]
+fn test_gas_metering_out_of_gas() {
+    let vm = new_vm(Some(GasSchedule::default()));
+    let mut state = new_state();
+
+    let result = call_and_store(&vm, &mut state, 50_000);
+    // out of gas
+    assert_eq!(result.result_status, 5);
+    assert_eq!(result.result_message, "not enough gas");
+    assert_eq!(result.gas_used, 50_000);
+    assert_eq!(sc_storage(&state), None);
+    assert_eq!(
+        sender_balance(&state),
+        BigUint::from(INITIAL_BALANCE - 50_000)
+    );
+}
@@ -0,0 +1,62 @@
+use dharitri_chain_vm::{
+    types::VMAddress,
+    world_mock::{AccountData, BlockchainState},
+};
+use num_bigint::BigUint;
+
+const ACCOUNT: [u8; 32] = [1u8; 32];
+const NEW_ACCOUNT: [u8; 32] = [2u8; 32];
+
+fn new_state() -> BlockchainState {
+    let mut state = BlockchainState::default();
+    let mut account = AccountData::new_empty(VMAddress::from(ACCOUNT));
+    account.rewa_balance = BigUint::from(1_000u32);
+    account.storage.insert(b"key".to_vec(), b"value".to_vec());
+    state.accounts.insert(VMAddress::from(ACCOUNT), account);
+    state
+}
+
+#[test]
+fn test_snapshot_restore() {
+    let mut state = new_state();
+    let snapshot = state.snapshot();
+
+    let account = state.accounts.get_mut(&VMAddress::from(ACCOUNT)).unwrap();
+    account.nonce = 5;
+    account.rewa_balance = BigUint::from(1u32);
+    account.storage.clear();
+    state.accounts.insert(
+        VMAddress::from(NEW_ACCOUNT),
+        AccountData::new_empty(VMAddress::from(NEW_ACCOUNT)),
+    );
+    state
+        .new_addresses
+        .insert((VMAddress::from(ACCOUNT), 5), VMAddress::from(NEW_ACCOUNT));
+    state.current_block_info.block_nonce = 100;
+    state.current_block_info.block_random_seed[0] = 7;
+
+    state.restore(&snapshot);
+    let account = &state.accounts[&VMAddress::from(ACCOUNT)];
+    assert_eq!(account.nonce, 0);
+    assert_eq!(account.rewa_balance, BigUint::from(1_000u32));
+    assert_eq!(
+        account.storage.get(b"key".as_slice()),
+        Some(&b"value".to_vec())
+    );
+    assert!(!state.account_exists(&VMAddress::from(NEW_ACCOUNT)));
+    assert!(state.new_addresses.is_empty());
+    assert_eq!(state.current_block_info.block_nonce, 0);
+    assert_eq!(state.current_block_info.block_random_seed[0], 0);
+}
+
+#[test]
+fn test_restore_twice() {
+    let mut state = new_state();
+    let snapshot = state.snapshot();
+
+    for _ in 0..2 {
+        state.increase_account_nonce(&VMAddress::from(ACCOUNT));
+        assert_eq!(state.accounts[&VMAddress::from(ACCOUNT)].nonce, 1);
+        state.restore(&snapshot);
+    }
+}
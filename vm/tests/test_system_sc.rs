@@ -0,0 +1,275 @@
+use std::sync::Arc;
+
+use dharitri_chain_vm::{
+    tx_execution::{execute_system_sc, DCDT_SYSTEM_SC_ADDRESS_ARRAY},
+    tx_mock::{TxCache, TxFunctionName, TxInput, TxResult},
+    types::VMAddress,
+    world_mock::{AccountData, BlockchainState, DcdtInstanceMetadata},
+};
+use num_bigint::BigUint;
+
+const OWNER: [u8; 32] = [1u8; 32];
+const USER: [u8; 32] = [2u8; 32];
+const NEW_ACCOUNT: [u8; 32] = [3u8; 32];
+
+fn new_state() -> BlockchainState {
+    let mut state = BlockchainState::default();
+    for address in [OWNER, USER] {
+        let address = VMAddress::from(address);
+        state
+            .accounts
+            .insert(address.clone(), AccountData::new_empty(address));
+    }
+    state.new_token_identifiers = vec!["TOKEN-123456".to_string()];
+    state
+}
+
+fn call_system_sc(
+    state: &mut BlockchainState,
+    from: [u8; 32],
+    func: &str,
+    args: &[&[u8]],
+) -> TxResult {
+    let tx_input = TxInput {
+        from: VMAddress::from(from),
+        to: VMAddress::from(DCDT_SYSTEM_SC_ADDRESS_ARRAY),
+        func_name: TxFunctionName::from(func),
+        args: args.iter().map(|arg| arg.to_vec()).collect(),
+        ..Default::default()
+    };
+    let tx_cache = TxCache::new(Arc::new(state.clone()));
+    let (tx_result, blockchain_update) = execute_system_sc(tx_input, tx_cache);
+    state.commit_updates(blockchain_update);
+    tx_result
+}
+
+fn issue_token(state: &mut BlockchainState) {
+    let result = call_system_sc(
+        state,
+        OWNER,
+        "issue",
+        &[
+            b"Token",
+            b"TOKEN",
+            &[100],
+            &[],
+            b"canFreeze",
+            b"true",
+            b"canWipe",
+            b"true",
+            b"canPause",
+            b"true",
+            b"canMint",
+            b"true",
+        ],
+    );
+    result.assert_ok();
+    assert_eq!(result.result_values, vec![b"TOKEN-123456".to_vec()]);
+}
+
+fn user_balance(state: &BlockchainState) -> BigUint {
+    state.accounts[&VMAddress::from(USER)]
+        .dcdt
+        .get_dcdt_balance(b"TOKEN-123456", 0)
+}
+
+#[test]
+fn test_system_sc_mint_freeze_wipe() {
+    let mut state = new_state();
+    issue_token(&mut state);
+
+    call_system_sc(&mut state, OWNER, "mint", &[b"TOKEN-123456", &[50], &USER]).assert_ok();
+    assert_eq!(user_balance(&state), BigUint::from(50u32));
+
+    // wipe only works on frozen accounts
+    let result = call_system_sc(&mut state, OWNER, "wipe", &[b"TOKEN-123456", &USER]);
+    assert_eq!(
+        result.result_message,
+        "cannot wipe because the account is not frozen for this dcdt token"
+    );
+
+    call_system_sc(&mut state, OWNER, "freeze", &[b"TOKEN-123456", &USER]).assert_ok();
+    assert!(state.accounts[&VMAddress::from(USER)]
+        .dcdt
+        .is_instance_frozen(b"TOKEN-123456", 0));
+
+    call_system_sc(&mut state, OWNER, "wipe", &[b"TOKEN-123456", &USER]).assert_ok();
+    assert_eq!(user_balance(&state), BigUint::from(0u32));
+
+    let token_data = state.get_dcdt_token_data(b"TOKEN-123456").unwrap();
+    assert_eq!(token_data.burnt_value, BigUint::from(50u32));
+    assert_eq!(token_data.num_wiped, 1);
+}
+
+#[test]
+fn test_system_sc_owner_only() {
+    let mut state = new_state();
+    issue_token(&mut state);
+
+    let result = call_system_sc(&mut state, USER, "pause", &[b"TOKEN-123456"]);
+    assert_eq!(result.result_message, "can be called by owner only");
+
+    let result = call_system_sc(&mut state, USER, "freeze", &[b"TOKEN-123456", &OWNER]);
+    assert_eq!(result.result_message, "can be called by owner only");
+}
+
+#[test]
+fn test_system_sc_pause_and_properties() {
+    let mut state = new_state();
+    issue_token(&mut state);
+
+    call_system_sc(&mut state, OWNER, "pause", &[b"TOKEN-123456"]).assert_ok();
+    let result = call_system_sc(&mut state, OWNER, "pause", &[b"TOKEN-123456"]);
+    assert_eq!(
+        result.result_message,
+        "cannot pause an already paused contract"
+    );
+
+    let result = call_system_sc(&mut state, OWNER, "getTokenProperties", &[b"TOKEN-123456"]);
+    result.assert_ok();
+    assert_eq!(result.result_values[1], b"FungibleDCDT".to_vec());
+    assert_eq!(result.result_values[6], b"IsPaused-true".to_vec());
+    assert_eq!(result.result_values[12], b"CanFreeze-true".to_vec());
+
+    call_system_sc(
+        &mut state,
+        OWNER,
+        "controlChanges",
+        &[
+            b"TOKEN-123456",
+            b"canFreeze",
+            b"false",
+            b"canChangeOwner",
+            b"true",
+        ],
+    )
+    .assert_ok();
+    let result = call_system_sc(&mut state, OWNER, "freeze", &[b"TOKEN-123456", &USER]);
+    assert_eq!(result.result_message, "cannot freeze");
+
+    call_system_sc(
+        &mut state,
+        OWNER,
+        "transferOwnership",
+        &[b"TOKEN-123456", &USER],
+    )
+    .assert_ok();
+    call_system_sc(&mut state, USER, "unPause", &[b"TOKEN-123456"]).assert_ok();
+    assert!(!state.is_dcdt_token_paused(b"TOKEN-123456"));
+}
+
+/// Tokens only set up in the state, e.g. via a scenario `setState`.
+fn set_up_unregistered_token(state: &mut BlockchainState) {
+    let user = state.accounts.get_mut(&VMAddress::from(USER)).unwrap();
+    user.dcdt.set_dcdt_balance(
+        b"OTHER-123456".to_vec(),
+        0,
+        &BigUint::from(100u32),
+        DcdtInstanceMetadata::default(),
+    );
+    user.dcdt
+        .set_special_role(b"OTHER-123456", b"DCDTRoleLocalMint");
+}
+
+#[test]
+fn test_system_sc_unregistered_token() {
+    let mut state = new_state();
+    set_up_unregistered_token(&mut state);
+
+    // failed calls do not register the token
+    let result = call_system_sc(
+        &mut state,
+        USER,
+        "controlChanges",
+        &[b"OTHER-123456", b"canFly", b"true"],
+    );
+    assert_eq!(result.result_message, "invalid argument");
+    assert!(state.get_dcdt_token_data(b"OTHER-123456").is_none());
+
+    // the first caller becomes the owner
+    call_system_sc(&mut state, OWNER, "freeze", &[b"OTHER-123456", &USER]).assert_ok();
+    let result = call_system_sc(&mut state, USER, "pause", &[b"OTHER-123456"]);
+    assert_eq!(result.result_message, "can be called by owner only");
+    let result = call_system_sc(
+        &mut state,
+        USER,
+        "unSetSpecialRole",
+        &[b"OTHER-123456", &USER, b"DCDTRoleLocalMint"],
+    );
+    assert_eq!(result.result_message, "can be called by owner only");
+
+    call_system_sc(&mut state, OWNER, "wipe", &[b"OTHER-123456", &USER]).assert_ok();
+    call_system_sc(&mut state, OWNER, "pause", &[b"OTHER-123456"]).assert_ok();
+    let result = call_system_sc(&mut state, OWNER, "getTokenProperties", &[b"OTHER-123456"]);
+    result.assert_ok();
+    assert_eq!(result.result_values[0], b"OTHER".to_vec());
+    assert_eq!(result.result_values[1], b"FungibleDCDT".to_vec());
+    assert_eq!(result.result_values[2], OWNER.to_vec());
+    assert_eq!(result.result_values[4], b"100".to_vec());
+    assert_eq!(result.result_values[12], b"CanFreeze-true".to_vec());
+}
+
+#[test]
+fn test_system_sc_unregistered_nft_type() {
+    let mut state = new_state();
+    let user = state.accounts.get_mut(&VMAddress::from(USER)).unwrap();
+    user.dcdt.set_dcdt_balance(
+        b"NFT-123456".to_vec(),
+        1,
+        &BigUint::from(1u32),
+        DcdtInstanceMetadata::default(),
+    );
+
+    let result = call_system_sc(&mut state, OWNER, "mint", &[b"NFT-123456", &[10]]);
+    assert_eq!(
+        result.result_message,
+        "mint is possible only for fungible tokens"
+    );
+    call_system_sc(
+        &mut state,
+        OWNER,
+        "freezeSingleNFT",
+        &[b"NFT-123456", &[1], &USER],
+    )
+    .assert_ok();
+    assert_eq!(
+        state
+            .get_dcdt_token_data(b"NFT-123456")
+            .unwrap()
+            .token_type_name(),
+        "NonFungibleDCDT"
+    );
+}
+
+#[test]
+fn test_system_sc_roles_of_new_accounts() {
+    let mut state = new_state();
+    set_up_unregistered_token(&mut state);
+
+    // an account created earlier in the same transaction, not yet in the state
+    let parent_cache = TxCache::new(Arc::new(state.clone()));
+    let mut new_account = AccountData::new_empty(VMAddress::from(NEW_ACCOUNT));
+    new_account
+        .dcdt
+        .set_special_role(b"OTHER-123456", b"DCDTRoleLocalBurn");
+    parent_cache.insert_account(new_account);
+
+    let tx_input = TxInput {
+        from: VMAddress::from(OWNER),
+        to: VMAddress::from(DCDT_SYSTEM_SC_ADDRESS_ARRAY),
+        func_name: TxFunctionName::from("getAllAddressesAndRoles"),
+        args: vec![b"OTHER-123456".to_vec()],
+        ..Default::default()
+    };
+    let (result, _) = execute_system_sc(tx_input, TxCache::new(Arc::new(parent_cache)));
+    result.assert_ok();
+    assert_eq!(
+        result.result_values,
+        vec![
+            USER.to_vec(),
+            b"DCDTRoleLocalMint".to_vec(),
+            NEW_ACCOUNT.to_vec(),
+            b"DCDTRoleLocalBurn".to_vec(),
+        ]
+    );
+}
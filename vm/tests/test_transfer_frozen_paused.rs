@@ -0,0 +1,206 @@
+use std::sync::Arc;
+
+use dharitri_chain_vm::{
+    tx_execution::{execute_system_sc, BlockchainVMRef, DCDT_SYSTEM_SC_ADDRESS_ARRAY},
+    tx_mock::{TxCache, TxFunctionName, TxInput, TxResult},
+    types::VMAddress,
+    world_mock::{AccountData, BlockchainState, DcdtInstanceMetadata, FailingExecutor},
+};
+use num_bigint::BigUint;
+
+const OWNER: [u8; 32] = [1u8; 32];
+const USER: [u8; 32] = [2u8; 32];
+const TOKEN: &[u8] = b"TOKEN-123456";
+const NFT: &[u8] = b"NFT-123456";
+
+fn new_state() -> BlockchainState {
+    let mut state = BlockchainState::default();
+    for address in [OWNER, USER] {
+        let address = VMAddress::from(address);
+        let mut account = AccountData::new_empty(address.clone());
+        account.dcdt.set_dcdt_balance(
+            TOKEN.to_vec(),
+            0,
+            &BigUint::from(100u32),
+            DcdtInstanceMetadata::default(),
+        );
+        for nonce in [1, 2] {
+            account.dcdt.set_dcdt_balance(
+                NFT.to_vec(),
+                nonce,
+                &BigUint::from(1u32),
+                DcdtInstanceMetadata::default(),
+            );
+        }
+        state.accounts.insert(address, account);
+    }
+    state.new_token_identifiers = vec!["TOKEN-123456".to_string()];
+    state
+}
+
+fn execute(
+    state: &mut BlockchainState,
+    from: [u8; 32],
+    to: [u8; 32],
+    func: &str,
+    args: &[&[u8]],
+) -> TxResult {
+    let tx_input = TxInput {
+        from: VMAddress::from(from),
+        to: VMAddress::from(to),
+        func_name: TxFunctionName::from(func),
+        args: args.iter().map(|arg| arg.to_vec()).collect(),
+        ..Default::default()
+    };
+    let tx_cache = TxCache::new(Arc::new(state.clone()));
+    let (tx_result, blockchain_update) = if to == DCDT_SYSTEM_SC_ADDRESS_ARRAY {
+        execute_system_sc(tx_input, tx_cache)
+    } else {
+        let vm = BlockchainVMRef::new(Box::new(FailingExecutor));
+        vm.execute_builtin_function_or_default(tx_input, tx_cache, || {})
+    };
+    if tx_result.result_status == 0 {
+        state.commit_updates(blockchain_update);
+    }
+    tx_result
+}
+
+fn issue_token(state: &mut BlockchainState) {
+    execute(
+        state,
+        OWNER,
+        DCDT_SYSTEM_SC_ADDRESS_ARRAY,
+        "issue",
+        &[
+            b"Token",
+            b"TOKEN",
+            &[100],
+            &[],
+            b"canFreeze",
+            b"true",
+            b"canPause",
+            b"true",
+        ],
+    )
+    .assert_ok();
+}
+
+fn balance(state: &BlockchainState, address: [u8; 32], token: &[u8], nonce: u64) -> BigUint {
+    state.accounts[&VMAddress::from(address)]
+        .dcdt
+        .get_dcdt_balance(token, nonce)
+}
+
+#[test]
+fn test_dcdt_transfer_frozen() {
+    let mut state = new_state();
+    issue_token(&mut state);
+
+    execute(
+        &mut state,
+        OWNER,
+        DCDT_SYSTEM_SC_ADDRESS_ARRAY,
+        "freeze",
+        &[TOKEN, &USER],
+    )
+    .assert_ok();
+
+    // frozen sender
+    let result = execute(&mut state, USER, OWNER, "DCDTTransfer", &[TOKEN, &[10]]);
+    assert_eq!(
+        result.result_message,
+        "account is frozen for this dcdt token"
+    );
+
+    // frozen receiver
+    let result = execute(&mut state, OWNER, USER, "DCDTTransfer", &[TOKEN, &[10]]);
+    assert_eq!(
+        result.result_message,
+        "account is frozen for this dcdt token"
+    );
+    assert_eq!(balance(&state, USER, TOKEN, 0), BigUint::from(100u32));
+
+    execute(
+        &mut state,
+        OWNER,
+        DCDT_SYSTEM_SC_ADDRESS_ARRAY,
+        "unFreeze",
+        &[TOKEN, &USER],
+    )
+    .assert_ok();
+    execute(&mut state, USER, OWNER, "DCDTTransfer", &[TOKEN, &[10]]).assert_ok();
+    assert_eq!(balance(&state, USER, TOKEN, 0), BigUint::from(90u32));
+}
+
+#[test]
+fn test_dcdt_transfer_paused() {
+    let mut state = new_state();
+    issue_token(&mut state);
+
+    execute(
+        &mut state,
+        OWNER,
+        DCDT_SYSTEM_SC_ADDRESS_ARRAY,
+        "pause",
+        &[TOKEN],
+    )
+    .assert_ok();
+    let result = execute(&mut state, USER, OWNER, "DCDTTransfer", &[TOKEN, &[10]]);
+    assert_eq!(result.result_message, "dcdt token is paused");
+
+    execute(
+        &mut state,
+        OWNER,
+        DCDT_SYSTEM_SC_ADDRESS_ARRAY,
+        "unPause",
+        &[TOKEN],
+    )
+    .assert_ok();
+    execute(&mut state, USER, OWNER, "DCDTTransfer", &[TOKEN, &[10]]).assert_ok();
+}
+
+#[test]
+fn test_nft_transfer_single_nonce_frozen() {
+    let mut state = new_state();
+    state
+        .accounts
+        .get_mut(&VMAddress::from(USER))
+        .unwrap()
+        .dcdt
+        .set_instance_frozen(NFT, 1, true);
+
+    let result = execute(
+        &mut state,
+        USER,
+        USER,
+        "DCDTNFTTransfer",
+        &[NFT, &[1], &[1], &OWNER],
+    );
+    assert_eq!(
+        result.result_message,
+        "account is frozen for this dcdt token"
+    );
+
+    // other nonces are not affected
+    execute(
+        &mut state,
+        USER,
+        USER,
+        "DCDTNFTTransfer",
+        &[NFT, &[2], &[1], &OWNER],
+    )
+    .assert_ok();
+
+    let result = execute(
+        &mut state,
+        OWNER,
+        OWNER,
+        "MultiDCDTNFTTransfer",
+        &[&USER, &[2], NFT, &[2], &[1], NFT, &[1], &[1]],
+    );
+    assert_eq!(
+        result.result_message,
+        "account is frozen for this dcdt token"
+    );
+    assert_eq!(balance(&state, OWNER, NFT, 2), BigUint::from(2u32));
+}